@@ -0,0 +1,251 @@
+use crate::categories::{ActionCategory, SUPPRESSION_ACTION_CATEGORY};
+use crate::signals::{AnalyzerActionIter, AnalyzerTransformationIter};
+use crate::{
+    AnalyzerAction, AnalyzerDiagnostic, AnalyzerSignal, LanguageRoot, RuleKey, SignalEntry,
+    SuppressionAction, SuppressionCommentEmitterPayload, Visitor, VisitorContext,
+};
+use biome_console::markup;
+use biome_diagnostics::{Applicability, Diagnostic, Error, Severity};
+use biome_rowan::{
+    AstNode, BatchMutation, Language, NodeOrToken, SendNode, SyntaxElement, SyntaxNode, TextRange,
+    WalkEvent,
+};
+use std::borrow::Cow;
+use std::fmt::Debug;
+use std::marker::PhantomData;
+use std::path::Path;
+use std::sync::Arc;
+
+/// The group that the diagnostics of the plugins belong to, e.g. `lint/plugin(myPlugin)`
+pub const PLUGIN_GROUP: &str = "plugin";
+
+/// A lint rule that isn't built into the analyzer, but loaded from the configuration
+pub trait AnalyzerPlugin: Debug + Send + Sync {
+    /// The name of the plugin, used to suppress its diagnostics
+    fn name(&self) -> &str;
+
+    /// The severity configured for the diagnostics of the plugin, which replaces the severity
+    /// chosen by the plugin
+    fn severity(&self) -> Option<Severity> {
+        None
+    }
+
+    /// Runs the plugin against the root of a file
+    fn evaluate(&self, root: SendNode, path: &Path) -> PluginEvaluation;
+}
+
+/// The result of running an [AnalyzerPlugin] against a file
+#[derive(Debug, Default)]
+pub struct PluginEvaluation {
+    /// The diagnostics registered by the plugin
+    pub diagnostics: Vec<PluginDiagnostic>,
+    /// The root of the file, rewritten by the plugin
+    pub rewritten: Option<SendNode>,
+}
+
+/// A diagnostic registered by an [AnalyzerPlugin]
+#[derive(Clone, Debug, Diagnostic)]
+#[diagnostic(category = "lint/plugin")]
+pub struct PluginDiagnostic {
+    #[location(span)]
+    pub span: TextRange,
+    #[message]
+    #[description]
+    pub message: String,
+    #[severity]
+    pub severity: Severity,
+}
+
+/// Runs an [AnalyzerPlugin] when the traversal enters the root of the file,
+/// and emits a signal for each of its diagnostics
+pub struct PluginVisitor<L: Language> {
+    plugin: Arc<dyn AnalyzerPlugin>,
+    _language: PhantomData<L>,
+}
+
+impl<L: Language> PluginVisitor<L> {
+    pub fn new(plugin: Arc<dyn AnalyzerPlugin>) -> Self {
+        Self {
+            plugin,
+            _language: PhantomData,
+        }
+    }
+}
+
+impl<L: Language + 'static> Visitor for PluginVisitor<L> {
+    type Language = L;
+
+    fn visit(&mut self, event: &WalkEvent<SyntaxNode<Self::Language>>, ctx: VisitorContext<L>) {
+        let WalkEvent::Enter(node) = event else {
+            return;
+        };
+
+        // `as_send` only succeeds on the root of the file
+        let Some(root) = node.as_send() else {
+            return;
+        };
+
+        let evaluation = self.plugin.evaluate(root, &ctx.options.file_path);
+        let rewritten = evaluation
+            .rewritten
+            .and_then(|root| root.into_node::<L>())
+            .filter(|root| root.text() != node.text());
+
+        for diagnostic in evaluation.diagnostics {
+            if let Some(range) = ctx.range {
+                if diagnostic.span.ordering(range).is_ne() {
+                    continue;
+                }
+            }
+
+            let text_range = diagnostic.span;
+            let signal = PluginSignal {
+                plugin_name: self.plugin.name().to_string(),
+                diagnostic,
+                root: ctx.root,
+                rewritten: rewritten.clone(),
+                suppression_action: ctx.suppression_action,
+                suppression_reason: ctx.options.suppression_reason.as_deref(),
+            };
+
+            ctx.signal_queue.push(SignalEntry {
+                signal: Box::new(signal),
+                rule: RuleKey::new(PLUGIN_GROUP, ""),
+                instances: Box::new([self.plugin.name().into()]),
+                text_range,
+            });
+        }
+    }
+}
+
+/// Returns the smallest elements of `prev` that must be replaced, or removed, to get `next`.
+///
+/// The trees are compared slot by slot: when a single child node differs, the comparison
+/// continues inside it, so that the rewrite only touches the nodes changed by the plugin.
+fn changed_elements<L: Language>(
+    prev: &SyntaxNode<L>,
+    next: &SyntaxNode<L>,
+    is_root: bool,
+) -> Vec<(SyntaxElement<L>, Option<SyntaxElement<L>>)> {
+    let replace_node = || vec![(prev.clone().into(), Some(next.clone().into()))];
+    let is_same_shape = prev.kind() == next.kind() && prev.slots().count() == next.slots().count();
+    if !is_same_shape && !is_root {
+        return replace_node();
+    }
+
+    let mut changes = Vec::new();
+    for (prev_slot, next_slot) in prev.slots().zip(next.slots()) {
+        match (
+            prev_slot.into_syntax_element(),
+            next_slot.into_syntax_element(),
+        ) {
+            (Some(prev), Some(next)) if prev.to_string() != next.to_string() => {
+                changes.push((prev, Some(next)));
+            }
+            (Some(prev), None) => changes.push((prev, None)),
+            // A slot that is only filled by the rewrite can't be replaced on its own
+            (None, Some(_)) if !is_root => return replace_node(),
+            _ => {}
+        }
+    }
+
+    if let [(NodeOrToken::Node(prev), Some(NodeOrToken::Node(next)))] = changes.as_slice() {
+        return changed_elements(prev, next, false);
+    }
+    changes
+}
+
+/// Implementation of [AnalyzerSignal] for the diagnostics of an [AnalyzerPlugin]
+struct PluginSignal<'phase, L: Language> {
+    plugin_name: String,
+    diagnostic: PluginDiagnostic,
+    root: &'phase LanguageRoot<L>,
+    rewritten: Option<SyntaxNode<L>>,
+    suppression_action: &'phase dyn SuppressionAction<Language = L>,
+    suppression_reason: Option<&'phase str>,
+}
+
+impl<'phase, L: Language> PluginSignal<'phase, L> {
+    /// Creates the action that applies the rewrite of the plugin to the range of the diagnostic.
+    ///
+    /// The changes outside the range belong to the other diagnostics of the plugin, so they
+    /// aren't part of the action.
+    fn rewrite_action(&self) -> Option<AnalyzerAction<L>> {
+        let rewritten = self.rewritten.as_ref()?;
+        let root = self.root.syntax();
+        let span = self.diagnostic.span;
+        let mut mutation = BatchMutation::new(root.clone());
+        let mut has_changes = false;
+        for (prev, next) in changed_elements(root, rewritten, true) {
+            let range = prev.text_trimmed_range();
+            if range.start() >= span.end() || span.start() >= range.end() {
+                continue;
+            }
+            has_changes = true;
+            match next {
+                Some(next) => mutation.replace_element_discard_trivia(prev, next),
+                None => mutation.remove_element(prev),
+            }
+        }
+        if !has_changes {
+            return None;
+        }
+
+        Some(AnalyzerAction {
+            rule_name: None,
+            category: ActionCategory::QuickFix(Cow::Borrowed("")),
+            applicability: Applicability::MaybeIncorrect,
+            message: markup! { "Apply the rewrite of the plugin "{self.plugin_name}"." }.to_owned(),
+            mutation,
+        })
+    }
+
+    /// Creates the action that suppresses the diagnostic with a comment
+    fn suppression_action(&self) -> Option<AnalyzerAction<L>> {
+        let rule_category = format!("lint/{PLUGIN_GROUP}({})", self.plugin_name);
+        let suppression_text = format!("biome-ignore {rule_category}");
+        let root = self.root.syntax();
+        let token = root.token_at_offset(self.diagnostic.span.start());
+        let mut mutation = BatchMutation::new(root.clone());
+        let is_applied =
+            self.suppression_action
+                .apply_suppression_comment(SuppressionCommentEmitterPayload {
+                    suppression_text: suppression_text.as_str(),
+                    mutation: &mut mutation,
+                    token_offset: token,
+                    diagnostic_text_range: &self.diagnostic.span,
+                    suppression_reason: self.suppression_reason.unwrap_or("<explanation>"),
+                });
+        if !is_applied {
+            return None;
+        }
+
+        Some(AnalyzerAction {
+            rule_name: None,
+            category: ActionCategory::Other(Cow::Borrowed(SUPPRESSION_ACTION_CATEGORY)),
+            applicability: Applicability::Always,
+            message: markup! { "Suppress rule "{rule_category} }.to_owned(),
+            mutation,
+        })
+    }
+}
+
+impl<'phase, L: Language> AnalyzerSignal<L> for PluginSignal<'phase, L> {
+    fn diagnostic(&self) -> Option<AnalyzerDiagnostic> {
+        let error = Error::from(self.diagnostic.clone());
+        Some(AnalyzerDiagnostic::from_plugin(&self.plugin_name, error))
+    }
+
+    fn actions(&self) -> AnalyzerActionIter<L> {
+        let actions: Vec<_> = self
+            .rewrite_action()
+            .into_iter()
+            .chain(self.suppression_action())
+            .collect();
+        AnalyzerActionIter::new(actions)
+    }
+
+    fn transformations(&self) -> AnalyzerTransformationIter<L> {
+        AnalyzerTransformationIter::new(vec![])
+    }
+}
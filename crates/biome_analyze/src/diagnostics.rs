@@ -21,6 +21,8 @@ pub struct AnalyzerDiagnostic {
     kind: DiagnosticKind,
     /// Series of code suggestions offered by rule code actions
     code_suggestion_list: Vec<CodeSuggestionAdvice<MarkupBuf>>,
+    /// The name of the plugin that emitted the diagnostic
+    plugin_name: Option<String>,
 }
 
 impl From<RuleDiagnostic> for AnalyzerDiagnostic {
@@ -28,6 +30,7 @@ impl From<RuleDiagnostic> for AnalyzerDiagnostic {
         Self {
             kind: DiagnosticKind::Rule(rule_diagnostic),
             code_suggestion_list: vec![],
+            plugin_name: None,
         }
     }
 }
@@ -107,9 +110,23 @@ impl AnalyzerDiagnostic {
         Self {
             kind: DiagnosticKind::Raw(error),
             code_suggestion_list: vec![],
+            plugin_name: None,
         }
     }
 
+    /// Creates a diagnostic emitted by the plugin with the given name
+    pub fn from_plugin(plugin_name: &str, error: Error) -> Self {
+        Self {
+            plugin_name: Some(plugin_name.to_string()),
+            ..Self::from_error(error)
+        }
+    }
+
+    /// The name of the plugin that emitted the diagnostic, if any
+    pub fn plugin_name(&self) -> Option<&str> {
+        self.plugin_name.as_deref()
+    }
+
     pub fn get_span(&self) -> Option<TextRange> {
         match &self.kind {
             DiagnosticKind::Rule(rule_diagnostic) => rule_diagnostic.span,
@@ -7,11 +7,13 @@ use std::fmt::{Debug, Display, Formatter};
 use std::ops;
 use tracing::trace;
 
+mod analyzer_plugin;
 mod categories;
 pub mod context;
 mod diagnostics;
 mod matcher;
 pub mod options;
+mod profiling;
 mod query;
 mod registry;
 mod rule;
@@ -24,13 +26,19 @@ mod visitor;
 // Re-exported for use in the `declare_group` macro
 pub use biome_diagnostics::category_concat;
 
+pub use crate::analyzer_plugin::{
+    AnalyzerPlugin, PluginDiagnostic, PluginEvaluation, PluginVisitor, PLUGIN_GROUP,
+};
 pub use crate::categories::{
     ActionCategory, RefactorKind, RuleCategories, RuleCategoriesBuilder, RuleCategory,
     SourceActionKind, SUPPRESSION_ACTION_CATEGORY,
 };
 pub use crate::diagnostics::{AnalyzerDiagnostic, RuleError, SuppressionDiagnostic};
 pub use crate::matcher::{InspectMatcher, MatchQueryParams, QueryMatcher, RuleKey, SignalEntry};
-pub use crate::options::{AnalyzerConfiguration, AnalyzerOptions, AnalyzerRules};
+pub use crate::options::{
+    AnalyzerConfiguration, AnalyzerOptions, AnalyzerRules, SuppressionPolicy,
+};
+pub use crate::profiling::{RulePhase, RuleProfiler};
 pub use crate::query::{AddVisitor, QueryKey, QueryMatch, Queryable};
 pub use crate::registry::{
     LanguageRoot, MetadataRegistry, Phase, Phases, RegistryRuleMetadata, RegistryVisitor,
@@ -38,7 +46,7 @@ pub use crate::registry::{
 };
 pub use crate::rule::{
     CategoryLanguage, FixKind, GroupCategory, GroupLanguage, Rule, RuleAction, RuleDiagnostic,
-    RuleGroup, RuleMeta, RuleMetadata, RuleSource, RuleSourceKind, SuppressAction,
+    RuleDomain, RuleGroup, RuleMeta, RuleMetadata, RuleSource, RuleSourceKind, SuppressAction,
 };
 pub use crate::services::{FromServices, MissingServicesDiagnostic, ServiceBag};
 pub use crate::signals::{
@@ -131,6 +139,7 @@ where
 
         let mut line_index = 0;
         let mut line_suppressions = Vec::new();
+        let mut range_suppressions = Vec::new();
 
         for (index, (phase, mut visitors)) in phases.into_iter().enumerate() {
             let runner = PhaseRunner {
@@ -142,6 +151,8 @@ where
                 parse_suppression_comment,
                 line_index: &mut line_index,
                 line_suppressions: &mut line_suppressions,
+                range_suppressions: &mut range_suppressions,
+                suppression_count: 0,
                 emit_signal: &mut emit_signal,
                 root: &ctx.root,
                 services: &ctx.services,
@@ -192,6 +203,28 @@ where
             }
         }
 
+        for suppression in range_suppressions {
+            let message = if !suppression.is_closed {
+                "Suppression comment has no matching end comment. Add an end comment suppressing the same rules, or remove the suppression."
+            } else if !suppression.did_suppress_signal {
+                "Suppression comment has no effect. Remove the suppression or make sure you are suppressing the correct rule."
+            } else {
+                continue;
+            };
+
+            let signal = DiagnosticSignal::new(|| {
+                SuppressionDiagnostic::new(
+                    category!("suppressions/unused"),
+                    suppression.comment_span,
+                    message,
+                )
+            });
+
+            if let ControlFlow::Break(br) = (emit_signal)(&signal) {
+                return Some(br);
+            }
+        }
+
         None
     }
 }
@@ -216,6 +249,11 @@ struct PhaseRunner<'analyzer, 'phase, L: Language, Matcher, Break, Diag> {
     line_index: &'phase mut usize,
     /// Track active suppression comments per-line, ordered by line index
     line_suppressions: &'phase mut Vec<LineSuppression>,
+    /// Track the top-level and range suppression comments, ordered by position
+    range_suppressions: &'phase mut Vec<RangeSuppression>,
+    /// Number of suppression comments found in the file, checked against the
+    /// suppression policy
+    suppression_count: usize,
     /// Handles analyzer signals emitted by individual rules
     emit_signal: &'phase mut SignalHandler<'analyzer, L, Break>,
     /// Root node of the file being analyzed
@@ -237,19 +275,101 @@ struct LineSuppression {
     comment_span: TextRange,
     /// Range of source text this comment is suppressing lint rules for
     text_range: TextRange,
-    /// Set to true if this comment has set the `suppress_all` flag to true
-    /// (must be restored to false on expiration)
-    suppress_all: bool,
-    /// List of all the rules this comment has started suppressing (must be
-    /// removed from the suppressed set on expiration)
-    suppressed_rules: Vec<RuleFilter<'static>>,
-    /// List of all the rule instances this comment has started suppressing.
-    suppressed_instances: Vec<(RuleFilter<'static>, String)>,
+    /// The rules this comment is suppressing
+    suppressed: SuppressedRules,
     /// Set to `true` when a signal matching this suppression was emitted and
     /// suppressed
     did_suppress_signal: bool,
 }
 
+/// Single entry for a `biome-ignore-all` comment, or a `biome-ignore-start`
+/// comment and its `biome-ignore-end` comment, in the `range_suppressions` buffer
+#[derive(Debug)]
+struct RangeSuppression {
+    /// Range of source text covered by the suppression comment
+    comment_span: TextRange,
+    /// Range of source text this comment is suppressing lint rules for: the
+    /// whole file for a top-level suppression, otherwise the text between the
+    /// start and the end comments, or until the end of the file while the end
+    /// comment isn't found
+    text_range: TextRange,
+    /// Whether the end comment was found. Top-level suppressions don't need one.
+    is_closed: bool,
+    /// The rules this comment is suppressing
+    suppressed: SuppressedRules,
+    /// Set to `true` when a signal matching this suppression was emitted and
+    /// suppressed
+    did_suppress_signal: bool,
+}
+
+/// The rules disabled by a suppression comment
+#[derive(Debug, Default)]
+struct SuppressedRules {
+    /// Set to true if the comment suppresses all the lint rules
+    suppress_all: bool,
+    /// List of all the rules this comment is suppressing
+    rules: Vec<RuleFilter<'static>>,
+    /// List of all the rule instances this comment is suppressing
+    instances: Vec<(RuleFilter<'static>, String)>,
+}
+
+impl SuppressedRules {
+    fn is_empty(&self) -> bool {
+        !self.suppress_all && self.rules.is_empty() && self.instances.is_empty()
+    }
+
+    fn suppress_all(&mut self) {
+        self.suppress_all = true;
+        self.rules.clear();
+        self.instances.clear();
+    }
+
+    fn extend(&mut self, other: Self) {
+        if other.suppress_all {
+            self.suppress_all();
+        } else if !self.suppress_all {
+            self.rules.extend(other.rules);
+            self.instances.extend(other.instances);
+        }
+    }
+
+    /// Whether the two comments suppress the same rules, regardless of their order
+    fn has_same_rules(&self, other: &Self) -> bool {
+        self.suppress_all == other.suppress_all
+            && self.rules.iter().all(|rule| other.rules.contains(rule))
+            && other.rules.iter().all(|rule| self.rules.contains(rule))
+            && self
+                .instances
+                .iter()
+                .all(|instance| other.instances.contains(instance))
+            && other
+                .instances
+                .iter()
+                .all(|instance| self.instances.contains(instance))
+    }
+
+    /// Whether the signal of the given entry is suppressed by these rules
+    fn suppresses<L: Language>(&self, entry: &SignalEntry<L>) -> bool {
+        if self.suppress_all {
+            return true;
+        }
+
+        if self.rules.iter().any(|filter| *filter == entry.rule) {
+            return true;
+        }
+
+        if entry.instances.is_empty() {
+            return false;
+        }
+
+        entry.instances.iter().all(|value| {
+            self.instances
+                .iter()
+                .any(|(filter, v)| *filter == entry.rule && v == value.as_ref())
+        })
+    }
+}
+
 impl<'a, 'phase, L, Matcher, Break, Diag> PhaseRunner<'a, 'phase, L, Matcher, Break, Diag>
 where
     L: Language,
@@ -382,12 +502,20 @@ where
             // if it matches the current line index, otherwise perform a binary
             // search over all the previously seen suppressions to find one
             // with a matching range
+            // The signals of the rules that can't be suppressed are always emitted
+            let is_forbidden = self
+                .options
+                .configuration
+                .suppression_policy
+                .is_forbidden(entry.rule.group(), Some(entry.rule.rule_name()));
+
             let suppression = self.line_suppressions.last_mut().filter(|suppression| {
                 suppression.line_index == *self.line_index
                     && suppression.text_range.start() <= start
             });
 
             let suppression = match suppression {
+                _ if is_forbidden => None,
                 Some(suppression) => Some(suppression),
                 None => {
                     let index = self.line_suppressions.binary_search_by(|suppression| {
@@ -404,35 +532,26 @@ where
                 }
             };
 
-            let suppression = suppression.filter(|suppression| {
-                if suppression.suppress_all {
-                    return true;
-                }
-
-                if suppression
-                    .suppressed_rules
-                    .iter()
-                    .any(|filter| *filter == entry.rule)
-                {
-                    return true;
-                }
-
-                if entry.instances.is_empty() {
-                    return false;
-                }
+            let suppression =
+                suppression.filter(|suppression| suppression.suppressed.suppresses(entry));
 
-                entry.instances.iter().all(|value| {
-                    suppression
-                        .suppressed_instances
-                        .iter()
-                        .any(|(filter, v)| *filter == entry.rule && v == value.as_ref())
+            // Otherwise search for a top-level or range suppression covering
+            // the start of this signal
+            let range_suppression = if suppression.is_none() && !is_forbidden {
+                self.range_suppressions.iter_mut().find(|suppression| {
+                    suppression.text_range.contains(start)
+                        && suppression.suppressed.suppresses(entry)
                 })
-            });
+            } else {
+                None
+            };
 
-            // If the signal is being suppressed mark the line suppression as
-            // hit, otherwise emit the signal
+            // If the signal is being suppressed mark the suppression as hit,
+            // otherwise emit the signal
             if let Some(suppression) = suppression {
                 suppression.did_suppress_signal = true;
+            } else if let Some(suppression) = range_suppression {
+                suppression.did_suppress_signal = true;
             } else if range_match(self.range, entry.text_range) {
                 (self.emit_signal)(&*entry.signal)?;
             }
@@ -454,14 +573,22 @@ where
         text: &str,
         range: TextRange,
     ) -> ControlFlow<Break> {
-        let mut suppress_all = false;
-        let mut suppressed_rules = Vec::new();
-        let mut suppressed_instances = Vec::new();
+        let mut line_suppressed = SuppressedRules::default();
+        let mut top_level_suppressed = SuppressedRules::default();
+        let mut range_start_suppressed = SuppressedRules::default();
+        let mut range_end_suppressed = SuppressedRules::default();
         let mut has_legacy = false;
+        let mut has_reason_diagnostic = false;
+        let options = self.options;
+        let policy = &options.configuration.suppression_policy;
 
         for result in (self.parse_suppression_comment)(text) {
-            let kind = match result {
-                Ok(kind) => kind,
+            let AnalyzerSuppression {
+                kind,
+                variant,
+                reason,
+            } = match result {
+                Ok(suppression) => suppression,
                 Err(diag) => {
                     // Emit the suppression parser diagnostic
                     let signal = DiagnosticSignal::new(move || {
@@ -490,6 +617,42 @@ where
                 (self.emit_signal)(&signal)?;
             }
 
+            // The reason of an end comment is only there to match its start comment
+            let should_check_reason = !has_reason_diagnostic
+                && variant != AnalyzerSuppressionVariant::RangeEnd
+                && !matches!(kind, SuppressionKind::Deprecated)
+                && range_match(self.range, range);
+
+            if should_check_reason
+                && policy.require_reason
+                && SuppressionPolicy::is_placeholder_reason(reason)
+            {
+                has_reason_diagnostic = true;
+                let signal = DiagnosticSignal::new(move || {
+                    SuppressionDiagnostic::new(
+                        category!("suppressions/missingReason"),
+                        range,
+                        "Suppression comment is missing a reason. Explain why the rule is suppressed after the colon.",
+                    )
+                    .with_severity(Severity::Error)
+                });
+
+                (self.emit_signal)(&signal)?;
+            }
+
+            let suppressed = match variant {
+                AnalyzerSuppressionVariant::Line => &mut line_suppressed,
+                AnalyzerSuppressionVariant::TopLevel => &mut top_level_suppressed,
+                AnalyzerSuppressionVariant::RangeStart => &mut range_start_suppressed,
+                AnalyzerSuppressionVariant::RangeEnd => &mut range_end_suppressed,
+            };
+
+            // If this comment already suppresses all lints, no need to
+            // parse anything else
+            if suppressed.suppress_all {
+                continue;
+            }
+
             let (rule, instance) = match kind {
                 SuppressionKind::Everything => (None, None),
                 SuppressionKind::Rule(rule) => (Some(rule), None),
@@ -502,16 +665,51 @@ where
                 let group_rule = rule.split_once('/');
 
                 let key = match group_rule {
+                    None if rule == PLUGIN_GROUP => Some(RuleFilter::Group(PLUGIN_GROUP)),
                     None => self.metadata.find_group(rule).map(RuleFilter::from),
                     Some((group, rule)) => {
                         self.metadata.find_rule(group, rule).map(RuleFilter::from)
                     }
                 };
 
+                if let Some(key) = key {
+                    let (group, rule) = match key {
+                        RuleFilter::Group(group) => (group, None),
+                        RuleFilter::Rule(group, rule) => (group, Some(rule)),
+                    };
+
+                    if policy.is_forbidden(group, rule) {
+                        if range_match(self.range, range) {
+                            let name = rule.map_or_else(
+                                || group.to_string(),
+                                |rule| format!("{group}/{rule}"),
+                            );
+                            let signal = DiagnosticSignal::new(move || {
+                                SuppressionDiagnostic::new(
+                                    category!("suppressions/forbidden"),
+                                    range,
+                                    format_args!("The suppression of lint/{name} is forbidden by the configuration. Fix the code instead."),
+                                )
+                                .with_severity(Severity::Error)
+                            });
+
+                            (self.emit_signal)(&signal)?;
+                        }
+                        continue;
+                    }
+
+                    if should_check_reason && !has_reason_diagnostic {
+                        has_reason_diagnostic =
+                            self.check_reason_patterns(Some(group), rule, reason, range)?;
+                    }
+                }
+
                 match (key, instance) {
-                    (Some(key), Some(value)) => suppressed_instances.push((key, value.to_owned())),
+                    (Some(key), Some(value)) => {
+                        suppressed.instances.push((key, value.to_owned()));
+                    }
                     (Some(key), None) => {
-                        suppressed_rules.push(key);
+                        suppressed.rules.push(key);
                         has_legacy |= matches!(kind, SuppressionKind::MaybeLegacy(_));
                     }
                     _ if range_match(self.range, range) => {
@@ -539,11 +737,12 @@ where
                     _ => {}
                 }
             } else {
-                suppressed_rules.clear();
-                suppress_all = true;
-                // If this if a "suppress all lints" comment, no need to
-                // parse anything else
-                break;
+                // A suppression of all the rules must follow the policy of every rule
+                if should_check_reason && !has_reason_diagnostic {
+                    has_reason_diagnostic =
+                        self.check_reason_patterns(None, None, reason, range)?;
+                }
+                suppressed.suppress_all();
             }
         }
 
@@ -564,7 +763,48 @@ where
             (self.emit_signal)(&signal)?;
         }
 
-        if !suppress_all && suppressed_rules.is_empty() && suppressed_instances.is_empty() {
+        let is_suppression = !line_suppressed.is_empty()
+            || !top_level_suppressed.is_empty()
+            || !range_start_suppressed.is_empty();
+        if is_suppression {
+            self.suppression_count += 1;
+            // The count covers the whole file, so the diagnostic is emitted even when the
+            // comment is outside of the analyzed range
+            if let Some(max_per_file) = policy.max_per_file {
+                if self.suppression_count == max_per_file + 1 {
+                    let signal = DiagnosticSignal::new(move || {
+                        SuppressionDiagnostic::new(
+                            category!("suppressions/tooMany"),
+                            range,
+                            format_args!("This file has more than {max_per_file} suppression comments, which is the maximum allowed by the configuration."),
+                        )
+                        .with_severity(Severity::Error)
+                    });
+
+                    (self.emit_signal)(&signal)?;
+                }
+            }
+        }
+
+        if !top_level_suppressed.is_empty() {
+            self.push_top_level_suppression(token, is_leading, range, top_level_suppressed)?;
+        }
+
+        if !range_start_suppressed.is_empty() {
+            self.range_suppressions.push(RangeSuppression {
+                comment_span: range,
+                text_range: TextRange::new(range.end(), self.root.syntax().text_range().end()),
+                is_closed: false,
+                suppressed: range_start_suppressed,
+                did_suppress_signal: false,
+            });
+        }
+
+        if !range_end_suppressed.is_empty() {
+            self.close_range_suppression(range, range_end_suppressed)?;
+        }
+
+        if line_suppressed.is_empty() {
             return ControlFlow::Continue(());
         }
 
@@ -579,16 +819,7 @@ where
             {
                 last_suppression.line_index = line_index;
                 last_suppression.text_range = last_suppression.text_range.cover(range);
-                last_suppression.suppress_all |= suppress_all;
-                if !last_suppression.suppress_all {
-                    last_suppression.suppressed_rules.extend(suppressed_rules);
-                    last_suppression
-                        .suppressed_instances
-                        .extend(suppressed_instances);
-                } else {
-                    last_suppression.suppressed_rules.clear();
-                    last_suppression.suppressed_instances.clear();
-                }
+                last_suppression.suppressed.extend(line_suppressed);
                 return ControlFlow::Continue(());
             }
         }
@@ -597,9 +828,7 @@ where
             line_index,
             comment_span: range,
             text_range: range,
-            suppress_all,
-            suppressed_rules,
-            suppressed_instances,
+            suppressed: line_suppressed,
             did_suppress_signal: false,
         };
 
@@ -608,6 +837,107 @@ where
         ControlFlow::Continue(())
     }
 
+    /// Emits a diagnostic when the reason of a suppression doesn't match one of the patterns
+    /// of the policy that apply to the suppressed rules. Returns whether a diagnostic was emitted.
+    fn check_reason_patterns(
+        &mut self,
+        group: Option<&str>,
+        rule: Option<&str>,
+        reason: &str,
+        range: TextRange,
+    ) -> ops::ControlFlow<Break, bool> {
+        let policy = &self.options.configuration.suppression_policy;
+        let Some((name, pattern)) = policy
+            .reason_patterns(group, rule)
+            .into_iter()
+            .find(|(_, pattern)| !pattern.is_match(reason))
+        else {
+            return ops::ControlFlow::Continue(false);
+        };
+        let name = name.to_string();
+        let pattern = pattern.as_str().to_string();
+        let signal = DiagnosticSignal::new(move || {
+            SuppressionDiagnostic::new(
+                category!("suppressions/invalidReason"),
+                range,
+                format_args!("The reason of a suppression of lint/{name} must match the pattern `{pattern}` required by the configuration."),
+            )
+            .with_severity(Severity::Error)
+        });
+
+        (self.emit_signal)(&signal)?;
+        ops::ControlFlow::Continue(true)
+    }
+
+    /// Create a suppression entry covering the whole file for a top-level
+    /// suppression comment, which must be placed before the first token of the file
+    fn push_top_level_suppression(
+        &mut self,
+        token: &SyntaxToken<L>,
+        is_leading: bool,
+        range: TextRange,
+        suppressed: SuppressedRules,
+    ) -> ControlFlow<Break> {
+        let is_top_of_file = is_leading && token.prev_token().is_none();
+        if !is_top_of_file {
+            if range_match(self.range, range) {
+                let signal = DiagnosticSignal::new(move || {
+                    SuppressionDiagnostic::new(
+                        category!("suppressions/unused"),
+                        range,
+                        "Top-level suppression comment has no effect. Move it to the top of the file, before any code.",
+                    )
+                });
+
+                (self.emit_signal)(&signal)?;
+            }
+            return ControlFlow::Continue(());
+        }
+
+        self.range_suppressions.push(RangeSuppression {
+            comment_span: range,
+            text_range: self.root.syntax().text_range(),
+            is_closed: true,
+            suppressed,
+            did_suppress_signal: false,
+        });
+
+        ControlFlow::Continue(())
+    }
+
+    /// Close the last range suppression that is still open and suppresses the
+    /// same rules as the end comment
+    fn close_range_suppression(
+        &mut self,
+        range: TextRange,
+        suppressed: SuppressedRules,
+    ) -> ControlFlow<Break> {
+        let start = self
+            .range_suppressions
+            .iter_mut()
+            .rev()
+            .find(|suppression| {
+                !suppression.is_closed && suppression.suppressed.has_same_rules(&suppressed)
+            });
+
+        if let Some(start) = start {
+            start.is_closed = true;
+            start.text_range = TextRange::new(start.text_range.start(), range.start());
+        } else if range_match(self.range, range) {
+            let signal = DiagnosticSignal::new(move || {
+                SuppressionDiagnostic::new(
+                    category!("suppressions/unused"),
+                    range,
+                    "Suppression end comment has no matching start comment. Add a start comment suppressing the same rules, or remove the suppression.",
+                )
+            });
+
+            (self.emit_signal)(&signal)?;
+        }
+
+        ControlFlow::Continue(())
+    }
+
     /// Check a piece of source text (token or trivia) for line breaks and
     /// increment the line index accordingly, extending the range of the
     /// current suppression as required
@@ -695,7 +1025,8 @@ fn range_match(filter: Option<TextRange>, range: TextRange) -> bool {
 ///
 /// This function receives the text content of a comment and returns a list of
 /// lint suppressions as an optional lint rule (if the lint rule is `None` the
-/// comment is interpreted as suppressing all lints)
+/// comment is interpreted as suppressing all lints), along with the part of the
+/// file they apply to
 ///
 /// # Examples
 ///
@@ -706,7 +1037,57 @@ fn range_match(filter: Option<TextRange>, range: TextRange) -> bool {
 /// - `// biome-ignore lint/style/useWhile lint/nursery/noUnreachable` -> `vec![Rule("style/useWhile"), Rule("nursery/noUnreachable")]`
 /// - `// biome-ignore lint(style/useWhile)` -> `vec![MaybeLegacy("style/useWhile")]`
 /// - `// biome-ignore lint(style/useWhile) lint(nursery/noUnreachable)` -> `vec![MaybeLegacy("style/useWhile"), MaybeLegacy("nursery/noUnreachable")]`
-type SuppressionParser<D> = fn(&str) -> Vec<Result<SuppressionKind, D>>;
+type SuppressionParser<D> = fn(&str) -> Vec<Result<AnalyzerSuppression, D>>;
+
+/// A single suppression parsed from a suppression comment
+pub struct AnalyzerSuppression<'a> {
+    /// What is disabled by the suppression
+    pub kind: SuppressionKind<'a>,
+    /// The part of the file the suppression applies to
+    pub variant: AnalyzerSuppressionVariant,
+    /// The reason of the suppression, written after the colon
+    pub reason: &'a str,
+}
+
+impl<'a> AnalyzerSuppression<'a> {
+    pub const fn new(kind: SuppressionKind<'a>, variant: AnalyzerSuppressionVariant) -> Self {
+        Self {
+            kind,
+            variant,
+            reason: "",
+        }
+    }
+
+    pub const fn with_reason(mut self, reason: &'a str) -> Self {
+        self.reason = reason;
+        self
+    }
+}
+
+/// The part of the file a suppression applies to
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+pub enum AnalyzerSuppressionVariant {
+    /// `// biome-ignore`: the suppression applies to the next line
+    #[default]
+    Line,
+    /// `// biome-ignore-all`: the suppression applies to the whole file
+    TopLevel,
+    /// `// biome-ignore-start`: the suppression applies until the matching end comment
+    RangeStart,
+    /// `// biome-ignore-end`: the end of a range suppression
+    RangeEnd,
+}
+
+impl From<biome_suppression::SuppressionKind> for AnalyzerSuppressionVariant {
+    fn from(kind: biome_suppression::SuppressionKind) -> Self {
+        match kind {
+            biome_suppression::SuppressionKind::Classic => Self::Line,
+            biome_suppression::SuppressionKind::All => Self::TopLevel,
+            biome_suppression::SuppressionKind::RangeStart => Self::RangeStart,
+            biome_suppression::SuppressionKind::RangeEnd => Self::RangeEnd,
+        }
+    }
+}
 
 /// This enum is used to categorize what is disabled by a suppression comment and with what syntax
 pub enum SuppressionKind<'a> {
@@ -903,6 +1284,16 @@ impl<'analysis> AnalysisFilter<'analysis> {
                 .any(|filter| matches!(filter, RuleFilter::Group(_)) && filter.match_group::<G>())
     }
 
+    /// Return `true` if the [plugins](AnalyzerPlugin) match this filter
+    pub fn match_plugins(&self) -> bool {
+        let filter = RuleFilter::Group(PLUGIN_GROUP);
+        self.categories.contains(RuleCategory::Lint)
+            && self
+                .enabled_rules
+                .map_or(true, |enabled_rules| enabled_rules.contains(&filter))
+            && !self.disabled_rules.contains(&filter)
+    }
+
     /// Return `true` if the rule `R` matches this filter
     pub fn match_rule<R: Rule>(&self) -> bool {
         self.match_category::<<R::Group as RuleGroup>::Category>()
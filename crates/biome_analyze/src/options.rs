@@ -1,9 +1,11 @@
+use regex::Regex;
 use rustc_hash::FxHashMap;
 
-use crate::{FixKind, Rule, RuleKey};
+use crate::{AnalyzerPlugin, FixKind, Rule, RuleKey, RuleProfiler};
 use std::any::{Any, TypeId};
 use std::fmt::Debug;
 use std::path::PathBuf;
+use std::sync::Arc;
 
 /// A convenient new type data structure to store the options that belong to a rule
 #[derive(Debug)]
@@ -70,6 +72,12 @@ pub struct AnalyzerConfiguration {
 
     /// Indicates the type of runtime or transformation used for interpreting JSX.
     pub jsx_runtime: Option<JsxRuntime>,
+
+    /// The policy that the suppression comments must follow
+    pub suppression_policy: SuppressionPolicy,
+
+    /// The plugins that run alongside the lint rules
+    pub plugins: Vec<Arc<dyn AnalyzerPlugin>>,
 }
 
 /// A set of information useful to the analyzer infrastructure
@@ -83,6 +91,9 @@ pub struct AnalyzerOptions {
 
     /// Suppression reason used when applying a suppression code action
     pub suppression_reason: Option<String>,
+
+    /// Receives the time spent in each rule, when the rules are measured
+    pub profiler: Option<Arc<dyn RuleProfiler>>,
 }
 
 impl AnalyzerOptions {
@@ -151,3 +162,72 @@ pub enum JsxRuntime {
     Transparent,
     ReactClassic,
 }
+
+/// The policy that the suppression comments must follow, derived from `linter.suppressions`.
+///
+/// Rule groups and rules are referenced by name, e.g. `security` or `security/noGlobalEval`.
+#[derive(Debug, Default)]
+pub struct SuppressionPolicy {
+    /// Whether the suppression comments must have a reason that isn't a placeholder
+    pub require_reason: bool,
+    /// Patterns that the reason of the suppressions of a rule group or a rule must match
+    pub reason_patterns: Vec<(String, Regex)>,
+    /// Rule groups and rules that can't be suppressed
+    pub forbidden: Vec<String>,
+    /// The maximum number of suppression comments in a file
+    pub max_per_file: Option<usize>,
+}
+
+impl SuppressionPolicy {
+    /// Whether the given reason is missing, or is a placeholder such as `<explanation>`
+    pub fn is_placeholder_reason(reason: &str) -> bool {
+        const PLACEHOLDERS: &[&str] = &["explanation", "reason", "todo", "fixme", "..."];
+        let reason = reason.trim();
+        reason.is_empty()
+            || (reason.starts_with('<') && reason.ends_with('>'))
+            || PLACEHOLDERS
+                .iter()
+                .any(|placeholder| reason.eq_ignore_ascii_case(placeholder))
+    }
+
+    /// Whether the rule of the given group can't be suppressed.
+    /// When `rule` is `None`, whether the whole group can't be suppressed.
+    pub fn is_forbidden(&self, group: &str, rule: Option<&str>) -> bool {
+        self.forbidden
+            .iter()
+            .any(|forbidden| match forbidden.split_once('/') {
+                Some((forbidden_group, forbidden_rule)) => {
+                    forbidden_group == group && Some(forbidden_rule) == rule
+                }
+                None => forbidden == group,
+            })
+    }
+
+    /// Returns the patterns that the reason of a suppression must match.
+    ///
+    /// For a rule, the pattern of the rule takes precedence over the pattern of its group. A
+    /// suppression of a whole group must match the patterns of the group and of its rules, and a
+    /// suppression of all the rules, when `group` is `None`, must match all the patterns.
+    pub fn reason_patterns(&self, group: Option<&str>, rule: Option<&str>) -> Vec<(&str, &Regex)> {
+        let patterns = self
+            .reason_patterns
+            .iter()
+            .map(|(name, pattern)| (name.as_str(), pattern));
+        match (group, rule) {
+            (Some(group), Some(rule)) => patterns
+                .clone()
+                .find(|(name, _)| name.split_once('/') == Some((group, rule)))
+                .or_else(|| patterns.clone().find(|(name, _)| *name == group))
+                .into_iter()
+                .collect(),
+            (Some(group), None) => patterns
+                .filter(|(name, _)| {
+                    name.split_once('/')
+                        .map_or(*name, |(pattern_group, _)| pattern_group)
+                        == group
+                })
+                .collect(),
+            (None, _) => patterns.collect(),
+        }
+    }
+}
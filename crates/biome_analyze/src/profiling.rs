@@ -0,0 +1,40 @@
+use crate::RuleKey;
+use std::fmt::{Debug, Formatter};
+use std::time::{Duration, Instant};
+
+/// The work of a rule that is measured by a [RuleProfiler]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum RulePhase {
+    /// The rule checks a node matched by its query, see [crate::Rule::run]
+    Run,
+    /// The rule computes the code action of one of its signals, see [crate::Rule::action]
+    Action,
+}
+
+/// Receives the time spent in each rule, when it's set in [crate::AnalyzerOptions::profiler]
+pub trait RuleProfiler: Send + Sync {
+    fn record(&self, rule: RuleKey, phase: RulePhase, duration: Duration);
+}
+
+impl Debug for dyn RuleProfiler {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("RuleProfiler")
+    }
+}
+
+/// Runs `func`, and reports its duration to `profiler`, if any
+pub(crate) fn profile_rule<T>(
+    profiler: Option<&dyn RuleProfiler>,
+    rule: RuleKey,
+    phase: RulePhase,
+    func: impl FnOnce() -> T,
+) -> T {
+    let Some(profiler) = profiler else {
+        return func();
+    };
+
+    let start = Instant::now();
+    let result = func();
+    profiler.record(rule, phase, start.elapsed());
+    result
+}
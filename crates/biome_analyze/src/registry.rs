@@ -1,6 +1,7 @@
 use crate::{
     context::RuleContext,
     matcher::{GroupKey, MatchQueryParams},
+    profiling::{profile_rule, RulePhase},
     query::{QueryKey, Queryable},
     signals::RuleSignal,
     AddVisitor, AnalysisFilter, GroupCategory, QueryMatcher, Rule, RuleGroup, RuleKey,
@@ -21,6 +22,7 @@ use std::{
 pub enum Phases {
     Syntax = 0,
     Semantic = 1,
+    Project = 2,
 }
 
 /// Defines which phase a rule will run. This will be defined
@@ -96,9 +98,11 @@ impl<L: Language> RegistryVisitor<L> for MetadataRegistry {
 /// - Syntax Phase: No services are offered, thus its rules can be run immediately;
 /// - Semantic Phase: Offers the semantic model, thus these rules can only run
 ///     after the "SemanticModel" is ready, which demands a whole transverse of the parsed tree.
+/// - Project Phase: Offers the module graph of the project, thus these rules can only run
+///     after the other files of the project have been analyzed.
 pub struct RuleRegistry<L: Language> {
     /// Holds a collection of rules for each phase.
-    phase_rules: [PhaseRules<L>; 2],
+    phase_rules: [PhaseRules<L>; 3],
 }
 
 impl<L: Language + Default> RuleRegistry<L> {
@@ -417,7 +421,13 @@ impl<L: Language + Default> RegistryRule<L> {
                 Err(error) => return Err(error),
             };
 
-            for result in R::run(&ctx) {
+            let signals = profile_rule(
+                params.options.profiler.as_deref(),
+                RuleKey::rule::<R>(),
+                RulePhase::Run,
+                || R::run(&ctx),
+            );
+            for result in signals {
                 let text_range =
                     R::text_range(&ctx, &result).unwrap_or_else(|| params.query.text_range());
 
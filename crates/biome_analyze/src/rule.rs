@@ -41,6 +41,8 @@ pub struct RuleMetadata {
     pub sources: &'static [RuleSource],
     /// The source kind of the rule
     pub source_kind: Option<RuleSourceKind>,
+    /// The domains of the rule, if the rule only makes sense for some libraries, frameworks or runtimes
+    pub domains: &'static [RuleDomain],
 }
 
 #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
@@ -323,6 +325,64 @@ impl RuleSourceKind {
     }
 }
 
+/// A rule domain groups the rules that only make sense in the projects that use a library,
+/// a framework or a runtime.
+///
+/// The rules of a domain can be enabled or disabled all at once with `linter.domains`.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(
+    feature = "serde",
+    derive(
+        biome_deserialize_macros::Deserializable,
+        schemars::JsonSchema,
+        serde::Deserialize,
+        serde::Serialize
+    )
+)]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub enum RuleDomain {
+    /// React library rules
+    React,
+    /// Next.js framework rules
+    Next,
+    /// SolidJS framework rules
+    Solid,
+    /// Node.js rules
+    Node,
+    /// Testing rules
+    Test,
+    /// Vue.js framework rules
+    Vue,
+}
+
+impl RuleDomain {
+    /// The dependencies of `package.json` that indicate that the project uses the domain
+    pub const fn manifest_dependencies(&self) -> &'static [&'static str] {
+        match self {
+            Self::React => &["react"],
+            Self::Next => &["next"],
+            Self::Solid => &["solid-js"],
+            Self::Node => &["@types/node"],
+            Self::Test => &["jest", "mocha", "ava", "vitest", "@jest/globals"],
+            Self::Vue => &["vue"],
+        }
+    }
+}
+
+impl std::fmt::Display for RuleDomain {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Self::React => "react",
+            Self::Next => "next",
+            Self::Solid => "solid",
+            Self::Node => "node",
+            Self::Test => "test",
+            Self::Vue => "vue",
+        };
+        fmt.write_str(name)
+    }
+}
+
 impl RuleMetadata {
     pub const fn new(
         version: &'static str,
@@ -340,6 +400,7 @@ impl RuleMetadata {
             fix_kind: FixKind::None,
             sources: &[],
             source_kind: None,
+            domains: &[],
         }
     }
 
@@ -376,6 +437,11 @@ impl RuleMetadata {
         self
     }
 
+    pub const fn domains(mut self, domains: &'static [RuleDomain]) -> Self {
+        self.domains = domains;
+        self
+    }
+
     pub fn applicability(&self) -> Applicability {
         self.fix_kind
             .try_into()
@@ -924,13 +990,17 @@ pub trait Rule: RuleMeta + Sized {
             let root = ctx.root();
             let token = root.syntax().token_at_offset(text_range.start());
             let mut mutation = root.begin();
-            suppression_action.apply_suppression_comment(SuppressionCommentEmitterPayload {
-                suppression_text: suppression_text.as_str(),
-                mutation: &mut mutation,
-                token_offset: token,
-                diagnostic_text_range: text_range,
-                suppression_reason: suppression_reason.unwrap_or("<explanation>"),
-            });
+            let is_applied =
+                suppression_action.apply_suppression_comment(SuppressionCommentEmitterPayload {
+                    suppression_text: suppression_text.as_str(),
+                    mutation: &mut mutation,
+                    token_offset: token,
+                    diagnostic_text_range: text_range,
+                    suppression_reason: suppression_reason.unwrap_or("<explanation>"),
+                });
+            if !is_applied {
+                return None;
+            }
 
             Some(SuppressAction {
                 mutation,
@@ -2,9 +2,11 @@ use crate::categories::SUPPRESSION_ACTION_CATEGORY;
 use crate::{
     categories::ActionCategory,
     context::RuleContext,
+    profiling::{profile_rule, RulePhase},
     registry::{RuleLanguage, RuleRoot},
     rule::Rule,
-    AnalyzerDiagnostic, AnalyzerOptions, Queryable, RuleGroup, ServiceBag, SuppressionAction,
+    AnalyzerDiagnostic, AnalyzerOptions, Queryable, RuleGroup, RuleKey, ServiceBag,
+    SuppressionAction,
 };
 use biome_console::MarkupBuf;
 use biome_diagnostics::{advice::CodeSuggestionAdvice, Applicability, CodeSuggestion, Error};
@@ -394,7 +396,13 @@ where
         .ok();
         if let Some(ctx) = ctx {
             let mut actions = Vec::new();
-            if let Some(action) = R::action(&ctx, &self.state) {
+            let action = profile_rule(
+                self.options.profiler.as_deref(),
+                RuleKey::rule::<R>(),
+                RulePhase::Action,
+                || R::action(&ctx, &self.state),
+            );
+            if let Some(action) = action {
                 actions.push(AnalyzerAction {
                     rule_name: Some((<R::Group as RuleGroup>::NAME, R::METADATA.name)),
                     applicability: configured_applicability.unwrap_or(action.applicability()),
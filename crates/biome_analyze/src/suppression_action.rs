@@ -4,7 +4,14 @@ use biome_rowan::{BatchMutation, Language, SyntaxToken, TextRange, TokenAtOffset
 pub trait SuppressionAction {
     type Language: Language;
 
-    fn apply_suppression_comment(&self, payload: SuppressionCommentEmitterPayload<Self::Language>) {
+    /// Adds the suppression comment to the mutation of the payload.
+    ///
+    /// Returns `false` when no suppression comment can be added, for example
+    /// when the file doesn't support comments.
+    fn apply_suppression_comment(
+        &self,
+        payload: SuppressionCommentEmitterPayload<Self::Language>,
+    ) -> bool {
         let SuppressionCommentEmitterPayload {
             token_offset,
             mutation,
@@ -22,14 +29,16 @@ pub trait SuppressionAction {
             self.find_token_to_apply_suppression(original_token.clone())
         });
 
-        if let Some(apply_suppression) = apply_suppression {
-            self.apply_suppression(
-                mutation,
-                apply_suppression,
-                suppression_text,
-                suppression_reason,
-            );
-        }
+        let Some(apply_suppression) = apply_suppression else {
+            return false;
+        };
+        self.apply_suppression(
+            mutation,
+            apply_suppression,
+            suppression_text,
+            suppression_reason,
+        );
+        true
     }
 
     /// Finds the first token, starting with the current token and traversing backwards,
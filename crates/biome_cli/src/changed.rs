@@ -1,25 +1,21 @@
 use crate::CliDiagnostic;
 use biome_configuration::PartialConfiguration;
+use biome_diagnostics::Error;
 use biome_fs::FileSystem;
+use biome_rowan::{TextRange, TextSize};
 use biome_service::DynRef;
+use rustc_hash::FxHashMap;
 use std::ffi::OsString;
+use std::ops::Range;
+use std::path::{Component, Path, PathBuf};
+use std::sync::Arc;
 
 pub(crate) fn get_changed_files(
     fs: &DynRef<'_, dyn FileSystem>,
     configuration: &PartialConfiguration,
     since: Option<&str>,
 ) -> Result<Vec<OsString>, CliDiagnostic> {
-    let default_branch = configuration
-        .vcs
-        .as_ref()
-        .and_then(|v| v.default_branch.as_ref());
-
-    let base = match (since, default_branch) {
-        (Some(since), Some(_)) => since,
-        (Some(since), None) => since,
-        (None, Some(branch)) => branch,
-        (None, None) => return Err(CliDiagnostic::incompatible_end_configuration("The `--changed` flag was set, but Biome couldn't determine the base to compare against. Either set configuration.vcs.defaultBranch or use the --since argument.")),
-    };
+    let base = get_base(configuration, since)?;
 
     let changed_files = fs.get_changed_files(base)?;
 
@@ -28,6 +24,30 @@ pub(crate) fn get_changed_files(
     Ok(filtered_changed_files)
 }
 
+pub(crate) fn get_changed_lines(
+    fs: &DynRef<'_, dyn FileSystem>,
+    configuration: &PartialConfiguration,
+    since: Option<&str>,
+) -> Result<ChangedLineRanges, CliDiagnostic> {
+    let base = get_base(configuration, since)?;
+
+    let files = fs
+        .get_changed_lines(base)?
+        .into_iter()
+        .map(|changed_lines| {
+            (
+                normalize(Path::new(&changed_lines.path)),
+                changed_lines.lines,
+            )
+        })
+        .collect();
+
+    Ok(ChangedLineRanges {
+        working_directory: fs.working_directory(),
+        files: Arc::new(files),
+    })
+}
+
 pub(crate) fn get_staged_files(
     fs: &DynRef<'_, dyn FileSystem>,
 ) -> Result<Vec<OsString>, CliDiagnostic> {
@@ -37,3 +57,101 @@ pub(crate) fn get_staged_files(
 
     Ok(filtered_staged_files)
 }
+
+/// Returns the branch or commit that `--changed` compares against
+fn get_base<'a>(
+    configuration: &'a PartialConfiguration,
+    since: Option<&'a str>,
+) -> Result<&'a str, CliDiagnostic> {
+    let default_branch = configuration
+        .vcs
+        .as_ref()
+        .and_then(|v| v.default_branch.as_deref());
+
+    match (since, default_branch) {
+        (Some(since), Some(_)) => Ok(since),
+        (Some(since), None) => Ok(since),
+        (None, Some(branch)) => Ok(branch),
+        (None, None) => Err(CliDiagnostic::incompatible_end_configuration("The `--changed` flag was set, but Biome couldn't determine the base to compare against. Either set configuration.vcs.defaultBranch or use the --since argument.")),
+    }
+}
+
+/// The lines that changed in each file, used by `--changed-lines-only` to only report the
+/// diagnostics of these lines, and only format them.
+#[derive(Debug, Clone)]
+pub(crate) struct ChangedLineRanges {
+    working_directory: Option<PathBuf>,
+    /// The zero-based ranges of the changed lines, by path relative to the working directory
+    files: Arc<FxHashMap<PathBuf, Vec<Range<u32>>>>,
+}
+
+impl ChangedLineRanges {
+    fn lines(&self, path: &Path) -> &[Range<u32>] {
+        let path = self
+            .working_directory
+            .as_deref()
+            .and_then(|working_directory| path.strip_prefix(working_directory).ok())
+            .unwrap_or(path);
+        self.files
+            .get(&normalize(path))
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// Returns `true` if the diagnostic is located on a changed line.
+    ///
+    /// The diagnostics that don't have a location are always reported.
+    pub(crate) fn contains(&self, path: &str, content: &str, diagnostic: &Error) -> bool {
+        let Some(span) = diagnostic.location().span else {
+            return true;
+        };
+        let line_of = |offset: TextSize| {
+            content
+                .get(..usize::from(offset))
+                .map_or(0, |text| text.matches('\n').count() as u32)
+        };
+        let start_line = line_of(span.start());
+        let end_line = line_of(span.end()).max(start_line);
+
+        self.lines(Path::new(path))
+            .iter()
+            .any(|lines| lines.start <= end_line && start_line < lines.end)
+    }
+
+    /// Returns the range of each changed line of the file, without its line break.
+    ///
+    /// The lines are kept separate because the range of several statements is extended to
+    /// their parent, which would cover the lines that didn't change.
+    pub(crate) fn text_ranges(&self, path: &Path, content: &str) -> Vec<TextRange> {
+        let mut offset = 0;
+        let lines: Vec<_> = content
+            .split_inclusive('\n')
+            .map(|line| {
+                let start = offset;
+                offset += line.len();
+                let line = line.strip_suffix('\n').unwrap_or(line);
+                let line = line.strip_suffix('\r').unwrap_or(line);
+                (start, line)
+            })
+            .collect();
+
+        self.lines(path)
+            .iter()
+            .flat_map(|changed_lines| {
+                lines
+                    .iter()
+                    .skip(changed_lines.start as usize)
+                    .take(changed_lines.len())
+            })
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(start, line)| TextRange::at(TextSize::from(*start as u32), TextSize::of(*line)))
+            .collect()
+    }
+}
+
+/// Removes the `.` components, which `git` doesn't print but the paths passed to the CLI can have
+fn normalize(path: &Path) -> PathBuf {
+    path.components()
+        .filter(|component| !matches!(component, Component::CurDir))
+        .collect()
+}
@@ -1,6 +1,10 @@
 use super::{determine_fix_file_mode, FixFileModeOptions, LoadEditorConfig};
+use crate::changed::ChangedLineRanges;
 use crate::cli_options::CliOptions;
-use crate::commands::{get_files_to_process_with_cli_options, CommandRunner};
+use crate::commands::{
+    get_baseline_options, get_changed_lines_with_cli_options,
+    get_files_to_process_with_cli_options, CommandRunner,
+};
 use crate::{CliDiagnostic, Execution, TraversalMode};
 use biome_configuration::analyzer::assists::PartialAssistsConfiguration;
 use biome_configuration::{
@@ -12,6 +16,7 @@ use biome_deserialize::Merge;
 use biome_fs::FileSystem;
 use biome_service::{configuration::LoadedConfiguration, DynRef, Workspace, WorkspaceError};
 use std::ffi::OsString;
+use std::path::PathBuf;
 
 pub(crate) struct CheckCommandPayload {
     pub(crate) apply: bool,
@@ -29,6 +34,13 @@ pub(crate) struct CheckCommandPayload {
     pub(crate) staged: bool,
     pub(crate) changed: bool,
     pub(crate) since: Option<String>,
+    pub(crate) changed_lines_only: bool,
+    pub(crate) cache: bool,
+    pub(crate) watch: bool,
+    pub(crate) diff: bool,
+    pub(crate) profile_rules: bool,
+    pub(crate) baseline: Option<PathBuf>,
+    pub(crate) write_baseline: bool,
 }
 
 impl LoadEditorConfig for CheckCommandPayload {
@@ -130,6 +142,51 @@ impl CommandRunner for CheckCommandPayload {
         self.write || self.fix
     }
 
+    fn check_incompatible_arguments(&self) -> Result<(), CliDiagnostic> {
+        if self.changed_lines_only && !self.changed {
+            return Err(CliDiagnostic::missing_argument(
+                "--changed",
+                Self::COMMAND_NAME,
+            ));
+        }
+        if self.diff && !self.should_write() {
+            return Err(CliDiagnostic::missing_argument(
+                "--write",
+                Self::COMMAND_NAME,
+            ));
+        }
+        Ok(())
+    }
+
+    fn should_use_cache(&self, configuration: &PartialConfiguration) -> bool {
+        self.cache || configuration.use_cache()
+    }
+
+    fn should_watch(&self) -> bool {
+        self.watch
+    }
+
+    fn should_print_diff(&self) -> bool {
+        self.diff
+    }
+
+    fn get_changed_lines(
+        &self,
+        fs: &DynRef<'_, dyn FileSystem>,
+        configuration: &PartialConfiguration,
+    ) -> Result<Option<ChangedLineRanges>, CliDiagnostic> {
+        get_changed_lines_with_cli_options(
+            self.changed_lines_only,
+            self.since.as_deref(),
+            fs,
+            configuration,
+        )
+    }
+
+    fn should_profile_rules(&self) -> bool {
+        self.profile_rules
+    }
+
     fn get_execution(
         &self,
         cli_options: &CliOptions,
@@ -154,6 +211,10 @@ impl CommandRunner for CheckCommandPayload {
             stdin: self.get_stdin(console)?,
             vcs_targeted: (self.staged, self.changed).into(),
         })
-        .set_report(cli_options))
+        .set_report(cli_options)
+        .with_baseline(get_baseline_options(
+            self.baseline.as_ref(),
+            self.write_baseline,
+        )))
     }
 }
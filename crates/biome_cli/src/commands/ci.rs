@@ -1,6 +1,7 @@
 use crate::changed::get_changed_files;
+use crate::changed::ChangedLineRanges;
 use crate::cli_options::CliOptions;
-use crate::commands::{CommandRunner, LoadEditorConfig};
+use crate::commands::{get_changed_lines_with_cli_options, CommandRunner, LoadEditorConfig};
 use crate::{CliDiagnostic, Execution};
 use biome_configuration::analyzer::assists::PartialAssistsConfiguration;
 use biome_configuration::{organize_imports::PartialOrganizeImports, PartialConfiguration};
@@ -21,6 +22,8 @@ pub(crate) struct CiCommandPayload {
     pub(crate) configuration: Option<PartialConfiguration>,
     pub(crate) changed: bool,
     pub(crate) since: Option<String>,
+    pub(crate) changed_lines_only: bool,
+    pub(crate) profile_rules: bool,
 }
 
 impl LoadEditorConfig for CiCommandPayload {
@@ -118,6 +121,23 @@ impl CommandRunner for CiCommandPayload {
         false
     }
 
+    fn get_changed_lines(
+        &self,
+        fs: &DynRef<'_, dyn FileSystem>,
+        configuration: &PartialConfiguration,
+    ) -> Result<Option<ChangedLineRanges>, CliDiagnostic> {
+        get_changed_lines_with_cli_options(
+            self.changed_lines_only,
+            self.since.as_deref(),
+            fs,
+            configuration,
+        )
+    }
+
+    fn should_profile_rules(&self) -> bool {
+        self.profile_rules
+    }
+
     fn get_execution(
         &self,
         cli_options: &CliOptions,
@@ -137,6 +157,12 @@ impl CommandRunner for CiCommandPayload {
         if self.since.is_some() && !self.changed {
             return Err(CliDiagnostic::incompatible_arguments("since", "changed"));
         }
+        if self.changed_lines_only && !self.changed {
+            return Err(CliDiagnostic::missing_argument(
+                "--changed",
+                Self::COMMAND_NAME,
+            ));
+        }
         Ok(())
     }
 }
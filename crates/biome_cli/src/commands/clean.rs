@@ -1,7 +1,9 @@
 use crate::commands::daemon::default_biome_log_path;
+use crate::execute::cache_directory;
 use crate::{CliDiagnostic, CliSession};
 use biome_flags::biome_env;
 use std::fs::{create_dir, remove_dir_all};
+use std::io;
 use std::path::PathBuf;
 
 /// Runs the clean command
@@ -11,5 +13,8 @@ pub fn clean(_cli_session: CliSession) -> Result<(), CliDiagnostic> {
         .value()
         .map_or(default_biome_log_path(), PathBuf::from);
     remove_dir_all(logs_path.clone()).and_then(|_| create_dir(logs_path))?;
-    Ok(())
+    match remove_dir_all(cache_directory()) {
+        Err(error) if error.kind() != io::ErrorKind::NotFound => Err(error.into()),
+        _ => Ok(()),
+    }
 }
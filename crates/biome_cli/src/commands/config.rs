@@ -0,0 +1,298 @@
+use crate::cli_options::CliOptions;
+use crate::commands::{setup_cli_subscriber, CommandRunner, LoadEditorConfig};
+use crate::{CliDiagnostic, CliSession, Execution, TraversalMode};
+use biome_configuration::{
+    PartialConfiguration, PartialCssConfiguration, PartialCssFormatter,
+    PartialFormatterConfiguration, PartialGraphqlConfiguration, PartialGraphqlFormatter,
+    PartialJavascriptConfiguration, PartialJavascriptFormatter, PartialJsonConfiguration,
+    PartialJsonFormatter,
+};
+use biome_console::fmt::{Display, Formatter};
+use biome_console::{markup, Console, ConsoleExt};
+use biome_diagnostics::Severity;
+use biome_fs::{BiomePath, FileSystem};
+use biome_service::configuration::LoadedConfiguration;
+use biome_service::workspace::{
+    FeatureKind, FeaturesBuilder, GetResolvedSettingsParams, ResolvedFormatterOption, ResolvedRule,
+    ResolvedSettingSource, SupportKind, SupportsFeatureParams,
+};
+use biome_service::{DynRef, Workspace, WorkspaceError};
+use serde::Serialize;
+use std::ffi::OsString;
+use std::path::PathBuf;
+
+pub(crate) struct ConfigPrintCommandPayload {
+    pub(crate) formatter_configuration: Option<PartialFormatterConfiguration>,
+    pub(crate) javascript_formatter: Option<PartialJavascriptFormatter>,
+    pub(crate) json_formatter: Option<PartialJsonFormatter>,
+    pub(crate) css_formatter: Option<PartialCssFormatter>,
+    pub(crate) graphql_formatter: Option<PartialGraphqlFormatter>,
+    pub(crate) json: bool,
+    pub(crate) path: OsString,
+    /// The path of the configuration file of the project, `None` until the configuration
+    /// of the project is loaded
+    pub(crate) configuration_file_path: Option<Option<PathBuf>>,
+    /// The configuration read from the `.editorconfig` file of the project. It isn't merged in
+    /// the configuration of the workspace, so that the source of each option is known.
+    pub(crate) editorconfig: Option<PartialConfiguration>,
+}
+
+impl ConfigPrintCommandPayload {
+    /// The formatter options passed as arguments, as a configuration
+    fn command_line_configuration(&self) -> PartialConfiguration {
+        PartialConfiguration {
+            formatter: self.formatter_configuration.clone(),
+            javascript: self.javascript_formatter.clone().map(|formatter| {
+                PartialJavascriptConfiguration {
+                    formatter: Some(formatter),
+                    ..Default::default()
+                }
+            }),
+            json: self
+                .json_formatter
+                .clone()
+                .map(|formatter| PartialJsonConfiguration {
+                    formatter: Some(formatter),
+                    ..Default::default()
+                }),
+            css: self
+                .css_formatter
+                .clone()
+                .map(|formatter| PartialCssConfiguration {
+                    formatter: Some(formatter),
+                    ..Default::default()
+                }),
+            graphql: self
+                .graphql_formatter
+                .clone()
+                .map(|formatter| PartialGraphqlConfiguration {
+                    formatter: Some(formatter),
+                    ..Default::default()
+                }),
+            ..Default::default()
+        }
+    }
+}
+
+impl LoadEditorConfig for ConfigPrintCommandPayload {
+    fn should_load_editor_config(&self, fs_configuration: &PartialConfiguration) -> bool {
+        fs_configuration.use_editorconfig().unwrap_or_default()
+    }
+}
+
+impl CommandRunner for ConfigPrintCommandPayload {
+    const COMMAND_NAME: &'static str = "config print";
+
+    fn run(&mut self, session: CliSession, cli_options: &CliOptions) -> Result<(), CliDiagnostic> {
+        setup_cli_subscriber(cli_options.log_level, cli_options.log_kind);
+        let fs = &session.app.fs;
+        let console = &mut *session.app.console;
+        let workspace = &*session.app.workspace;
+        self.configure_workspace(fs, console, workspace, cli_options)?;
+
+        let path = BiomePath::new(&self.path);
+        let file_features = workspace.file_features(SupportsFeatureParams {
+            path: path.clone(),
+            features: FeaturesBuilder::new()
+                .with_formatter()
+                .with_linter()
+                .with_organize_imports()
+                .with_assists()
+                .build(),
+        })?;
+        let settings = workspace.get_resolved_settings(GetResolvedSettingsParams {
+            path: path.clone(),
+            editorconfig: self.editorconfig.clone(),
+            command_line: Some(self.command_line_configuration()),
+        })?;
+
+        let features = [
+            ("formatter", FeatureKind::Format),
+            ("linter", FeatureKind::Lint),
+            ("organizeImports", FeatureKind::OrganizeImports),
+            ("assists", FeatureKind::Assists),
+        ]
+        .into_iter()
+        .map(|(name, feature)| {
+            let status = match file_features.support_kind_for(&feature) {
+                Some(SupportKind::Supported) => "enabled",
+                Some(SupportKind::Ignored) => "ignored",
+                Some(SupportKind::Protected) => "protected",
+                Some(SupportKind::FeatureNotEnabled) => "disabled",
+                Some(SupportKind::FileNotSupported) | None => "unsupported",
+            };
+            FeatureStatus { name, status }
+        })
+        .collect();
+
+        let printed = PrintedSettings {
+            path: path.to_path_buf(),
+            configuration_file: self.configuration_file_path.clone().flatten(),
+            nested_configuration_directory: settings.nested_configuration_directory,
+            overrides: settings.overrides,
+            features,
+            formatter_options: settings.formatter_options,
+            rules: settings.rules,
+        };
+
+        if self.json {
+            console.log(markup! {{printed}});
+        } else {
+            print_settings(console, &printed);
+        }
+        Ok(())
+    }
+
+    fn merge_configuration(
+        &mut self,
+        loaded_configuration: LoadedConfiguration,
+        fs: &DynRef<'_, dyn FileSystem>,
+        console: &mut dyn Console,
+    ) -> Result<PartialConfiguration, WorkspaceError> {
+        let LoadedConfiguration {
+            configuration,
+            directory_path,
+            file_path,
+            ..
+        } = loaded_configuration;
+        // The nested configurations are merged after the configuration of the project
+        if self.configuration_file_path.is_none() {
+            self.configuration_file_path = Some(file_path);
+            self.editorconfig =
+                Some(self.load_editor_config(directory_path, &configuration, fs, console)?);
+        }
+
+        Ok(configuration)
+    }
+
+    fn get_files_to_process(
+        &self,
+        _fs: &DynRef<'_, dyn FileSystem>,
+        _configuration: &PartialConfiguration,
+    ) -> Result<Vec<OsString>, CliDiagnostic> {
+        Ok(vec![self.path.clone()])
+    }
+
+    fn get_stdin_file_path(&self) -> Option<&str> {
+        None
+    }
+
+    fn should_write(&self) -> bool {
+        false
+    }
+
+    fn get_execution(
+        &self,
+        cli_options: &CliOptions,
+        _console: &mut dyn Console,
+        _workspace: &dyn Workspace,
+    ) -> Result<Execution, CliDiagnostic> {
+        Ok(Execution::new(TraversalMode::Check {
+            fix_file_mode: None,
+            stdin: None,
+            vcs_targeted: (false, false).into(),
+        })
+        .set_report(cli_options))
+    }
+}
+
+fn print_settings(console: &mut dyn Console, settings: &PrintedSettings) {
+    let mut summary = format!(
+        "  configuration file    {}\n",
+        settings
+            .configuration_file
+            .as_ref()
+            .map_or_else(|| "none".to_string(), |path| path.display().to_string())
+    );
+    if let Some(directory) = &settings.nested_configuration_directory {
+        summary += &format!("  nested configuration  {}\n", directory.display());
+    }
+    if !settings.overrides.is_empty() {
+        let overrides = settings
+            .overrides
+            .iter()
+            .map(|index| index.to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+        summary += &format!("  overrides             {overrides}\n");
+    }
+    for feature in &settings.features {
+        summary += &format!("  {:<22}{}\n", feature.name, feature.status);
+    }
+    console.log(markup! {
+        <Emphasis>{settings.path.display().to_string()}</Emphasis>"\n"{summary.trim_end()}
+    });
+
+    if !settings.formatter_options.is_empty() {
+        let mut formatter_options = String::new();
+        for option in &settings.formatter_options {
+            formatter_options += &format!(
+                "  {:<22}{} ({})\n",
+                option.name,
+                option.value,
+                source_name(option.source)
+            );
+        }
+        console.log(markup! {
+            <Emphasis>"Formatter options"</Emphasis>"\n"{formatter_options.trim_end()}
+        });
+    }
+
+    if !settings.rules.is_empty() {
+        let mut rules = String::new();
+        for rule in &settings.rules {
+            let severity = match rule.severity {
+                Severity::Fatal | Severity::Error => "error",
+                Severity::Warning => "warn",
+                Severity::Information | Severity::Hint => "info",
+            };
+            let source = source_name(rule.source);
+            rules += &format!("  {} ({severity}, {source})\n", rule.name);
+            if let Some(options) = &rule.options {
+                rules += &format!("    options  {options}\n");
+            }
+        }
+        console.log(markup! {
+            <Emphasis>"Enabled rules"</Emphasis>"\n"{rules.trim_end()}
+        });
+    }
+}
+
+fn source_name(source: ResolvedSettingSource) -> &'static str {
+    match source {
+        ResolvedSettingSource::Default => "default",
+        ResolvedSettingSource::EditorConfig => "editorconfig",
+        ResolvedSettingSource::Configuration => "configuration",
+        ResolvedSettingSource::CommandLine => "command line",
+        ResolvedSettingSource::Override => "override",
+    }
+}
+
+/// The settings printed by `biome config print`
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct PrintedSettings {
+    path: PathBuf,
+    /// The configuration file of the project, if any
+    configuration_file: Option<PathBuf>,
+    /// The directory of the nested configuration file that applies to the file, if any
+    nested_configuration_directory: Option<PathBuf>,
+    /// The indexes of the overrides that match the file
+    overrides: Vec<usize>,
+    features: Vec<FeatureStatus>,
+    formatter_options: Vec<ResolvedFormatterOption>,
+    rules: Vec<ResolvedRule>,
+}
+
+impl Display for PrintedSettings {
+    fn fmt(&self, fmt: &mut Formatter) -> std::io::Result<()> {
+        let content = serde_json::to_string(&self)?;
+        fmt.write_str(content.as_str())
+    }
+}
+
+#[derive(Serialize)]
+struct FeatureStatus {
+    name: &'static str,
+    status: &'static str,
+}
@@ -1,5 +1,9 @@
+use crate::changed::ChangedLineRanges;
 use crate::cli_options::CliOptions;
-use crate::commands::{get_files_to_process_with_cli_options, CommandRunner, LoadEditorConfig};
+use crate::commands::{
+    get_changed_lines_with_cli_options, get_files_to_process_with_cli_options, CommandRunner,
+    LoadEditorConfig,
+};
 use crate::diagnostics::DeprecatedArgument;
 use crate::{CliDiagnostic, Execution, TraversalMode};
 use biome_configuration::vcs::PartialVcsConfiguration;
@@ -31,6 +35,10 @@ pub(crate) struct FormatCommandPayload {
     pub(crate) staged: bool,
     pub(crate) changed: bool,
     pub(crate) since: Option<String>,
+    pub(crate) changed_lines_only: bool,
+    pub(crate) cache: bool,
+    pub(crate) watch: bool,
+    pub(crate) diff: bool,
 }
 
 impl LoadEditorConfig for FormatCommandPayload {
@@ -190,6 +198,41 @@ impl CommandRunner for FormatCommandPayload {
         self.write || self.fix
     }
 
+    fn check_incompatible_arguments(&self) -> Result<(), CliDiagnostic> {
+        if self.changed_lines_only && !self.changed {
+            return Err(CliDiagnostic::missing_argument(
+                "--changed",
+                Self::COMMAND_NAME,
+            ));
+        }
+        Ok(())
+    }
+
+    fn should_use_cache(&self, configuration: &PartialConfiguration) -> bool {
+        self.cache || configuration.use_cache()
+    }
+
+    fn should_watch(&self) -> bool {
+        self.watch
+    }
+
+    fn should_print_diff(&self) -> bool {
+        self.diff
+    }
+
+    fn get_changed_lines(
+        &self,
+        fs: &DynRef<'_, dyn FileSystem>,
+        configuration: &PartialConfiguration,
+    ) -> Result<Option<ChangedLineRanges>, CliDiagnostic> {
+        get_changed_lines_with_cli_options(
+            self.changed_lines_only,
+            self.since.as_deref(),
+            fs,
+            configuration,
+        )
+    }
+
     fn get_execution(
         &self,
         cli_options: &CliOptions,
@@ -198,7 +241,8 @@ impl CommandRunner for FormatCommandPayload {
     ) -> Result<Execution, CliDiagnostic> {
         Ok(Execution::new(TraversalMode::Format {
             ignore_errors: cli_options.skip_errors,
-            write: self.should_write(),
+            // With `--diff`, the changes are printed instead of being written
+            write: self.should_write() && !self.diff,
             stdin: self.get_stdin(console)?,
             vcs_targeted: (self.staged, self.changed).into(),
         })
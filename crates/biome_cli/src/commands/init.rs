@@ -1,12 +1,36 @@
+use crate::execute::detect_migratable_tools;
 use crate::{CliDiagnostic, CliSession};
-use biome_configuration::PartialConfiguration;
+use biome_configuration::javascript::JsxRuntime;
+use biome_configuration::{
+    OverrideLinterConfiguration, OverridePattern, PartialConfiguration,
+    PartialJavascriptConfiguration, RulePlainConfiguration, Rules,
+};
+use biome_console::fmt::{Display, Formatter};
 use biome_console::{markup, ConsoleExt};
-use biome_fs::ConfigName;
+use biome_deserialize::json::deserialize_from_json_str;
+use biome_deserialize_macros::Deserializable;
+use biome_diagnostics::Error;
+use biome_fs::{BiomePath, ConfigName, FileSystem, PathInterner, TraversalContext};
+use biome_json_parser::JsonParserOptions;
+use biome_project::{PackageJson, Version};
 use biome_service::configuration::create_config;
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
 
-pub(crate) fn init(mut session: CliSession, emit_jsonc: bool) -> Result<(), CliDiagnostic> {
+pub(crate) fn init(
+    mut session: CliSession,
+    emit_jsonc: bool,
+    detect: bool,
+) -> Result<(), CliDiagnostic> {
     let fs = &mut session.app.fs;
-    create_config(fs, PartialConfiguration::init(), emit_jsonc)?;
+    let mut configuration = PartialConfiguration::init();
+    let findings = if detect {
+        detect_configuration(&**fs, &mut configuration)
+    } else {
+        Vec::new()
+    };
+    create_config(fs, configuration, emit_jsonc)?;
     let file_created = if emit_jsonc {
         ConfigName::biome_jsonc()
     } else {
@@ -21,7 +45,7 @@ Welcome to Biome! Let's get you started...
   "<Dim>"- "</Dim><Emphasis>{file_created}</Emphasis>"
     Your project configuration. See "<Hyperlink href="https://biomejs.dev/reference/configuration">"https://biomejs.dev/reference/configuration"</Hyperlink>"
 
-"<Info><Emphasis>"Next Steps "</Emphasis></Info>"
+"{Findings(&findings)}<Info><Emphasis>"Next Steps "</Emphasis></Info>"
 
   "<Dim>"1."</Dim>" "<Emphasis>"Setup an editor extension"</Emphasis>"
      Get live errors as you type and format when you save.
@@ -45,3 +69,367 @@ Welcome to Biome! Let's get you started...
     });
     Ok(())
 }
+
+/// Directories that usually contain the outputs of build tools and test runners
+const BUILD_OUTPUTS: [&str; 9] = [
+    "dist",
+    "build",
+    "out",
+    "coverage",
+    ".next",
+    ".nuxt",
+    ".output",
+    ".svelte-kit",
+    ".astro",
+];
+
+/// Frameworks with single-file components, identified by their dependency and the
+/// extension of their files. Biome only analyzes the script part of these files.
+const COMPONENT_FRAMEWORKS: [(&str, &str); 3] =
+    [("vue", "vue"), ("svelte", "svelte"), ("astro", "astro")];
+
+/// The Next.js rules, all of them are still in the nursery group
+const NEXT_RULES: [&str; 5] = [
+    "noDocumentImportInPage",
+    "noHeadElement",
+    "noHeadImportInDocument",
+    "noImgElement",
+    "useGoogleFontDisplay",
+];
+
+/// Something that `biome init --detect` found in the project, and how it changed the
+/// generated configuration
+struct Finding {
+    subject: String,
+    action: String,
+}
+
+impl Finding {
+    fn new(subject: impl Into<String>, action: impl Into<String>) -> Self {
+        Self {
+            subject: subject.into(),
+            action: action.into(),
+        }
+    }
+}
+
+struct Findings<'a>(&'a [Finding]);
+
+impl Display for Findings<'_> {
+    fn fmt(&self, fmt: &mut Formatter) -> std::io::Result<()> {
+        if self.0.is_empty() {
+            return Ok(());
+        }
+        fmt.write_markup(markup! {
+            <Info><Emphasis>"Detected "</Emphasis></Info>"\n\n"
+        })?;
+        for finding in self.0 {
+            fmt.write_markup(markup! {
+                "  "<Dim>"- "</Dim><Emphasis>{finding.subject}</Emphasis>"\n    "{finding.action}"\n"
+            })?;
+        }
+        fmt.write_str("\n")
+    }
+}
+
+/// Inspects the project in the working directory, and adapts the initial configuration to it
+fn detect_configuration(
+    fs: &dyn FileSystem,
+    configuration: &mut PartialConfiguration,
+) -> Vec<Finding> {
+    let mut findings = Vec::new();
+    let package_json = fs
+        .read_file_from_path(&PathBuf::from(ConfigName::package_json()))
+        .ok()
+        .and_then(|content| {
+            deserialize_from_json_str::<PackageJson>(&content, JsonParserOptions::default(), "")
+                .into_deserialized()
+        })
+        .unwrap_or_default();
+    let tsconfig = fs
+        .read_file_from_path(&PathBuf::from("tsconfig.json"))
+        .ok()
+        .and_then(|content| {
+            let parser_options = JsonParserOptions::default()
+                .with_allow_comments()
+                .with_allow_trailing_commas();
+            deserialize_from_json_str::<TsConfig>(&content, parser_options, "").into_deserialized()
+        })
+        .unwrap_or_default();
+    let project_files = ProjectFiles::collect(fs);
+
+    if fs.path_exists(Path::new(".git")) {
+        let vcs = configuration.vcs.get_or_insert_with(Default::default);
+        vcs.enabled = Some(true);
+        vcs.use_ignore_file = Some(true);
+        findings.push(Finding::new(
+            ".git",
+            "Enabled the VCS integration, so that the files ignored by Git are ignored by Biome.",
+        ));
+    }
+
+    if !project_files.build_outputs.is_empty() {
+        let ignore = configuration
+            .files
+            .get_or_insert_with(Default::default)
+            .ignore
+            .get_or_insert_with(Default::default);
+        for build_output in &project_files.build_outputs {
+            ignore.insert(format!("{build_output}/**"));
+        }
+        let build_outputs = project_files
+            .build_outputs
+            .iter()
+            .copied()
+            .collect::<Vec<_>>()
+            .join(", ");
+        findings.push(Finding::new(build_outputs, "Ignored these build outputs."));
+    }
+
+    let rules = configuration
+        .linter
+        .get_or_insert_with(Default::default)
+        .rules
+        .get_or_insert_with(Default::default);
+    if has_dependency(&package_json, "react") {
+        let group = rules.correctness.get_or_insert_with(Default::default);
+        group
+            .use_exhaustive_dependencies
+            .get_or_insert(Default::default())
+            .set_level(RulePlainConfiguration::Error);
+        group
+            .use_hook_at_top_level
+            .get_or_insert(Default::default())
+            .set_level(RulePlainConfiguration::Error);
+        findings.push(Finding::new(
+            "react",
+            "Enabled the rules useExhaustiveDependencies and useHookAtTopLevel.",
+        ));
+    }
+    if has_dependency(&package_json, "next") {
+        enable_next_rules(rules);
+        findings.push(Finding::new(
+            "next",
+            format!(
+                "Enabled the Next.js rules of the nursery group: {}.",
+                NEXT_RULES.join(", ")
+            ),
+        ));
+    }
+
+    if tsconfig.compiler_options.jsx.as_deref() == Some("react") {
+        set_classic_jsx_runtime(configuration);
+        findings.push(Finding::new(
+            "tsconfig.json",
+            "Set javascript.jsxRuntime to reactClassic, because compilerOptions.jsx is react.",
+        ));
+    } else if let Some(major) = dependency_version(&package_json, "react").and_then(major_version) {
+        if major < 17 {
+            set_classic_jsx_runtime(configuration);
+            findings.push(Finding::new(
+                format!("react@{major}"),
+                "Set javascript.jsxRuntime to reactClassic, because this version of React doesn't support the new JSX transform.",
+            ));
+        }
+    }
+
+    for (dependency, extension) in COMPONENT_FRAMEWORKS {
+        if has_dependency(&package_json, dependency) || project_files.extensions.contains(extension)
+        {
+            configuration
+                .overrides
+                .get_or_insert_with(Default::default)
+                .0
+                .push(component_files_override(extension));
+            findings.push(Finding::new(
+                format!("*.{extension}"),
+                "Disabled the rules useConst, useImportType, noUnusedVariables and noUnusedImports for these files, because Biome only analyzes their script part.",
+            ));
+        }
+    }
+
+    for tool in detect_migratable_tools(fs) {
+        findings.push(Finding::new(
+            tool,
+            format!("Run biome migrate {tool} to migrate its configuration."),
+        ));
+    }
+
+    findings
+}
+
+fn has_dependency(package_json: &PackageJson, specifier: &str) -> bool {
+    package_json.dependencies.contains(specifier)
+        || package_json.dev_dependencies.contains(specifier)
+        || package_json.peer_dependencies.contains(specifier)
+}
+
+fn dependency_version<'a>(package_json: &'a PackageJson, specifier: &str) -> Option<&'a Version> {
+    package_json
+        .dependencies
+        .get(specifier)
+        .or_else(|| package_json.dev_dependencies.get(specifier))
+        .or_else(|| package_json.peer_dependencies.get(specifier))
+}
+
+/// Returns the major version of a dependency, including when it's a range such as `^16.8.0`
+fn major_version(version: &Version) -> Option<u64> {
+    match version {
+        Version::SemVer(version) => Some(version.major),
+        Version::Literal(range) => range
+            .trim_start_matches(|c: char| !c.is_ascii_digit())
+            .split(|c: char| !c.is_ascii_digit())
+            .next()?
+            .parse()
+            .ok(),
+    }
+}
+
+fn enable_next_rules(rules: &mut Rules) {
+    let group = rules.nursery.get_or_insert_with(Default::default);
+    group
+        .no_document_import_in_page
+        .get_or_insert(Default::default())
+        .set_level(RulePlainConfiguration::Error);
+    group
+        .no_head_element
+        .get_or_insert(Default::default())
+        .set_level(RulePlainConfiguration::Error);
+    group
+        .no_head_import_in_document
+        .get_or_insert(Default::default())
+        .set_level(RulePlainConfiguration::Error);
+    group
+        .no_img_element
+        .get_or_insert(Default::default())
+        .set_level(RulePlainConfiguration::Error);
+    group
+        .use_google_font_display
+        .get_or_insert(Default::default())
+        .set_level(RulePlainConfiguration::Error);
+}
+
+fn set_classic_jsx_runtime(configuration: &mut PartialConfiguration) {
+    configuration
+        .javascript
+        .get_or_insert_with(PartialJavascriptConfiguration::default)
+        .jsx_runtime = Some(JsxRuntime::ReactClassic);
+}
+
+/// Disables the rules that report false positives in single-file components, because the
+/// bindings used by their template aren't visible to Biome
+fn component_files_override(extension: &str) -> OverridePattern {
+    let mut rules = Rules::default();
+    let style = rules.style.get_or_insert_with(Default::default);
+    style
+        .use_const
+        .get_or_insert(Default::default())
+        .set_level(RulePlainConfiguration::Off);
+    style
+        .use_import_type
+        .get_or_insert(Default::default())
+        .set_level(RulePlainConfiguration::Off);
+    let correctness = rules.correctness.get_or_insert_with(Default::default);
+    correctness
+        .no_unused_variables
+        .get_or_insert(Default::default())
+        .set_level(RulePlainConfiguration::Off);
+    correctness
+        .no_unused_imports
+        .get_or_insert(Default::default())
+        .set_level(RulePlainConfiguration::Off);
+    OverridePattern {
+        include: Some([format!("*.{extension}")].into_iter().collect()),
+        linter: Some(OverrideLinterConfiguration {
+            rules: Some(rules),
+            ..Default::default()
+        }),
+        ..Default::default()
+    }
+}
+
+#[derive(Debug, Default, Deserializable)]
+#[deserializable(unknown_fields = "allow")]
+struct TsConfig {
+    compiler_options: TsCompilerOptions,
+}
+
+#[derive(Debug, Default, Deserializable)]
+#[deserializable(unknown_fields = "allow")]
+struct TsCompilerOptions {
+    jsx: Option<String>,
+}
+
+/// The build outputs and the file extensions found in the working directory
+#[derive(Default)]
+struct ProjectFiles {
+    build_outputs: BTreeSet<&'static str>,
+    extensions: BTreeSet<String>,
+}
+
+impl ProjectFiles {
+    fn collect(fs: &dyn FileSystem) -> Self {
+        let (interner, _) = PathInterner::new();
+        let ctx = ProjectFilesContext {
+            interner,
+            project_files: Mutex::default(),
+        };
+        fs.traversal(Box::new(|scope| {
+            scope.evaluate(&ctx, PathBuf::from("."));
+        }));
+        ctx.project_files
+            .into_inner()
+            .expect("the traversal doesn't poison the lock")
+    }
+}
+
+struct ProjectFilesContext {
+    interner: PathInterner,
+    project_files: Mutex<ProjectFiles>,
+}
+
+impl TraversalContext for ProjectFilesContext {
+    fn interner(&self) -> &PathInterner {
+        &self.interner
+    }
+
+    fn push_diagnostic(&self, _error: Error) {}
+
+    fn can_handle(&self, path: &BiomePath) -> bool {
+        let path = path.strip_prefix(".").unwrap_or(path);
+        let first_component = path
+            .components()
+            .next()
+            .map(|component| component.as_os_str());
+        // Build outputs are recorded without being traversed
+        match BUILD_OUTPUTS
+            .into_iter()
+            .find(|build_output| first_component == Some(build_output.as_ref()))
+        {
+            Some(build_output) => {
+                if let Ok(mut project_files) = self.project_files.lock() {
+                    project_files.build_outputs.insert(build_output);
+                }
+                false
+            }
+            None => true,
+        }
+    }
+
+    fn handle_path(&self, path: BiomePath) {
+        self.store_path(path);
+    }
+
+    fn store_path(&self, path: BiomePath) {
+        let Some(extension) = path.extension().and_then(|extension| extension.to_str()) else {
+            return;
+        };
+        if let Ok(mut project_files) = self.project_files.lock() {
+            project_files.extensions.insert(extension.to_string());
+        }
+    }
+
+    fn evaluated_paths(&self) -> BTreeSet<BiomePath> {
+        BTreeSet::new()
+    }
+}
@@ -92,4 +92,8 @@ impl CommandRunner for MigrateCommandPayload {
     fn should_validate_configuration_diagnostics(&self) -> bool {
         false
     }
+
+    fn should_traverse(&self) -> bool {
+        false
+    }
 }
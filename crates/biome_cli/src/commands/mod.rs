@@ -1,7 +1,8 @@
-use crate::changed::{get_changed_files, get_staged_files};
+use crate::changed::{get_changed_files, get_changed_lines, get_staged_files, ChangedLineRanges};
 use crate::cli_options::{cli_options, CliOptions, CliReporter, ColorsArg};
+use crate::commands::rules::RuleLanguage;
 use crate::diagnostics::{DeprecatedArgument, DeprecatedConfigurationFile};
-use crate::execute::Stdin;
+use crate::execute::{settings_hash, BaselineOptions, Stdin, DEFAULT_BASELINE_FILE};
 use crate::logging::LoggingKind;
 use crate::{
     execute_mode, setup_cli_subscriber, CliDiagnostic, CliSession, Execution, LoggingLevel, VERSION,
@@ -25,18 +26,22 @@ use biome_console::{markup, Console, ConsoleExt};
 use biome_diagnostics::{Diagnostic, PrintDiagnostic};
 use biome_fs::{BiomePath, FileSystem};
 use biome_service::configuration::{
-    load_configuration, load_editorconfig, LoadedConfiguration, PartialConfigurationExt,
+    find_nested_configuration_files, load_configuration, load_editorconfig,
+    load_nested_configuration, LoadedConfiguration, PartialConfigurationExt,
 };
 use biome_service::documentation::Doc;
-use biome_service::workspace::{FixFileMode, RegisterProjectFolderParams, UpdateSettingsParams};
+use biome_service::workspace::{
+    FixFileMode, PluginFile, RegisterProjectFolderParams, UpdateSettingsParams, VcsIgnoreFile,
+};
 use biome_service::{DynRef, Workspace, WorkspaceError};
 use bpaf::Bpaf;
 use std::ffi::OsString;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 pub(crate) mod check;
 pub(crate) mod ci;
 pub(crate) mod clean;
+pub(crate) mod config;
 pub(crate) mod daemon;
 pub(crate) mod explain;
 pub(crate) mod format;
@@ -44,6 +49,7 @@ pub(crate) mod init;
 pub(crate) mod lint;
 pub(crate) mod migrate;
 pub(crate) mod rage;
+pub(crate) mod rules;
 pub(crate) mod search;
 pub(crate) mod version;
 
@@ -176,6 +182,42 @@ pub enum BiomeCommand {
         #[bpaf(long("since"), argument("REF"))]
         since: Option<String>,
 
+        /// When set to true, only the diagnostics of the lines that have been changed compared to the
+        /// base of `--changed` are reported, and only these lines are formatted. It requires `--changed`.
+        #[bpaf(long("changed-lines-only"), switch)]
+        changed_lines_only: bool,
+
+        /// Reuse the results of previous runs for the files that didn't change since then.
+        /// The results are stored in Biome's cache directory, and they are discarded when the
+        /// configuration or the version of Biome changes.
+        #[bpaf(long("cache"), switch)]
+        cache: bool,
+
+        /// Suppresses the lint diagnostics recorded in the given baseline file, and reports the
+        /// entries of the baseline that don't occur anymore.
+        #[bpaf(long("baseline"), argument("PATH"))]
+        baseline: Option<PathBuf>,
+
+        /// Records the current lint diagnostics in the baseline file, instead of reporting them.
+        /// The file is `biome-baseline.json`, unless `--baseline` is provided.
+        #[bpaf(long("write-baseline"), switch)]
+        write_baseline: bool,
+
+        /// Keeps running, and processes the files again when they change.
+        /// Only the files that changed are processed, and the results are reported after each change.
+        #[bpaf(long("watch"), switch)]
+        watch: bool,
+
+        /// Prints the changes as unified diffs, which can be applied with `git apply`, instead of
+        /// writing them to the files. It requires `--write` or `--fix`.
+        #[bpaf(long("diff"), switch)]
+        diff: bool,
+
+        /// Measures the time spent in each rule, and prints the rules that took the most time.
+        /// With `--reporter=json`, the timings of all the rules are part of the report instead.
+        #[bpaf(long("profile-rules"), switch)]
+        profile_rules: bool,
+
         /// Single file, single path or list of paths
         #[bpaf(positional("PATH"), many)]
         paths: Vec<OsString>,
@@ -269,6 +311,37 @@ pub enum BiomeCommand {
         /// flag and the `defaultBranch` is not set in your biome.json
         #[bpaf(long("since"), argument("REF"))]
         since: Option<String>,
+
+        /// When set to true, only the diagnostics of the lines that have been changed compared to the
+        /// base of `--changed` are reported. It requires `--changed`.
+        #[bpaf(long("changed-lines-only"), switch)]
+        changed_lines_only: bool,
+        /// Reuse the results of previous runs for the files that didn't change since then.
+        /// The results are stored in Biome's cache directory, and they are discarded when the
+        /// configuration or the version of Biome changes.
+        #[bpaf(long("cache"), switch)]
+        cache: bool,
+        /// Suppresses the lint diagnostics recorded in the given baseline file, and reports the
+        /// entries of the baseline that don't occur anymore.
+        #[bpaf(long("baseline"), argument("PATH"))]
+        baseline: Option<PathBuf>,
+        /// Records the current lint diagnostics in the baseline file, instead of reporting them.
+        /// The file is `biome-baseline.json`, unless `--baseline` is provided.
+        #[bpaf(long("write-baseline"), switch)]
+        write_baseline: bool,
+        /// Keeps running, and processes the files again when they change.
+        /// Only the files that changed are processed, and the results are reported after each change.
+        #[bpaf(long("watch"), switch)]
+        watch: bool,
+        /// Prints the changes as unified diffs, which can be applied with `git apply`, instead of
+        /// writing them to the files. It requires `--write` or `--fix`.
+        #[bpaf(long("diff"), switch)]
+        diff: bool,
+
+        /// Measures the time spent in each rule, and prints the rules that took the most time.
+        /// With `--reporter=json`, the timings of all the rules are part of the report instead.
+        #[bpaf(long("profile-rules"), switch)]
+        profile_rules: bool,
         /// Single file, single path or list of paths
         #[bpaf(positional("PATH"), many)]
         paths: Vec<OsString>,
@@ -330,6 +403,27 @@ pub enum BiomeCommand {
         #[bpaf(long("since"), argument("REF"))]
         since: Option<String>,
 
+        /// When set to true, only the lines that have been changed compared to the base of `--changed`
+        /// are formatted. It requires `--changed`.
+        #[bpaf(long("changed-lines-only"), switch)]
+        changed_lines_only: bool,
+
+        /// Reuse the results of previous runs for the files that didn't change since then.
+        /// The results are stored in Biome's cache directory, and they are discarded when the
+        /// configuration or the version of Biome changes.
+        #[bpaf(long("cache"), switch)]
+        cache: bool,
+
+        /// Keeps running, and processes the files again when they change.
+        /// Only the files that changed are processed, and the results are reported after each change.
+        #[bpaf(long("watch"), switch)]
+        watch: bool,
+
+        /// Prints the formatting changes as unified diffs, which can be applied with `git apply`,
+        /// instead of writing them to the files.
+        #[bpaf(long("diff"), switch)]
+        diff: bool,
+
         /// Single file, single path or list of paths.
         #[bpaf(positional("PATH"), many)]
         paths: Vec<OsString>,
@@ -368,6 +462,17 @@ pub enum BiomeCommand {
         #[bpaf(long("since"), argument("REF"))]
         since: Option<String>,
 
+        /// When set to true, only the diagnostics of the lines that have been changed compared to the
+        /// base of `--changed` are reported, and only these lines are checked for formatting. It
+        /// requires `--changed`.
+        #[bpaf(long("changed-lines-only"), switch)]
+        changed_lines_only: bool,
+
+        /// Measures the time spent in each rule, and prints the rules that took the most time.
+        /// With `--reporter=json`, the timings of all the rules are part of the report instead.
+        #[bpaf(long("profile-rules"), switch)]
+        profile_rules: bool,
+
         /// Single file, single path or list of paths
         #[bpaf(positional("PATH"), many)]
         paths: Vec<OsString>,
@@ -375,11 +480,17 @@ pub enum BiomeCommand {
 
     /// Bootstraps a new biome project. Creates a configuration file with some defaults.
     #[bpaf(command)]
-    Init(
+    Init {
         /// Tells Biome to emit a `biome.jsonc` file.
         #[bpaf(long("jsonc"), switch)]
-        bool,
-    ),
+        emit_jsonc: bool,
+
+        /// Inspects the project to propose a configuration tailored to it: the dependencies
+        /// of `package.json`, `tsconfig.json`, the file extensions, the build outputs,
+        /// the Git repository and the configurations of other tools.
+        #[bpaf(long("detect"), switch)]
+        detect: bool,
+    },
     /// Acts as a server for the Language Server Protocol over stdin/stdout.
     #[bpaf(command("lsp-proxy"))]
     LspProxy {
@@ -438,6 +549,7 @@ pub enum BiomeCommand {
     ///
     /// ```shell
     /// biome search '`console.log($message)`' # find all `console.log` invocations
+    /// biome search --write '`var $name = $value` => `let $name = $value`' # rewrite all `var` declarations
     /// ```
     #[bpaf(command)]
     Search {
@@ -461,9 +573,12 @@ pub enum BiomeCommand {
         #[bpaf(long("stdin-file-path"), argument("PATH"), hide_usage)]
         stdin_file_path: Option<String>,
 
+        /// Writes the rewrites of the pattern to the files. Without this
+        /// option, the rewrites are printed as a diff.
+        #[bpaf(long("write"), switch)]
+        write: bool,
+
         /// The GritQL pattern to search for.
-        ///
-        /// Note that the search command (currently) does not support rewrites.
         #[bpaf(positional("PATTERN"))]
         pattern: String,
 
@@ -490,8 +605,44 @@ pub enum BiomeCommand {
         doc: Doc,
     },
 
+    /// Shows the metadata of the lint rules.
+    ///
+    /// ## Examples
+    ///
+    /// ```shell
+    /// biome rules list --language=css
+    /// ```
+    ///
+    /// ```shell
+    /// biome rules list --source=eslint-plugin-unicorn --json
+    /// ```
+    #[bpaf(command)]
+    Rules {
+        #[bpaf(external(rules_sub_command))]
+        sub_command: RulesSubCommand,
+    },
+
+    /// Shows the settings that Biome uses for a file: the formatter options, the enabled lint
+    /// rules with their severity and options, whether the file is ignored and where the
+    /// settings come from.
+    ///
+    /// ## Examples
+    ///
+    /// ```shell
+    /// biome config print src/index.ts
+    /// ```
+    ///
+    /// ```shell
+    /// biome config print --json packages/app/package.json
+    /// ```
+    #[bpaf(command)]
+    Config {
+        #[bpaf(external(config_sub_command))]
+        sub_command: ConfigSubCommand,
+    },
+
     #[bpaf(command)]
-    /// Cleans the logs emitted by the daemon.
+    /// Cleans the logs emitted by the daemon and the cache written by `--cache`.
     Clean,
 
     #[bpaf(command("__run_server"), hide)]
@@ -542,6 +693,72 @@ pub enum MigrateSubCommand {
         #[bpaf(long("include-nursery"))]
         include_nursery: bool,
     },
+    /// It attempts to find the files `dprint.json`/`.dprint.jsonc`, and map the dprint's configuration into Biome's configuration file.
+    #[bpaf(command)]
+    Dprint,
+    /// It attempts to find the `.editorconfig` file, and map its formatting options into Biome's configuration file.
+    #[bpaf(command)]
+    Editorconfig,
+    /// It attempts to find the Stylelint configuration file in the working directory, and update the Biome's configuration file as a result.
+    #[bpaf(command)]
+    Stylelint {
+        /// Includes nursery rules in the migration
+        #[bpaf(long("include-nursery"))]
+        include_nursery: bool,
+    },
+}
+
+#[derive(Debug, Bpaf, Clone)]
+pub enum RulesSubCommand {
+    /// Lists the lint rules of all the languages, with their group, language, fix kind,
+    /// version and the rules they were ported from.
+    #[bpaf(command)]
+    List {
+        /// Prints the rules as JSON
+        #[bpaf(long("json"), switch)]
+        json: bool,
+        /// Only lists the rules of this language: `js`, `jsx`, `ts`, `json`, `css` or `graphql`
+        #[bpaf(long("language"), argument("LANGUAGE"))]
+        language: Option<RuleLanguage>,
+        /// Only lists the rules of this group, e.g. `suspicious`
+        #[bpaf(long("group"), argument("GROUP"))]
+        group: Option<String>,
+        /// Only lists the rules ported from this source, e.g. `eslint-plugin-unicorn`
+        #[bpaf(long("source"), argument("SOURCE"))]
+        source: Option<String>,
+    },
+}
+
+#[derive(Debug, Bpaf, Clone)]
+pub enum ConfigSubCommand {
+    /// Prints the settings resolved from the configuration files, the nested configurations
+    /// and the overrides for a file.
+    #[bpaf(command)]
+    Print {
+        #[bpaf(external(partial_formatter_configuration), optional, hide_usage)]
+        formatter_configuration: Option<PartialFormatterConfiguration>,
+
+        #[bpaf(external(partial_javascript_formatter), optional, hide_usage)]
+        javascript_formatter: Option<PartialJavascriptFormatter>,
+
+        #[bpaf(external(partial_json_formatter), optional, hide_usage)]
+        json_formatter: Option<PartialJsonFormatter>,
+
+        #[bpaf(external(partial_css_formatter), optional, hide_usage, hide)]
+        css_formatter: Option<PartialCssFormatter>,
+
+        #[bpaf(external(partial_graphql_formatter), optional, hide_usage, hide)]
+        graphql_formatter: Option<PartialGraphqlFormatter>,
+
+        #[bpaf(external, hide_usage)]
+        cli_options: CliOptions,
+        /// Prints the settings as JSON
+        #[bpaf(long("json"), switch)]
+        json: bool,
+        /// The path of the file
+        #[bpaf(positional("PATH"))]
+        path: OsString,
+    },
 }
 
 impl MigrateSubCommand {
@@ -560,12 +777,16 @@ impl BiomeCommand {
             | BiomeCommand::Ci { cli_options, .. }
             | BiomeCommand::Format { cli_options, .. }
             | BiomeCommand::Migrate { cli_options, .. }
-            | BiomeCommand::Search { cli_options, .. } => Some(cli_options),
+            | BiomeCommand::Search { cli_options, .. }
+            | BiomeCommand::Config {
+                sub_command: ConfigSubCommand::Print { cli_options, .. },
+            } => Some(cli_options),
             BiomeCommand::LspProxy { .. }
             | BiomeCommand::Start { .. }
             | BiomeCommand::Stop
-            | BiomeCommand::Init(_)
+            | BiomeCommand::Init { .. }
             | BiomeCommand::Explain { .. }
+            | BiomeCommand::Rules { .. }
             | BiomeCommand::RunServer { .. }
             | BiomeCommand::Clean { .. }
             | BiomeCommand::PrintSocket => None,
@@ -678,6 +899,22 @@ fn resolve_manifest(
     Ok(None)
 }
 
+/// Computes the [BaselineOptions] from the arguments `--baseline` and `--write-baseline`
+fn get_baseline_options(
+    baseline: Option<&PathBuf>,
+    write_baseline: bool,
+) -> Option<BaselineOptions> {
+    if baseline.is_none() && !write_baseline {
+        return None;
+    }
+    Some(BaselineOptions {
+        path: baseline
+            .cloned()
+            .unwrap_or_else(|| PathBuf::from(DEFAULT_BASELINE_FILE)),
+        write: write_baseline,
+    })
+}
+
 fn get_files_to_process_with_cli_options(
     since: Option<&str>,
     changed: bool,
@@ -706,6 +943,20 @@ fn get_files_to_process_with_cli_options(
     }
 }
 
+/// Computes the changed lines when `--changed-lines-only` is used, which requires `--changed`
+fn get_changed_lines_with_cli_options(
+    changed_lines_only: bool,
+    since: Option<&str>,
+    fs: &DynRef<'_, dyn FileSystem>,
+    configuration: &PartialConfiguration,
+) -> Result<Option<ChangedLineRanges>, CliDiagnostic> {
+    if changed_lines_only {
+        Ok(Some(get_changed_lines(fs, configuration, since)?))
+    } else {
+        Ok(None)
+    }
+}
+
 /// Holds the options to determine the fix file mode.
 pub(crate) struct FixFileModeOptions {
     apply: bool,
@@ -833,6 +1084,18 @@ pub(crate) trait CommandRunner: Sized {
         let console = &mut *session.app.console;
         let workspace = &*session.app.workspace;
         self.check_incompatible_arguments()?;
+        if self.should_watch() && self.get_stdin_file_path().is_some() {
+            return Err(CliDiagnostic::incompatible_arguments(
+                "stdin-file-path",
+                "watch",
+            ));
+        }
+        if self.should_print_diff() && self.get_stdin_file_path().is_some() {
+            return Err(CliDiagnostic::incompatible_arguments(
+                "stdin-file-path",
+                "diff",
+            ));
+        }
         let (execution, paths) = self.configure_workspace(fs, console, workspace, cli_options)?;
         execute_mode(execution, session, cli_options, paths)
     }
@@ -863,30 +1126,170 @@ pub(crate) trait CommandRunner: Sized {
         let configuration_path = loaded_configuration.directory_path.clone();
         let configuration = self.merge_configuration(loaded_configuration, fs, console)?;
         let vcs_base_path = configuration_path.or(fs.working_directory());
+        let project_path = vcs_base_path.clone().unwrap_or_default();
+        let mut plugins = configuration
+            .retrieve_plugins(fs, vcs_base_path.as_deref().unwrap_or(Path::new("")))?;
         let (vcs_base_path, gitignore_matches) =
             configuration.retrieve_gitignore_matches(fs, vcs_base_path.as_deref())?;
         let paths = self.get_files_to_process(fs, &configuration)?;
+        let changed_lines = self.get_changed_lines(fs, &configuration)?;
         workspace.register_project_folder(RegisterProjectFolderParams {
             path: fs.working_directory(),
             set_as_current_workspace: true,
         })?;
 
         let manifest_data = resolve_manifest(fs)?;
+        let manifest_content = manifest_data.as_ref().map(|(_, content)| content.clone());
 
         if let Some(manifest_data) = manifest_data {
             workspace.set_manifest_for_project(manifest_data.into())?;
         }
+        let mut execution = self.get_execution(cli_options, console, workspace)?;
+        let traversal_roots = self.get_traversal_roots(fs, &execution, &paths);
+        let ignore_files = match vcs_base_path.as_deref() {
+            Some(vcs_base_path) => configuration.retrieve_vcs_ignore_files(fs, vcs_base_path)?,
+            None => Vec::new(),
+        };
+        if let Some(loader) = vcs_base_path
+            .as_deref()
+            .and_then(|vcs_base_path| configuration.vcs_ignore_file_loader(vcs_base_path))
+        {
+            execution = execution.with_vcs_ignore_files(loader);
+        }
         workspace.update_settings(UpdateSettingsParams {
             workspace_directory: fs.working_directory(),
-            configuration,
-            vcs_base_path,
-            gitignore_matches,
+            configuration: configuration.clone(),
+            vcs_base_path: vcs_base_path.clone(),
+            gitignore_matches: gitignore_matches.clone(),
+            nested: false,
+            ignore_files: ignore_files.clone(),
+            plugins: plugins.clone(),
         })?;
 
-        let execution = self.get_execution(cli_options, console, workspace)?;
+        let nested_configurations = self.update_nested_settings(
+            fs,
+            console,
+            workspace,
+            cli_options,
+            &execution,
+            &traversal_roots,
+            vcs_base_path,
+            gitignore_matches,
+            ignore_files,
+            &mut plugins,
+        )?;
+        let requires_module_graph = configuration.requires_module_graph(execution.only())
+            || nested_configurations
+                .iter()
+                .any(|(_, configuration)| configuration.requires_module_graph(execution.only()));
+        if requires_module_graph {
+            execution = execution.with_module_graph(project_path);
+        }
+        // The results of the rules that analyze the other files of the project can't be cached
+        // per file
+        if self.should_use_cache(&configuration) && !requires_module_graph {
+            execution = execution.with_cache(settings_hash(
+                &configuration,
+                &nested_configurations,
+                &plugins,
+                manifest_content.as_deref(),
+            ));
+        }
+        if self.should_watch() {
+            execution = execution.with_watch();
+        }
+        if self.should_print_diff() {
+            execution = execution.with_diff();
+        }
+        if let Some(changed_lines) = changed_lines {
+            execution = execution.with_changed_lines(changed_lines);
+        }
+        if self.should_profile_rules() {
+            execution = execution.with_profile_rules();
+        }
         Ok((execution, paths))
     }
 
+    /// Returns the paths that the command traverses, joined to the working directory
+    fn get_traversal_roots(
+        &self,
+        fs: &DynRef<'_, dyn FileSystem>,
+        execution: &Execution,
+        paths: &[OsString],
+    ) -> Vec<PathBuf> {
+        let working_directory = fs.working_directory().unwrap_or_default();
+        if !self.should_traverse() {
+            Vec::new()
+        } else if let Some(stdin_file_path) = self.get_stdin_file_path() {
+            vec![working_directory.join(stdin_file_path)]
+        } else if paths.is_empty() {
+            if execution.is_vcs_targeted() {
+                Vec::new()
+            } else {
+                vec![working_directory]
+            }
+        } else {
+            paths
+                .iter()
+                .map(|path| working_directory.join(path))
+                .collect()
+        }
+    }
+
+    /// Loads the configuration files of the subdirectories of the paths to process, and registers
+    /// their settings in the workspace. The arguments passed to the CLI take precedence over them.
+    ///
+    /// It returns the configurations by directory, and adds their plugins to `plugins`.
+    #[expect(clippy::too_many_arguments)]
+    fn update_nested_settings(
+        &mut self,
+        fs: &DynRef<'_, dyn FileSystem>,
+        console: &mut dyn Console,
+        workspace: &dyn Workspace,
+        cli_options: &CliOptions,
+        execution: &Execution,
+        paths: &[PathBuf],
+        vcs_base_path: Option<PathBuf>,
+        gitignore_matches: Vec<String>,
+        ignore_files: Vec<VcsIgnoreFile>,
+        plugins: &mut Vec<PluginFile>,
+    ) -> Result<Vec<(PathBuf, PartialConfiguration)>, CliDiagnostic> {
+        let root = fs.working_directory().unwrap_or_default();
+        let configuration_files =
+            find_nested_configuration_files(&**fs, workspace, execution.to_feature(), &root, paths);
+
+        let mut nested_configurations = Vec::with_capacity(configuration_files.len());
+        for configuration_file in configuration_files {
+            let loaded_configuration = load_nested_configuration(fs, &configuration_file)?;
+            if self.should_validate_configuration_diagnostics() {
+                validate_configuration_diagnostics(
+                    &loaded_configuration,
+                    console,
+                    cli_options.verbose,
+                )?;
+            }
+            let directory = loaded_configuration
+                .directory_path
+                .clone()
+                .unwrap_or_default();
+            let configuration = self.merge_configuration(loaded_configuration, fs, console)?;
+            let nested_plugins = configuration.retrieve_plugins(fs, &directory)?;
+            workspace.update_settings(UpdateSettingsParams {
+                workspace_directory: Some(directory.clone()),
+                configuration: configuration.clone(),
+                vcs_base_path: vcs_base_path.clone(),
+                gitignore_matches: gitignore_matches.clone(),
+                nested: true,
+                ignore_files: ignore_files.clone(),
+                plugins: nested_plugins.clone(),
+            })?;
+            plugins.extend(nested_plugins);
+            nested_configurations.push((directory, configuration));
+        }
+
+        Ok(nested_configurations)
+    }
+
     /// Computes [Stdin] if the CLI has the necessary information.
     ///
     /// ## Errors
@@ -954,6 +1357,41 @@ pub(crate) trait CommandRunner: Sized {
     fn should_validate_configuration_diagnostics(&self) -> bool {
         true
     }
+
+    /// Whether the command traverses the paths to process. The configuration files and the
+    /// ignore files of their subdirectories are loaded only in this case.
+    fn should_traverse(&self) -> bool {
+        true
+    }
+
+    /// Whether the results of the traversal should be read from and stored in the on-disk cache.
+    fn should_use_cache(&self, _configuration: &PartialConfiguration) -> bool {
+        false
+    }
+
+    /// Whether the command should keep running, and process the files again when they change.
+    fn should_watch(&self) -> bool {
+        false
+    }
+
+    /// Whether the changes should be printed as unified diffs, instead of being written.
+    fn should_print_diff(&self) -> bool {
+        false
+    }
+
+    /// Whether the time spent in each rule should be measured and reported.
+    fn should_profile_rules(&self) -> bool {
+        false
+    }
+
+    /// Computes the lines the diagnostics and the formatting should be restricted to.
+    fn get_changed_lines(
+        &self,
+        _fs: &DynRef<'_, dyn FileSystem>,
+        _configuration: &PartialConfiguration,
+    ) -> Result<Option<ChangedLineRanges>, CliDiagnostic> {
+        Ok(None)
+    }
 }
 
 pub trait LoadEditorConfig: CommandRunner {
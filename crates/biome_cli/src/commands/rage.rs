@@ -182,14 +182,25 @@ impl Display for RageConfiguration<'_, '_> {
 
         match load_configuration(self.fs, ConfigurationPathHint::default()) {
             Ok(loaded_configuration) => {
-                if loaded_configuration.directory_path.is_none() {
+                if loaded_configuration.file_path.is_none() {
                     KeyValuePair("Status", markup!(<Dim>"unset"</Dim>)).fmt(fmt)?;
                 } else {
                     let LoadedConfiguration {
                         configuration,
                         diagnostics,
+                        file_path,
+                        is_user_configuration,
                         ..
                     } = loaded_configuration;
+                    if is_user_configuration {
+                        if let Some(file_path) = file_path {
+                            KeyValuePair(
+                                "User configuration",
+                                markup!({ file_path.display().to_string() }),
+                            )
+                            .fmt(fmt)?;
+                        }
+                    }
                     let status = if !diagnostics.is_empty() {
                         for diagnostic in diagnostics {
                             (markup! {
@@ -0,0 +1,194 @@
+use biome_analyze::{FixKind, RuleSourceKind};
+use biome_console::fmt::{Display, Formatter};
+use biome_console::{markup, Console, ConsoleExt};
+use biome_service::documentation::{lint_rules_metadata, LintRuleMetadata};
+use serde::Serialize;
+use std::str::FromStr;
+
+use crate::commands::RulesSubCommand;
+use crate::{CliDiagnostic, CliSession};
+
+/// The languages of the lint rules, see [biome_analyze::RuleMetadata::language]
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum RuleLanguage {
+    Js,
+    Jsx,
+    Ts,
+    Json,
+    Css,
+    Graphql,
+}
+
+impl RuleLanguage {
+    const fn as_str(self) -> &'static str {
+        match self {
+            Self::Js => "js",
+            Self::Jsx => "jsx",
+            Self::Ts => "ts",
+            Self::Json => "json",
+            Self::Css => "css",
+            Self::Graphql => "graphql",
+        }
+    }
+}
+
+impl FromStr for RuleLanguage {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "js" => Ok(Self::Js),
+            "jsx" => Ok(Self::Jsx),
+            "ts" => Ok(Self::Ts),
+            "json" => Ok(Self::Json),
+            "css" => Ok(Self::Css),
+            "graphql" => Ok(Self::Graphql),
+            _ => Err(format!(
+                "value {s:?} is not a language of the rules, expected one of js, jsx, ts, json, css or graphql"
+            )),
+        }
+    }
+}
+
+pub(crate) fn rules(
+    session: CliSession,
+    sub_command: RulesSubCommand,
+) -> Result<(), CliDiagnostic> {
+    match sub_command {
+        RulesSubCommand::List {
+            json,
+            language,
+            group,
+            source,
+        } => {
+            let rules: Vec<_> = lint_rules_metadata()
+                .iter()
+                .filter(|rule| {
+                    language.map_or(true, |language| rule.metadata.language == language.as_str())
+                })
+                .filter(|rule| group.as_deref().map_or(true, |group| rule.group == group))
+                .filter(|rule| {
+                    source.as_deref().map_or(true, |source| {
+                        rule.metadata
+                            .sources
+                            .iter()
+                            .any(|rule_source| rule_source.to_string().eq_ignore_ascii_case(source))
+                    })
+                })
+                .map(RuleDescription::from_rule)
+                .collect();
+
+            if json {
+                session.app.console.log(markup! {{RulesList { rules }}});
+            } else {
+                for rule in rules {
+                    print_rule(session.app.console, &rule);
+                }
+            }
+            Ok(())
+        }
+    }
+}
+
+fn print_rule(console: &mut dyn Console, rule: &RuleDescription) {
+    let fix = match rule.fix_kind {
+        FixKind::None => "none",
+        FixKind::Safe => "safe",
+        FixKind::Unsafe => "unsafe",
+    };
+    let sources = rule
+        .sources
+        .iter()
+        .map(|source| format!("{} ({})", source.rule, source.source))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    console.log(markup! {
+        <Emphasis>{rule.group}"/"{rule.name}</Emphasis>"\n"
+        "  language     "{rule.language}"\n"
+        "  recommended  "{if rule.recommended { "yes" } else { "no" }}"\n"
+        "  fix          "{fix}"\n"
+        "  version      "{rule.version}
+    });
+    if let Some(deprecated) = rule.deprecated {
+        console.log(markup! {
+            "  deprecated   "{deprecated}
+        });
+    }
+    if !sources.is_empty() {
+        let kind = if rule.source_kind.is_some_and(|kind| kind.is_inspired()) {
+            " (inspired)"
+        } else {
+            ""
+        };
+        console.log(markup! {
+            "  sources      "{sources}{kind}
+        });
+    }
+}
+
+/// The list printed by `biome rules list --json`
+#[derive(Serialize)]
+struct RulesList {
+    rules: Vec<RuleDescription>,
+}
+
+impl Display for RulesList {
+    fn fmt(&self, fmt: &mut Formatter) -> std::io::Result<()> {
+        let content = serde_json::to_string(&self)?;
+        fmt.write_str(content.as_str())
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct RuleDescription {
+    group: &'static str,
+    name: &'static str,
+    language: &'static str,
+    recommended: bool,
+    fix_kind: FixKind,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    deprecated: Option<&'static str>,
+    /// The version of Biome that added the rule, `next` for the unreleased rules
+    version: &'static str,
+    sources: Vec<RuleSourceDescription>,
+    /// Whether the rule implements the same logic as its sources
+    #[serde(skip_serializing_if = "Option::is_none")]
+    source_kind: Option<RuleSourceKind>,
+}
+
+impl RuleDescription {
+    fn from_rule(rule: &LintRuleMetadata) -> Self {
+        let LintRuleMetadata { group, metadata } = rule;
+        Self {
+            group,
+            name: metadata.name,
+            language: metadata.language,
+            recommended: metadata.recommended,
+            fix_kind: metadata.fix_kind,
+            deprecated: metadata.deprecated,
+            version: metadata.version,
+            sources: metadata
+                .sources
+                .iter()
+                .map(|source| RuleSourceDescription {
+                    source: source.to_string(),
+                    rule: source.to_namespaced_rule_name(),
+                    url: source.to_rule_url(),
+                })
+                .collect(),
+            source_kind: (!metadata.sources.is_empty())
+                .then(|| metadata.source_kind.unwrap_or_default()),
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct RuleSourceDescription {
+    /// The linter or the plugin of the rule, e.g. `eslint-plugin-unicorn`
+    source: String,
+    /// The name of the rule, with the prefix of its plugin
+    rule: String,
+    url: String,
+}
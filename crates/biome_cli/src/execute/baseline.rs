@@ -0,0 +1,259 @@
+use crate::execute::diagnostics::{
+    BaselineWrittenAdvice, BaselineWrittenDiagnostic, UnusedBaselineEntriesAdvice,
+    UnusedBaselineEntriesDiagnostic,
+};
+use crate::execute::Execution;
+use crate::CliDiagnostic;
+use biome_diagnostics::Error;
+use biome_fs::{BiomePath, FileSystem, FileSystemExt, OpenOptions};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::io;
+use std::path::{Component, Path, PathBuf};
+use std::sync::Mutex;
+
+/// The file used by `--write-baseline` when `--baseline` isn't provided
+pub(crate) const DEFAULT_BASELINE_FILE: &str = "biome-baseline.json";
+
+/// How the baseline should be used during the traversal
+#[derive(Debug, Clone)]
+pub(crate) struct BaselineOptions {
+    /// The path to the baseline file
+    pub(crate) path: PathBuf,
+    /// Whether the diagnostics should be recorded in the baseline, instead of being suppressed by it
+    pub(crate) write: bool,
+}
+
+/// The content of a baseline file
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct BaselineFile {
+    /// The entries of each file, by path relative to the working directory
+    files: BTreeMap<String, Vec<BaselineEntry>>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct BaselineEntry {
+    /// The category of the diagnostic, e.g. `lint/suspicious/noDebugger`
+    rule: String,
+    /// The hash of the code reported by the diagnostic
+    fingerprint: String,
+    /// The number of diagnostics with the same rule and fingerprint in the file
+    count: u32,
+}
+
+/// The occurrences of each rule and fingerprint in a file
+type FileEntries = BTreeMap<(String, String), u32>;
+
+/// Diagnostics that existed when the baseline was written.
+///
+/// A diagnostic is identified by its file, its rule and the code it reports, so that
+/// the entries still match when the code around them moves.
+pub(crate) struct Baseline {
+    options: BaselineOptions,
+    working_directory: Option<PathBuf>,
+    /// When reading, the occurrences that weren't matched yet.
+    /// When writing, the occurrences recorded so far.
+    files: Mutex<BTreeMap<String, FileEntries>>,
+    /// The entries of the files that weren't processed, kept when the baseline is written
+    previous_files: BTreeMap<String, FileEntries>,
+}
+
+impl Baseline {
+    /// Loads the baseline for the given execution, if the baseline was enabled
+    pub(crate) fn load(
+        fs: &dyn FileSystem,
+        execution: &Execution,
+    ) -> Result<Option<Self>, CliDiagnostic> {
+        let Some(options) = execution.baseline() else {
+            return Ok(None);
+        };
+
+        let content = fs
+            .open_with_options(&options.path, OpenOptions::default().read(true))
+            .and_then(|mut file| {
+                let mut content = String::new();
+                file.read_to_string(&mut content)?;
+                Ok(content)
+            });
+        let previous_files = match content {
+            Ok(content) => {
+                let baseline_file: BaselineFile =
+                    serde_json::from_str(&content).map_err(io::Error::from)?;
+                baseline_file
+                    .files
+                    .into_iter()
+                    .map(|(path, entries)| {
+                        let entries = entries
+                            .into_iter()
+                            .map(|entry| ((entry.rule, entry.fingerprint), entry.count))
+                            .collect();
+                        (path, entries)
+                    })
+                    .collect()
+            }
+            // The baseline is created the first time it's written
+            Err(error) if options.write && error.kind() == io::ErrorKind::NotFound => {
+                BTreeMap::new()
+            }
+            Err(error) => return Err(CliDiagnostic::io_error(error)),
+        };
+
+        let (files, previous_files) = if options.write {
+            (BTreeMap::new(), previous_files)
+        } else {
+            (previous_files, BTreeMap::new())
+        };
+
+        Ok(Some(Self {
+            options: options.clone(),
+            working_directory: fs.working_directory(),
+            files: Mutex::new(files),
+            previous_files,
+        }))
+    }
+
+    /// Returns `true` if the diagnostic belongs to the baseline, in which case it shouldn't be reported.
+    ///
+    /// When the baseline is written, all the lint diagnostics are recorded.
+    pub(crate) fn contains(&self, file_name: &str, content: &str, diagnostic: &Error) -> bool {
+        let Some(category) = diagnostic.category() else {
+            return false;
+        };
+        if !category.name().starts_with("lint/") {
+            return false;
+        }
+
+        let code = diagnostic
+            .location()
+            .span
+            .and_then(|span| content.get(span.start().into()..span.end().into()))
+            .unwrap_or_default();
+        let key = (category.name().to_string(), fingerprint(code));
+
+        let mut files = self.files.lock().unwrap();
+        if self.options.write {
+            *files
+                .entry(self.relative_path(file_name))
+                .or_default()
+                .entry(key)
+                .or_default() += 1;
+            return true;
+        }
+
+        match files
+            .get_mut(&self.relative_path(file_name))
+            .and_then(|entries| entries.get_mut(&key))
+        {
+            Some(count) if *count > 0 => {
+                *count -= 1;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Writes the baseline when requested, otherwise reports the entries of the processed
+    /// files that didn't match any diagnostic.
+    pub(crate) fn finish(
+        &self,
+        fs: &dyn FileSystem,
+        evaluated_paths: &BTreeSet<BiomePath>,
+    ) -> Result<Option<Error>, CliDiagnostic> {
+        let evaluated_paths: BTreeSet<_> = evaluated_paths
+            .iter()
+            .map(|path| self.relative_path(&path.display().to_string()))
+            .collect();
+        let file_name = self.options.path.display().to_string();
+        let files = std::mem::take(&mut *self.files.lock().unwrap());
+
+        if self.options.write {
+            let mut all_files = self.previous_files.clone();
+            all_files.retain(|path, _| !evaluated_paths.contains(path));
+            all_files.extend(files);
+
+            let diagnostics = all_files
+                .values()
+                .flat_map(|entries| entries.values())
+                .sum();
+            let baseline_file = BaselineFile {
+                files: all_files
+                    .into_iter()
+                    .map(|(path, entries)| {
+                        let entries = entries
+                            .into_iter()
+                            .map(|((rule, fingerprint), count)| BaselineEntry {
+                                rule,
+                                fingerprint,
+                                count,
+                            })
+                            .collect();
+                        (path, entries)
+                    })
+                    .collect(),
+            };
+            let files = baseline_file.files.len();
+            let content = serde_json::to_string_pretty(&baseline_file).map_err(io::Error::from)?;
+            fs.create(&self.options.path)
+                .and_then(|mut file| file.set_content(content.as_bytes()))
+                .map_err(CliDiagnostic::io_error)?;
+
+            return Ok(Some(Error::from(BaselineWrittenDiagnostic {
+                file_name,
+                advice: BaselineWrittenAdvice { diagnostics, files },
+            })));
+        }
+
+        let entries: Vec<_> = files
+            .into_iter()
+            .filter(|(path, _)| evaluated_paths.contains(path))
+            .flat_map(|(path, entries)| {
+                entries
+                    .into_iter()
+                    .filter(|(_, count)| *count > 0)
+                    .map(move |((rule, _), count)| format!("{path}: {rule} ({count})"))
+            })
+            .collect();
+        if entries.is_empty() {
+            return Ok(None);
+        }
+
+        Ok(Some(Error::from(UnusedBaselineEntriesDiagnostic {
+            file_name,
+            advice: UnusedBaselineEntriesAdvice { entries },
+        })))
+    }
+
+    /// The path of the file relative to the working directory, with forward slashes
+    fn relative_path(&self, file_name: &str) -> String {
+        let path = Path::new(file_name);
+        let path = self
+            .working_directory
+            .as_deref()
+            .and_then(|working_directory| path.strip_prefix(working_directory).ok())
+            .unwrap_or(path);
+        path.components()
+            .filter(|component| !matches!(component, Component::CurDir))
+            .map(|component| component.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/")
+    }
+}
+
+/// Hashes the code reported by a diagnostic, ignoring the differences of whitespaces.
+///
+/// It uses the 64-bit FNV-1a hash, which stays the same across platforms and versions of
+/// Rust, since the baseline is usually committed.
+fn fingerprint(code: &str) -> String {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for (index, word) in code.split_whitespace().enumerate() {
+        if index > 0 {
+            hash = (hash ^ u64::from(b' ')).wrapping_mul(0x0100_0000_01b3);
+        }
+        for byte in word.bytes() {
+            hash = (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+    format!("{hash:016x}")
+}
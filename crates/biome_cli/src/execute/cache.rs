@@ -0,0 +1,342 @@
+use crate::execute::process_file::{DiffKind, Message};
+use crate::execute::Execution;
+use crate::VERSION;
+use biome_configuration::PartialConfiguration;
+use biome_diagnostics::serde::Diagnostic;
+use biome_diagnostics::Error;
+use biome_fs::{BiomePath, FileSystem, FileSystemExt, OpenOptions};
+use biome_service::workspace::PluginFile;
+use rustc_hash::FxHasher;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::hash::{Hash, Hasher};
+use std::io;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Mutex, RwLock};
+
+/// The directory, inside Biome's cache directory, where the results of the traversals are stored
+pub(crate) fn cache_directory() -> PathBuf {
+    biome_fs::ensure_cache_dir().join("biome-cache")
+}
+
+/// Version of the format of the cache files, hashed with their content.
+///
+/// It must be bumped when the hashed values or the way they're hashed change,
+/// so the results stored with the previous hashes are discarded.
+const CACHE_VERSION: u32 = 1;
+
+/// Returns a hasher whose hashes are stable across runs, unlike the ones of [std::hash::DefaultHasher]
+fn stable_hasher() -> FxHasher {
+    let mut hasher = FxHasher::default();
+    CACHE_VERSION.hash(&mut hasher);
+    hasher
+}
+
+/// Computes the hash of the settings resolved from the configuration, the nested configurations,
+/// their plugins and the manifest of the project.
+///
+/// A cached result is only valid for the settings used to compute it.
+pub(crate) fn settings_hash(
+    configuration: &PartialConfiguration,
+    nested_configurations: &[(PathBuf, PartialConfiguration)],
+    plugins: &[PluginFile],
+    manifest: Option<&str>,
+) -> u64 {
+    let mut hasher = stable_hasher();
+    // The configuration doesn't implement `Hash`, its serialized form is hashed instead
+    serde_json::to_string(configuration)
+        .unwrap_or_default()
+        .hash(&mut hasher);
+    for (directory, configuration) in nested_configurations {
+        directory.hash(&mut hasher);
+        serde_json::to_string(configuration)
+            .unwrap_or_default()
+            .hash(&mut hasher);
+    }
+    for plugin in plugins {
+        plugin.path.hash(&mut hasher);
+        plugin.content.hash(&mut hasher);
+    }
+    manifest.hash(&mut hasher);
+    hasher.finish()
+}
+
+fn content_hash(content: &str) -> u64 {
+    let mut hasher = stable_hasher();
+    content.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// The content of a cache file
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct CacheFile {
+    /// The version of Biome that wrote the file
+    version: String,
+    /// The hash of the settings and of the traversal options used to compute the results
+    settings: u64,
+    files: BTreeMap<String, CachedFile>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct CachedFile {
+    content_hash: u64,
+    messages: Vec<CachedMessage>,
+}
+
+/// Serializable version of [Message]. The content of the file isn't stored,
+/// it's the same content that is read when the message is replayed.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+enum CachedMessage {
+    SkippedFixes {
+        skipped_suggested_fixes: u32,
+    },
+    Failure,
+    Error(Diagnostic),
+    Diagnostics {
+        name: String,
+        diagnostics: Vec<Diagnostic>,
+    },
+    Diff {
+        file_name: String,
+        new: String,
+        diff_kind: DiffKind,
+    },
+}
+
+impl CachedMessage {
+    fn into_message(self, content: &str) -> Message {
+        match self {
+            Self::SkippedFixes {
+                skipped_suggested_fixes,
+            } => Message::SkippedFixes {
+                skipped_suggested_fixes,
+            },
+            Self::Failure => Message::Failure,
+            Self::Error(diagnostic) => Message::Error(Error::from(diagnostic)),
+            Self::Diagnostics { name, diagnostics } => Message::Diagnostics {
+                name,
+                content: content.to_string(),
+                diagnostics: diagnostics.into_iter().map(Error::from).collect(),
+                skipped_diagnostics: 0,
+            },
+            Self::Diff {
+                file_name,
+                new,
+                diff_kind,
+            } => Message::Diff {
+                file_name,
+                old: content.to_string(),
+                new,
+                diff_kind,
+            },
+        }
+    }
+}
+
+/// Results of the previous traversals, stored in Biome's cache directory.
+///
+/// The results are stored per command and per working directory. They are discarded
+/// when the settings, the traversal options or the version of Biome change.
+pub(crate) struct TraversalCache {
+    path: PathBuf,
+    settings: u64,
+    files: RwLock<BTreeMap<String, CachedFile>>,
+    has_changes: AtomicBool,
+}
+
+/// The result of [TraversalCache::lookup]
+pub(crate) enum CacheLookup {
+    /// The file didn't change, these are the messages emitted when it was processed
+    Hit(Vec<Message>),
+    /// The file must be processed, its messages should be recorded
+    Miss(CacheRecorder),
+}
+
+impl TraversalCache {
+    /// Loads the cache for the given execution, if the cache was enabled
+    pub(crate) fn load(fs: &dyn FileSystem, execution: &Execution) -> Option<Self> {
+        let settings_hash = execution.cache()?;
+        // The formatting of a file depends on its changed lines, which aren't part of its content
+        if execution.changed_lines().is_some() {
+            return None;
+        }
+
+        let mut hasher = stable_hasher();
+        settings_hash.hash(&mut hasher);
+        // Options such as `--only`, `--skip` or `--write` change the results too
+        format!("{:?}", execution.traversal_mode()).hash(&mut hasher);
+        let settings = hasher.finish();
+
+        let mut hasher = stable_hasher();
+        fs.working_directory().hash(&mut hasher);
+        execution.as_diagnostic_category().name().hash(&mut hasher);
+        let path = cache_directory().join(format!("{:016x}.json", hasher.finish()));
+
+        let files = fs
+            .open_with_options(&path, OpenOptions::default().read(true))
+            .and_then(|mut file| {
+                let mut content = String::new();
+                file.read_to_string(&mut content)?;
+                Ok(content)
+            })
+            .ok()
+            .and_then(|content| serde_json::from_str::<CacheFile>(&content).ok())
+            .filter(|cache_file| cache_file.version == VERSION && cache_file.settings == settings)
+            .map(|cache_file| cache_file.files)
+            .unwrap_or_default();
+
+        Some(Self {
+            path,
+            settings,
+            files: RwLock::new(files),
+            has_changes: AtomicBool::new(false),
+        })
+    }
+
+    /// Reads the file and checks whether its results are stored in the cache.
+    ///
+    /// It returns [None] if the file can't be read, its errors are emitted when it's processed.
+    pub(crate) fn lookup(&self, fs: &dyn FileSystem, path: &BiomePath) -> Option<CacheLookup> {
+        let mut file = fs
+            .open_with_options(path, OpenOptions::default().read(true))
+            .ok()?;
+        let mut content = String::new();
+        file.read_to_string(&mut content).ok()?;
+
+        let key = path.display().to_string();
+        let content_hash = content_hash(&content);
+        let files = self.files.read().unwrap();
+        let lookup = match files.get(&key) {
+            Some(cached) if cached.content_hash == content_hash => CacheLookup::Hit(
+                cached
+                    .messages
+                    .iter()
+                    .cloned()
+                    .map(|message| message.into_message(&content))
+                    .collect(),
+            ),
+            _ => CacheLookup::Miss(CacheRecorder {
+                key,
+                content_hash,
+                messages: Mutex::new(Some(Vec::new())),
+            }),
+        };
+        Some(lookup)
+    }
+
+    /// Stores the messages recorded while processing a file
+    pub(crate) fn store(&self, recorder: CacheRecorder) {
+        let Some(messages) = recorder.messages.into_inner().unwrap() else {
+            return;
+        };
+        self.files.write().unwrap().insert(
+            recorder.key,
+            CachedFile {
+                content_hash: recorder.content_hash,
+                messages,
+            },
+        );
+        self.has_changes.store(true, Ordering::Relaxed);
+    }
+
+    /// Writes the cache to disk, if some results were stored
+    pub(crate) fn save(self, fs: &dyn FileSystem) -> io::Result<()> {
+        if !self.has_changes.load(Ordering::Relaxed) {
+            return Ok(());
+        }
+
+        if let Some(directory) = self.path.parent() {
+            fs.create_dir_all(directory)?;
+        }
+        let cache_file = CacheFile {
+            version: VERSION.to_string(),
+            settings: self.settings,
+            files: self.files.into_inner().unwrap(),
+        };
+        let content = serde_json::to_string(&cache_file)?;
+        fs.create(&self.path)?.set_content(content.as_bytes())
+    }
+}
+
+/// Records the messages emitted while processing a file, so they can be stored in the cache
+pub(crate) struct CacheRecorder {
+    key: String,
+    content_hash: u64,
+    /// It's [None] when the messages are incomplete and can't be cached
+    messages: Mutex<Option<Vec<CachedMessage>>>,
+}
+
+impl CacheRecorder {
+    /// Records a copy of the message, and returns a message that can be sent to the display thread
+    pub(crate) fn record(&self, message: Message) -> Message {
+        let mut messages = self.messages.lock().unwrap();
+        let (cached, message) = match message {
+            Message::SkippedFixes {
+                skipped_suggested_fixes,
+            } => (
+                Some(CachedMessage::SkippedFixes {
+                    skipped_suggested_fixes,
+                }),
+                message,
+            ),
+            Message::Failure => (Some(CachedMessage::Failure), message),
+            Message::Error(error) => {
+                let diagnostic = Diagnostic::new(error);
+                (
+                    Some(CachedMessage::Error(diagnostic.clone())),
+                    Message::Error(Error::from(diagnostic)),
+                )
+            }
+            Message::Diagnostics {
+                name,
+                content,
+                diagnostics,
+                skipped_diagnostics,
+            } => {
+                let diagnostics: Vec<_> = diagnostics.into_iter().map(Diagnostic::new).collect();
+                // Some diagnostics weren't pulled because of `--max-diagnostics`
+                let cached = (skipped_diagnostics == 0).then(|| CachedMessage::Diagnostics {
+                    name: name.clone(),
+                    diagnostics: diagnostics.clone(),
+                });
+                (
+                    cached,
+                    Message::Diagnostics {
+                        name,
+                        content,
+                        diagnostics: diagnostics.into_iter().map(Error::from).collect(),
+                        skipped_diagnostics,
+                    },
+                )
+            }
+            Message::Diff {
+                ref file_name,
+                ref new,
+                ref diff_kind,
+                ..
+            } => (
+                Some(CachedMessage::Diff {
+                    file_name: file_name.clone(),
+                    new: new.clone(),
+                    diff_kind: diff_kind.clone(),
+                }),
+                message,
+            ),
+        };
+
+        match cached {
+            Some(cached) => {
+                if let Some(messages) = messages.as_mut() {
+                    messages.push(cached);
+                }
+            }
+            None => *messages = None,
+        }
+
+        message
+    }
+}
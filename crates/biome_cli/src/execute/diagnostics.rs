@@ -1,6 +1,7 @@
+use biome_console::markup;
 use biome_diagnostics::adapters::{IoError, StdError};
 use biome_diagnostics::{
-    Advices, Category, Diagnostic, DiagnosticExt, DiagnosticTags, Error, Visit,
+    Advices, Category, Diagnostic, DiagnosticExt, DiagnosticTags, Error, LogCategory, Visit,
 };
 use biome_text_edit::TextEdit;
 use std::io;
@@ -80,6 +81,19 @@ pub(crate) struct AssistsDiffDiagnostic {
     pub(crate) diff: ContentDiffAdvice,
 }
 
+#[derive(Debug, Diagnostic)]
+#[diagnostic(
+    category = "search/rewrite",
+    severity = Information,
+    message = "The pattern would have rewritten the following content:"
+)]
+pub(crate) struct RewriteDiffDiagnostic {
+    #[location(resource)]
+    pub(crate) file_name: String,
+    #[advice]
+    pub(crate) diff: ContentDiffAdvice,
+}
+
 #[derive(Debug, Diagnostic)]
 #[diagnostic(
 	category = "migrate",
@@ -106,6 +120,72 @@ impl Advices for ContentDiffAdvice {
     }
 }
 
+#[derive(Debug, Diagnostic)]
+#[diagnostic(
+    category = "baseline",
+    severity = Information,
+    message = "The baseline was written."
+)]
+pub(crate) struct BaselineWrittenDiagnostic {
+    #[location(resource)]
+    pub(crate) file_name: String,
+    #[advice]
+    pub(crate) advice: BaselineWrittenAdvice,
+}
+
+#[derive(Debug)]
+pub(crate) struct BaselineWrittenAdvice {
+    pub(crate) diagnostics: u32,
+    pub(crate) files: usize,
+}
+
+impl Advices for BaselineWrittenAdvice {
+    fn record(&self, visitor: &mut dyn Visit) -> io::Result<()> {
+        visitor.record_log(
+            LogCategory::Info,
+            &markup! {
+                "It contains "{self.diagnostics}" diagnostic(s) from "{self.files}" file(s)."
+            },
+        )
+    }
+}
+
+#[derive(Debug, Diagnostic)]
+#[diagnostic(
+    category = "baseline",
+    severity = Information,
+    message = "Some entries of the baseline don't occur anymore."
+)]
+pub(crate) struct UnusedBaselineEntriesDiagnostic {
+    #[location(resource)]
+    pub(crate) file_name: String,
+    #[advice]
+    pub(crate) advice: UnusedBaselineEntriesAdvice,
+}
+
+#[derive(Debug)]
+pub(crate) struct UnusedBaselineEntriesAdvice {
+    /// The file and the rule of each entry
+    pub(crate) entries: Vec<String>,
+}
+
+impl Advices for UnusedBaselineEntriesAdvice {
+    fn record(&self, visitor: &mut dyn Visit) -> io::Result<()> {
+        let entries: Vec<_> = self
+            .entries
+            .iter()
+            .map(|entry| entry as &dyn biome_console::fmt::Display)
+            .collect();
+        visitor.record_list(&entries)?;
+        visitor.record_log(
+            LogCategory::Info,
+            &markup! {
+                "Run the command with "<Emphasis>"--write-baseline"</Emphasis>" to remove them from the baseline."
+            },
+        )
+    }
+}
+
 #[derive(Debug, Diagnostic)]
 #[diagnostic(category = "internalError/panic", tags(INTERNAL))]
 pub(crate) struct PanicDiagnostic {
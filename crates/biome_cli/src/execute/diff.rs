@@ -0,0 +1,55 @@
+use biome_console::{markup, Console, ConsoleExt};
+use biome_text_edit::TextEdit;
+use std::path::{Component, Path};
+
+/// The number of unchanged lines printed around the changes, like `diff -u` and `git diff` do
+const CONTEXT_LINES: usize = 3;
+
+/// The changes of a file, printed as a unified diff with `--diff`
+pub(crate) struct UnifiedDiff {
+    file_name: String,
+    /// The hunks of the diff, without the header
+    hunks: String,
+}
+
+impl UnifiedDiff {
+    pub(crate) fn new(file_name: String, old: &str, new: &str) -> Self {
+        let hunks = TextEdit::from_unicode_words(old, new).unified_diff(old, CONTEXT_LINES);
+        Self { file_name, hunks }
+    }
+}
+
+/// Prints the diffs ordered by file name, with the paths relative to the working directory,
+/// so that the output can be applied with `git apply`.
+pub(crate) fn print_diffs(
+    console: &mut dyn Console,
+    working_directory: Option<&Path>,
+    mut diffs: Vec<UnifiedDiff>,
+) {
+    diffs.sort_unstable_by(|left, right| left.file_name.cmp(&right.file_name));
+    for diff in diffs {
+        if diff.hunks.is_empty() {
+            continue;
+        }
+        let path = relative_path(working_directory, Path::new(&diff.file_name));
+        let patch = format!(
+            "diff --git a/{path} b/{path}\n--- a/{path}\n+++ b/{path}\n{}",
+            diff.hunks
+        );
+        // The console adds the last line break
+        let patch = patch.strip_suffix('\n').unwrap_or(&patch);
+        console.log(markup! {{patch}});
+    }
+}
+
+/// The path of the file relative to the working directory, with forward slashes
+fn relative_path(working_directory: Option<&Path>, path: &Path) -> String {
+    let path = working_directory
+        .and_then(|working_directory| path.strip_prefix(working_directory).ok())
+        .unwrap_or(path);
+    path.components()
+        .filter(|component| !matches!(component, Component::CurDir))
+        .map(|component| component.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/")
+}
@@ -3,22 +3,28 @@ use crate::diagnostics::MigrationDiagnostic;
 use crate::execute::diagnostics::{ContentDiffAdvice, MigrateDiffDiagnostic};
 use crate::{CliDiagnostic, CliSession};
 use biome_configuration::PartialConfiguration;
-use biome_console::{markup, ConsoleExt};
+use biome_console::{markup, Console, ConsoleExt};
 use biome_deserialize::json::deserialize_from_json_ast;
 use biome_deserialize::Merge;
 use biome_diagnostics::Diagnostic;
 use biome_diagnostics::{category, PrintDiagnostic};
-use biome_formatter::ParseFormatNumberError;
-use biome_fs::{BiomePath, ConfigName, FileSystemExt, OpenOptions};
-use biome_json_parser::{parse_json_with_cache, JsonParserOptions};
+use biome_formatter::{IndentStyle, IndentWidth, ParseFormatNumberError};
+use biome_fs::{BiomePath, ConfigName, File, FileSystem, FileSystemExt, OpenOptions};
+use biome_json_formatter::context::JsonFormatOptions;
+use biome_json_parser::{parse_json, parse_json_with_cache, JsonParserOptions};
 use biome_json_syntax::{JsonFileSource, JsonRoot};
 use biome_migrate::{migrate_configuration, ControlFlow};
-use biome_rowan::{AstNode, NodeCache};
-use biome_service::workspace::{ChangeFileParams, FixAction, FormatFileParams, OpenFileParams};
+use biome_rowan::{AstNode, AstSeparatedList, NodeCache, TextRange};
+use biome_service::configuration::load_editorconfig;
+use biome_service::workspace::{
+    ChangeFileParams, FixAction, FormatFileParams, OpenFileParams, Workspace,
+};
 use std::borrow::Cow;
 use std::ffi::OsStr;
-use std::path::PathBuf;
+use std::io;
+use std::path::{Path, PathBuf};
 
+mod dprint;
 mod eslint;
 mod eslint_any_rule_to_biome;
 mod eslint_eslint;
@@ -29,6 +35,9 @@ mod eslint_unicorn;
 mod ignorefile;
 mod node;
 mod prettier;
+mod stylelint;
+mod stylelint_any_rule_to_biome;
+mod stylelint_to_biome;
 
 pub(crate) struct MigratePayload<'a> {
     pub(crate) session: CliSession<'a>,
@@ -53,15 +62,18 @@ pub(crate) fn run(migrate_payload: MigratePayload) -> Result<(), CliDiagnostic>
     let console = session.app.console;
     let workspace = session.app.workspace;
 
-    let open_options = if write {
-        OpenOptions::default().read(true).write(true)
-    } else {
-        OpenOptions::default().read(true)
+    // The file isn't kept open, since the migrations of Prettier and ESLint can read
+    // the `package.json` file that contains the configuration
+    let open_biome_config_file = || {
+        let open_options = if write {
+            OpenOptions::default().read(true).write(true)
+        } else {
+            OpenOptions::default().read(true)
+        };
+        fs.open_with_options(configuration_file_path.as_path(), open_options)
     };
-    let mut biome_config_file =
-        fs.open_with_options(configuration_file_path.as_path(), open_options)?;
     let mut biome_config_content = String::new();
-    biome_config_file.read_to_string(&mut biome_config_content)?;
+    open_biome_config_file()?.read_to_string(&mut biome_config_content)?;
 
     let biome_path = BiomePath::new(configuration_file_path.as_path());
     workspace.open_file(OpenFileParams {
@@ -70,8 +82,12 @@ pub(crate) fn run(migrate_payload: MigratePayload) -> Result<(), CliDiagnostic>
         version: 0,
         document_file_source: Some(JsonFileSource::json().into()),
     })?;
+    let configuration_range =
+        package_json_configuration_range(&configuration_file_path, &biome_config_content);
     let parsed = parse_json_with_cache(
-        &biome_config_content,
+        configuration_range.map_or(biome_config_content.as_str(), |range| {
+            &biome_config_content[range]
+        }),
         &mut cache,
         JsonParserOptions::default(),
     );
@@ -117,42 +133,21 @@ pub(crate) fn run(migrate_payload: MigratePayload) -> Result<(), CliDiagnostic>
                     });
                 }
             }
-            if biome_config == old_biome_config {
-                console.log(markup! {
-                    <Info>"No changes to apply to the Biome configuration file."</Info>
-                });
-            } else {
-                let new_content = serde_json::to_string(&biome_config).map_err(|err| {
-                    CliDiagnostic::MigrateError(MigrationDiagnostic {
-                        reason: err.to_string(),
-                    })
-                })?;
-                workspace.change_file(ChangeFileParams {
-                    path: biome_path.clone(),
-                    content: new_content,
-                    version: 1,
-                })?;
-                let printed = workspace.format_file(FormatFileParams { path: biome_path })?;
-                if write {
-                    biome_config_file.set_content(printed.as_code().as_bytes())?;
-                    console.log(markup!{
-                        <Info><Emphasis>{prettier_path}</Emphasis>" has been successfully migrated."</Info>
-                    });
-                } else {
-                    let file_name = configuration_file_path.display().to_string();
-                    let diagnostic = MigrateDiffDiagnostic {
-                        file_name,
-                        diff: ContentDiffAdvice {
-                            old: biome_config_content,
-                            new: printed.as_code().to_string(),
-                        },
-                    };
-                    console.error(markup! {{PrintDiagnostic::simple(&diagnostic)}});
-                    console.log(markup! {
-                        <Info>"Run the command with the option "<Emphasis>"--write"</Emphasis>" to apply the changes."</Info>
-                    })
-                }
-            }
+            write_migrated_configuration(
+                &*workspace,
+                console,
+                MigratedConfiguration {
+                    biome_path,
+                    file_path: &configuration_file_path,
+                    content: biome_config_content,
+                    range: configuration_range,
+                    old: &old_biome_config,
+                    new: &biome_config,
+                    source_path: prettier_path,
+                },
+                write,
+                open_biome_config_file,
+            )?;
         }
         Some(MigrateSubCommand::Eslint {
             include_inspired,
@@ -194,45 +189,182 @@ pub(crate) fn run(migrate_payload: MigratePayload) -> Result<(), CliDiagnostic>
                     });
                 }
             }
-            if biome_config == old_biome_config {
+            write_migrated_configuration(
+                &*workspace,
+                console,
+                MigratedConfiguration {
+                    biome_path,
+                    file_path: &configuration_file_path,
+                    content: biome_config_content,
+                    range: configuration_range,
+                    old: &old_biome_config,
+                    new: &biome_config,
+                    source_path: eslint_path,
+                },
+                write,
+                open_biome_config_file,
+            )?;
+            if results.has_inspired_rules {
                 console.log(markup! {
-                    <Info>"No changes to apply to the Biome configuration file."</Info>
-                });
-            } else {
-                let new_content = serde_json::to_string(&biome_config).map_err(|err| {
+                    <Info>"Run the command with the option "<Emphasis>"--include-inspired"</Emphasis>" to also migrate inspired rules."</Info>
+                })
+            }
+        }
+        Some(MigrateSubCommand::Dprint) => {
+            let dprint::Config {
+                path: dprint_path,
+                data: dprint_config,
+                mut unsupported_options,
+            } = dprint::read_config_file(fs, console)?;
+            let biome_config =
+                deserialize_from_json_ast::<PartialConfiguration>(&parsed.tree(), "")
+                    .into_deserialized();
+            let Some(mut biome_config) = biome_config else {
+                return Ok(());
+            };
+            let old_biome_config = biome_config.clone();
+            let dprint_biome_config = dprint_config
+                .try_into_biome_config(&mut unsupported_options)
+                .map_err(|err: ParseFormatNumberError| {
                     CliDiagnostic::MigrateError(MigrationDiagnostic {
                         reason: err.to_string(),
                     })
                 })?;
-                workspace.change_file(ChangeFileParams {
-                    path: biome_path.clone(),
-                    content: new_content,
-                    version: 1,
-                })?;
-                let printed = workspace.format_file(FormatFileParams { path: biome_path })?;
-                if write {
-                    biome_config_file.set_content(printed.as_code().as_bytes())?;
-                    console.log(markup!{
-                        <Info><Emphasis>{eslint_path}</Emphasis>" has been successfully migrated."</Info>
-                    });
-                } else {
-                    let file_name = configuration_file_path.display().to_string();
-                    let diagnostic = MigrateDiffDiagnostic {
-                        file_name,
-                        diff: ContentDiffAdvice {
-                            old: biome_config_content,
-                            new: printed.as_code().to_string(),
-                        },
-                    };
-                    console.error(markup! {{PrintDiagnostic::simple(&diagnostic)}});
+            biome_config.merge_with(dprint_biome_config);
+            write_migrated_configuration(
+                &*workspace,
+                console,
+                MigratedConfiguration {
+                    biome_path,
+                    file_path: &configuration_file_path,
+                    content: biome_config_content,
+                    range: configuration_range,
+                    old: &old_biome_config,
+                    new: &biome_config,
+                    source_path: dprint_path,
+                },
+                write,
+                open_biome_config_file,
+            )?;
+            if !unsupported_options.is_empty() {
+                let unsupported_options = unsupported_options
+                    .into_iter()
+                    .map(|option| format!("- {option}"))
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                console.log(markup! {
+                    <Warn>"The following dprint options don't have an equivalent in Biome and were not migrated:\n"{unsupported_options}</Warn>
+                })
+            }
+        }
+        Some(MigrateSubCommand::Editorconfig) => {
+            let (editorconfig, editorconfig_diagnostics) =
+                load_editorconfig(fs, fs.working_directory().unwrap_or_default())?;
+            for diagnostic in editorconfig_diagnostics {
+                console.error(markup! {
+                    {PrintDiagnostic::simple(&diagnostic)}
+                })
+            }
+            let Some(mut editorconfig) = editorconfig else {
+                return Err(CliDiagnostic::MigrateError(MigrationDiagnostic {
+                    reason: "Biome couldn't find an .editorconfig file.".to_string(),
+                }));
+            };
+            let biome_config =
+                deserialize_from_json_ast::<PartialConfiguration>(&parsed.tree(), "")
+                    .into_deserialized();
+            let Some(mut biome_config) = biome_config else {
+                return Ok(());
+            };
+            // `.editorconfig` files without sections other than `[*]` produce no overrides
+            if editorconfig
+                .overrides
+                .as_ref()
+                .is_some_and(|overrides| overrides.0.is_empty())
+            {
+                editorconfig.overrides = None;
+            }
+            let old_biome_config = biome_config.clone();
+            biome_config.merge_with(editorconfig);
+            write_migrated_configuration(
+                &*workspace,
+                console,
+                MigratedConfiguration {
+                    biome_path,
+                    file_path: &configuration_file_path,
+                    content: biome_config_content,
+                    range: configuration_range,
+                    old: &old_biome_config,
+                    new: &biome_config,
+                    source_path: ".editorconfig",
+                },
+                write,
+                open_biome_config_file,
+            )?;
+        }
+        Some(MigrateSubCommand::Stylelint { include_nursery }) => {
+            let stylelint::Config {
+                path: stylelint_path,
+                data: stylelint_config,
+            } = stylelint::read_stylelint_config(fs, console)?;
+            let biome_config =
+                deserialize_from_json_ast::<PartialConfiguration>(&parsed.tree(), "")
+                    .into_deserialized();
+            let Some(mut biome_config) = biome_config else {
+                return Ok(());
+            };
+            let (biome_stylelint_config, results) = stylelint_config
+                .into_biome_config(&stylelint_to_biome::MigrationOptions { include_nursery });
+            let old_biome_config = biome_config.clone();
+            biome_config.merge_with(biome_stylelint_config);
+            if let Ok(ignore_patterns) = ignorefile::read_ignore_file(fs, stylelint::IGNORE_FILE) {
+                if !ignore_patterns.patterns.is_empty() {
+                    biome_config
+                        .linter
+                        .get_or_insert(Default::default())
+                        .ignore
+                        .get_or_insert(Default::default())
+                        .extend(ignore_patterns.patterns);
+                }
+                if ignore_patterns.has_negated_patterns {
                     console.log(markup! {
-                        <Info>"Run the command with the option "<Emphasis>"--write"</Emphasis>" to apply the changes."</Info>
+                        <Warn><Emphasis>{stylelint::IGNORE_FILE}</Emphasis>" contains negated glob patterns that start with "<Emphasis>"!"</Emphasis>".\nThese patterns cannot be migrated because Biome doesn't support them."</Warn>
                     })
+                } else if write && biome_config != old_biome_config {
+                    console.log(markup!{
+                        <Info><Emphasis>{stylelint::IGNORE_FILE}</Emphasis>" has been successfully migrated."</Info>
+                    });
                 }
             }
-            if results.has_inspired_rules {
+            write_migrated_configuration(
+                &*workspace,
+                console,
+                MigratedConfiguration {
+                    biome_path,
+                    file_path: &configuration_file_path,
+                    content: biome_config_content,
+                    range: configuration_range,
+                    old: &old_biome_config,
+                    new: &biome_config,
+                    source_path: stylelint_path,
+                },
+                write,
+                open_biome_config_file,
+            )?;
+            if !results.unsupported_rules.is_empty() {
+                let unsupported_rules = results
+                    .unsupported_rules
+                    .into_iter()
+                    .map(|rule| format!("- {rule}"))
+                    .collect::<Vec<_>>()
+                    .join("\n");
                 console.log(markup! {
-                    <Info>"Run the command with the option "<Emphasis>"--include-inspired"</Emphasis>" to also migrate inspired rules."</Info>
+                    <Warn>"The following Stylelint rules don't have an equivalent Biome rule and were not migrated:\n"{unsupported_rules}</Warn>
+                })
+            }
+            if results.has_nursery_rules {
+                console.log(markup! {
+                    <Info>"Run the command with the option "<Emphasis>"--include-nursery"</Emphasis>" to also migrate nursery rules."</Info>
                 })
             }
         }
@@ -284,7 +416,11 @@ pub(crate) fn run(migrate_payload: MigratePayload) -> Result<(), CliDiagnostic>
                 }
             }
 
-            let new_configuration_content = tree.to_string();
+            let new_configuration_content = replace_configuration(
+                &biome_config_content,
+                configuration_range,
+                &tree.to_string(),
+            );
             if biome_config_content != new_configuration_content || has_deprecated_configuration {
                 if write {
                     let mut configuration_file = if has_deprecated_configuration {
@@ -292,9 +428,9 @@ pub(crate) fn run(migrate_payload: MigratePayload) -> Result<(), CliDiagnostic>
                             configuration_directory_path.join(ConfigName::biome_json());
                         fs.create_new(biome_file_path.as_path())?
                     } else {
-                        biome_config_file
+                        open_biome_config_file()?
                     };
-                    configuration_file.set_content(tree.to_string().as_bytes())?;
+                    configuration_file.set_content(new_configuration_content.as_bytes())?;
                     console.log(markup!{
                             <Info>"The configuration "<Emphasis>{{configuration_file_path.display().to_string()}}</Emphasis>" has been successfully migrated."</Info>
                         })
@@ -339,3 +475,201 @@ pub(crate) fn run(migrate_payload: MigratePayload) -> Result<(), CliDiagnostic>
     }
     Ok(())
 }
+
+/// A configuration migrated from the configuration file of another tool
+struct MigratedConfiguration<'a> {
+    /// The path of the Biome configuration file, opened in the workspace
+    biome_path: BiomePath,
+    file_path: &'a Path,
+    /// The content of the Biome configuration file before the migration
+    content: String,
+    /// The range of the configuration when it's embedded in a `package.json` file
+    range: Option<TextRange>,
+    old: &'a PartialConfiguration,
+    new: &'a PartialConfiguration,
+    /// The path of the migrated configuration file
+    source_path: &'a str,
+}
+
+/// Writes the migrated configuration to the Biome configuration file, or prints the difference
+/// with the current configuration when `write` is `false`.
+fn write_migrated_configuration(
+    workspace: &dyn Workspace,
+    console: &mut dyn Console,
+    configuration: MigratedConfiguration,
+    write: bool,
+    open_biome_config_file: impl Fn() -> io::Result<Box<dyn File>>,
+) -> Result<(), CliDiagnostic> {
+    let MigratedConfiguration {
+        biome_path,
+        file_path,
+        content,
+        range,
+        old,
+        new,
+        source_path,
+    } = configuration;
+    if new == old {
+        console.log(markup! {
+            <Info>"No changes to apply to the Biome configuration file."</Info>
+        });
+        return Ok(());
+    }
+    let new_configuration = serde_json::to_string(new).map_err(|err| {
+        CliDiagnostic::MigrateError(MigrationDiagnostic {
+            reason: err.to_string(),
+        })
+    })?;
+    let new_content = match range {
+        // Only the configuration is formatted, the rest of the `package.json` file is left untouched
+        Some(range) => replace_configuration(
+            &content,
+            Some(range),
+            &format_package_json_configuration(&content, range, &new_configuration)?,
+        ),
+        None => {
+            workspace.change_file(ChangeFileParams {
+                path: biome_path.clone(),
+                content: new_configuration,
+                version: 1,
+            })?;
+            workspace
+                .format_file(FormatFileParams { path: biome_path })?
+                .into_code()
+        }
+    };
+    if write {
+        open_biome_config_file()?.set_content(new_content.as_bytes())?;
+        console.log(markup! {
+            <Info><Emphasis>{source_path}</Emphasis>" has been successfully migrated."</Info>
+        });
+    } else {
+        let file_name = file_path.display().to_string();
+        let diagnostic = MigrateDiffDiagnostic {
+            file_name,
+            diff: ContentDiffAdvice {
+                old: content,
+                new: new_content,
+            },
+        };
+        console.error(markup! {{PrintDiagnostic::simple(&diagnostic)}});
+        console.log(markup! {
+            <Info>"Run the command with the option "<Emphasis>"--write"</Emphasis>" to apply the changes."</Info>
+        })
+    }
+    Ok(())
+}
+
+/// Formats the configuration embedded in a `package.json` file, with the indentation
+/// of the `biome` member that contains it.
+fn format_package_json_configuration(
+    configuration_file_content: &str,
+    configuration_range: TextRange,
+    configuration: &str,
+) -> Result<String, CliDiagnostic> {
+    let start = usize::from(configuration_range.start());
+    let line_start = configuration_file_content[..start]
+        .rfind('\n')
+        .map_or(0, |index| index + 1);
+    let indentation: String = configuration_file_content[line_start..start]
+        .chars()
+        .take_while(|c| *c == ' ' || *c == '\t')
+        .collect();
+    let options = if indentation.starts_with('\t') {
+        JsonFormatOptions::default().with_indent_style(IndentStyle::Tab)
+    } else {
+        JsonFormatOptions::default()
+            .with_indent_style(IndentStyle::Space)
+            .with_indent_width(
+                u8::try_from(indentation.len())
+                    .ok()
+                    .and_then(|width| IndentWidth::try_from(width).ok())
+                    .filter(|width| width.value() > 0)
+                    .unwrap_or_default(),
+            )
+    };
+    let parsed = parse_json(configuration, JsonParserOptions::default());
+    let to_migration_error =
+        |reason: String| CliDiagnostic::MigrateError(MigrationDiagnostic { reason });
+    let printed = biome_json_formatter::format_node(options, &parsed.syntax())
+        .map_err(|err| to_migration_error(err.to_string()))?
+        .print()
+        .map_err(|err| to_migration_error(err.to_string()))?;
+    let line_ending = if configuration_file_content.contains("\r\n") {
+        "\r\n"
+    } else {
+        "\n"
+    };
+    Ok(printed
+        .as_code()
+        .trim_end()
+        .lines()
+        .collect::<Vec<_>>()
+        .join(&format!("{line_ending}{indentation}")))
+}
+
+/// Returns the tools that have a configuration file in the working directory, named after
+/// the subcommand of `biome migrate` that migrates their configuration.
+pub(crate) fn detect_migratable_tools(fs: &dyn FileSystem) -> Vec<&'static str> {
+    let tools: [(&'static str, &[&str]); 5] = [
+        ("eslint", &eslint::FLAT_CONFIG_FILES),
+        ("eslint", &eslint::LEGACY_CONFIG_FILES),
+        ("prettier", &prettier::CONFIG_FILES),
+        ("stylelint", &stylelint::CONFIG_FILES),
+        ("dprint", &dprint::CONFIG_FILES),
+    ];
+    let mut detected = tools
+        .into_iter()
+        .filter(|(_, config_files)| {
+            config_files.iter().any(|config_file| {
+                // The files loaded via Node.js are prefixed with `./`
+                fs.path_exists(Path::new(config_file.trim_start_matches("./")))
+            })
+        })
+        .map(|(tool, _)| tool)
+        .collect::<Vec<_>>();
+    detected.dedup();
+    detected
+}
+
+/// Returns the range of the value of the `biome` key when the configuration file is a
+/// `package.json` file, since the other keys of the file must be left untouched.
+fn package_json_configuration_range(
+    configuration_file_path: &Path,
+    configuration_content: &str,
+) -> Option<TextRange> {
+    if configuration_file_path.file_name() != Some(OsStr::new(ConfigName::package_json())) {
+        return None;
+    }
+    let parsed = parse_json(configuration_content, JsonParserOptions::default());
+    let object = parsed.tree().value().ok()?;
+    let object = object.as_json_object_value()?;
+    object
+        .json_member_list()
+        .iter()
+        .flatten()
+        .find(|member| {
+            member
+                .name()
+                .and_then(|name| name.inner_string_text())
+                .is_ok_and(|name| name.text() == "biome")
+        })
+        .and_then(|member| member.value().ok())
+        .map(|value| value.range())
+}
+
+/// Replaces the configuration in the content of the configuration file, see [package_json_configuration_range]
+fn replace_configuration(
+    configuration_file_content: &str,
+    configuration_range: Option<TextRange>,
+    configuration: &str,
+) -> String {
+    match configuration_range {
+        Some(range) => {
+            let mut content = configuration_file_content.to_string();
+            content.replace_range(std::ops::Range::<usize>::from(range), configuration);
+            content
+        }
+        None => configuration.to_string(),
+    }
+}
@@ -0,0 +1,537 @@
+use crate::diagnostics::MigrationDiagnostic;
+use crate::CliDiagnostic;
+use biome_console::{markup, Console, ConsoleExt};
+use biome_deserialize::json::deserialize_from_json_str;
+use biome_deserialize::{Deserializable, DeserializableType, DeserializableValue, StringSet};
+use biome_deserialize_macros::Deserializable;
+use biome_diagnostics::{DiagnosticExt, PrintDiagnostic};
+use biome_formatter::{
+    BracketSpacing, IndentStyle, IndentWidth, LineEnding, LineWidth, ParseFormatNumberError,
+};
+use biome_fs::{FileSystem, OpenOptions};
+use biome_html_formatter::context::SelfCloseVoidElements;
+use biome_js_formatter::context::{ArrowParentheses, QuoteProperties, Semicolons};
+use biome_json_parser::JsonParserOptions;
+use biome_service::DynRef;
+use indexmap::IndexMap;
+use std::path::Path;
+
+/// This modules includes implementations for loading a dprint configuration,
+/// and for converting it to a Biome configuration.
+///
+/// The global options, and the options of the TypeScript, JSON and Markup plugins are migrated.
+/// Only the options set in the dprint configuration are migrated:
+/// the other options are left to their Biome's default.
+///
+/// See https://dprint.dev/config/
+
+/// dprint config files ordered by precedence
+pub(super) const CONFIG_FILES: [&str; 4] = [
+    "dprint.json",
+    "dprint.jsonc",
+    ".dprint.json",
+    ".dprint.jsonc",
+];
+
+/// Options that are migrated, or that don't affect the formatting.
+///
+/// The options of a plugin are prefixed with the name of the plugin.
+const SUPPORTED_OPTIONS: [&str; 32] = [
+    "$schema",
+    "plugins",
+    "lineWidth",
+    "indentWidth",
+    "useTabs",
+    "newLineKind",
+    "includes",
+    "excludes",
+    "typescript.lineWidth",
+    "typescript.indentWidth",
+    "typescript.useTabs",
+    "typescript.newLineKind",
+    "typescript.quoteStyle",
+    "typescript.jsx.quoteStyle",
+    "typescript.quoteProps",
+    "typescript.semiColons",
+    "typescript.trailingCommas",
+    "typescript.arrowFunction.useParentheses",
+    "typescript.spaceSurroundingProperties",
+    "json.lineWidth",
+    "json.indentWidth",
+    "json.useTabs",
+    "json.newLineKind",
+    "json.trailingCommas",
+    "markup.printWidth",
+    "markup.indentWidth",
+    "markup.useTabs",
+    "markup.lineBreak",
+    "markup.whitespaceSensitivity",
+    "markup.scriptIndent",
+    "markup.styleIndent",
+    "markup.htmlVoidSelfClosing",
+];
+
+/// The plugins which have their own section in the configuration
+const PLUGINS: [&str; 3] = ["typescript", "json", "markup"];
+
+#[derive(Debug)]
+pub(crate) struct Config {
+    /// Path of the dprint config file
+    pub(crate) path: &'static str,
+    /// Deserialized dprint config
+    pub(crate) data: DprintConfiguration,
+    /// Options of the dprint config that don't have an equivalent in Biome
+    pub(crate) unsupported_options: Vec<String>,
+}
+
+/// Returns the dprint configuration file in the working directory with the highest priority.
+///
+/// Deserialization errors are reported using `console`.
+/// Other errors (File Not found, ...) are directly returned.
+pub(crate) fn read_config_file(
+    fs: &DynRef<'_, dyn FileSystem>,
+    console: &mut dyn Console,
+) -> Result<Config, CliDiagnostic> {
+    for config_name in CONFIG_FILES {
+        let path = Path::new(config_name);
+        if fs.path_exists(path) {
+            let mut file = fs.open_with_options(path, OpenOptions::default().read(true))?;
+            let mut content = String::new();
+            file.read_to_string(&mut content)?;
+            let parser_options = JsonParserOptions::default()
+                .with_allow_trailing_commas()
+                .with_allow_comments();
+            let (deserialized, diagnostics) =
+                deserialize_from_json_str::<DprintConfiguration>(&content, parser_options, "")
+                    .consume();
+            for diagnostic in diagnostics.into_iter().filter(|diag| {
+                matches!(
+                    diag.severity(),
+                    biome_diagnostics::Severity::Fatal
+                        | biome_diagnostics::Severity::Error
+                        | biome_diagnostics::Severity::Warning
+                )
+            }) {
+                let diagnostic = diagnostic
+                    .with_file_path(config_name)
+                    .with_file_source_code(&content);
+                console.error(markup! {{PrintDiagnostic::simple(&diagnostic)}});
+            }
+            let Some(data) = deserialized else {
+                return Err(CliDiagnostic::MigrateError(MigrationDiagnostic {
+                    reason: "Could not deserialize the dprint configuration file".to_string(),
+                }));
+            };
+            let option_names =
+                deserialize_from_json_str::<OptionNames>(&content, parser_options, "")
+                    .into_deserialized()
+                    .unwrap_or_default();
+            return Ok(Config {
+                path: config_name,
+                data,
+                unsupported_options: option_names
+                    .into_names()
+                    .filter(|name| !SUPPORTED_OPTIONS.contains(&name.as_str()))
+                    .collect(),
+            });
+        }
+    }
+    Err(CliDiagnostic::MigrateError(MigrationDiagnostic {
+        reason: "Biome couldn't find a dprint configuration file.".to_string(),
+    }))
+}
+
+#[derive(Debug, Default, Deserializable)]
+#[deserializable(unknown_fields = "allow")]
+pub(crate) struct DprintConfiguration {
+    /// https://dprint.dev/config/#global-configuration
+    line_width: Option<u16>,
+    indent_width: Option<u8>,
+    use_tabs: Option<bool>,
+    new_line_kind: Option<NewLineKind>,
+    /// https://dprint.dev/config/#includes-and-excludes
+    includes: Vec<String>,
+    excludes: Vec<String>,
+    /// https://dprint.dev/plugins/typescript/config/
+    typescript: TypeScriptOptions,
+    /// https://dprint.dev/plugins/json/config/
+    json: JsonOptions,
+    /// https://dprint.dev/plugins/markup_fmt/config/
+    markup: MarkupOptions,
+}
+
+#[derive(Debug, Default, Deserializable)]
+#[deserializable(unknown_fields = "allow")]
+struct TypeScriptOptions {
+    line_width: Option<u16>,
+    indent_width: Option<u8>,
+    use_tabs: Option<bool>,
+    new_line_kind: Option<NewLineKind>,
+    quote_style: Option<QuoteStyle>,
+    #[deserializable(rename = "jsx.quoteStyle")]
+    jsx_quote_style: Option<JsxQuoteStyle>,
+    quote_props: Option<QuoteProps>,
+    semi_colons: Option<SemiColons>,
+    trailing_commas: Option<TrailingCommas>,
+    #[deserializable(rename = "arrowFunction.useParentheses")]
+    arrow_function_use_parentheses: Option<UseParentheses>,
+    space_surrounding_properties: Option<bool>,
+}
+
+#[derive(Debug, Default, Deserializable)]
+#[deserializable(unknown_fields = "allow")]
+struct JsonOptions {
+    line_width: Option<u16>,
+    indent_width: Option<u8>,
+    use_tabs: Option<bool>,
+    new_line_kind: Option<NewLineKind>,
+    trailing_commas: Option<JsonTrailingCommas>,
+}
+
+#[derive(Debug, Default, Deserializable)]
+#[deserializable(unknown_fields = "allow")]
+struct MarkupOptions {
+    print_width: Option<u16>,
+    indent_width: Option<u8>,
+    use_tabs: Option<bool>,
+    line_break: Option<LineBreak>,
+    whitespace_sensitivity: Option<WhitespaceSensitivity>,
+    script_indent: Option<bool>,
+    style_indent: Option<bool>,
+    html_void_self_closing: Option<bool>,
+}
+
+#[derive(Clone, Copy, Debug, Deserializable)]
+enum NewLineKind {
+    Auto,
+    Lf,
+    Crlf,
+    System,
+}
+
+#[derive(Clone, Copy, Debug, Deserializable)]
+enum QuoteStyle {
+    AlwaysDouble,
+    AlwaysSingle,
+    PreferDouble,
+    PreferSingle,
+}
+
+#[derive(Clone, Copy, Debug, Deserializable)]
+enum JsxQuoteStyle {
+    PreferDouble,
+    PreferSingle,
+}
+
+#[derive(Clone, Copy, Debug, Deserializable)]
+enum QuoteProps {
+    Preserve,
+    AsNeeded,
+    Consistent,
+}
+
+#[derive(Clone, Copy, Debug, Deserializable)]
+enum SemiColons {
+    Always,
+    Prefer,
+    Asi,
+}
+
+#[derive(Clone, Copy, Debug, Deserializable)]
+enum TrailingCommas {
+    Never,
+    Always,
+    OnlyMultiLine,
+}
+
+#[derive(Clone, Copy, Debug, Deserializable)]
+enum UseParentheses {
+    Force,
+    Maintain,
+    PreferNone,
+}
+
+#[derive(Clone, Copy, Debug, Deserializable)]
+enum LineBreak {
+    Lf,
+    Crlf,
+}
+
+#[derive(Clone, Copy, Debug, Deserializable)]
+enum WhitespaceSensitivity {
+    Css,
+    Strict,
+    Ignore,
+}
+
+#[derive(Clone, Copy, Debug, Deserializable)]
+enum JsonTrailingCommas {
+    Always,
+    Jsonc,
+    Maintain,
+    Never,
+}
+
+/// The names of the options set in a dprint configuration.
+///
+/// The options of a plugin are prefixed with the name of the plugin,
+/// e.g. `typescript.quoteStyle`.
+#[derive(Debug, Default, Deserializable)]
+struct OptionNames(IndexMap<String, OptionValue>);
+impl OptionNames {
+    fn into_names(self) -> impl Iterator<Item = String> {
+        self.0.into_iter().flat_map(|(name, value)| match value {
+            OptionValue::Section(options) if PLUGINS.contains(&name.as_str()) => options
+                .0
+                .into_keys()
+                .map(|option| format!("{name}.{option}"))
+                .collect::<Vec<_>>(),
+            _ => vec![name],
+        })
+    }
+}
+
+#[derive(Debug)]
+enum OptionValue {
+    Section(OptionNames),
+    Value,
+}
+impl Deserializable for OptionValue {
+    fn deserialize(
+        value: &impl DeserializableValue,
+        name: &str,
+        diagnostics: &mut Vec<biome_deserialize::DeserializationDiagnostic>,
+    ) -> Option<Self> {
+        if value.visitable_type()? == DeserializableType::Map {
+            Deserializable::deserialize(value, name, diagnostics).map(OptionValue::Section)
+        } else {
+            Some(OptionValue::Value)
+        }
+    }
+}
+
+impl DprintConfiguration {
+    /// Converts the dprint configuration to a Biome configuration.
+    ///
+    /// The options with values that don't have an equivalent in Biome are added to `unsupported_options`.
+    pub(crate) fn try_into_biome_config(
+        self,
+        unsupported_options: &mut Vec<String>,
+    ) -> Result<biome_configuration::PartialConfiguration, ParseFormatNumberError> {
+        let mut result = biome_configuration::PartialConfiguration::default();
+
+        let formatter = biome_configuration::PartialFormatterConfiguration {
+            line_width: self.line_width.map(LineWidth::try_from).transpose()?,
+            indent_width: self.indent_width.map(IndentWidth::try_from).transpose()?,
+            indent_style: self.use_tabs.map(to_indent_style),
+            line_ending: to_line_ending(self.new_line_kind, "newLineKind", unsupported_options),
+            include: (!self.includes.is_empty())
+                .then(|| StringSet::new(self.includes.into_iter().collect())),
+            ignore: (!self.excludes.is_empty())
+                .then(|| StringSet::new(self.excludes.into_iter().collect())),
+            ..Default::default()
+        };
+        if formatter != Default::default() {
+            result.formatter = Some(formatter);
+        }
+
+        let typescript = self.typescript;
+        let js_formatter = biome_configuration::PartialJavascriptFormatter {
+            line_width: typescript.line_width.map(LineWidth::try_from).transpose()?,
+            indent_width: typescript
+                .indent_width
+                .map(IndentWidth::try_from)
+                .transpose()?,
+            indent_style: typescript.use_tabs.map(to_indent_style),
+            line_ending: to_line_ending(
+                typescript.new_line_kind,
+                "typescript.newLineKind",
+                unsupported_options,
+            ),
+            quote_style: typescript.quote_style.map(|quote_style| match quote_style {
+                QuoteStyle::AlwaysDouble | QuoteStyle::PreferDouble => {
+                    biome_formatter::QuoteStyle::Double
+                }
+                QuoteStyle::AlwaysSingle | QuoteStyle::PreferSingle => {
+                    biome_formatter::QuoteStyle::Single
+                }
+            }),
+            jsx_quote_style: typescript.jsx_quote_style.map(
+                |jsx_quote_style| match jsx_quote_style {
+                    JsxQuoteStyle::PreferDouble => biome_formatter::QuoteStyle::Double,
+                    JsxQuoteStyle::PreferSingle => biome_formatter::QuoteStyle::Single,
+                },
+            ),
+            quote_properties: typescript
+                .quote_props
+                .and_then(|quote_props| match quote_props {
+                    QuoteProps::Preserve => Some(QuoteProperties::Preserve),
+                    QuoteProps::AsNeeded => Some(QuoteProperties::AsNeeded),
+                    QuoteProps::Consistent => {
+                        unsupported_options
+                            .push(r#"typescript.quoteProps: "consistent""#.to_string());
+                        None
+                    }
+                }),
+            semicolons: typescript.semi_colons.map(|semi_colons| match semi_colons {
+                SemiColons::Always | SemiColons::Prefer => Semicolons::Always,
+                SemiColons::Asi => Semicolons::AsNeeded,
+            }),
+            // Biome only prints trailing commas in multi-line structures
+            trailing_commas: typescript.trailing_commas.and_then(|trailing_commas| {
+                match trailing_commas {
+                    TrailingCommas::Never => {
+                        Some(biome_js_formatter::context::TrailingCommas::None)
+                    }
+                    TrailingCommas::OnlyMultiLine => {
+                        Some(biome_js_formatter::context::TrailingCommas::All)
+                    }
+                    TrailingCommas::Always => {
+                        unsupported_options
+                            .push(r#"typescript.trailingCommas: "always""#.to_string());
+                        None
+                    }
+                }
+            }),
+            arrow_parentheses: typescript.arrow_function_use_parentheses.and_then(
+                |use_parentheses| match use_parentheses {
+                    UseParentheses::Force => Some(ArrowParentheses::Always),
+                    UseParentheses::PreferNone => Some(ArrowParentheses::AsNeeded),
+                    UseParentheses::Maintain => {
+                        unsupported_options.push(
+                            r#"typescript.arrowFunction.useParentheses: "maintain""#.to_string(),
+                        );
+                        None
+                    }
+                },
+            ),
+            bracket_spacing: typescript
+                .space_surrounding_properties
+                .map(BracketSpacing::from),
+            ..Default::default()
+        };
+        if js_formatter != Default::default() {
+            result.javascript = Some(biome_configuration::PartialJavascriptConfiguration {
+                formatter: Some(js_formatter),
+                ..Default::default()
+            });
+        }
+
+        let json = self.json;
+        let json_formatter = biome_configuration::PartialJsonFormatter {
+            line_width: json.line_width.map(LineWidth::try_from).transpose()?,
+            indent_width: json.indent_width.map(IndentWidth::try_from).transpose()?,
+            indent_style: json.use_tabs.map(to_indent_style),
+            line_ending: to_line_ending(
+                json.new_line_kind,
+                "json.newLineKind",
+                unsupported_options,
+            ),
+            trailing_commas: json.trailing_commas.and_then(
+                |trailing_commas| match trailing_commas {
+                    JsonTrailingCommas::Never => {
+                        Some(biome_json_formatter::context::TrailingCommas::None)
+                    }
+                    JsonTrailingCommas::Always => {
+                        Some(biome_json_formatter::context::TrailingCommas::All)
+                    }
+                    JsonTrailingCommas::Jsonc => {
+                        unsupported_options.push(r#"json.trailingCommas: "jsonc""#.to_string());
+                        None
+                    }
+                    JsonTrailingCommas::Maintain => {
+                        unsupported_options.push(r#"json.trailingCommas: "maintain""#.to_string());
+                        None
+                    }
+                },
+            ),
+            ..Default::default()
+        };
+        if json_formatter != Default::default() {
+            result.json = Some(biome_configuration::PartialJsonConfiguration {
+                formatter: Some(json_formatter),
+                ..Default::default()
+            });
+        }
+
+        let markup = self.markup;
+        // Biome has a single option for the indentation of `<script>` and `<style>` tags
+        if markup
+            .script_indent
+            .zip(markup.style_indent)
+            .is_some_and(|(script_indent, style_indent)| script_indent != style_indent)
+        {
+            unsupported_options.push(format!(
+                "markup.styleIndent: {}",
+                markup.style_indent.unwrap_or_default()
+            ));
+        }
+        let html_formatter = biome_configuration::PartialHtmlFormatter {
+            line_width: markup.print_width.map(LineWidth::try_from).transpose()?,
+            indent_width: markup.indent_width.map(IndentWidth::try_from).transpose()?,
+            indent_style: markup.use_tabs.map(to_indent_style),
+            line_ending: markup.line_break.map(|line_break| match line_break {
+                LineBreak::Lf => LineEnding::Lf,
+                LineBreak::Crlf => LineEnding::Crlf,
+            }),
+            whitespace_sensitivity: markup.whitespace_sensitivity.map(|whitespace_sensitivity| {
+                match whitespace_sensitivity {
+                    WhitespaceSensitivity::Css => {
+                        biome_html_formatter::context::WhitespaceSensitivity::Css
+                    }
+                    WhitespaceSensitivity::Strict => {
+                        biome_html_formatter::context::WhitespaceSensitivity::Strict
+                    }
+                    WhitespaceSensitivity::Ignore => {
+                        biome_html_formatter::context::WhitespaceSensitivity::Ignore
+                    }
+                }
+            }),
+            indent_script_and_style: markup.script_indent.or(markup.style_indent),
+            self_close_void_elements: markup.html_void_self_closing.map(|self_closing| {
+                if self_closing {
+                    SelfCloseVoidElements::Always
+                } else {
+                    SelfCloseVoidElements::Never
+                }
+            }),
+            ..Default::default()
+        };
+        if html_formatter != Default::default() {
+            result.html = Some(biome_configuration::PartialHtmlConfiguration {
+                formatter: Some(html_formatter),
+                ..Default::default()
+            });
+        }
+
+        Ok(result)
+    }
+}
+
+fn to_indent_style(use_tabs: bool) -> IndentStyle {
+    if use_tabs {
+        IndentStyle::Tab
+    } else {
+        IndentStyle::Space
+    }
+}
+
+/// Biome doesn't detect the line ending of the files, or use the one of the system.
+fn to_line_ending(
+    new_line_kind: Option<NewLineKind>,
+    option_name: &str,
+    unsupported_options: &mut Vec<String>,
+) -> Option<LineEnding> {
+    match new_line_kind? {
+        NewLineKind::Lf => Some(LineEnding::Lf),
+        NewLineKind::Crlf => Some(LineEnding::Crlf),
+        NewLineKind::Auto => {
+            unsupported_options.push(format!(r#"{option_name}: "auto""#));
+            None
+        }
+        NewLineKind::System => {
+            unsupported_options.push(format!(r#"{option_name}: "system""#));
+            None
+        }
+    }
+}
@@ -28,7 +28,7 @@ use super::node;
 /// ESLint flat configuration filenames.
 ///
 /// See https://eslint.org/docs/latest/use/configure/configuration-files-new
-const FLAT_CONFIG_FILES: [&str; 3] = [
+pub(super) const FLAT_CONFIG_FILES: [&str; 3] = [
     // Prefixed with `./` to ensure that it is loadable via Node.js's `import()`
     "./eslint.config.js",
     "./eslint.config.mjs",
@@ -42,7 +42,7 @@ const FLAT_CONFIG_FILES: [&str; 3] = [
 /// Order is important.
 /// It translates the priority of the files.
 /// For example, ESLint looks for `./.eslintrc.js` before looking for `./.eslintrc.json`.
-const LEGACY_CONFIG_FILES: [&str; 6] = [
+pub(super) const LEGACY_CONFIG_FILES: [&str; 6] = [
     // Prefixed with `./` to ensure that it is loadable via Node.js's `import()`
     "./.eslintrc.js",
     // Prefixed with `./` to ensure that it is loadable via Node.js's `import()`
@@ -503,6 +503,16 @@ pub(crate) fn migrate_eslint_any_rule(
             let rule = group.use_exports_last.get_or_insert(Default::default());
             rule.set_level(rule_severity.into());
         }
+        "import/named" => {
+            if !options.include_nursery {
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group
+                .no_unresolved_imports
+                .get_or_insert(Default::default());
+            rule.set_level(rule_severity.into());
+        }
         "import/no-commonjs" => {
             if !options.include_nursery {
                 return false;
@@ -370,7 +370,7 @@ impl TryFrom<Override> for biome_configuration::OverridePattern {
 const PACKAGE_JSON: &str = "package.json";
 
 /// Prettie config files ordered by precedence
-const CONFIG_FILES: [&str; 8] = [
+pub(super) const CONFIG_FILES: [&str; 8] = [
     ".prettierrc",
     ".prettierrc.json",
     // Prefixed with `./` to ensure that it is loadable via Node.js's `import()`
@@ -0,0 +1,403 @@
+use biome_console::{markup, Console, ConsoleExt};
+use biome_deserialize::json::deserialize_from_json_str;
+use biome_deserialize::{
+    Deserializable, DeserializableType, DeserializableTypes, DeserializableValue,
+    DeserializationDiagnostic, DeserializationVisitor, Merge,
+};
+use biome_deserialize_macros::Deserializable;
+use biome_diagnostics::{DiagnosticExt, PrintDiagnostic};
+use biome_fs::{FileSystem, OpenOptions};
+use biome_json_parser::JsonParserOptions;
+use biome_rowan::TextRange;
+use biome_service::DynRef;
+use indexmap::IndexMap;
+use std::ffi::OsStr;
+use std::path::Path;
+use yaml_rust2::{Yaml, YamlLoader};
+
+use crate::diagnostics::MigrationDiagnostic;
+use crate::CliDiagnostic;
+
+use super::eslint_eslint::ShorthandVec;
+use super::node;
+
+/// This modules includes implementations for loading and deserializing a Stylelint configuration.
+///
+/// Only the fields that have an equivalent in Biome are deserialized:
+/// the rules with their severity, the ignored files, and the overrides.
+/// The options of the rules are ignored.
+///
+/// See [super::stylelint_to_biome] for converting a Stylelint configuration to a Biome configuration.
+
+/// List of Stylelint configuration filenames.
+///
+/// See https://stylelint.io/user-guide/configure
+///
+/// Order is important.
+/// It translates the priority of the files.
+pub(super) const CONFIG_FILES: [&str; 10] = [
+    ".stylelintrc",
+    ".stylelintrc.json",
+    ".stylelintrc.yaml",
+    ".stylelintrc.yml",
+    // Prefixed with `./` to ensure that it is loadable via Node.js's `import()`
+    "./.stylelintrc.js",
+    // Prefixed with `./` to ensure that it is loadable via Node.js's `import()`
+    "./.stylelintrc.cjs",
+    // Prefixed with `./` to ensure that it is loadable via Node.js's `import()`
+    "./.stylelintrc.mjs",
+    // Prefixed with `./` to ensure that it is loadable via Node.js's `import()`
+    "./stylelint.config.js",
+    // Prefixed with `./` to ensure that it is loadable via Node.js's `import()`
+    "./stylelint.config.cjs",
+    // Prefixed with `./` to ensure that it is loadable via Node.js's `import()`
+    "./stylelint.config.mjs",
+];
+
+/// A Stylelint config can be embedded in `package.json`
+const PACKAGE_JSON: &str = "package.json";
+
+/// Stylelint ignore file. Use the same syntax as gitignore.
+pub(crate) const IGNORE_FILE: &str = ".stylelintignore";
+
+/// Returns the Stylelint configuration file in the working directory with the highest priority.
+///
+/// Unlike Stylelint, it doesn't look for a configuration file in parent directories
+/// when no configuration file is found in the working directory.
+///
+/// Deserialization errors are reported using `console`.
+/// Other errors (File Not found, unsupported config format, ...) are directly returned.
+///
+/// We extract the Stylelint configuration from a JavaScript file, by invoking `node`.
+///
+/// The `extends` field is recursively resolved.
+pub(crate) fn read_stylelint_config(
+    fs: &DynRef<'_, dyn FileSystem>,
+    console: &mut dyn Console,
+) -> Result<Config, CliDiagnostic> {
+    for config_path_str in CONFIG_FILES {
+        let path = Path::new(config_path_str);
+        if fs.path_exists(path) {
+            return load_config_data(fs, path, console).map(|data| Config {
+                path: config_path_str,
+                data,
+            });
+        }
+    }
+    // We don't report an error if Stylelint config is not embedded in `PACKAGE_JSON`.
+    if let Ok(data) = load_config_data(fs, Path::new(PACKAGE_JSON), console) {
+        return Ok(Config {
+            path: PACKAGE_JSON,
+            data,
+        });
+    }
+    Err(CliDiagnostic::MigrateError(MigrationDiagnostic { reason: "The default Stylelint configuration file `.stylelintrc[.*]` or `stylelint.config.*` was not found in the working directory.".to_string()}))
+}
+
+#[derive(Debug)]
+pub(crate) struct Config {
+    /// Path of the Stylelint config file
+    pub(crate) path: &'static str,
+    /// Resolved Stylelint config
+    pub(crate) data: ConfigData,
+}
+
+/// Load a Stylelint config
+/// See https://stylelint.io/user-guide/configure
+fn load_config_data(
+    fs: &DynRef<'_, dyn FileSystem>,
+    path: &Path,
+    console: &mut dyn Console,
+) -> Result<ConfigData, CliDiagnostic> {
+    let (deserialized, diagnostics) = match path.extension().and_then(OsStr::to_str) {
+        None | Some("json" | "yaml" | "yml") => {
+            let mut file = fs.open_with_options(path, OpenOptions::default().read(true))?;
+            let mut content = String::new();
+            file.read_to_string(&mut content)?;
+            if path.file_name().is_some_and(|name| name == PACKAGE_JSON) {
+                let (deserialized, diagnostics) =
+                    deserialize_from_json_str::<StylelintPackageJson>(
+                        &content,
+                        JsonParserOptions::default(),
+                        "",
+                    )
+                    .consume();
+                (
+                    deserialized.and_then(|packagejson| packagejson.stylelint),
+                    diagnostics,
+                )
+            } else if is_yaml(path, &content) {
+                deserialize_from_json_str::<ConfigData>(
+                    &yaml_to_json(&content)?,
+                    JsonParserOptions::default(),
+                    "",
+                )
+                .consume()
+            } else {
+                deserialize_from_json_str::<ConfigData>(
+                    &content,
+                    JsonParserOptions::default()
+                        .with_allow_trailing_commas()
+                        .with_allow_comments(),
+                    "",
+                )
+                .consume()
+            }
+        }
+        Some("js" | "cjs" | "mjs") => {
+            let node::Resolution { content, .. } = node::load_config(&path.to_string_lossy())?;
+            deserialize_from_json_str::<ConfigData>(&content, JsonParserOptions::default(), "")
+                .consume()
+        }
+        Some(ext) => {
+            return Err(CliDiagnostic::MigrateError(MigrationDiagnostic {
+                reason: format!(
+                    "Stylelint configuration ending with the extension `{ext}` are not supported."
+                ),
+            }))
+        }
+    };
+    let path_str = path.to_string_lossy();
+    for diagnostic in diagnostics.into_iter().filter(|diag| {
+        matches!(
+            diag.severity(),
+            biome_diagnostics::Severity::Fatal
+                | biome_diagnostics::Severity::Error
+                | biome_diagnostics::Severity::Warning
+        )
+    }) {
+        let diagnostic = diagnostic.with_file_path(path_str.to_string());
+        console.error(markup! {{PrintDiagnostic::simple(&diagnostic)}});
+    }
+    if let Some(result) = deserialized {
+        Ok(resolve_extends(result, console))
+    } else {
+        Err(CliDiagnostic::MigrateError(MigrationDiagnostic {
+            reason: "Could not deserialize the Stylelint configuration file".to_string(),
+        }))
+    }
+}
+
+/// Returns `true` if the configuration file is written in YAML.
+///
+/// `.stylelintrc` can be written in JSON or in YAML.
+fn is_yaml(path: &Path, content: &str) -> bool {
+    match path.extension().and_then(OsStr::to_str) {
+        Some("yaml" | "yml") => true,
+        Some(_) => false,
+        None => !content.trim_start().starts_with('{'),
+    }
+}
+
+/// Converts a YAML configuration to JSON,
+/// so that it can be deserialized like the other configurations.
+fn yaml_to_json(content: &str) -> Result<String, CliDiagnostic> {
+    let documents = YamlLoader::load_from_str(content).map_err(|err| {
+        CliDiagnostic::MigrateError(MigrationDiagnostic {
+            reason: format!("The Stylelint configuration file isn't a valid YAML file: {err}"),
+        })
+    })?;
+    let value = documents
+        .into_iter()
+        .next()
+        .map_or(serde_json::Value::Null, yaml_to_json_value);
+    Ok(value.to_string())
+}
+
+fn yaml_to_json_value(yaml: Yaml) -> serde_json::Value {
+    match yaml {
+        Yaml::Real(_) => yaml
+            .as_f64()
+            .and_then(serde_json::Number::from_f64)
+            .map_or(serde_json::Value::Null, serde_json::Value::Number),
+        Yaml::Integer(integer) => serde_json::Value::from(integer),
+        Yaml::String(string) => serde_json::Value::String(string),
+        Yaml::Boolean(boolean) => serde_json::Value::Bool(boolean),
+        Yaml::Array(array) => array.into_iter().map(yaml_to_json_value).collect(),
+        Yaml::Hash(hash) => hash
+            .into_iter()
+            .filter_map(|(key, value)| {
+                let key = match key {
+                    Yaml::String(key) => key,
+                    Yaml::Real(key) => key,
+                    Yaml::Integer(key) => key.to_string(),
+                    Yaml::Boolean(key) => key.to_string(),
+                    _ => return None,
+                };
+                Some((key, yaml_to_json_value(value)))
+            })
+            .collect(),
+        Yaml::Alias(_) | Yaml::Null | Yaml::BadValue => serde_json::Value::Null,
+    }
+}
+
+/// Returns the configuration of a shared configuration such as `stylelint-config-standard`,
+/// or an error if the resolution failed.
+fn load_extends_config(specifier: &str) -> Result<ConfigData, CliDiagnostic> {
+    let node::Resolution {
+        content,
+        resolved_path,
+    } = node::load_config(specifier)?;
+    let Some(mut deserialized) =
+        deserialize_from_json_str::<ConfigData>(&content, JsonParserOptions::default(), "")
+            .into_deserialized()
+    else {
+        return Err(CliDiagnostic::MigrateError(MigrationDiagnostic {
+            reason: format!("The Stylelint configuration of the module '{specifier}' cannot be extracted. This is likely an internal error.")
+        }));
+    };
+    // Resolve relative path in `extends`.
+    if let Some(resolved_path) = Path::new(&resolved_path).parent() {
+        for extends_item in deserialized.extends.iter_mut() {
+            if extends_item.starts_with('.') {
+                *extends_item = resolved_path
+                    .join(extends_item.as_str())
+                    .to_string_lossy()
+                    .to_string();
+            }
+        }
+    }
+    Ok(deserialized)
+}
+
+/// Load the configurations of `config.extends`, and merge `config` into them.
+///
+/// The configurations that cannot be loaded are reported using `console` and ignored.
+fn resolve_extends(mut config: ConfigData, console: &mut dyn Console) -> ConfigData {
+    let extends = std::mem::take(&mut config.extends);
+    let mut result = ConfigData::default();
+    for specifier in extends {
+        match load_extends_config(&specifier) {
+            Ok(extended) => result.merge_with(resolve_extends(extended, console)),
+            Err(diag) => console.error(markup! {{PrintDiagnostic::simple(&diag)}}),
+        }
+    }
+    result.merge_with(config);
+    result
+}
+
+#[derive(Debug, Default, Deserializable)]
+#[deserializable(unknown_fields = "allow")]
+pub(crate) struct StylelintPackageJson {
+    pub(crate) stylelint: Option<ConfigData>,
+}
+
+#[derive(Debug, Default, Deserializable)]
+#[deserializable(unknown_fields = "allow")]
+pub(crate) struct ConfigData {
+    pub(crate) extends: ShorthandVec<String>,
+    /// The severity of the rules that don't specify one.
+    pub(crate) default_severity: Option<Severity>,
+    /// The glob patterns of the ignored files.
+    pub(crate) ignore_files: ShorthandVec<String>,
+    pub(crate) rules: Rules,
+    pub(crate) overrides: Vec<OverrideConfigData>,
+}
+impl Merge for ConfigData {
+    fn merge_with(&mut self, mut other: Self) {
+        self.extends.merge_with(other.extends);
+        if other.default_severity.is_some() {
+            self.default_severity = other.default_severity;
+        }
+        self.ignore_files.merge_with(other.ignore_files);
+        self.rules.merge_with(other.rules);
+        self.overrides.append(&mut other.overrides);
+    }
+}
+
+#[derive(Debug, Default, Deserializable)]
+#[deserializable(unknown_fields = "allow")]
+pub(crate) struct OverrideConfigData {
+    /// The glob patterns for target files.
+    pub(crate) files: ShorthandVec<String>,
+    pub(crate) rules: Rules,
+}
+
+#[derive(Debug, Default, Deserializable)]
+pub(crate) struct Rules(
+    // We use `IndexMap` to preserve the order.
+    // Keeping the order is important because several Stylelint rules can have
+    // the same equivalent Biome rule.
+    // The severity level of the last one is thus used.
+    pub(crate) IndexMap<String, RuleConf>,
+);
+impl Merge for Rules {
+    fn merge_with(&mut self, other: Self) {
+        self.0.extend(other.0);
+    }
+}
+
+/// Model the possible shapes of a Stylelint's rule configuration
+#[derive(Debug)]
+pub(crate) enum RuleConf {
+    /// `{ rule: null }` and `{ rule: [null] }`
+    Off,
+    /// `{ rule: <primary option> }` and `{ rule: [<primary option>, <secondary options>] }`
+    ///
+    /// The severity is taken from the secondary options, if any.
+    On(Option<Severity>),
+}
+impl Deserializable for RuleConf {
+    fn deserialize(
+        value: &impl DeserializableValue,
+        name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self> {
+        struct Visitor;
+        impl DeserializationVisitor for Visitor {
+            type Output = RuleConf;
+            const EXPECTED_TYPE: DeserializableTypes = DeserializableTypes::ARRAY;
+            fn visit_array(
+                self,
+                values: impl Iterator<Item = Option<impl DeserializableValue>>,
+                range: TextRange,
+                _name: &str,
+                diagnostics: &mut Vec<DeserializationDiagnostic>,
+            ) -> Option<Self::Output> {
+                let mut values = values.flatten();
+                let Some(primary_option) = values.next() else {
+                    diagnostics.push(
+                        DeserializationDiagnostic::new("A primary option is expected.")
+                            .with_range(range),
+                    );
+                    return None;
+                };
+                if primary_option.visitable_type()? == DeserializableType::Null {
+                    return Some(RuleConf::Off);
+                }
+                // The primary option can be an array of several values
+                let severity = values
+                    .next()
+                    .filter(|value| value.visitable_type() == Some(DeserializableType::Map))
+                    .and_then(|value| SecondaryOptions::deserialize(&value, "", diagnostics))
+                    .and_then(|options| options.severity);
+                Some(RuleConf::On(severity))
+            }
+        }
+        match value.visitable_type()? {
+            DeserializableType::Null => Some(RuleConf::Off),
+            DeserializableType::Array => value.deserialize(Visitor, name, diagnostics),
+            _ => Some(RuleConf::On(None)),
+        }
+    }
+}
+
+#[derive(Debug, Default, Deserializable)]
+#[deserializable(unknown_fields = "allow")]
+struct SecondaryOptions {
+    severity: Option<Severity>,
+}
+
+#[derive(Clone, Copy, Debug, Deserializable)]
+pub(crate) enum Severity {
+    Warning,
+    Error,
+}
+impl From<Severity> for biome_configuration::RulePlainConfiguration {
+    fn from(value: Severity) -> biome_configuration::RulePlainConfiguration {
+        match value {
+            Severity::Warning => biome_configuration::RulePlainConfiguration::Warn,
+            Severity::Error => biome_configuration::RulePlainConfiguration::Error,
+        }
+    }
+}
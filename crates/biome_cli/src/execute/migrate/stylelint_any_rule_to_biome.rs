@@ -0,0 +1,217 @@
+//! Generated file, do not edit by hand, see `xtask/codegen`
+
+use super::stylelint_to_biome;
+pub(crate) fn migrate_stylelint_any_rule(
+    rules: &mut biome_configuration::Rules,
+    stylelint_name: &str,
+    rule_severity: biome_configuration::RulePlainConfiguration,
+    options: &stylelint_to_biome::MigrationOptions,
+    results: &mut stylelint_to_biome::MigrationResults,
+) -> bool {
+    match stylelint_name {
+        "at-rule-no-unknown" => {
+            if !options.include_nursery {
+                results.has_nursery_rules = true;
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group.no_unknown_at_rule.get_or_insert(Default::default());
+            rule.set_level(rule_severity);
+        }
+        "block-no-empty" => {
+            let group = rules.suspicious.get_or_insert_with(Default::default);
+            let rule = group.no_empty_block.get_or_insert(Default::default());
+            rule.set_level(rule_severity);
+        }
+        "custom-property-no-missing-var-function" => {
+            if !options.include_nursery {
+                results.has_nursery_rules = true;
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group
+                .no_missing_var_function
+                .get_or_insert(Default::default());
+            rule.set_level(rule_severity);
+        }
+        "declaration-block-no-duplicate-custom-properties" => {
+            if !options.include_nursery {
+                results.has_nursery_rules = true;
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group
+                .no_duplicate_custom_properties
+                .get_or_insert(Default::default());
+            rule.set_level(rule_severity);
+        }
+        "declaration-block-no-duplicate-properties" => {
+            if !options.include_nursery {
+                results.has_nursery_rules = true;
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group
+                .no_duplicate_properties
+                .get_or_insert(Default::default());
+            rule.set_level(rule_severity);
+        }
+        "declaration-block-no-shorthand-property-overrides" => {
+            let group = rules.suspicious.get_or_insert_with(Default::default);
+            let rule = group
+                .no_shorthand_property_overrides
+                .get_or_insert(Default::default());
+            rule.set_level(rule_severity);
+        }
+        "font-family-no-duplicate-names" => {
+            let group = rules.suspicious.get_or_insert_with(Default::default);
+            let rule = group
+                .no_duplicate_font_names
+                .get_or_insert(Default::default());
+            rule.set_level(rule_severity);
+        }
+        "font-family-no-missing-generic-family-keyword" => {
+            let group = rules.a11y.get_or_insert_with(Default::default);
+            let rule = group
+                .use_generic_font_names
+                .get_or_insert(Default::default());
+            rule.set_level(rule_severity);
+        }
+        "function-linear-gradient-no-nonstandard-direction" => {
+            let group = rules.correctness.get_or_insert_with(Default::default);
+            let rule = group
+                .no_invalid_direction_in_linear_gradient
+                .get_or_insert(Default::default());
+            rule.set_level(rule_severity);
+        }
+        "function-no-unknown" => {
+            let group = rules.correctness.get_or_insert_with(Default::default);
+            let rule = group.no_unknown_function.get_or_insert(Default::default());
+            rule.set_level(rule_severity);
+        }
+        "keyframe-block-no-duplicate-selectors" => {
+            let group = rules.suspicious.get_or_insert_with(Default::default);
+            let rule = group
+                .no_duplicate_selectors_keyframe_block
+                .get_or_insert(Default::default());
+            rule.set_level(rule_severity);
+        }
+        "keyframe-declaration-no-important" => {
+            let group = rules.suspicious.get_or_insert_with(Default::default);
+            let rule = group
+                .no_important_in_keyframe
+                .get_or_insert(Default::default());
+            rule.set_level(rule_severity);
+        }
+        "media-feature-name-no-unknown" => {
+            let group = rules.correctness.get_or_insert_with(Default::default);
+            let rule = group
+                .no_unknown_media_feature_name
+                .get_or_insert(Default::default());
+            rule.set_level(rule_severity);
+        }
+        "named-grid-areas-no-invalid" => {
+            let group = rules.correctness.get_or_insert_with(Default::default);
+            let rule = group
+                .no_invalid_grid_areas
+                .get_or_insert(Default::default());
+            rule.set_level(rule_severity);
+        }
+        "no-descending-specificity" => {
+            if !options.include_nursery {
+                results.has_nursery_rules = true;
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group
+                .no_descending_specificity
+                .get_or_insert(Default::default());
+            rule.set_level(rule_severity);
+        }
+        "no-duplicate-at-import-rules" => {
+            let group = rules.suspicious.get_or_insert_with(Default::default);
+            let rule = group
+                .no_duplicate_at_import_rules
+                .get_or_insert(Default::default());
+            rule.set_level(rule_severity);
+        }
+        "no-invalid-position-at-import-rule" => {
+            let group = rules.correctness.get_or_insert_with(Default::default);
+            let rule = group
+                .no_invalid_position_at_import_rule
+                .get_or_insert(Default::default());
+            rule.set_level(rule_severity);
+        }
+        "no-irregular-whitespace" => {
+            if !options.include_nursery {
+                results.has_nursery_rules = true;
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group
+                .no_irregular_whitespace
+                .get_or_insert(Default::default());
+            rule.set_level(rule_severity);
+        }
+        "property-no-unknown" => {
+            let group = rules.correctness.get_or_insert_with(Default::default);
+            let rule = group.no_unknown_property.get_or_insert(Default::default());
+            rule.set_level(rule_severity);
+        }
+        "selector-anb-no-unmatchable" => {
+            let group = rules.correctness.get_or_insert_with(Default::default);
+            let rule = group
+                .no_unmatchable_anb_selector
+                .get_or_insert(Default::default());
+            rule.set_level(rule_severity);
+        }
+        "selector-pseudo-class-no-unknown" => {
+            if !options.include_nursery {
+                results.has_nursery_rules = true;
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group
+                .no_unknown_pseudo_class
+                .get_or_insert(Default::default());
+            rule.set_level(rule_severity);
+        }
+        "selector-pseudo-element-no-unknown" => {
+            if !options.include_nursery {
+                results.has_nursery_rules = true;
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group
+                .no_unknown_pseudo_element
+                .get_or_insert(Default::default());
+            rule.set_level(rule_severity);
+        }
+        "selector-type-no-unknown" => {
+            if !options.include_nursery {
+                results.has_nursery_rules = true;
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group
+                .no_unknown_type_selector
+                .get_or_insert(Default::default());
+            rule.set_level(rule_severity);
+        }
+        "unit-no-unknown" => {
+            let group = rules.correctness.get_or_insert_with(Default::default);
+            let rule = group.no_unknown_unit.get_or_insert(Default::default());
+            rule.set_level(rule_severity);
+        }
+        _ => {
+            if !matches!(
+                rule_severity,
+                biome_configuration::RulePlainConfiguration::Off
+            ) {
+                results.unsupported_rules.insert(stylelint_name.to_string());
+            }
+            return false;
+        }
+    }
+    true
+}
@@ -0,0 +1,93 @@
+use biome_configuration::{self as biome_config};
+use std::collections::BTreeSet;
+
+use super::{stylelint, stylelint_any_rule_to_biome::migrate_stylelint_any_rule};
+
+/// This modules includes implementations for converting a Stylelint config to a Biome config.
+///
+/// The conversion relies on the generated [super::stylelint_any_rule_to_biome::migrate_stylelint_any_rule]
+/// module that relies on Biome's rule metadata to determine
+/// the equivalent Biome's rule of a Stylelint rule.
+
+#[derive(Clone, Debug, Default)]
+pub(crate) struct MigrationOptions {
+    /// Migrate nursery rules from Stylelint?
+    pub(crate) include_nursery: bool,
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct MigrationResults {
+    /// Contains nursery rules that were not migrated because `include_nursery` is disabled
+    pub(crate) has_nursery_rules: bool,
+    /// Enabled Stylelint rules that don't have an equivalent Biome rule
+    pub(crate) unsupported_rules: BTreeSet<String>,
+}
+
+impl stylelint::ConfigData {
+    pub(crate) fn into_biome_config(
+        self,
+        options: &MigrationOptions,
+    ) -> (biome_config::PartialConfiguration, MigrationResults) {
+        let mut results = MigrationResults::default();
+        let mut biome_config = biome_config::PartialConfiguration::default();
+        let default_severity = self.default_severity.unwrap_or(stylelint::Severity::Error);
+        let mut linter = biome_config::PartialLinterConfiguration {
+            rules: Some(
+                self.rules
+                    .into_biome_rules(default_severity, options, &mut results),
+            ),
+            ..Default::default()
+        };
+        if !self.ignore_files.is_empty() {
+            linter.ignore = Some(self.ignore_files.into_iter().collect());
+        }
+        if !self.overrides.is_empty() {
+            let mut overrides = biome_config::Overrides::default();
+            for override_elt in self.overrides {
+                let mut override_pattern = biome_config::OverridePattern::default();
+                if !override_elt.files.is_empty() {
+                    override_pattern.include = Some(override_elt.files.into_iter().collect());
+                }
+                if !override_elt.rules.0.is_empty() {
+                    override_pattern.linter = Some(biome_config::OverrideLinterConfiguration {
+                        rules: Some(override_elt.rules.into_biome_rules(
+                            default_severity,
+                            options,
+                            &mut results,
+                        )),
+                        ..Default::default()
+                    });
+                }
+                overrides.0.push(override_pattern);
+            }
+            biome_config.overrides = Some(overrides);
+        }
+        biome_config.linter = Some(linter);
+        (biome_config, results)
+    }
+}
+
+impl stylelint::Rules {
+    pub(crate) fn into_biome_rules(
+        self,
+        default_severity: stylelint::Severity,
+        options: &MigrationOptions,
+        results: &mut MigrationResults,
+    ) -> biome_config::Rules {
+        let mut rules = biome_config::Rules::default();
+        for (stylelint_name, conf) in self.0 {
+            let rule_severity = match conf {
+                stylelint::RuleConf::Off => biome_config::RulePlainConfiguration::Off,
+                stylelint::RuleConf::On(severity) => severity.unwrap_or(default_severity).into(),
+            };
+            migrate_stylelint_any_rule(
+                &mut rules,
+                &stylelint_name,
+                rule_severity,
+                options,
+                results,
+            );
+        }
+        rules
+    }
+}
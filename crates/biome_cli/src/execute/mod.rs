@@ -1,33 +1,48 @@
+mod baseline;
+mod cache;
 mod diagnostics;
+mod diff;
 mod migrate;
 mod process_file;
 mod std_in;
 pub(crate) mod traverse;
+mod watch;
 
+use crate::changed::ChangedLineRanges;
 use crate::cli_options::{CliOptions, CliReporter};
 use crate::commands::MigrateSubCommand;
 use crate::diagnostics::ReportDiagnostic;
+pub(crate) use crate::execute::baseline::{BaselineOptions, DEFAULT_BASELINE_FILE};
+pub(crate) use crate::execute::cache::{cache_directory, settings_hash};
+use crate::execute::diff::print_diffs;
+pub(crate) use crate::execute::migrate::detect_migratable_tools;
 use crate::execute::migrate::MigratePayload;
 use crate::execute::traverse::{traverse, TraverseResult};
+use crate::reporter::checkstyle::{CheckstyleReporter, CheckstyleReporterVisitor};
 use crate::reporter::github::{GithubReporter, GithubReporterVisitor};
 use crate::reporter::gitlab::{GitLabReporter, GitLabReporterVisitor};
 use crate::reporter::json::{JsonReporter, JsonReporterVisitor};
 use crate::reporter::junit::{JunitReporter, JunitReporterVisitor};
+use crate::reporter::sarif::{SarifReporter, SarifReporterVisitor};
 use crate::reporter::summary::{SummaryReporter, SummaryReporterVisitor};
 use crate::reporter::terminal::{ConsoleReporter, ConsoleReporterVisitor};
+use crate::rule_profile::RuleMetrics;
 use crate::{CliDiagnostic, CliSession, DiagnosticsPayload, Reporter};
 use biome_configuration::analyzer::RuleSelector;
 use biome_console::{markup, ConsoleExt};
 use biome_diagnostics::adapters::SerdeJsonError;
 use biome_diagnostics::{category, Category};
 use biome_fs::BiomePath;
+use biome_service::configuration::VcsIgnoreFileLoader;
 use biome_service::workspace::{
     FeatureName, FeaturesBuilder, FixFileMode, FormatFileParams, OpenFileParams, PatternId,
+    RuleDurations,
 };
 use std::borrow::Borrow;
 use std::ffi::OsString;
 use std::fmt::{Display, Formatter};
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
 use tracing::info;
 
 /// Useful information during the traversal of files and virtual content
@@ -41,6 +56,33 @@ pub struct Execution {
 
     /// The maximum number of diagnostics that can be printed in console
     max_diagnostics: u32,
+
+    /// The hash of the resolved settings, set when the results of the traversal
+    /// should be read from and stored in the on-disk cache
+    cache: Option<u64>,
+
+    /// The baseline used to suppress or record the lint diagnostics
+    baseline: Option<BaselineOptions>,
+
+    /// Whether the files should be processed again when they change
+    watch: bool,
+
+    /// Whether the changes should be printed as unified diffs, instead of being written
+    diff: bool,
+
+    /// The lines the diagnostics and the formatting are restricted to, with `--changed-lines-only`
+    changed_lines: Option<ChangedLineRanges>,
+
+    /// The time spent in each rule, collected when it's measured and reported
+    rule_metrics: Option<Arc<RuleMetrics>>,
+
+    /// The directory of the project whose module graph is built before the files are processed,
+    /// set when the linter enables rules that analyze the other files of the project
+    module_graph: Option<PathBuf>,
+
+    /// Loads the `.gitignore` files of the directories reached by the traversal, set when the
+    /// VCS integration uses them
+    vcs_ignore_files: Option<Arc<VcsIgnoreFileLoader>>,
 }
 
 impl Execution {
@@ -54,6 +96,14 @@ impl Execution {
             },
             report_mode: ReportMode::default(),
             max_diagnostics: 0,
+            cache: None,
+            baseline: None,
+            watch: false,
+            diff: false,
+            changed_lines: None,
+            rule_metrics: None,
+            module_graph: None,
+            vcs_ignore_files: None,
         }
     }
 
@@ -195,10 +245,11 @@ pub enum TraversalMode {
     /// This mode is enabled when running the command `biome search`
     Search {
         /// The GritQL pattern to search for.
-        ///
-        /// Note that the search command does not support rewrites.
         pattern: PatternId,
 
+        /// Write the rewrites of the pattern to disk
+        write: bool,
+
         /// An optional tuple.
         /// 1. The virtual path to the file
         /// 2. The content of the file
@@ -233,6 +284,10 @@ pub enum ReportMode {
     Junit,
     /// Reports information in the [GitLab Code Quality](https://docs.gitlab.com/ee/ci/testing/code_quality.html#implement-a-custom-tool) format.
     GitLab,
+    /// Reports information in the [SARIF 2.1.0](https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html) format.
+    Sarif,
+    /// Reports information in the [Checkstyle](https://checkstyle.org) XML format
+    Checkstyle,
 }
 
 impl Default for ReportMode {
@@ -255,6 +310,8 @@ impl From<CliReporter> for ReportMode {
             CliReporter::GitHub => Self::GitHub,
             CliReporter::Junit => Self::Junit,
             CliReporter::GitLab => Self::GitLab {},
+            CliReporter::Sarif => Self::Sarif,
+            CliReporter::Checkstyle => Self::Checkstyle,
         }
     }
 }
@@ -265,6 +322,14 @@ impl Execution {
             report_mode: ReportMode::default(),
             traversal_mode: mode,
             max_diagnostics: 20,
+            cache: None,
+            baseline: None,
+            watch: false,
+            diff: false,
+            changed_lines: None,
+            rule_metrics: None,
+            module_graph: None,
+            vcs_ignore_files: None,
         }
     }
 
@@ -285,6 +350,14 @@ impl Execution {
                 vcs_targeted,
             },
             max_diagnostics: 20,
+            cache: None,
+            baseline: None,
+            watch: false,
+            diff: false,
+            changed_lines: None,
+            rule_metrics: None,
+            module_graph: None,
+            vcs_ignore_files: None,
         }
     }
 
@@ -294,6 +367,98 @@ impl Execution {
         self
     }
 
+    /// Enables the on-disk cache, for the given hash of the resolved settings
+    pub(crate) fn with_cache(mut self, settings_hash: u64) -> Self {
+        self.cache = Some(settings_hash);
+        self
+    }
+
+    pub(crate) fn cache(&self) -> Option<u64> {
+        self.cache
+    }
+
+    pub(crate) fn with_baseline(mut self, baseline: Option<BaselineOptions>) -> Self {
+        self.baseline = baseline;
+        self
+    }
+
+    pub(crate) fn baseline(&self) -> Option<&BaselineOptions> {
+        self.baseline.as_ref()
+    }
+
+    /// Enables the watch mode, where the files are processed again when they change
+    pub(crate) fn with_watch(mut self) -> Self {
+        self.watch = true;
+        self
+    }
+
+    pub(crate) const fn is_watch(&self) -> bool {
+        self.watch
+    }
+
+    /// Prints the changes as unified diffs, instead of writing them to the files
+    pub(crate) fn with_diff(mut self) -> Self {
+        self.diff = true;
+        self
+    }
+
+    pub(crate) const fn is_diff(&self) -> bool {
+        self.diff
+    }
+
+    pub(crate) fn with_changed_lines(mut self, changed_lines: ChangedLineRanges) -> Self {
+        self.changed_lines = Some(changed_lines);
+        self
+    }
+
+    pub(crate) fn changed_lines(&self) -> Option<&ChangedLineRanges> {
+        self.changed_lines.as_ref()
+    }
+
+    pub(crate) fn with_profile_rules(mut self) -> Self {
+        self.rule_metrics = Some(Arc::default());
+        self
+    }
+
+    pub(crate) const fn is_profiling_rules(&self) -> bool {
+        self.rule_metrics.is_some()
+    }
+
+    /// Adds the time spent in the rules that ran on a file, when the rules are measured
+    pub(crate) fn record_rule_durations(&self, durations: Vec<RuleDurations>) {
+        if let Some(rule_metrics) = &self.rule_metrics {
+            rule_metrics.record(durations);
+        }
+    }
+
+    pub(crate) fn with_module_graph(mut self, project_path: PathBuf) -> Self {
+        self.module_graph = Some(project_path);
+        self
+    }
+
+    pub(crate) fn with_vcs_ignore_files(mut self, loader: VcsIgnoreFileLoader) -> Self {
+        self.vcs_ignore_files = Some(Arc::new(loader));
+        self
+    }
+
+    /// The loader of the `.gitignore` files of the traversed directories, if any
+    pub(crate) fn vcs_ignore_files(&self) -> Option<&VcsIgnoreFileLoader> {
+        self.vcs_ignore_files.as_deref()
+    }
+
+    /// The directory of the project whose module graph should be built, if any
+    pub(crate) fn module_graph(&self) -> Option<&Path> {
+        self.module_graph.as_deref()
+    }
+
+    /// The rules selected with `--only`, empty when all the enabled rules run
+    pub(crate) fn only(&self) -> &[RuleSelector] {
+        match &self.traversal_mode {
+            TraversalMode::Lint { only, .. } => only,
+            _ => &[],
+        }
+    }
+
     pub(crate) fn traversal_mode(&self) -> &TraversalMode {
         &self.traversal_mode
     }
@@ -375,11 +540,16 @@ impl Execution {
 
     /// Whether the traversal mode requires write access to files
     pub(crate) const fn requires_write_access(&self) -> bool {
+        if self.diff {
+            return false;
+        }
         match self.traversal_mode {
             TraversalMode::Check { fix_file_mode, .. }
             | TraversalMode::Lint { fix_file_mode, .. } => fix_file_mode.is_some(),
-            TraversalMode::CI { .. } | TraversalMode::Search { .. } => false,
-            TraversalMode::Format { write, .. } | TraversalMode::Migrate { write, .. } => write,
+            TraversalMode::CI { .. } => false,
+            TraversalMode::Format { write, .. }
+            | TraversalMode::Migrate { write, .. }
+            | TraversalMode::Search { write, .. } => write,
         }
     }
 
@@ -411,7 +581,7 @@ impl Execution {
             TraversalMode::CI { .. } => false,
             TraversalMode::Format { write, .. } => write,
             TraversalMode::Migrate { write, .. } => write,
-            TraversalMode::Search { .. } => false,
+            TraversalMode::Search { write, .. } => write,
         }
     }
 }
@@ -458,87 +628,49 @@ pub fn execute_mode(
             sub_command,
         };
         migrate::run(payload)
+    } else if execution.is_watch() {
+        watch::run(&execution, &mut session, cli_options, paths)
     } else {
-        let TraverseResult {
-            summary,
-            evaluated_paths,
-            diagnostics,
-        } = traverse(&execution, &mut session, cli_options, paths)?;
-        let console = session.app.console;
-        let errors = summary.errors;
-        let skipped = summary.skipped;
-        let processed = summary.changed + summary.unchanged;
-        let should_exit_on_warnings = summary.warnings > 0 && cli_options.error_on_warnings;
-
-        match execution.report_mode {
-            ReportMode::Terminal { with_summary } => {
-                if with_summary {
-                    let reporter = SummaryReporter {
-                        summary,
-                        diagnostics_payload: DiagnosticsPayload {
-                            verbose: cli_options.verbose,
-                            diagnostic_level: cli_options.diagnostic_level,
-                            diagnostics,
-                        },
-                        execution: execution.clone(),
-                    };
-                    reporter.write(&mut SummaryReporterVisitor(console))?;
-                } else {
-                    let reporter = ConsoleReporter {
-                        summary,
-                        diagnostics_payload: DiagnosticsPayload {
-                            verbose: cli_options.verbose,
-                            diagnostic_level: cli_options.diagnostic_level,
-                            diagnostics,
-                        },
-                        execution: execution.clone(),
-                        evaluated_paths,
-                    };
-                    reporter.write(&mut ConsoleReporterVisitor(console))?;
-                }
-            }
-            ReportMode::Json { pretty } => {
-                console.error(markup!{
-                    <Warn>"The "<Emphasis>"--json"</Emphasis>" option is "<Underline>"unstable/experimental"</Underline>" and its output might change between patches/minor releases."</Warn>
-                });
-                let reporter = JsonReporter {
+        traverse_and_report(&execution, &mut session, cli_options, paths)
+    }
+}
+
+/// Traverses the paths, and reports the diagnostics and the summary with the reporter
+/// of the execution.
+///
+/// It returns an error if the traversal emitted errors, or when no files were processed.
+fn traverse_and_report(
+    execution: &Execution,
+    session: &mut CliSession,
+    cli_options: &CliOptions,
+    paths: Vec<OsString>,
+) -> Result<(), CliDiagnostic> {
+    let TraverseResult {
+        summary,
+        evaluated_paths,
+        diagnostics,
+        diffs,
+    } = traverse(execution, session, cli_options, paths)?;
+    let mut rule_profile = execution
+        .rule_metrics
+        .as_deref()
+        .map(RuleMetrics::take_profile);
+    let console = &mut *session.app.console;
+    print_diffs(
+        console,
+        session.app.fs.working_directory().as_deref(),
+        diffs,
+    );
+    let errors = summary.errors;
+    let skipped = summary.skipped;
+    let processed = summary.changed + summary.unchanged;
+    let should_exit_on_warnings = summary.warnings > 0 && cli_options.error_on_warnings;
+
+    match execution.report_mode {
+        ReportMode::Terminal { with_summary } => {
+            if with_summary {
+                let reporter = SummaryReporter {
                     summary,
-                    diagnostics: DiagnosticsPayload {
-                        verbose: cli_options.verbose,
-                        diagnostic_level: cli_options.diagnostic_level,
-                        diagnostics,
-                    },
-                    execution: execution.clone(),
-                };
-                let mut buffer = JsonReporterVisitor::new(summary);
-                reporter.write(&mut buffer)?;
-                if pretty {
-                    let content = serde_json::to_string(&buffer).map_err(|error| {
-                        CliDiagnostic::Report(ReportDiagnostic::Serialization(
-                            SerdeJsonError::from(error),
-                        ))
-                    })?;
-                    let report_file = BiomePath::new("_report_output.json");
-                    session.app.workspace.open_file(OpenFileParams {
-                        content,
-                        path: report_file.clone(),
-                        version: 0,
-                        document_file_source: None,
-                    })?;
-                    let code = session.app.workspace.format_file(FormatFileParams {
-                        path: report_file.clone(),
-                    })?;
-                    console.log(markup! {
-                        {code.as_code()}
-                    });
-                } else {
-                    console.log(markup! {
-                        {buffer}
-                    });
-                }
-            }
-            ReportMode::GitHub => {
-                let reporter = GithubReporter {
                     diagnostics_payload: DiagnosticsPayload {
                         verbose: cli_options.verbose,
                         diagnostic_level: cli_options.diagnostic_level,
@@ -546,24 +678,9 @@ pub fn execute_mode(
                     },
                     execution: execution.clone(),
                 };
-                reporter.write(&mut GithubReporterVisitor(console))?;
-            }
-            ReportMode::GitLab => {
-                let reporter = GitLabReporter {
-                    diagnostics: DiagnosticsPayload {
-                        verbose: cli_options.verbose,
-                        diagnostic_level: cli_options.diagnostic_level,
-                        diagnostics,
-                    },
-                    execution: execution.clone(),
-                };
-                reporter.write(&mut GitLabReporterVisitor::new(
-                    console,
-                    session.app.fs.borrow().working_directory(),
-                ))?;
-            }
-            ReportMode::Junit => {
-                let reporter = JunitReporter {
+                reporter.write(&mut SummaryReporterVisitor(console))?;
+            } else {
+                let reporter = ConsoleReporter {
                     summary,
                     diagnostics_payload: DiagnosticsPayload {
                         verbose: cli_options.verbose,
@@ -571,29 +688,138 @@ pub fn execute_mode(
                         diagnostics,
                     },
                     execution: execution.clone(),
+                    evaluated_paths,
                 };
-                reporter.write(&mut JunitReporterVisitor::new(console))?;
+                reporter.write(&mut ConsoleReporterVisitor(console))?;
             }
         }
+        ReportMode::Json { pretty } => {
+            console.error(markup!{
+                <Warn>"The "<Emphasis>"--json"</Emphasis>" option is "<Underline>"unstable/experimental"</Underline>" and its output might change between patches/minor releases."</Warn>
+            });
+            let reporter = JsonReporter {
+                summary,
+                diagnostics: DiagnosticsPayload {
+                    verbose: cli_options.verbose,
+                    diagnostic_level: cli_options.diagnostic_level,
+                    diagnostics,
+                },
+                execution: execution.clone(),
+            };
+            let mut buffer =
+                JsonReporterVisitor::new(summary).with_rule_profile(rule_profile.take());
+            reporter.write(&mut buffer)?;
+            if pretty {
+                let content = serde_json::to_string(&buffer).map_err(|error| {
+                    CliDiagnostic::Report(ReportDiagnostic::Serialization(SerdeJsonError::from(
+                        error,
+                    )))
+                })?;
+                let report_file = BiomePath::new("_report_output.json");
+                session.app.workspace.open_file(OpenFileParams {
+                    content,
+                    path: report_file.clone(),
+                    version: 0,
+                    document_file_source: None,
+                })?;
+                let code = session.app.workspace.format_file(FormatFileParams {
+                    path: report_file.clone(),
+                })?;
+                console.log(markup! {
+                    {code.as_code()}
+                });
+            } else {
+                console.log(markup! {
+                    {buffer}
+                });
+            }
+        }
+        ReportMode::GitHub => {
+            let reporter = GithubReporter {
+                diagnostics_payload: DiagnosticsPayload {
+                    verbose: cli_options.verbose,
+                    diagnostic_level: cli_options.diagnostic_level,
+                    diagnostics,
+                },
+                execution: execution.clone(),
+            };
+            reporter.write(&mut GithubReporterVisitor(console))?;
+        }
+        ReportMode::GitLab => {
+            let reporter = GitLabReporter {
+                diagnostics: DiagnosticsPayload {
+                    verbose: cli_options.verbose,
+                    diagnostic_level: cli_options.diagnostic_level,
+                    diagnostics,
+                },
+                execution: execution.clone(),
+            };
+            reporter.write(&mut GitLabReporterVisitor::new(
+                console,
+                session.app.fs.borrow().working_directory(),
+            ))?;
+        }
+        ReportMode::Junit => {
+            let reporter = JunitReporter {
+                summary,
+                diagnostics_payload: DiagnosticsPayload {
+                    verbose: cli_options.verbose,
+                    diagnostic_level: cli_options.diagnostic_level,
+                    diagnostics,
+                },
+                execution: execution.clone(),
+            };
+            reporter.write(&mut JunitReporterVisitor::new(console))?;
+        }
+        ReportMode::Sarif => {
+            let reporter = SarifReporter {
+                diagnostics_payload: DiagnosticsPayload {
+                    verbose: cli_options.verbose,
+                    diagnostic_level: cli_options.diagnostic_level,
+                    diagnostics,
+                },
+                execution: execution.clone(),
+            };
+            reporter.write(&mut SarifReporterVisitor::new(
+                console,
+                session.app.fs.borrow().working_directory(),
+            ))?;
+        }
+        ReportMode::Checkstyle => {
+            let reporter = CheckstyleReporter {
+                diagnostics_payload: DiagnosticsPayload {
+                    verbose: cli_options.verbose,
+                    diagnostic_level: cli_options.diagnostic_level,
+                    diagnostics,
+                },
+                execution: execution.clone(),
+            };
+            reporter.write(&mut CheckstyleReporterVisitor(console))?;
+        }
+    }
 
-        // Processing emitted error diagnostics, exit with a non-zero code
-        if processed.saturating_sub(skipped) == 0 && !cli_options.no_errors_on_unmatched {
-            Err(CliDiagnostic::no_files_processed())
-        } else if errors > 0 || should_exit_on_warnings {
-            let category = execution.as_diagnostic_category();
-            if should_exit_on_warnings {
-                if execution.is_check_apply() {
-                    Err(CliDiagnostic::apply_warnings(category))
-                } else {
-                    Err(CliDiagnostic::check_warnings(category))
-                }
-            } else if execution.is_check_apply() {
-                Err(CliDiagnostic::apply_error(category))
+    // The profile is printed apart from the reports, which can be read by other tools
+    if let Some(rule_profile) = rule_profile {
+        console.error(markup! {{rule_profile}});
+    }
+
+    // Processing emitted error diagnostics, exit with a non-zero code
+    if processed.saturating_sub(skipped) == 0 && !cli_options.no_errors_on_unmatched {
+        Err(CliDiagnostic::no_files_processed())
+    } else if errors > 0 || should_exit_on_warnings {
+        let category = execution.as_diagnostic_category();
+        if should_exit_on_warnings {
+            if execution.is_check_apply() {
+                Err(CliDiagnostic::apply_warnings(category))
             } else {
-                Err(CliDiagnostic::check_error(category))
+                Err(CliDiagnostic::check_warnings(category))
             }
+        } else if execution.is_check_apply() {
+            Err(CliDiagnostic::apply_error(category))
         } else {
-            Ok(())
+            Err(CliDiagnostic::check_error(category))
         }
+    } else {
+        Ok(())
     }
 }
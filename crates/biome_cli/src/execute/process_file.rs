@@ -6,6 +6,7 @@ mod organize_imports;
 mod search;
 pub(crate) mod workspace_file;
 
+use crate::execute::cache::{CacheLookup, CacheRecorder};
 use crate::execute::diagnostics::{ResultExt, UnhandledDiagnostic};
 use crate::execute::traverse::TraversalOptions;
 use crate::execute::TraversalMode;
@@ -16,6 +17,7 @@ use check::check_file;
 use format::format;
 use lint::lint;
 use search::search;
+use serde::{Deserialize, Serialize};
 use std::marker::PhantomData;
 use std::ops::Deref;
 
@@ -70,11 +72,14 @@ impl Message {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub(crate) enum DiffKind {
     Format,
     OrganizeImports,
     Assists,
+    Rewrite,
+    /// All the changes of the command, which are printed instead of being written with `--diff`
+    Write,
 }
 
 impl<D> From<D> for Message
@@ -102,14 +107,26 @@ pub(crate) type FileResult = Result<FileStatus, Message>;
 /// compiler constraints set by the lifetimes of the [TraversalOptions]
 pub(crate) struct SharedTraversalOptions<'ctx, 'app> {
     inner: &'app TraversalOptions<'ctx, 'app>,
+    /// Records the messages of the processed file when the cache is enabled
+    recorder: Option<CacheRecorder>,
     _p: PhantomData<&'app ()>,
 }
 
 impl<'ctx, 'app> SharedTraversalOptions<'ctx, 'app> {
-    fn new(t: &'app TraversalOptions<'ctx, 'app>) -> Self {
+    fn new(t: &'app TraversalOptions<'ctx, 'app>, recorder: Option<CacheRecorder>) -> Self {
         Self {
             _p: PhantomData,
             inner: t,
+            recorder,
+        }
+    }
+
+    /// Send a message to the display thread, recording it in the cache if needed
+    pub(crate) fn push_message(&self, msg: impl Into<Message>) {
+        let msg = msg.into();
+        match &self.recorder {
+            Some(recorder) => self.inner.push_message(recorder.record(msg)),
+            None => self.inner.push_message(msg),
         }
     }
 }
@@ -206,9 +223,20 @@ pub(crate) fn process_file(ctx: &TraversalOptions, biome_path: &BiomePath) -> Fi
             };
         }
 
-        let shared_context = &SharedTraversalOptions::new(ctx);
+        let recorder = match ctx.cache.and_then(|cache| cache.lookup(ctx.fs, biome_path)) {
+            Some(CacheLookup::Hit(messages)) => {
+                for message in messages {
+                    ctx.push_message(message);
+                }
+                return Ok(FileStatus::Unchanged);
+            }
+            Some(CacheLookup::Miss(recorder)) => Some(recorder),
+            None => None,
+        };
 
-        match ctx.execution.traversal_mode {
+        let shared_context = SharedTraversalOptions::new(ctx, recorder);
+
+        let result = match ctx.execution.traversal_mode {
             TraversalMode::Lint {
                 ref suppression_reason,
                 suppress,
@@ -216,7 +244,7 @@ pub(crate) fn process_file(ctx: &TraversalOptions, biome_path: &BiomePath) -> Fi
             } => {
                 // the unsupported case should be handled already at this point
                 lint(
-                    shared_context,
+                    &shared_context,
                     biome_path,
                     suppress,
                     suppression_reason.as_deref(),
@@ -224,18 +252,30 @@ pub(crate) fn process_file(ctx: &TraversalOptions, biome_path: &BiomePath) -> Fi
             }
             TraversalMode::Format { .. } => {
                 // the unsupported case should be handled already at this point
-                format(shared_context, biome_path)
+                format(&shared_context, biome_path)
             }
             TraversalMode::Check { .. } | TraversalMode::CI { .. } => {
-                check_file(shared_context, biome_path, &file_features)
+                check_file(&shared_context, biome_path, &file_features)
             }
             TraversalMode::Migrate { .. } => {
                 unreachable!("The migration should not be called for this file")
             }
             TraversalMode::Search { ref pattern, .. } => {
                 // the unsupported case should be handled already at this point
-                search(shared_context, biome_path, pattern)
+                search(&shared_context, biome_path, pattern)
             }
-        }
+        };
+
+        let (Some(cache), Some(recorder)) = (ctx.cache, shared_context.recorder) else {
+            return result;
+        };
+        // Only the results of the files that weren't changed can be replayed
+        let result = match result {
+            Ok(FileStatus::Unchanged) => Ok(FileStatus::Unchanged),
+            Ok(FileStatus::Message(msg)) => Ok(FileStatus::Message(recorder.record(msg))),
+            result => return result,
+        };
+        cache.store(recorder);
+        result
     })
 }
@@ -29,12 +29,15 @@ pub(crate) fn assists_with_guard<'ctx>(
                     only.clone(),
                     skip.clone(),
                     None,
+                    ctx.execution.is_profiling_rules(),
                 )
                 .with_file_path_and_code(
                     workspace_file.path.display().to_string(),
                     category!("assists"),
                 )?;
 
+            ctx.execution
+                .record_rule_durations(fix_result.rule_durations);
             ctx.push_message(Message::SkippedFixes {
                 skipped_suggested_fixes: fix_result.skipped_suggested_fixes,
             });
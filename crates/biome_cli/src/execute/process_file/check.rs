@@ -101,6 +101,11 @@ pub(crate) fn check_file<'ctx>(
                 }
             }
 
+            if changed && ctx.execution.is_diff() {
+                ctx.push_message(workspace_file.diff()?);
+                changed = false;
+            }
+
             if has_failures {
                 Ok(FileStatus::Message(Message::Failure))
             } else if changed {
@@ -1,3 +1,4 @@
+use crate::changed::ChangedLineRanges;
 use crate::execute::diagnostics::{ResultExt, SkippedDiagnostic};
 use crate::execute::process_file::workspace_file::WorkspaceFile;
 use crate::execute::process_file::{
@@ -6,6 +7,7 @@ use crate::execute::process_file::{
 use crate::execute::TraversalMode;
 use biome_analyze::RuleCategoriesBuilder;
 use biome_diagnostics::{category, Diagnostic, DiagnosticExt, Error, Severity};
+use biome_rowan::{TextRange, TextSize};
 use biome_service::file_handlers::{AstroFileHandler, SvelteFileHandler, VueFileHandler};
 use std::ffi::OsStr;
 use std::path::Path;
@@ -23,7 +25,12 @@ pub(crate) fn format_with_guard<'ctx>(
 ) -> FileResult {
     tracing::info_span!("Processes formatting", path =? workspace_file.path.display()).in_scope(
         move || {
-            let max_diagnostics = ctx.remaining_diagnostics.load(Ordering::Relaxed);
+            // The diagnostics are filtered by the changed lines before the ones to print are capped
+            let max_diagnostics = if ctx.execution.changed_lines().is_some() {
+                u32::MAX
+            } else {
+                ctx.remaining_diagnostics.load(Ordering::Relaxed)
+            };
             debug!("Pulling diagnostics from parsed file");
             let diagnostics_result = workspace_file
                 .guard()
@@ -32,6 +39,7 @@ pub(crate) fn format_with_guard<'ctx>(
                     max_diagnostics,
                     Vec::new(),
                     Vec::new(),
+                    false,
                 )
                 .with_file_path_and_code(
                     workspace_file.path.display().to_string(),
@@ -77,15 +85,19 @@ pub(crate) fn format_with_guard<'ctx>(
                 skipped_diagnostics: diagnostics_result.skipped_diagnostics as u32,
             });
 
-            let printed = workspace_file
-                .guard()
-                .format_file()
-                .with_file_path_and_code(
-                    workspace_file.path.display().to_string(),
-                    category!("format"),
-                )?;
+            let mut output = if let Some(changed_lines) = ctx.execution.changed_lines() {
+                format_changed_lines(workspace_file, &input, changed_lines)?
+            } else {
+                let printed = workspace_file
+                    .guard()
+                    .format_file()
+                    .with_file_path_and_code(
+                        workspace_file.path.display().to_string(),
+                        category!("format"),
+                    )?;
 
-            let mut output = printed.into_code();
+                printed.into_code()
+            };
 
             if ignore_errors {
                 return Ok(FileStatus::Ignored);
@@ -132,3 +144,62 @@ pub(crate) fn format_with_guard<'ctx>(
         },
     )
 }
+
+/// Formats only the changed lines of the file, with `--changed-lines-only`.
+///
+/// Like the formatting of the whole file, only the code of the script is returned
+/// for the files that embed it.
+fn format_changed_lines(
+    workspace_file: &WorkspaceFile,
+    input: &str,
+    changed_lines: &ChangedLineRanges,
+) -> Result<String, Message> {
+    let (code, offset) = match workspace_file.as_extension().map(OsStr::as_encoded_bytes) {
+        Some(b"astro") => (
+            AstroFileHandler::input(input),
+            AstroFileHandler::start(input),
+        ),
+        Some(b"vue") => (VueFileHandler::input(input), VueFileHandler::start(input)),
+        Some(b"svelte") => (
+            SvelteFileHandler::input(input),
+            SvelteFileHandler::start(input),
+        ),
+        _ => (input, None),
+    };
+    let offset = TextSize::from(offset.unwrap_or_default());
+    let code_range = TextRange::at(offset, TextSize::of(code));
+
+    let mut edits: Vec<(TextRange, String)> = Vec::new();
+    for range in changed_lines.text_ranges(&workspace_file.path, input) {
+        let Some(range) = range
+            .intersect(code_range)
+            .filter(|range| !range.is_empty())
+        else {
+            continue;
+        };
+        let printed = workspace_file
+            .guard()
+            .format_range(range - offset)
+            .with_file_path_and_code(
+                workspace_file.path.display().to_string(),
+                category!("format"),
+            )?;
+        let range = printed
+            .range()
+            .unwrap_or_else(|| TextRange::up_to(TextSize::of(code)));
+        // The ranges of close changes can be extended to the same nodes
+        if edits.iter().any(|(edit_range, _)| {
+            edit_range.start() < range.end() && range.start() < edit_range.end()
+        }) {
+            continue;
+        }
+        edits.push((range, printed.into_code()));
+    }
+
+    edits.sort_unstable_by_key(|(range, _)| range.start());
+    let mut output = code.to_string();
+    for (range, text) in edits.into_iter().rev() {
+        output.replace_range(std::ops::Range::<usize>::from(range), &text);
+    }
+    Ok(output)
+}
@@ -18,7 +18,12 @@ pub(crate) fn lint<'ctx>(
     suppression_reason: Option<&str>,
 ) -> FileResult {
     let mut workspace_file = WorkspaceFile::new(ctx, path)?;
-    lint_with_guard(ctx, &mut workspace_file, suppress, suppression_reason)
+    let status = lint_with_guard(ctx, &mut workspace_file, suppress, suppression_reason)?;
+    if status.is_changed() && ctx.execution.is_diff() {
+        ctx.push_message(workspace_file.diff()?);
+        return Ok(FileStatus::Unchanged);
+    }
+    Ok(status)
 }
 
 pub(crate) fn lint_with_guard<'ctx>(
@@ -56,12 +61,15 @@ pub(crate) fn lint_with_guard<'ctx>(
                         only.clone(),
                         skip.clone(),
                         Some(suppression_explanation.to_string()),
+                        ctx.execution.is_profiling_rules(),
                     )
                     .with_file_path_and_code(
                         workspace_file.path.display().to_string(),
                         category!("lint"),
                     )?;
 
+                ctx.execution
+                    .record_rule_durations(fix_result.rule_durations);
                 ctx.push_message(Message::SkippedFixes {
                     skipped_suggested_fixes: fix_result.skipped_suggested_fixes,
                 });
@@ -87,7 +95,14 @@ pub(crate) fn lint_with_guard<'ctx>(
                 }
             }
 
-            let max_diagnostics = ctx.remaining_diagnostics.load(Ordering::Relaxed);
+            // The diagnostics must all be matched against the baseline, or recorded in it, and
+            // filtered by the changed lines. The ones to print are capped after they're filtered.
+            let max_diagnostics =
+                if ctx.execution.baseline().is_some() || ctx.execution.changed_lines().is_some() {
+                    u32::MAX
+                } else {
+                    ctx.remaining_diagnostics.load(Ordering::Relaxed)
+                };
             let pull_diagnostics_result = workspace_file
                 .guard()
                 .pull_diagnostics(
@@ -98,12 +113,15 @@ pub(crate) fn lint_with_guard<'ctx>(
                     max_diagnostics,
                     only,
                     skip,
+                    ctx.execution.is_profiling_rules(),
                 )
                 .with_file_path_and_code(
                     workspace_file.path.display().to_string(),
                     category!("lint"),
                 )?;
 
+            ctx.execution
+                .record_rule_durations(pull_diagnostics_result.rule_durations);
             let no_diagnostics = pull_diagnostics_result.diagnostics.is_empty()
                 && pull_diagnostics_result.skipped_diagnostics == 0;
 
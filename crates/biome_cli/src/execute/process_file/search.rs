@@ -1,6 +1,8 @@
 use crate::execute::diagnostics::{ResultExt, SearchDiagnostic};
 use crate::execute::process_file::workspace_file::WorkspaceFile;
-use crate::execute::process_file::{FileResult, FileStatus, Message, SharedTraversalOptions};
+use crate::execute::process_file::{
+    DiffKind, FileResult, FileStatus, Message, SharedTraversalOptions,
+};
 use biome_diagnostics::{category, DiagnosticExt};
 use biome_service::workspace::PatternId;
 use std::path::Path;
@@ -15,7 +17,7 @@ pub(crate) fn search<'ctx>(
 }
 
 pub(crate) fn search_with_guard<'ctx>(
-    _ctx: &'ctx SharedTraversalOptions<'ctx, '_>,
+    ctx: &'ctx SharedTraversalOptions<'ctx, '_>,
     workspace_file: &mut WorkspaceFile,
     pattern: &PatternId,
 ) -> FileResult {
@@ -33,6 +35,24 @@ pub(crate) fn search_with_guard<'ctx>(
             let file_name = workspace_file.path.display().to_string();
             let matches_len = result.matches.len();
 
+            if let Some(rewritten) = result.rewritten.filter(|rewritten| *rewritten != input) {
+                if ctx.execution.is_write() {
+                    workspace_file.update_file(rewritten)?;
+                    ctx.increment_matches(matches_len);
+                    return Ok(FileStatus::Changed);
+                }
+
+                return Ok(FileStatus::SearchResult(
+                    matches_len,
+                    Message::Diff {
+                        file_name,
+                        old: input,
+                        new: rewritten,
+                        diff_kind: DiffKind::Rewrite,
+                    },
+                ));
+            }
+
             let search_results = Message::Diagnostics {
                 name: file_name,
                 content: input,
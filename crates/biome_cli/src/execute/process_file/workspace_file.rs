@@ -1,5 +1,5 @@
 use crate::execute::diagnostics::{ResultExt, ResultIoExt};
-use crate::execute::process_file::SharedTraversalOptions;
+use crate::execute::process_file::{DiffKind, Message, SharedTraversalOptions};
 use biome_diagnostics::{category, Error};
 use biome_fs::{BiomePath, File, OpenOptions};
 use biome_service::workspace::{FileGuard, OpenFileParams};
@@ -12,6 +12,12 @@ pub(crate) struct WorkspaceFile<'ctx, 'app> {
     guard: FileGuard<'app, dyn Workspace + 'ctx>,
     file: Box<dyn File>,
     pub(crate) path: PathBuf,
+    /// The content of the file before it was processed
+    original: String,
+    /// The version of the document in the workspace
+    version: i32,
+    /// Whether the changes are only applied to the document, to be printed as a diff
+    is_diff: bool,
 }
 
 impl<'ctx, 'app> WorkspaceFile<'ctx, 'app> {
@@ -34,21 +40,27 @@ impl<'ctx, 'app> WorkspaceFile<'ctx, 'app> {
         file.read_to_string(&mut input)
             .with_file_path(path.display().to_string())?;
 
-        let guard = FileGuard::open(
-            ctx.workspace,
-            OpenFileParams {
-                document_file_source: None,
-                path: biome_path,
-                version: 0,
-                content: input.clone(),
-            },
-        )
+        let params = OpenFileParams {
+            document_file_source: None,
+            path: biome_path,
+            version: 0,
+            content: input.clone(),
+        };
+        // In watch mode, the files stay open so that they aren't parsed again when they don't change
+        let guard = if ctx.execution.is_watch() {
+            FileGuard::open_persistent(ctx.workspace, params)
+        } else {
+            FileGuard::open(ctx.workspace, params)
+        }
         .with_file_path_and_code(path.display().to_string(), category!("internalError/fs"))?;
 
         Ok(Self {
             file,
             guard,
             path: PathBuf::from(path),
+            original: input,
+            version: 0,
+            is_diff: ctx.execution.is_diff(),
         })
     }
 
@@ -65,14 +77,28 @@ impl<'ctx, 'app> WorkspaceFile<'ctx, 'app> {
     }
 
     /// It updates the workspace file with `new_content`
+    ///
+    /// With `--diff`, the file on disk is left untouched, and only the document is updated.
     pub(crate) fn update_file(&mut self, new_content: impl Into<String>) -> Result<(), Error> {
         let new_content = new_content.into();
 
-        self.file
-            .set_content(new_content.as_bytes())
-            .with_file_path(self.path.display().to_string())?;
-        self.guard
-            .change_file(self.file.file_version(), new_content)?;
+        if !self.is_diff {
+            self.file
+                .set_content(new_content.as_bytes())
+                .with_file_path(self.path.display().to_string())?;
+        }
+        self.version += 1;
+        self.guard.change_file(self.version, new_content)?;
         Ok(())
     }
+
+    /// Returns the changes applied to the file as a [Message::Diff], for `--diff`
+    pub(crate) fn diff(&self) -> Result<Message, WorkspaceError> {
+        Ok(Message::Diff {
+            file_name: self.path.display().to_string(),
+            old: self.original.clone(),
+            new: self.input()?,
+            diff_kind: DiffKind::Write,
+        })
+    }
 }
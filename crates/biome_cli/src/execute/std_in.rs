@@ -10,7 +10,7 @@ use biome_fs::BiomePath;
 use biome_service::file_handlers::{AstroFileHandler, SvelteFileHandler, VueFileHandler};
 use biome_service::workspace::{
     ChangeFileParams, DropPatternParams, FeaturesBuilder, FixFileParams, FormatFileParams,
-    OpenFileParams, OrganizeImportsParams, SupportsFeatureParams,
+    OpenFileParams, OrganizeImportsParams, SearchPatternParams, SupportsFeatureParams,
 };
 use biome_service::WorkspaceError;
 use std::borrow::Cow;
@@ -122,6 +122,7 @@ pub(crate) fn run<'a>(
                     only: only.clone(),
                     skip: skip.clone(),
                     suppression_reason: None,
+                    profile_rules: false,
                     rule_categories: RuleCategoriesBuilder::default()
                         .with_syntax()
                         .with_lint()
@@ -201,12 +202,32 @@ pub(crate) fn run<'a>(
             }
         }
     } else if let TraversalMode::Search { pattern, .. } = mode.traversal_mode() {
+        let file_features = workspace.file_features(SupportsFeatureParams {
+            path: biome_path.clone(),
+            features: FeaturesBuilder::new().with_search().build(),
+        })?;
+        let result = if file_features.supports_search() {
+            workspace.open_file(OpenFileParams {
+                path: biome_path.clone(),
+                version: 0,
+                content: content.into(),
+                document_file_source: None,
+            })?;
+            Some(workspace.search_pattern(SearchPatternParams {
+                path: biome_path.clone(),
+                pattern: pattern.clone(),
+            }))
+        } else {
+            None
+        };
+
         // Make sure patterns are always cleaned up at the end of execution.
         let _ = session.app.workspace.drop_pattern(DropPatternParams {
             pattern: pattern.clone(),
         });
 
-        console.append(markup! {{content}});
+        let rewritten = result.transpose()?.and_then(|result| result.rewritten);
+        console.append(markup! {{rewritten.as_deref().unwrap_or(content)}});
     } else {
         console.append(markup! {{content}});
     }
@@ -1,10 +1,13 @@
+use super::baseline::Baseline;
+use super::cache::TraversalCache;
+use super::diff::UnifiedDiff;
 use super::process_file::{process_file, DiffKind, FileStatus, Message};
 use super::{Execution, TraversalMode};
 use crate::cli_options::CliOptions;
 use crate::execute::diagnostics::{
     AssistsDiffDiagnostic, CIAssistsDiffDiagnostic, CIFormatDiffDiagnostic,
     CIOrganizeImportsDiffDiagnostic, ContentDiffAdvice, FormatDiffDiagnostic,
-    OrganizeImportsDiffDiagnostic, PanicDiagnostic,
+    OrganizeImportsDiffDiagnostic, PanicDiagnostic, RewriteDiffDiagnostic,
 };
 use crate::reporter::TraversalSummary;
 use crate::{CliDiagnostic, CliSession};
@@ -13,7 +16,10 @@ use biome_diagnostics::{category, DiagnosticExt, Error, Resource, Severity};
 use biome_fs::{BiomePath, FileSystem, PathInterner};
 use biome_fs::{TraversalContext, TraversalScope};
 use biome_service::dome::Dome;
-use biome_service::workspace::{DropPatternParams, IsPathIgnoredParams};
+use biome_service::module_graph::ModuleGraphBuilder;
+use biome_service::workspace::{
+    DropPatternParams, GetModuleGraphPathsParams, IsPathIgnoredParams, UpdateModuleGraphParams,
+};
 use biome_service::{extension_error, workspace::SupportsFeatureParams, Workspace, WorkspaceError};
 use crossbeam::channel::{unbounded, Receiver, Sender};
 use rustc_hash::FxHashSet;
@@ -24,7 +30,7 @@ use std::{
     env::current_dir,
     ffi::OsString,
     panic::catch_unwind,
-    path::PathBuf,
+    path::{Path, PathBuf},
     sync::{
         atomic::{AtomicUsize, Ordering},
         Once,
@@ -32,11 +38,13 @@ use std::{
     thread,
     time::{Duration, Instant},
 };
+use tracing::warn;
 
 pub(crate) struct TraverseResult {
     pub(crate) summary: TraversalSummary,
     pub(crate) evaluated_paths: BTreeSet<BiomePath>,
     pub(crate) diagnostics: Vec<Error>,
+    pub(crate) diffs: Vec<UnifiedDiff>,
 }
 
 pub(crate) fn traverse(
@@ -87,12 +95,16 @@ pub(crate) fn traverse(
     let max_diagnostics = execution.get_max_diagnostics();
     let remaining_diagnostics = AtomicU32::new(max_diagnostics);
 
+    let cache = TraversalCache::load(fs, execution);
+    let baseline = Baseline::load(fs, execution)?;
+
     let printer = DiagnosticsPrinter::new(execution)
+        .with_baseline(baseline.as_ref())
         .with_verbose(cli_options.verbose)
         .with_diagnostic_level(cli_options.diagnostic_level)
         .with_max_diagnostics(max_diagnostics);
 
-    let (duration, evaluated_paths, diagnostics) = thread::scope(|s| {
+    let (duration, evaluated_paths, (mut diagnostics, diffs)) = thread::scope(|s| {
         let handler = thread::Builder::new()
             .name(String::from("biome::console"))
             .spawn_scoped(s, || printer.run(receiver, recv_files))
@@ -115,14 +127,29 @@ pub(crate) fn traverse(
                 messages: sender,
                 remaining_diagnostics: &remaining_diagnostics,
                 evaluated_paths: RwLock::default(),
+                cache: cache.as_ref(),
             },
         );
         // wait for the main thread to finish
-        let diagnostics = handler.join().unwrap();
+        let printed = handler.join().unwrap();
 
-        (elapsed, evaluated_paths, diagnostics)
+        (elapsed, evaluated_paths, printed)
     });
 
+    if let Some(cache) = cache {
+        if let Err(error) = cache.save(fs) {
+            warn!("Failed to write the cache: {error}");
+        }
+    }
+
+    if let Some(baseline) = &baseline {
+        if let Some(diagnostic) = baseline.finish(fs, &evaluated_paths)? {
+            if !printer.should_skip_diagnostic(diagnostic.severity(), diagnostic.tags()) {
+                diagnostics.push(diagnostic);
+            }
+        }
+    }
+
     // Make sure patterns are always cleaned up at the end of traversal.
     if let TraversalMode::Search { pattern, .. } = execution.traversal_mode() {
         let _ = session.app.workspace.drop_pattern(DropPatternParams {
@@ -152,6 +179,7 @@ pub(crate) fn traverse(
         },
         evaluated_paths,
         diagnostics,
+        diffs,
     })
 }
 
@@ -183,6 +211,9 @@ fn traverse_inputs(
     }));
 
     let paths = ctx.evaluated_paths();
+    if let Some(project_path) = ctx.execution.module_graph() {
+        update_module_graph(fs, project_path, &paths, ctx);
+    }
     let dome = Dome::new(paths);
     let mut iter = dome.iter();
     fs.traversal(Box::new(|scope: &dyn TraversalScope| {
@@ -202,11 +233,50 @@ fn traverse_inputs(
     (start.elapsed(), ctx.evaluated_paths())
 }
 
+/// Builds the module graph of the files that are going to be processed, so that the rules of the
+/// project phase can look at the modules they import.
+///
+/// The graph must be complete before the files are processed, so the modules are parsed in
+/// parallel ahead of the traversal. The modules of a previous run, kept by the daemon, that
+/// aren't part of the new graph are removed.
+fn update_module_graph(
+    fs: &dyn FileSystem,
+    project_path: &Path,
+    paths: &BTreeSet<BiomePath>,
+    ctx: &TraversalOptions,
+) {
+    let mut builder = ModuleGraphBuilder::new(fs, project_path);
+    builder.add_paths(paths.iter().map(|path| path.to_path_buf()));
+    let module_graph = builder.build();
+    let removed_paths = match ctx
+        .workspace
+        .get_module_graph_paths(GetModuleGraphPathsParams {})
+    {
+        Ok(result) => result
+            .paths
+            .into_iter()
+            .filter(|path| module_graph.module(path).is_none())
+            .collect(),
+        Err(error) => {
+            ctx.push_diagnostic(error.into());
+            Vec::new()
+        }
+    };
+    if let Err(error) = ctx.workspace.update_module_graph(UpdateModuleGraphParams {
+        module_graph,
+        removed_paths,
+    }) {
+        ctx.push_diagnostic(error.into());
+    }
+}
+
 // struct DiagnosticsReporter<'ctx> {}
 
 struct DiagnosticsPrinter<'ctx> {
     ///  Execution of the traversal
     execution: &'ctx Execution,
+    /// The baseline that suppresses or records the lint diagnostics
+    baseline: Option<&'ctx Baseline>,
     /// The maximum number of diagnostics the console thread is allowed to print
     max_diagnostics: u32,
     /// The approximate number of diagnostics the console will print before
@@ -235,6 +305,7 @@ impl<'ctx> DiagnosticsPrinter<'ctx> {
             warnings: AtomicU32::new(0),
             remaining_diagnostics: AtomicU32::new(0),
             execution,
+            baseline: None,
             diagnostic_level: Severity::Hint,
             verbose: false,
             max_diagnostics: 20,
@@ -244,6 +315,11 @@ impl<'ctx> DiagnosticsPrinter<'ctx> {
         }
     }
 
+    fn with_baseline(mut self, baseline: Option<&'ctx Baseline>) -> Self {
+        self.baseline = baseline;
+        self
+    }
+
     fn with_verbose(mut self, verbose: bool) -> Self {
         self.verbose = verbose;
         self
@@ -290,6 +366,21 @@ impl<'ctx> DiagnosticsPrinter<'ctx> {
         false
     }
 
+    /// Checks if the diagnostic is suppressed, or recorded, by the baseline
+    fn is_in_baseline(&self, file_name: &str, content: &str, diagnostic: &Error) -> bool {
+        self.baseline
+            .is_some_and(|baseline| baseline.contains(file_name, content, diagnostic))
+    }
+
+    /// Checks if the diagnostic is located on a changed line, when only these lines are reported
+    fn is_on_changed_lines(&self, file_name: &str, content: &str, diagnostic: &Error) -> bool {
+        self.execution
+            .changed_lines()
+            .map_or(true, |changed_lines| {
+                changed_lines.contains(file_name, content, diagnostic)
+            })
+    }
+
     /// Count the diagnostic, and then returns a boolean that tells if it should be printed
     fn should_print(&self) -> bool {
         let printed_diagnostics = self.printed_diagnostics.load(Ordering::Relaxed);
@@ -307,10 +398,16 @@ impl<'ctx> DiagnosticsPrinter<'ctx> {
         should_print
     }
 
-    fn run(&self, receiver: Receiver<Message>, interner: Receiver<PathBuf>) -> Vec<Error> {
+    /// Collects the diagnostics to print, and the diffs to print with `--diff`
+    fn run(
+        &self,
+        receiver: Receiver<Message>,
+        interner: Receiver<PathBuf>,
+    ) -> (Vec<Error>, Vec<UnifiedDiff>) {
         let mut paths: FxHashSet<String> = FxHashSet::default();
 
         let mut diagnostics_to_print = vec![];
+        let mut diffs = vec![];
 
         while let Ok(msg) = receiver.recv() {
             match msg {
@@ -380,6 +477,11 @@ impl<'ctx> DiagnosticsPrinter<'ctx> {
                     // is CI mode we want to print all the diagnostics
                     if self.execution.is_ci() {
                         for diag in diagnostics {
+                            if self.is_in_baseline(&name, &content, &diag)
+                                || !self.is_on_changed_lines(&name, &content, &diag)
+                            {
+                                continue;
+                            }
                             let severity = diag.severity();
                             if self.should_skip_diagnostic(severity, diag.tags()) {
                                 continue;
@@ -397,6 +499,11 @@ impl<'ctx> DiagnosticsPrinter<'ctx> {
                         }
                     } else {
                         for diag in diagnostics {
+                            if self.is_in_baseline(&name, &content, &diag)
+                                || !self.is_on_changed_lines(&name, &content, &diag)
+                            {
+                                continue;
+                            }
                             let severity = diag.severity();
                             if self.should_skip_diagnostic(severity, diag.tags()) {
                                 continue;
@@ -424,14 +531,23 @@ impl<'ctx> DiagnosticsPrinter<'ctx> {
                     new,
                     diff_kind,
                 } => {
-                    // A diff is an error in CI mode and in format check mode
-                    let is_error = self.execution.is_ci() || !self.execution.is_format_write();
+                    // A diff is an error in CI mode and in format check mode, while the
+                    // rewrites of a search are only reported
+                    let is_error = !matches!(diff_kind, DiffKind::Rewrite)
+                        && (self.execution.is_ci() || !self.execution.is_format_write());
                     if is_error {
                         self.errors.fetch_add(1, Ordering::Relaxed);
                     }
 
+                    if self.execution.is_diff() {
+                        diffs.push(UnifiedDiff::new(file_name, &old, &new));
+                        continue;
+                    }
+
                     let severity: Severity = if is_error {
                         Severity::Error
+                    } else if matches!(diff_kind, DiffKind::Rewrite) {
+                        Severity::Information
                     } else {
                         // we set lowest
                         Severity::Hint
@@ -446,7 +562,7 @@ impl<'ctx> DiagnosticsPrinter<'ctx> {
                     if should_print {
                         if self.execution.is_ci() {
                             match diff_kind {
-                                DiffKind::Format => {
+                                DiffKind::Format | DiffKind::Write => {
                                     let diag = CIFormatDiffDiagnostic {
                                         file_name: file_name.clone(),
                                         diff: ContentDiffAdvice {
@@ -485,10 +601,23 @@ impl<'ctx> DiagnosticsPrinter<'ctx> {
                                             .with_file_source_code(old.clone()),
                                     )
                                 }
+                                DiffKind::Rewrite => {
+                                    let diag = RewriteDiffDiagnostic {
+                                        file_name: file_name.clone(),
+                                        diff: ContentDiffAdvice {
+                                            old: old.clone(),
+                                            new: new.clone(),
+                                        },
+                                    };
+                                    diagnostics_to_print.push(
+                                        diag.with_severity(severity)
+                                            .with_file_source_code(old.clone()),
+                                    )
+                                }
                             };
                         } else {
                             match diff_kind {
-                                DiffKind::Format => {
+                                DiffKind::Format | DiffKind::Write => {
                                     let diag = FormatDiffDiagnostic {
                                         file_name: file_name.clone(),
                                         diff: ContentDiffAdvice {
@@ -527,13 +656,26 @@ impl<'ctx> DiagnosticsPrinter<'ctx> {
                                             .with_file_source_code(old.clone()),
                                     )
                                 }
+                                DiffKind::Rewrite => {
+                                    let diag = RewriteDiffDiagnostic {
+                                        file_name: file_name.clone(),
+                                        diff: ContentDiffAdvice {
+                                            old: old.clone(),
+                                            new: new.clone(),
+                                        },
+                                    };
+                                    diagnostics_to_print.push(
+                                        diag.with_severity(severity)
+                                            .with_file_source_code(old.clone()),
+                                    )
+                                }
                             };
                         }
                     }
                 }
             }
         }
-        diagnostics_to_print
+        (diagnostics_to_print, diffs)
     }
 }
 
@@ -563,6 +705,9 @@ pub(crate) struct TraversalOptions<'ctx, 'app> {
 
     /// List of paths that should be processed
     pub(crate) evaluated_paths: RwLock<BTreeSet<BiomePath>>,
+
+    /// The results of the previous traversals, when the cache is enabled
+    pub(crate) cache: Option<&'ctx TraversalCache>,
 }
 
 impl<'ctx, 'app> TraversalOptions<'ctx, 'app> {
@@ -616,6 +761,12 @@ impl<'ctx, 'app> TraversalContext for TraversalOptions<'ctx, 'app> {
 
     fn can_handle(&self, biome_path: &BiomePath) -> bool {
         let path = biome_path.as_path();
+        // Some file systems only traverse files, and the inputs can be inside subdirectories
+        if let Some(loader) = self.execution.vcs_ignore_files() {
+            if let Err(error) = loader.load_parent_directories(self.fs, self.workspace, path) {
+                self.push_diagnostic(error.into());
+            }
+        }
         if self.fs.path_is_dir(path) || self.fs.path_is_symlink(path) {
             // handle:
             // - directories
@@ -633,6 +784,13 @@ impl<'ctx, 'app> TraversalContext for TraversalOptions<'ctx, 'app> {
                     self.push_diagnostic(err.into());
                     false
                 });
+            if can_handle {
+                if let Some(loader) = self.execution.vcs_ignore_files() {
+                    if let Err(error) = loader.load_directory(self.fs, self.workspace, path) {
+                        self.push_diagnostic(error.into());
+                    }
+                }
+            }
             return can_handle;
         }
 
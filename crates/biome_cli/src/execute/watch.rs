@@ -0,0 +1,417 @@
+use super::{traverse_and_report, Execution};
+use crate::cli_options::CliOptions;
+use crate::{CliDiagnostic, CliSession};
+use biome_console::{markup, ConsoleExt};
+use biome_diagnostics::PrintDiagnostic;
+use biome_fs::{BiomePath, ConfigName, FileSystem, OpenOptions, DEFAULT_IGNORE};
+use biome_service::workspace::{
+    CloseFileParams, GetFileContentParams, IsPathIgnoredParams, SupportsFeatureParams,
+};
+use biome_service::Workspace;
+use crossbeam::channel::{unbounded, Receiver};
+use notify::event::ModifyKind;
+use notify::{Event, EventKind, RecursiveMode, Watcher};
+use std::collections::BTreeSet;
+use std::ffi::OsString;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use tracing::warn;
+
+/// How long to wait for other events after a change, so that the files that are saved
+/// together (e.g. by a `git checkout`) are processed in a single run
+const DEBOUNCE_DURATION: Duration = Duration::from_millis(100);
+
+/// Processes the paths, then watches them and processes again the files that change,
+/// until the process is stopped.
+///
+/// The files stay open in the workspace between the runs, and the diagnostics emitted by
+/// each run are reported instead of stopping the command.
+pub(crate) fn run(
+    execution: &Execution,
+    session: &mut CliSession,
+    cli_options: &CliOptions,
+    paths: Vec<OsString>,
+) -> Result<(), CliDiagnostic> {
+    let working_directory = session.app.fs.working_directory().unwrap_or_default();
+    let roots = if paths.is_empty() {
+        vec![working_directory.clone()]
+    } else {
+        paths.iter().map(PathBuf::from).collect()
+    };
+
+    let (sender, receiver) = unbounded();
+    let mut watcher = notify::recommended_watcher(sender).map_err(watch_error)?;
+    for root in &roots {
+        watcher
+            .watch(&working_directory.join(root), RecursiveMode::Recursive)
+            .map_err(watch_error)?;
+    }
+
+    watch(
+        execution,
+        session,
+        cli_options,
+        paths,
+        Watched {
+            roots: &roots,
+            working_directory: &working_directory,
+            receiver: &receiver,
+        },
+    );
+    Ok(())
+}
+
+/// The paths watched by the command, and the events of their changes
+struct Watched<'a> {
+    roots: &'a [PathBuf],
+    working_directory: &'a Path,
+    receiver: &'a Receiver<notify::Result<Event>>,
+}
+
+/// Processes the paths, then processes again the files that change until the events stop
+fn watch(
+    execution: &Execution,
+    session: &mut CliSession,
+    cli_options: &CliOptions,
+    paths: Vec<OsString>,
+    watched: Watched,
+) {
+    let Watched {
+        roots,
+        working_directory,
+        receiver,
+    } = watched;
+
+    let result = traverse_and_report(execution, session, cli_options, paths);
+    print_result(session, cli_options, result);
+
+    while let Some(changes) = next_changes(&*session.app.fs, receiver) {
+        let fs = &*session.app.fs;
+        let workspace = &*session.app.workspace;
+        let console = &mut *session.app.console;
+        let input_path = |path: &PathBuf| as_input_path(roots, working_directory, path);
+
+        for path in &changes.removed {
+            // The file might have never been processed, in which case it isn't open
+            let _ = workspace.close_file(CloseFileParams {
+                path: BiomePath::new(input_path(path)),
+            });
+        }
+
+        if changes
+            .changed
+            .iter()
+            .any(|path| is_configuration_file(path))
+        {
+            console.error(markup! {
+                <Warn>"The configuration file changed, restart the command to apply it."</Warn>
+            });
+        }
+
+        let changed: Vec<_> = changes
+            .changed
+            .iter()
+            .map(input_path)
+            .filter(|path| should_process(fs, workspace, execution, path))
+            .collect();
+        if changed.is_empty() {
+            continue;
+        }
+
+        let file_names: Vec<_> = changed
+            .iter()
+            .map(|path| {
+                path.strip_prefix(working_directory)
+                    .unwrap_or(path)
+                    .display()
+                    .to_string()
+            })
+            .collect();
+        let file_names = file_names.join(", ");
+        console.log(markup! {
+            "\n"<Info>"Changed: "</Info>{file_names}
+        });
+
+        let paths = changed.into_iter().map(PathBuf::into_os_string).collect();
+        let result = traverse_and_report(execution, session, cli_options, paths);
+        print_result(session, cli_options, result);
+    }
+}
+
+/// Returns the path of a changed file as it's reached from the watched paths, so that it's
+/// the same path the file was processed with.
+///
+/// The watcher always reports absolute paths, while the paths passed to the command can be
+/// relative to the working directory.
+fn as_input_path(roots: &[PathBuf], working_directory: &Path, path: &Path) -> PathBuf {
+    roots
+        .iter()
+        .find_map(|root| {
+            let relative_path = path.strip_prefix(working_directory.join(root)).ok()?;
+            Some(if relative_path.as_os_str().is_empty() {
+                root.clone()
+            } else {
+                root.join(relative_path)
+            })
+        })
+        .unwrap_or_else(|| path.to_path_buf())
+}
+
+/// The paths affected by the events received after a change
+#[derive(Default)]
+struct Changes {
+    /// The files that were created or modified, and the directories that were created
+    changed: BTreeSet<PathBuf>,
+    /// The paths that don't exist anymore
+    removed: BTreeSet<PathBuf>,
+}
+
+impl Changes {
+    fn push(&mut self, fs: &dyn FileSystem, event: Event) {
+        if matches!(event.kind, EventKind::Access(_)) {
+            return;
+        }
+        let may_add_directory = matches!(
+            event.kind,
+            EventKind::Create(_) | EventKind::Modify(ModifyKind::Name(_))
+        );
+        for path in event.paths {
+            if fs.path_is_file(&path) || (may_add_directory && fs.path_is_dir(&path)) {
+                self.removed.remove(&path);
+                self.changed.insert(path);
+            } else if !fs.path_exists(&path) {
+                self.changed.remove(&path);
+                self.removed.insert(path);
+            }
+        }
+    }
+}
+
+/// Waits for a change, and collects the events that follow it shortly.
+///
+/// Returns `None` once the watcher stopped.
+fn next_changes(
+    fs: &dyn FileSystem,
+    receiver: &Receiver<notify::Result<Event>>,
+) -> Option<Changes> {
+    let mut changes = Changes::default();
+    let mut push = |event: notify::Result<Event>| match event {
+        Ok(event) => changes.push(fs, event),
+        Err(error) => warn!("Failed to watch the files: {error}"),
+    };
+
+    push(receiver.recv().ok()?);
+    while let Ok(event) = receiver.recv_timeout(DEBOUNCE_DURATION) {
+        push(event);
+    }
+    Some(changes)
+}
+
+/// Whether a changed path should be processed again.
+///
+/// The paths that are ignored are skipped, as well as the files whose content didn't change since
+/// they were processed, which avoids processing again the files that were just written.
+fn should_process(
+    fs: &dyn FileSystem,
+    workspace: &dyn Workspace,
+    execution: &Execution,
+    path: &Path,
+) -> bool {
+    if path
+        .components()
+        .any(|component| DEFAULT_IGNORE.contains(&component.as_os_str().as_encoded_bytes()))
+        || is_configuration_file(path)
+    {
+        return false;
+    }
+
+    let biome_path = BiomePath::new(path);
+    let is_ignored = workspace
+        .is_path_ignored(IsPathIgnoredParams {
+            biome_path: biome_path.clone(),
+            features: execution.to_feature(),
+        })
+        .unwrap_or(true);
+    if is_ignored {
+        return false;
+    }
+    if fs.path_is_dir(path) {
+        return true;
+    }
+
+    let is_supported = workspace
+        .file_features(SupportsFeatureParams {
+            path: biome_path.clone(),
+            features: execution.to_feature(),
+        })
+        .is_ok_and(|file_features| {
+            !file_features.is_ignored()
+                && !file_features.is_not_supported()
+                && !file_features.is_protected()
+        });
+    if !is_supported {
+        return false;
+    }
+
+    let Ok(previous_content) =
+        workspace.get_file_content(GetFileContentParams { path: biome_path })
+    else {
+        return true;
+    };
+    let content = fs
+        .open_with_options(path, OpenOptions::default().read(true))
+        .and_then(|mut file| {
+            let mut content = String::new();
+            file.read_to_string(&mut content)?;
+            Ok(content)
+        });
+    content.map_or(true, |content| content != previous_content)
+}
+
+fn is_configuration_file(path: &Path) -> bool {
+    path.file_name()
+        .and_then(|file_name| file_name.to_str())
+        .is_some_and(|file_name| ConfigName::file_names().contains(&file_name))
+}
+
+/// Prints the error of a run, since the command keeps watching the files
+fn print_result(
+    session: &mut CliSession,
+    cli_options: &CliOptions,
+    result: Result<(), CliDiagnostic>,
+) {
+    if let Err(error) = result {
+        let console = &mut *session.app.console;
+        if cli_options.verbose {
+            console.error(markup! {{PrintDiagnostic::verbose(&error)}});
+        } else {
+            console.error(markup! {{PrintDiagnostic::simple(&error)}});
+        }
+    }
+}
+
+fn watch_error(error: notify::Error) -> CliDiagnostic {
+    CliDiagnostic::io_error(io::Error::other(error))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{watch, Watched};
+    use crate::cli_options::cli_options;
+    use crate::execute::VcsTargeted;
+    use crate::{CliSession, Execution};
+    use biome_configuration::{PartialConfiguration, PartialFilesConfiguration};
+    use biome_console::fmt::{Formatter, Termcolor};
+    use biome_console::{markup, BufferConsole};
+    use biome_deserialize::StringSet;
+    use biome_diagnostics::termcolor::NoColor;
+    use biome_fs::MemoryFileSystem;
+    use biome_service::workspace::{server, RegisterProjectFolderParams, UpdateSettingsParams};
+    use biome_service::{App, DynRef, WorkspaceRef};
+    use bpaf::Parser;
+    use crossbeam::channel::unbounded;
+    use notify::event::{DataChange, ModifyKind};
+    use notify::{Event, EventKind};
+    use std::path::PathBuf;
+
+    /// Processes `processed.js`, then the events of the changes of `changed`, and returns the
+    /// messages printed by the command
+    fn run_watch(changed: &[&str]) -> Vec<String> {
+        let mut fs = MemoryFileSystem::default();
+        for file_name in ["processed.js", "changed.js", "ignored.js"] {
+            fs.insert(PathBuf::from(file_name), "statement();\n");
+        }
+
+        let workspace = server();
+        workspace
+            .register_project_folder(RegisterProjectFolderParams {
+                path: None,
+                set_as_current_workspace: true,
+            })
+            .unwrap();
+        workspace
+            .update_settings(UpdateSettingsParams {
+                configuration: PartialConfiguration {
+                    files: Some(PartialFilesConfiguration {
+                        ignore: Some(StringSet::from_iter(["ignored.js".to_string()])),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                },
+                vcs_base_path: None,
+                gitignore_matches: Vec::new(),
+                workspace_directory: None,
+                nested: false,
+                ignore_files: Vec::new(),
+                plugins: Vec::new(),
+            })
+            .unwrap();
+
+        let (sender, receiver) = unbounded();
+        for path in changed {
+            let event = Event::new(EventKind::Modify(ModifyKind::Data(DataChange::Content)))
+                .add_path(PathBuf::from(path));
+            sender.send(Ok(event)).unwrap();
+        }
+        drop(sender);
+
+        let execution = Execution::new_format(VcsTargeted {
+            staged: false,
+            changed: false,
+        })
+        .with_watch();
+        let cli_options = cli_options()
+            .to_options()
+            .run_inner(&[] as &[&str])
+            .unwrap();
+        let mut console = BufferConsole::default();
+        let mut session = CliSession {
+            app: App::new(
+                DynRef::Owned(Box::new(fs)),
+                &mut console,
+                WorkspaceRef::Borrowed(&*workspace),
+            ),
+        };
+        watch(
+            &execution,
+            &mut session,
+            &cli_options,
+            vec!["processed.js".into()],
+            Watched {
+                roots: &[PathBuf::new()],
+                working_directory: &PathBuf::new(),
+                receiver: &receiver,
+            },
+        );
+        drop(session);
+
+        console
+            .out_buffer
+            .iter()
+            .map(|message| {
+                let mut buffer = Vec::new();
+                Formatter::new(&mut Termcolor(NoColor::new(&mut buffer)))
+                    .write_markup(markup! {{message.content}})
+                    .unwrap();
+                String::from_utf8(buffer).unwrap()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn processes_the_changed_files_again() {
+        let messages = run_watch(&["changed.js"]);
+
+        assert!(messages
+            .iter()
+            .any(|message| message.contains("Changed: changed.js")));
+    }
+
+    #[test]
+    fn skips_the_ignored_and_unchanged_files() {
+        let messages = run_watch(&["ignored.js", "processed.js"]);
+
+        assert!(!messages.iter().any(|message| message.contains("Changed:")));
+    }
+}
@@ -21,16 +21,18 @@ mod logging;
 mod metrics;
 mod panic;
 mod reporter;
+mod rule_profile;
 mod service;
 
 use crate::cli_options::{CliOptions, ColorsArg};
 use crate::commands::check::CheckCommandPayload;
 use crate::commands::ci::CiCommandPayload;
+use crate::commands::config::ConfigPrintCommandPayload;
 use crate::commands::format::FormatCommandPayload;
 use crate::commands::lint::LintCommandPayload;
 use crate::commands::migrate::MigrateCommandPayload;
-use crate::commands::CommandRunner;
 pub use crate::commands::{biome_command, BiomeCommand};
+use crate::commands::{CommandRunner, ConfigSubCommand};
 pub use crate::logging::{setup_cli_subscriber, LoggingLevel};
 pub use diagnostics::CliDiagnostic;
 pub use execute::{execute_mode, Execution, TraversalMode, VcsTargeted};
@@ -105,6 +107,13 @@ impl<'app> CliSession<'app> {
                 staged,
                 changed,
                 since,
+                changed_lines_only,
+                cache,
+                baseline,
+                write_baseline,
+                watch,
+                diff,
+                profile_rules,
             } => run_command(
                 self,
                 &cli_options,
@@ -124,6 +133,13 @@ impl<'app> CliSession<'app> {
                     staged,
                     changed,
                     since,
+                    changed_lines_only,
+                    cache,
+                    baseline,
+                    write_baseline,
+                    watch,
+                    diff,
+                    profile_rules,
                 },
             ),
             BiomeCommand::Lint {
@@ -145,6 +161,13 @@ impl<'app> CliSession<'app> {
                 staged,
                 changed,
                 since,
+                changed_lines_only,
+                cache,
+                baseline,
+                write_baseline,
+                watch,
+                diff,
+                profile_rules,
                 css_linter,
                 javascript_linter,
                 json_linter,
@@ -170,6 +193,13 @@ impl<'app> CliSession<'app> {
                     staged,
                     changed,
                     since,
+                    changed_lines_only,
+                    cache,
+                    baseline,
+                    write_baseline,
+                    watch,
+                    diff,
+                    profile_rules,
                     css_linter,
                     javascript_linter,
                     json_linter,
@@ -186,6 +216,8 @@ impl<'app> CliSession<'app> {
                 cli_options,
                 changed,
                 since,
+                changed_lines_only,
+                profile_rules,
             } => run_command(
                 self,
                 &cli_options,
@@ -198,6 +230,8 @@ impl<'app> CliSession<'app> {
                     paths,
                     changed,
                     since,
+                    changed_lines_only,
+                    profile_rules,
                 },
             ),
             BiomeCommand::Format {
@@ -216,6 +250,10 @@ impl<'app> CliSession<'app> {
                 staged,
                 changed,
                 since,
+                changed_lines_only,
+                cache,
+                watch,
+                diff,
             } => run_command(
                 self,
                 &cli_options,
@@ -234,10 +272,17 @@ impl<'app> CliSession<'app> {
                     staged,
                     changed,
                     since,
+                    changed_lines_only,
+                    cache,
+                    watch,
+                    diff,
                 },
             ),
             BiomeCommand::Explain { doc } => commands::explain::explain(self, doc),
-            BiomeCommand::Init(emit_jsonc) => commands::init::init(self, emit_jsonc),
+            BiomeCommand::Rules { sub_command } => commands::rules::rules(self, sub_command),
+            BiomeCommand::Init { emit_jsonc, detect } => {
+                commands::init::init(self, emit_jsonc, detect)
+            }
             BiomeCommand::LspProxy {
                 config_path,
                 log_path,
@@ -267,6 +312,7 @@ impl<'app> CliSession<'app> {
                 pattern,
                 stdin_file_path,
                 vcs_configuration,
+                write,
             } => run_command(
                 self,
                 &cli_options,
@@ -276,6 +322,34 @@ impl<'app> CliSession<'app> {
                     pattern,
                     stdin_file_path,
                     vcs_configuration,
+                    write,
+                },
+            ),
+            BiomeCommand::Config {
+                sub_command:
+                    ConfigSubCommand::Print {
+                        formatter_configuration,
+                        javascript_formatter,
+                        json_formatter,
+                        css_formatter,
+                        graphql_formatter,
+                        cli_options,
+                        json,
+                        path,
+                    },
+            } => run_command(
+                self,
+                &cli_options,
+                ConfigPrintCommandPayload {
+                    formatter_configuration,
+                    javascript_formatter,
+                    json_formatter,
+                    css_formatter,
+                    graphql_formatter,
+                    json,
+                    path,
+                    configuration_file_path: None,
+                    editorconfig: None,
                 },
             ),
             BiomeCommand::RunServer {
@@ -0,0 +1,180 @@
+use crate::{DiagnosticsPayload, Execution, Reporter, ReporterVisitor, TraversalSummary};
+use biome_console::{markup, Console, ConsoleExt};
+use biome_diagnostics::display::SourceFile;
+use biome_diagnostics::{Error, PrintDescription, Resource, Severity};
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::io;
+
+pub(crate) struct CheckstyleReporter {
+    pub(crate) diagnostics_payload: DiagnosticsPayload,
+    pub(crate) execution: Execution,
+}
+
+impl Reporter for CheckstyleReporter {
+    fn write(self, visitor: &mut dyn ReporterVisitor) -> io::Result<()> {
+        visitor.report_diagnostics(&self.execution, self.diagnostics_payload)?;
+        Ok(())
+    }
+}
+
+pub(crate) struct CheckstyleReporterVisitor<'a>(pub(crate) &'a mut dyn Console);
+
+impl<'a> ReporterVisitor for CheckstyleReporterVisitor<'a> {
+    fn report_summary(
+        &mut self,
+        _execution: &Execution,
+        _summary: TraversalSummary,
+    ) -> io::Result<()> {
+        Ok(())
+    }
+
+    fn report_diagnostics(
+        &mut self,
+        _execution: &Execution,
+        payload: DiagnosticsPayload,
+    ) -> io::Result<()> {
+        let diagnostics = payload
+            .diagnostics
+            .iter()
+            .filter(|diagnostic| diagnostic.severity() >= payload.diagnostic_level)
+            .filter(|diagnostic| {
+                if diagnostic.tags().is_verbose() {
+                    payload.verbose
+                } else {
+                    true
+                }
+            });
+
+        // Checkstyle reports group the diagnostics by file. The diagnostics that
+        // don't belong to a file are reported under a file without a name.
+        let mut files: BTreeMap<&str, Vec<&Error>> = BTreeMap::new();
+        for diagnostic in diagnostics {
+            let path = match diagnostic.location().resource {
+                Some(Resource::File(path)) => path,
+                _ => "",
+            };
+            files.entry(path).or_default().push(diagnostic);
+        }
+
+        let mut output = String::new();
+        output.push_str("<?xml version=\"1.0\" encoding=\"utf-8\"?>\n");
+        output.push_str("<checkstyle version=\"4.3\">\n");
+        for (path, diagnostics) in files {
+            writeln!(output, "  <file name=\"{}\">", escape(path)).unwrap();
+            for diagnostic in diagnostics {
+                output.push_str("    <error");
+                if let Some((line, column)) = line_and_column(diagnostic) {
+                    write!(output, " line=\"{line}\" column=\"{column}\"").unwrap();
+                }
+                write!(
+                    output,
+                    " severity=\"{}\" message=\"{}\"",
+                    to_checkstyle_severity(diagnostic.severity()),
+                    escape(&PrintDescription(diagnostic).to_string())
+                )
+                .unwrap();
+                if let Some(category) = diagnostic.category() {
+                    write!(output, " source=\"{}\"", escape(category.name())).unwrap();
+                }
+                output.push_str(" />\n");
+            }
+            output.push_str("  </file>\n");
+        }
+        output.push_str("</checkstyle>");
+
+        self.0.log(markup! {{output}});
+
+        Ok(())
+    }
+}
+
+/// Maps the severity of a diagnostic to the severities known by Checkstyle
+const fn to_checkstyle_severity(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Hint | Severity::Information => "info",
+        Severity::Warning => "warning",
+        Severity::Error | Severity::Fatal => "error",
+    }
+}
+
+/// Returns the one-based line and column where the diagnostic starts, if the
+/// diagnostic points to a range of its file.
+///
+/// Formatter and organize imports diagnostics apply to the whole file, so they
+/// don't have any.
+fn line_and_column(diagnostic: &Error) -> Option<(usize, usize)> {
+    let location = diagnostic.location();
+    let span = location.span?;
+    let source_code = location.source_code?;
+    let start = SourceFile::new(source_code).location(span.start()).ok()?;
+    Some((start.line_number.get(), start.column_number.get()))
+}
+
+/// Escapes the characters that aren't allowed inside XML attribute values
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            '\n' => escaped.push_str("&#10;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CheckstyleReporterVisitor;
+    use crate::execute::VcsTargeted;
+    use crate::{DiagnosticsPayload, Execution, ReporterVisitor};
+    use biome_console::fmt::{Formatter, Termcolor};
+    use biome_console::{markup, BufferConsole};
+    use biome_diagnostics::termcolor::NoColor;
+    use biome_diagnostics::{Diagnostic, Error, Severity};
+
+    #[derive(Debug, Diagnostic)]
+    #[diagnostic(
+        category = "internalError/io",
+        severity = Error,
+        message = "The configuration file couldn't be read."
+    )]
+    struct DiagnosticWithoutFile;
+
+    #[test]
+    fn reports_diagnostics_without_file() {
+        let mut console = BufferConsole::default();
+        let payload = DiagnosticsPayload {
+            diagnostics: vec![Error::from(DiagnosticWithoutFile)],
+            verbose: false,
+            diagnostic_level: Severity::Information,
+        };
+
+        let execution = Execution::new_format(VcsTargeted {
+            staged: false,
+            changed: false,
+        });
+        CheckstyleReporterVisitor(&mut console)
+            .report_diagnostics(&execution, payload)
+            .unwrap();
+
+        let mut buffer = Vec::new();
+        Formatter::new(&mut Termcolor(NoColor::new(&mut buffer)))
+            .write_markup(markup! {{console.out_buffer[0].content}})
+            .unwrap();
+        assert_eq!(
+            String::from_utf8(buffer).unwrap(),
+            r#"<?xml version="1.0" encoding="utf-8"?>
+<checkstyle version="4.3">
+  <file name="">
+    <error severity="error" message="The configuration file couldn&apos;t be read." source="internalError/io" />
+  </file>
+</checkstyle>"#
+        );
+    }
+}
@@ -1,3 +1,4 @@
+use crate::rule_profile::RuleProfile;
 use crate::{DiagnosticsPayload, Execution, Reporter, ReporterVisitor, TraversalSummary};
 use biome_console::fmt::Formatter;
 use serde::Serialize;
@@ -8,6 +9,9 @@ pub(crate) struct JsonReporterVisitor {
     summary: TraversalSummary,
     diagnostics: Vec<biome_diagnostics::serde::Diagnostic>,
     command: String,
+    /// The timings of the rules, with `--profile-rules`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rule_profile: Option<RuleProfile>,
 }
 
 impl JsonReporterVisitor {
@@ -16,8 +20,14 @@ impl JsonReporterVisitor {
             summary,
             diagnostics: vec![],
             command: String::new(),
+            rule_profile: None,
         }
     }
+
+    pub(crate) fn with_rule_profile(mut self, rule_profile: Option<RuleProfile>) -> Self {
+        self.rule_profile = rule_profile;
+        self
+    }
 }
 
 impl biome_console::fmt::Display for JsonReporterVisitor {
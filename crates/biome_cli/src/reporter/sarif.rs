@@ -0,0 +1,495 @@
+use crate::{DiagnosticsPayload, Execution, Reporter, ReporterVisitor, TraversalSummary, VERSION};
+use biome_console::fmt::{self, Display, Formatter};
+use biome_console::{markup, Console, ConsoleExt};
+use biome_diagnostics::display::SourceFile;
+use biome_diagnostics::termcolor::NoColor;
+use biome_diagnostics::{
+    Advices, Category, Error, LogCategory, PrintDescription, Resource, Severity, Visit,
+};
+use biome_rowan::{TextRange, TextSize};
+use biome_service::documentation::{lint_rules_metadata, LintRuleMetadata};
+use biome_text_edit::{CompressedOp, DiffOp, TextEdit};
+use path_absolutize::Absolutize;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::io;
+use std::path::{Path, PathBuf};
+
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+const SARIF_VERSION: &str = "2.1.0";
+
+pub(crate) struct SarifReporter {
+    pub(crate) diagnostics_payload: DiagnosticsPayload,
+    pub(crate) execution: Execution,
+}
+
+impl Reporter for SarifReporter {
+    fn write(self, visitor: &mut dyn ReporterVisitor) -> io::Result<()> {
+        visitor.report_diagnostics(&self.execution, self.diagnostics_payload)?;
+        Ok(())
+    }
+}
+
+pub(crate) struct SarifReporterVisitor<'a> {
+    console: &'a mut dyn Console,
+    repository_root: Option<PathBuf>,
+}
+
+impl<'a> SarifReporterVisitor<'a> {
+    pub(crate) fn new(console: &'a mut dyn Console, repository_root: Option<PathBuf>) -> Self {
+        Self {
+            console,
+            repository_root,
+        }
+    }
+}
+
+impl<'a> ReporterVisitor for SarifReporterVisitor<'a> {
+    fn report_summary(&mut self, _: &Execution, _: TraversalSummary) -> io::Result<()> {
+        Ok(())
+    }
+
+    fn report_diagnostics(
+        &mut self,
+        _execution: &Execution,
+        payload: DiagnosticsPayload,
+    ) -> io::Result<()> {
+        let log = SarifLog::new(&payload, self.repository_root.as_deref());
+        let serialized = serde_json::to_string_pretty(&log)?;
+        self.console.log(markup!({ serialized }));
+        Ok(())
+    }
+}
+
+/// The root object of a SARIF file.
+/// See https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html
+#[derive(Serialize)]
+struct SarifLog {
+    #[serde(rename = "$schema")]
+    schema: &'static str,
+    version: &'static str,
+    runs: Vec<Run>,
+}
+
+impl SarifLog {
+    fn new(payload: &DiagnosticsPayload, repository_root: Option<&Path>) -> Self {
+        let mut rules: Vec<ReportingDescriptor> = lint_rules_metadata()
+            .iter()
+            .map(ReportingDescriptor::from_rule)
+            .collect();
+        // The rules that exist for several languages share the same category
+        rules.dedup_by(|left, right| left.id == right.id);
+        let rule_indices: HashMap<&str, usize> = rules
+            .iter()
+            .enumerate()
+            .map(|(index, rule)| (rule.id.as_str(), index))
+            .collect();
+
+        let results = payload
+            .diagnostics
+            .iter()
+            .filter(|diagnostic| diagnostic.severity() >= payload.diagnostic_level)
+            .filter(|diagnostic| {
+                if diagnostic.tags().is_verbose() {
+                    payload.verbose
+                } else {
+                    true
+                }
+            })
+            .map(|diagnostic| {
+                SarifResult::from_diagnostic(diagnostic, &rule_indices, repository_root)
+            })
+            .collect();
+
+        Self {
+            schema: SARIF_SCHEMA,
+            version: SARIF_VERSION,
+            runs: vec![Run {
+                tool: Tool {
+                    driver: ToolComponent {
+                        name: "Biome",
+                        information_uri: "https://biomejs.dev",
+                        version: VERSION,
+                        rules,
+                    },
+                },
+                // Columns are computed by [SourceFile], which counts characters
+                column_kind: "unicodeCodePoints",
+                results,
+            }],
+        }
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Run {
+    tool: Tool,
+    column_kind: &'static str,
+    results: Vec<SarifResult>,
+}
+
+#[derive(Serialize)]
+struct Tool {
+    driver: ToolComponent,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ToolComponent {
+    name: &'static str,
+    information_uri: &'static str,
+    version: &'static str,
+    rules: Vec<ReportingDescriptor>,
+}
+
+/// The description of a rule, part of the catalogue of the tool
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ReportingDescriptor {
+    id: String,
+    name: &'static str,
+    short_description: Message,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    help_uri: Option<&'static str>,
+    default_configuration: ReportingConfiguration,
+    properties: RuleProperties,
+}
+
+impl ReportingDescriptor {
+    fn from_rule(rule: &LintRuleMetadata) -> Self {
+        let LintRuleMetadata { group, metadata } = rule;
+        let id = format!("lint/{group}/{}", metadata.name);
+        let help_uri = id
+            .parse::<&'static Category>()
+            .ok()
+            .and_then(|category| category.link());
+        let description = metadata
+            .docs
+            .lines()
+            .map(str::trim)
+            .find(|line| !line.is_empty())
+            .unwrap_or_default();
+
+        Self {
+            id,
+            name: metadata.name,
+            short_description: Message {
+                text: description.to_string(),
+            },
+            help_uri,
+            default_configuration: ReportingConfiguration {
+                enabled: metadata.recommended,
+                // Same default severity as the one of the linter configuration
+                level: to_level(if metadata.recommended {
+                    Severity::Error
+                } else {
+                    Severity::Warning
+                }),
+            },
+            properties: RuleProperties {
+                language: metadata.language,
+                recommended: metadata.recommended,
+                deprecated: metadata.deprecated,
+                sources: metadata
+                    .sources
+                    .iter()
+                    .map(|source| RuleSourceProperty {
+                        name: source.to_string(),
+                        rule: source.to_namespaced_rule_name(),
+                        uri: source.to_rule_url(),
+                    })
+                    .collect(),
+            },
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct ReportingConfiguration {
+    enabled: bool,
+    level: &'static str,
+}
+
+#[derive(Serialize)]
+struct RuleProperties {
+    language: &'static str,
+    recommended: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    deprecated: Option<&'static str>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    sources: Vec<RuleSourceProperty>,
+}
+
+/// A rule of another tool that is implemented by the Biome rule
+#[derive(Serialize)]
+struct RuleSourceProperty {
+    name: String,
+    rule: String,
+    uri: String,
+}
+
+#[derive(Serialize)]
+struct Message {
+    text: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifResult {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rule_id: Option<&'static str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rule_index: Option<usize>,
+    level: &'static str,
+    message: Message,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    locations: Vec<SarifLocation>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    fixes: Vec<Fix>,
+}
+
+impl SarifResult {
+    fn from_diagnostic(
+        diagnostic: &Error,
+        rule_indices: &HashMap<&str, usize>,
+        repository_root: Option<&Path>,
+    ) -> Self {
+        let rule_id = diagnostic.category().map(|category| category.name());
+        let rule_index = rule_id.and_then(|rule_id| rule_indices.get(rule_id).copied());
+        let location = diagnostic.location();
+        let uri = match location.resource {
+            Some(Resource::File(file)) => Some(to_artifact_uri(file, repository_root)),
+            _ => None,
+        };
+
+        let mut locations = Vec::new();
+        let mut fixes = Vec::new();
+        if let Some(uri) = uri {
+            let region = location
+                .span
+                .zip(location.source_code)
+                .and_then(|(span, source_code)| {
+                    let source = SourceFile::new(source_code);
+                    let start = source.location(span.start()).ok()?;
+                    let end = source.location(span.end()).ok()?;
+                    Some(Region {
+                        start_line: start.line_number.get(),
+                        start_column: start.column_number.get(),
+                        end_line: end.line_number.get(),
+                        end_column: end.column_number.get(),
+                    })
+                });
+
+            if let Some(source_code) = location.source_code {
+                let mut suggestions = SuggestionsVisitor::default();
+                // SAFETY: the visitor never returns an error
+                diagnostic.advices(&mut suggestions).unwrap();
+                fixes = suggestions
+                    .0
+                    .into_iter()
+                    .filter_map(|(description, edit)| {
+                        Some(Fix {
+                            description: Message { text: description },
+                            artifact_changes: vec![ArtifactChange {
+                                artifact_location: ArtifactLocation { uri: uri.clone() },
+                                replacements: to_replacements(&edit, source_code.text)?,
+                            }],
+                        })
+                    })
+                    .collect();
+            }
+
+            locations.push(SarifLocation {
+                physical_location: PhysicalLocation {
+                    artifact_location: ArtifactLocation { uri },
+                    region,
+                },
+            });
+        }
+
+        Self {
+            rule_id,
+            rule_index,
+            level: to_level(diagnostic.severity()),
+            message: Message {
+                text: PrintDescription(diagnostic).to_string(),
+            },
+            locations,
+            fixes,
+        }
+    }
+}
+
+/// Returns the SARIF level matching the severity of a diagnostic
+fn to_level(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Hint | Severity::Information => "note",
+        Severity::Warning => "warning",
+        Severity::Error | Severity::Fatal => "error",
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifLocation {
+    physical_location: PhysicalLocation,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct PhysicalLocation {
+    artifact_location: ArtifactLocation,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    region: Option<Region>,
+}
+
+#[derive(Serialize)]
+struct ArtifactLocation {
+    uri: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Region {
+    start_line: usize,
+    start_column: usize,
+    end_line: usize,
+    end_column: usize,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Fix {
+    description: Message,
+    artifact_changes: Vec<ArtifactChange>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ArtifactChange {
+    artifact_location: ArtifactLocation,
+    replacements: Vec<Replacement>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Replacement {
+    deleted_region: ByteRegion,
+    inserted_content: ArtifactContent,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ByteRegion {
+    byte_offset: u32,
+    byte_length: u32,
+}
+
+#[derive(Serialize)]
+struct ArtifactContent {
+    text: String,
+}
+
+/// Collects the code suggestions of a diagnostic. Each suggestion is made of
+/// an info log, that describes the fix, followed by its diff.
+#[derive(Default)]
+struct SuggestionsVisitor(Vec<(String, TextEdit)>, Option<String>);
+
+impl Visit for SuggestionsVisitor {
+    fn record_log(&mut self, category: LogCategory, text: &dyn Display) -> io::Result<()> {
+        if matches!(category, LogCategory::Info) {
+            self.1 = Some(markup_to_string(text)?);
+        }
+        Ok(())
+    }
+
+    fn record_diff(&mut self, diff: &TextEdit) -> io::Result<()> {
+        if let Some(description) = self.1.take() {
+            self.0.push((description, diff.clone()));
+        }
+        Ok(())
+    }
+
+    fn record_group(&mut self, _title: &dyn Display, advice: &dyn Advices) -> io::Result<()> {
+        advice.record(self)
+    }
+}
+
+fn markup_to_string(text: &dyn Display) -> io::Result<String> {
+    let mut buffer = Vec::new();
+    let mut write = fmt::Termcolor(NoColor::new(&mut buffer));
+    let mut fmt = Formatter::new(&mut write);
+    fmt.write_markup(markup!({ text }))?;
+    String::from_utf8(buffer).map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))
+}
+
+/// Converts a [TextEdit] computed over the whole `source` into a list of replacements.
+///
+/// Returns [None] if the edit doesn't apply to `source`, for example when the
+/// diagnostic was emitted for a snippet embedded in a bigger file.
+fn to_replacements(edit: &TextEdit, source: &str) -> Option<Vec<Replacement>> {
+    let mut replacements = Vec::new();
+    let mut pending: Option<(TextRange, String)> = None;
+    let mut offset = TextSize::from(0);
+
+    for op in edit {
+        match op {
+            CompressedOp::DiffOp(DiffOp::Equal { range }) => {
+                replacements.extend(pending.take().map(Replacement::from));
+                offset += range.len();
+            }
+            CompressedOp::DiffOp(DiffOp::Delete { range }) => {
+                let (deleted, _) =
+                    pending.get_or_insert_with(|| (TextRange::empty(offset), String::new()));
+                *deleted = TextRange::new(deleted.start(), deleted.end() + range.len());
+                offset += range.len();
+            }
+            CompressedOp::DiffOp(DiffOp::Insert { range }) => {
+                let (_, inserted) =
+                    pending.get_or_insert_with(|| (TextRange::empty(offset), String::new()));
+                inserted.push_str(edit.get_text(*range));
+            }
+            CompressedOp::EqualLines { line_count } => {
+                replacements.extend(pending.take().map(Replacement::from));
+                let input = source.get(usize::from(offset)..)?;
+                for line in input
+                    .split_inclusive('\n')
+                    .take(line_count.get() as usize + 1)
+                {
+                    offset += TextSize::of(line);
+                }
+            }
+        }
+    }
+    replacements.extend(pending.take().map(Replacement::from));
+
+    (offset == TextSize::of(source)).then_some(replacements)
+}
+
+impl From<(TextRange, String)> for Replacement {
+    fn from((deleted, inserted): (TextRange, String)) -> Self {
+        Self {
+            deleted_region: ByteRegion {
+                byte_offset: deleted.start().into(),
+                byte_length: deleted.len().into(),
+            },
+            inserted_content: ArtifactContent { text: inserted },
+        }
+    }
+}
+
+/// Returns the path of the file relative to the root of the repository,
+/// using forward slashes, as SARIF consumers expect URIs
+fn to_artifact_uri(path: &str, repository_root: Option<&Path>) -> String {
+    let relativized = Path::new(path).absolutize().ok().and_then(|resolved| {
+        resolved
+            .strip_prefix(repository_root?)
+            .ok()
+            .map(Path::to_path_buf)
+    });
+    match relativized {
+        Some(relativized) => relativized.to_string_lossy().replace('\\', "/"),
+        None => path.replace('\\', "/"),
+    }
+}
@@ -4,7 +4,7 @@ use crate::Reporter;
 use biome_console::fmt::Formatter;
 use biome_console::{fmt, markup, Console, ConsoleExt};
 use biome_diagnostics::advice::ListAdvice;
-use biome_diagnostics::{Diagnostic, PrintDiagnostic};
+use biome_diagnostics::{category, Diagnostic, PrintDiagnostic};
 use biome_fs::BiomePath;
 use std::collections::BTreeSet;
 use std::io;
@@ -116,7 +116,7 @@ impl<'a> ReporterVisitor for ConsoleReporterVisitor<'a> {
         diagnostics_payload: DiagnosticsPayload,
     ) -> io::Result<()> {
         for diagnostic in &diagnostics_payload.diagnostics {
-            if execution.is_search() {
+            if execution.is_search() && diagnostic.category() == Some(category!("search")) {
                 self.0.log(markup! {{PrintDiagnostic::search(diagnostic)}});
                 continue;
             }
@@ -153,7 +153,12 @@ struct SummaryDetail<'a>(pub(crate) &'a TraversalMode, usize);
 
 impl<'a> fmt::Display for SummaryDetail<'a> {
     fn fmt(&self, fmt: &mut Formatter) -> io::Result<()> {
-        if let TraversalMode::Search { .. } = self.0 {
+        if let TraversalMode::Search { write, .. } = self.0 {
+            if *write {
+                return fmt.write_markup(markup! {
+                    " Rewrote "{Files(self.1)}"."
+                });
+            }
             return Ok(());
         }
 
@@ -0,0 +1,164 @@
+use biome_console::fmt::{Display, Formatter};
+use biome_console::markup;
+use biome_service::workspace::RuleDurations;
+use hdrhistogram::Histogram;
+use rustc_hash::FxHashMap;
+use serde::Serialize;
+use std::io;
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// The number of rules printed in the table of `--profile-rules`
+const PRINTED_RULES: usize = 20;
+
+/// Collects the durations of each rule returned by the workspace into [Histogram]s,
+/// like the metrics layer does for the tracing spans
+#[derive(Debug, Default)]
+pub(crate) struct RuleMetrics {
+    rules: Mutex<FxHashMap<String, RuleEntry>>,
+}
+
+/// The histograms of a rule, in nanoseconds
+#[derive(Debug)]
+struct RuleEntry {
+    run: Histogram<u64>,
+    action: Histogram<u64>,
+}
+
+impl RuleEntry {
+    fn new() -> Self {
+        /// Number of significant figures retained by the histogram
+        const SIGNIFICANT_FIGURES: u8 = 3;
+
+        // SAFETY: Histogram::new only returns an error if the value of
+        // SIGNIFICANT_FIGURES is invalid, 3 is statically known to work
+        Self {
+            run: Histogram::new(SIGNIFICANT_FIGURES).unwrap(),
+            action: Histogram::new(SIGNIFICANT_FIGURES).unwrap(),
+        }
+    }
+
+    fn record(&mut self, durations: &RuleDurations) {
+        // The histograms grow automatically, recording can't fail
+        for duration in &durations.run {
+            let _ = self.run.record(*duration);
+        }
+        for duration in &durations.action {
+            let _ = self.action.record(*duration);
+        }
+    }
+}
+
+impl RuleMetrics {
+    /// Adds the durations of the rules that ran on a file
+    pub(crate) fn record(&self, durations: Vec<RuleDurations>) {
+        let mut rules = self.rules.lock().unwrap();
+        for durations in durations {
+            rules
+                .entry(durations.rule.clone())
+                .or_insert_with(RuleEntry::new)
+                .record(&durations);
+        }
+    }
+
+    /// Returns the timings of the rules that ran since the last call, from the slowest
+    /// to the fastest rule.
+    pub(crate) fn take_profile(&self) -> RuleProfile {
+        let rules = std::mem::take(&mut *self.rules.lock().unwrap());
+        let mut rules: Vec<_> = rules
+            .into_iter()
+            .map(|(rule, entry)| RuleTimings::new(rule, entry))
+            .collect();
+        rules.sort_by(|left, right| {
+            right
+                .total_time()
+                .cmp(&left.total_time())
+                .then_with(|| left.rule.cmp(&right.rule))
+        });
+        RuleProfile(rules)
+    }
+}
+
+/// The timings of all the rules, printed as a table or serialized in the JSON report
+#[derive(Debug, Serialize)]
+pub(crate) struct RuleProfile(Vec<RuleTimings>);
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct RuleTimings {
+    /// The group and the name of the rule, e.g. `suspicious/noDebugger`
+    rule: String,
+    /// How many times the rule checked a node
+    count: u64,
+    /// The time spent checking the nodes, in nanoseconds
+    total: u64,
+    /// The average time spent checking a node, in nanoseconds
+    average: u64,
+    /// The 99th percentile of the time spent checking a node, in nanoseconds
+    p99: u64,
+    /// How many code actions the rule computed
+    action_count: u64,
+    /// The time spent computing the code actions, in nanoseconds
+    action_total: u64,
+}
+
+impl RuleTimings {
+    fn new(rule: String, entry: RuleEntry) -> Self {
+        // The histograms don't keep the exact sum of the values
+        let total = |histogram: &Histogram<u64>| (histogram.mean() * histogram.len() as f64) as u64;
+        Self {
+            rule,
+            count: entry.run.len(),
+            total: total(&entry.run),
+            average: entry.run.mean() as u64,
+            p99: entry.run.value_at_quantile(0.99),
+            action_count: entry.action.len(),
+            action_total: total(&entry.action),
+        }
+    }
+
+    fn total_time(&self) -> u64 {
+        self.total + self.action_total
+    }
+}
+
+impl Display for RuleProfile {
+    fn fmt(&self, fmt: &mut Formatter) -> io::Result<()> {
+        let duration = |nanoseconds: u64| format!("{:.1?}", Duration::from_nanos(nanoseconds));
+
+        let rules = &self.0[..self.0.len().min(PRINTED_RULES)];
+        let rule_width = rules
+            .iter()
+            .map(|timings| timings.rule.len())
+            .chain(std::iter::once("Rule".len()))
+            .max()
+            .unwrap_or_default();
+
+        fmt.write_markup(markup! {
+            "\n"<Emphasis>"Rules profile"</Emphasis>" (the "{rules.len()}" slowest of "{self.0.len()}" rules)\n\n"
+        })?;
+        let header = format!(
+            "{:rule_width$}  {:>9}  {:>9}  {:>9}  {:>9}  {:>9}  {:>11}",
+            "Rule", "Count", "Total", "Average", "P99", "Actions", "Action time"
+        );
+        fmt.write_markup(markup! { <Emphasis>{header}</Emphasis> })?;
+        for timings in rules {
+            let row = format!(
+                "\n{:rule_width$}  {:>9}  {:>9}  {:>9}  {:>9}  {:>9}  {:>11}",
+                timings.rule,
+                timings.count,
+                duration(timings.total),
+                duration(timings.average),
+                duration(timings.p99),
+                timings.action_count,
+                if timings.action_count > 0 {
+                    duration(timings.action_total)
+                } else {
+                    String::from("-")
+                },
+            );
+            fmt.write_str(&row)?;
+        }
+        Ok(())
+    }
+}
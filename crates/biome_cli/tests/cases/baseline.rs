@@ -0,0 +1,204 @@
+use crate::run_cli;
+use crate::snap_test::{assert_cli_snapshot, SnapshotPayload};
+use biome_console::BufferConsole;
+use biome_fs::MemoryFileSystem;
+use biome_service::DynRef;
+use bpaf::Args;
+use std::path::Path;
+
+const MAIN: &str = r#"debugger;
+if (a == b) {
+}
+"#;
+
+fn write_baseline(fs: &mut MemoryFileSystem, command: &str) {
+    let mut console = BufferConsole::default();
+    let result = run_cli(
+        DynRef::Borrowed(fs),
+        &mut console,
+        Args::from([command, "--write-baseline", "main.js"].as_slice()),
+    );
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+}
+
+#[test]
+fn lint_writes_the_baseline() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("main.js");
+    fs.insert(file_path.into(), MAIN.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                "lint",
+                "--write-baseline",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_writes_the_baseline",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn lint_suppresses_the_diagnostics_of_the_baseline() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("main.js");
+    fs.insert(file_path.into(), MAIN.as_bytes());
+    write_baseline(&mut fs, "lint");
+
+    // The diagnostics of the baseline moved, and a new one was added
+    fs.insert(
+        file_path.into(),
+        format!("const c = 1;\n\n{MAIN}debugger;\n").as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                "lint",
+                "--baseline=biome-baseline.json",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_suppresses_the_diagnostics_of_the_baseline",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn check_reports_the_unused_entries_of_the_baseline() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("main.js");
+    fs.insert(file_path.into(), MAIN.as_bytes());
+    write_baseline(&mut fs, "check");
+
+    fs.insert(file_path.into(), "if (a == b) {\n}\n".as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                "check",
+                "--baseline=biome-baseline.json",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "check_reports_the_unused_entries_of_the_baseline",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn lint_fails_when_the_baseline_doesnt_exist() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("main.js");
+    fs.insert(file_path.into(), MAIN.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                "lint",
+                "--baseline=biome-baseline.json",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_fails_when_the_baseline_doesnt_exist",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn lint_suppresses_the_diagnostics_beyond_max_diagnostics() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("main.js");
+    fs.insert(
+        file_path.into(),
+        "debugger;\ndebugger;\ndebugger;\n".as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(["lint", "--write-baseline", "--max-diagnostics=1", "main.js"].as_slice()),
+    );
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    // All the diagnostics were recorded, so none of them is reported
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                "lint",
+                "--baseline=biome-baseline.json",
+                "--max-diagnostics=1",
+                "main.js",
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_suppresses_the_diagnostics_beyond_max_diagnostics",
+        fs,
+        console,
+        result,
+    ));
+}
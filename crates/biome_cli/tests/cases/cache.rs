@@ -0,0 +1,146 @@
+use crate::run_cli;
+use crate::snap_test::markup_to_string;
+use biome_console::{markup, BufferConsole};
+use biome_fs::MemoryFileSystem;
+use biome_service::DynRef;
+use bpaf::Args;
+use std::path::{Path, PathBuf};
+
+const MAIN: &str = r#"debugger;
+let a =     1;
+"#;
+
+fn run(fs: &mut MemoryFileSystem, args: &[&str]) -> (bool, Vec<String>) {
+    let mut console = BufferConsole::default();
+    let result = run_cli(DynRef::Borrowed(fs), &mut console, Args::from(args));
+    let messages = console
+        .out_buffer
+        .iter()
+        .map(|message| {
+            markup_to_string(markup! {
+                {message.content}
+            })
+        })
+        // The summary contains the duration of the run
+        .filter(|message| !message.starts_with("Checked") && !message.starts_with("Formatted"))
+        .collect();
+    (result.is_ok(), messages)
+}
+
+fn cache_files(fs: MemoryFileSystem) -> Vec<(PathBuf, String)> {
+    fs.files()
+        .filter(|(path, _)| path.components().any(|c| c.as_os_str() == "biome-cache"))
+        .map(|(path, entry)| {
+            let content = entry.lock();
+            (path, String::from_utf8(content.to_vec()).unwrap())
+        })
+        .collect()
+}
+
+#[test]
+fn check_replays_the_cached_results() {
+    let mut fs = MemoryFileSystem::default();
+    let file_path = Path::new("main.js");
+    fs.insert(file_path.into(), MAIN.as_bytes());
+
+    let (first_ok, first) = run(&mut fs, &["check", "--cache", "main.js"]);
+    assert!(!first_ok);
+
+    let (second_ok, second) = run(&mut fs, &["check", "--cache", "main.js"]);
+    assert!(!second_ok);
+    assert_eq!(first, second);
+
+    let cache = cache_files(fs);
+    assert_eq!(cache.len(), 1, "The cache should have been written");
+    assert!(cache[0].1.contains("main.js"));
+    assert!(cache[0].1.contains("lint/suspicious/noDebugger"));
+}
+
+#[test]
+fn check_doesnt_replay_the_results_of_changed_files() {
+    let mut fs = MemoryFileSystem::default();
+    let file_path = Path::new("main.js");
+    fs.insert(file_path.into(), MAIN.as_bytes());
+
+    let (ok, _) = run(&mut fs, &["check", "--cache", "main.js"]);
+    assert!(!ok);
+
+    fs.insert(file_path.into(), "const a = 1;\n".as_bytes());
+
+    let (ok, messages) = run(&mut fs, &["check", "--cache", "main.js"]);
+    assert!(
+        ok,
+        "The file should have been processed again: {messages:?}"
+    );
+}
+
+#[test]
+fn format_doesnt_replay_the_results_of_other_settings() {
+    let mut fs = MemoryFileSystem::default();
+    let file_path = Path::new("main.js");
+    fs.insert(file_path.into(), "let a =     1;\n".as_bytes());
+
+    let (ok, _) = run(&mut fs, &["format", "--cache", "main.js"]);
+    assert!(!ok);
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{ "formatter": { "enabled": false } }"#.as_bytes(),
+    );
+
+    let (ok, messages) = run(&mut fs, &["format", "--cache", "main.js"]);
+    assert!(!ok);
+    assert!(
+        messages
+            .iter()
+            .all(|message| !message.contains("Formatter would have printed")),
+        "The cached diff shouldn't be replayed: {messages:?}"
+    );
+}
+
+#[test]
+fn format_stores_the_files_that_are_already_formatted() {
+    let mut fs = MemoryFileSystem::default();
+    let file_path = Path::new("main.js");
+    fs.insert(file_path.into(), "let a = 1;\n".as_bytes());
+
+    let (ok, _) = run(&mut fs, &["format", "--cache", "main.js"]);
+    assert!(ok);
+
+    let (ok, messages) = run(&mut fs, &["format", "--cache", "main.js"]);
+    assert!(ok, "{messages:?}");
+
+    let cache = cache_files(fs);
+    assert_eq!(cache.len(), 1, "The cache should have been written");
+    assert!(cache[0].1.contains("main.js"));
+}
+
+#[test]
+fn doesnt_write_the_cache_without_the_option() {
+    let mut fs = MemoryFileSystem::default();
+    let file_path = Path::new("main.js");
+    fs.insert(file_path.into(), MAIN.as_bytes());
+
+    let (ok, _) = run(&mut fs, &["check", "main.js"]);
+    assert!(!ok);
+
+    assert!(cache_files(fs).is_empty());
+}
+
+#[test]
+fn lint_uses_the_cache_enabled_in_the_configuration() {
+    let mut fs = MemoryFileSystem::default();
+    let file_path = Path::new("main.js");
+    fs.insert(file_path.into(), MAIN.as_bytes());
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{ "files": { "cache": true } }"#.as_bytes(),
+    );
+
+    let (ok, _) = run(&mut fs, &["lint", "main.js"]);
+    assert!(!ok);
+
+    let cache = cache_files(fs);
+    assert_eq!(cache.len(), 1, "The cache should have been written");
+    assert!(cache[0].1.contains("lint/suspicious/noDebugger"));
+}
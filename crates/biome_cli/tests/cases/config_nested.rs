@@ -0,0 +1,203 @@
+use crate::run_cli;
+use crate::snap_test::{assert_cli_snapshot, SnapshotPayload};
+use biome_console::BufferConsole;
+use biome_fs::MemoryFileSystem;
+use biome_service::DynRef;
+use bpaf::Args;
+use std::path::Path;
+
+#[test]
+fn nested_config_extends_parent_config() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{ "linter": { "rules": { "suspicious": { "noDoubleEquals": "off" } } } }"#,
+    );
+    fs.insert(
+        Path::new("packages/a/biome.json").into(),
+        r#"{
+    "root": false,
+    "linter": { "rules": { "suspicious": { "noDebugger": "off" } } }
+}
+"#,
+    );
+    fs.insert(
+        Path::new("packages/a/file.js").into(),
+        "debugger;\na == b;\n",
+    );
+    fs.insert(
+        Path::new("packages/b/file.js").into(),
+        "debugger;\na == b;\n",
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(["lint", "."].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "nested_config_extends_parent_config",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn nested_config_replaces_parent_config() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{ "linter": { "rules": { "suspicious": { "noDoubleEquals": "off" } } } }"#,
+    );
+    fs.insert(
+        Path::new("packages/a/biome.json").into(),
+        r#"{
+    "linter": { "rules": { "suspicious": { "noDebugger": "off" } } }
+}
+"#,
+    );
+    fs.insert(
+        Path::new("packages/a/file.js").into(),
+        "debugger;\na == b;\n",
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(["lint", "."].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "nested_config_replaces_parent_config",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn nested_config_applies_to_file_argument() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("packages/a/biome.json").into(),
+        r#"{
+    "root": false,
+    "javascript": { "formatter": { "quoteStyle": "single" } }
+}
+"#,
+    );
+    let file_path = Path::new("packages/a/src/file.js");
+    fs.insert(file_path.into(), "const a = 'a';\n");
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(["format", file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "nested_config_applies_to_file_argument",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn cli_arguments_override_nested_config() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("packages/a/biome.json").into(),
+        r#"{
+    "root": false,
+    "javascript": { "formatter": { "quoteStyle": "single" } }
+}
+"#,
+    );
+    let file_path = Path::new("packages/a/file.js");
+    fs.insert(file_path.into(), "const a = 'a';\n");
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                "format",
+                "--quote-style=double",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "cli_arguments_override_nested_config",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn nested_config_takes_precedence_over_parent_overrides() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{
+    "overrides": [
+        { "include": ["*.js"], "javascript": { "formatter": { "quoteStyle": "single" } } }
+    ]
+}
+"#,
+    );
+    fs.insert(
+        Path::new("packages/a/biome.json").into(),
+        r#"{
+    "root": false,
+    "javascript": { "formatter": { "quoteStyle": "double" } }
+}
+"#,
+    );
+    fs.insert(Path::new("packages/a/file.js").into(), "const a = 'a';\n");
+    fs.insert(Path::new("packages/b/file.js").into(), "const b = \"b\";\n");
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(["format", "packages/a/file.js", "packages/b/file.js"].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "nested_config_takes_precedence_over_parent_overrides",
+        fs,
+        console,
+        result,
+    ));
+}
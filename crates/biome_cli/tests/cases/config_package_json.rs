@@ -0,0 +1,218 @@
+use crate::run_cli;
+use crate::snap_test::{assert_cli_snapshot, SnapshotPayload};
+use biome_console::BufferConsole;
+use biome_fs::MemoryFileSystem;
+use biome_service::DynRef;
+use bpaf::Args;
+use std::path::Path;
+
+#[test]
+fn package_json_configuration() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("package.json").into(),
+        r#"{
+    "name": "app",
+    "biome": {
+        "formatter": { "indentStyle": "space" },
+        "linter": { "rules": { "suspicious": { "noDebugger": "off" } } }
+    }
+}"#,
+    );
+
+    fs.insert(
+        Path::new("file.js").into(),
+        r#"debugger;
+function f() {
+	return 1;
+}
+"#,
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(["check", "file.js"].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "package_json_configuration",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn biome_json_takes_precedence_over_package_json() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{ "linter": { "rules": { "suspicious": { "noDoubleEquals": "off" } } } }"#,
+    );
+
+    fs.insert(
+        Path::new("package.json").into(),
+        r#"{
+    "name": "app",
+    "biome": { "linter": { "rules": { "suspicious": { "noDebugger": "off" } } } }
+}"#,
+    );
+
+    fs.insert(
+        Path::new("file.js").into(),
+        r#"debugger;
+a == b;
+"#,
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(["lint", "file.js"].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "biome_json_takes_precedence_over_package_json",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn package_json_without_configuration_is_ignored() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{ "linter": { "rules": { "suspicious": { "noDoubleEquals": "off" } } } }"#,
+    );
+
+    fs.insert(
+        Path::new("packages/a/package.json").into(),
+        r#"{ "name": "a" }"#,
+    );
+
+    fs.insert(
+        Path::new("packages/a/file.js").into(),
+        r#"debugger;
+a == b;
+"#,
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(["lint", "packages/a/file.js"].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "package_json_without_configuration_is_ignored",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn nested_package_json_configuration() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{ "linter": { "rules": { "suspicious": { "noDoubleEquals": "off" } } } }"#,
+    );
+
+    fs.insert(
+        Path::new("packages/a/package.json").into(),
+        r#"{
+    "name": "a",
+    "biome": {
+        "root": false,
+        "linter": { "rules": { "suspicious": { "noDebugger": "off" } } }
+    }
+}"#,
+    );
+
+    fs.insert(
+        Path::new("packages/a/file.js").into(),
+        r#"debugger;
+a == b;
+"#,
+    );
+
+    fs.insert(
+        Path::new("packages/b/file.js").into(),
+        r#"debugger;
+a == b;
+"#,
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(["lint", "."].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "nested_package_json_configuration",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn package_json_configuration_with_errors() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("package.json").into(),
+        r#"{
+    "name": "app",
+    "biome": { "formatter": { "indentStyle": "tabs" } }
+}"#,
+    );
+
+    fs.insert(
+        Path::new("file.js").into(),
+        r#"a;
+"#,
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(["format", "file.js"].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "package_json_configuration_with_errors",
+        fs,
+        console,
+        result,
+    ));
+}
@@ -0,0 +1,84 @@
+use crate::run_cli;
+use crate::snap_test::{assert_cli_snapshot, SnapshotPayload};
+use biome_console::BufferConsole;
+use biome_fs::MemoryFileSystem;
+use biome_service::DynRef;
+use bpaf::Args;
+use std::path::Path;
+
+#[test]
+fn user_configuration_without_project_configuration() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.set_user_config_directory(Path::new("user-config").into());
+    fs.insert(
+        Path::new("user-config/biome.json").into(),
+        r#"{ "formatter": { "indentStyle": "space", "indentWidth": 4 } }"#,
+    );
+
+    fs.insert(
+        Path::new("file.js").into(),
+        r#"function f() {
+	return 1;
+}
+"#,
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(["format", "file.js"].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "user_configuration_without_project_configuration",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn user_configuration_is_ignored_with_project_configuration() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.set_user_config_directory(Path::new("user-config").into());
+    fs.insert(
+        Path::new("user-config/biome.json").into(),
+        r#"{ "formatter": { "indentStyle": "space", "indentWidth": 4 } }"#,
+    );
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{ "formatter": { "lineWidth": 120 } }"#,
+    );
+
+    fs.insert(
+        Path::new("file.js").into(),
+        r#"function f() {
+	return 1;
+}
+"#,
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(["format", "file.js"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "user_configuration_is_ignored_with_project_configuration",
+        fs,
+        console,
+        result,
+    ));
+}
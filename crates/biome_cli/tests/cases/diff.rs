@@ -0,0 +1,124 @@
+use crate::run_cli;
+use crate::snap_test::{assert_cli_snapshot, assert_file_contents, SnapshotPayload};
+use biome_console::BufferConsole;
+use biome_fs::MemoryFileSystem;
+use biome_service::DynRef;
+use bpaf::Args;
+use std::path::Path;
+
+const UNFORMATTED: &str = "let a   = 1\nconst b = 2;\n";
+
+const UNSAFE_FIXES: &str = "debugger;\nlet c = 3;\n";
+
+#[test]
+fn format_prints_unified_diffs() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("src/unformatted.js");
+    fs.insert(file_path.into(), UNFORMATTED.as_bytes());
+    fs.insert(
+        Path::new("src/formatted.js").into(),
+        "const c = 3;\n".as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(["format", "--write", "--diff", "src"].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, UNFORMATTED);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "format_prints_unified_diffs",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn check_write_prints_unified_diffs() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let unformatted_path = Path::new("src/unformatted.js");
+    fs.insert(unformatted_path.into(), UNFORMATTED.as_bytes());
+    let unsafe_fixes_path = Path::new("src/unsafe.js");
+    fs.insert(unsafe_fixes_path.into(), UNSAFE_FIXES.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(["check", "--write", "--unsafe", "--diff", "src"].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, unformatted_path, UNFORMATTED);
+    assert_file_contents(&fs, unsafe_fixes_path, UNSAFE_FIXES);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "check_write_prints_unified_diffs",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn lint_write_prints_unified_diffs() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("src/unsafe.js");
+    fs.insert(file_path.into(), UNSAFE_FIXES.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(["lint", "--write", "--diff", "src"].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, UNSAFE_FIXES);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_write_prints_unified_diffs",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn lint_diff_requires_write() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("src/unsafe.js");
+    fs.insert(file_path.into(), UNSAFE_FIXES.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(["lint", "--diff", "src"].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_diff_requires_write",
+        fs,
+        console,
+        result,
+    ));
+}
@@ -0,0 +1,84 @@
+use crate::run_cli;
+use crate::snap_test::{assert_cli_snapshot, assert_file_contents, SnapshotPayload};
+use biome_console::BufferConsole;
+use biome_fs::MemoryFileSystem;
+use biome_service::DynRef;
+use bpaf::Args;
+use std::path::Path;
+
+const UNFORMATTED: &str = r#"<div><br/>
+<script>
+  greet();
+</script></div>
+"#;
+
+#[test]
+fn format_html_files_with_configured_options() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{
+  "html": {
+    "formatter": {
+      "enabled": true,
+      "indentStyle": "space",
+      "indentScriptAndStyle": true
+    }
+  },
+  "overrides": [
+    {
+      "include": ["legacy/**"],
+      "html": {
+        "formatter": {
+          "indentWidth": 4,
+          "selfCloseVoidElements": "never"
+        }
+      }
+    }
+  ]
+}"#
+        .as_bytes(),
+    );
+
+    let file_path = Path::new("index.html");
+    fs.insert(file_path.into(), UNFORMATTED.as_bytes());
+    let legacy_file_path = Path::new("legacy/index.html");
+    fs.insert(legacy_file_path.into(), UNFORMATTED.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("format"),
+                ("--write"),
+                file_path.as_os_str().to_str().unwrap(),
+                legacy_file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(
+        &fs,
+        file_path,
+        "<div>\n  <br />\n  <script>\n    greet();\n  </script>\n</div>\n",
+    );
+    assert_file_contents(
+        &fs,
+        legacy_file_path,
+        "<div>\n    <br>\n    <script>\n        greet();\n    </script>\n</div>\n",
+    );
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "format_html_files_with_configured_options",
+        fs,
+        console,
+        result,
+    ));
+}
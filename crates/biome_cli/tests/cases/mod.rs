@@ -2,25 +2,37 @@
 //! case that affects many commands
 
 mod assists;
+mod baseline;
 mod biome_json_support;
+mod cache;
 mod config_extends;
+mod config_nested;
+mod config_package_json;
 mod config_path;
+mod config_user;
 mod cts_files;
 mod diagnostics;
+mod diff;
 mod editorconfig;
 mod graphql;
 mod handle_astro_files;
 mod handle_css_files;
 mod handle_svelte_files;
 mod handle_vue_files;
+#[cfg(feature = "experimental-html")]
+mod html;
 mod included_files;
+mod module_graph;
 mod overrides_formatter;
 mod overrides_linter;
 mod overrides_organize_imports;
+mod plugins;
 mod protected_files;
+mod reporter_checkstyle;
 mod reporter_github;
 mod reporter_gitlab;
 mod reporter_junit;
+mod reporter_sarif;
 mod reporter_summary;
 mod suppressions;
 mod unknown_files;
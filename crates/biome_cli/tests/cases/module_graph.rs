@@ -0,0 +1,197 @@
+use crate::run_cli;
+use crate::snap_test::{assert_cli_snapshot, SnapshotPayload};
+use biome_console::BufferConsole;
+use biome_fs::MemoryFileSystem;
+use biome_service::DynRef;
+use bpaf::Args;
+use std::path::Path;
+
+const CONFIG: &str = r#"{
+    "linter": {
+        "rules": {
+            "nursery": {
+                "noUnresolvedImports": "error"
+            }
+        }
+    }
+}"#;
+
+#[test]
+fn reports_imports_of_missing_exports() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(Path::new("biome.json").into(), CONFIG);
+    fs.insert(
+        Path::new("src/utils.js").into(),
+        "export const foo = 1;\nexport function bar() {}\nexport * from \"./constants.js\";\n",
+    );
+    fs.insert(
+        Path::new("src/constants.js").into(),
+        "export const PI = 3.14;\nexport default 1;\n",
+    );
+    fs.insert(
+        Path::new("src/index.js").into(),
+        "import { foo, bar, PI, baz } from \"./utils.js\";\nimport utils from \"./utils.js\";\nimport * as ns from \"./utils.js\";\n",
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(["lint", "src/index.js"].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "reports_imports_of_missing_exports",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn resolves_imports_with_tsconfig_paths() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(Path::new("biome.json").into(), CONFIG);
+    fs.insert(
+        Path::new("tsconfig.json").into(),
+        r#"{ "compilerOptions": { "baseUrl": ".", "paths": { "@lib/*": ["lib/*"] } } }"#,
+    );
+    fs.insert(
+        Path::new("lib/math.ts").into(),
+        "export function add(a: number, b: number) { return a + b; }\nexport type Operation = \"add\";\n",
+    );
+    fs.insert(
+        Path::new("src/index.ts").into(),
+        "import { add, type Operation, subtract } from \"@lib/math\";\n",
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(["lint", "src/index.ts"].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "resolves_imports_with_tsconfig_paths",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn resolves_imports_of_packages() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(Path::new("biome.json").into(), CONFIG);
+    fs.insert(
+        Path::new("node_modules/with-exports/package.json").into(),
+        r#"{ "name": "with-exports", "exports": { ".": { "import": "./dist/index.mjs" } } }"#,
+    );
+    fs.insert(
+        Path::new("node_modules/with-exports/dist/index.mjs").into(),
+        "export * from \"./helpers.mjs\";\n",
+    );
+    fs.insert(
+        Path::new("node_modules/with-exports/dist/helpers.mjs").into(),
+        "export const helper = () => {};\n",
+    );
+    fs.insert(
+        Path::new("node_modules/with-main/package.json").into(),
+        r#"{ "name": "with-main", "main": "./lib/main.js" }"#,
+    );
+    fs.insert(
+        Path::new("node_modules/with-main/lib/main.js").into(),
+        "export default function main() {}\n",
+    );
+    fs.insert(
+        Path::new("node_modules/commonjs/index.js").into(),
+        "module.exports = { value: 1 };\n",
+    );
+    fs.insert(
+        Path::new("index.js").into(),
+        "import { helper, missing } from \"with-exports\";\nimport main, { other } from \"with-main\";\nimport { value } from \"commonjs\";\nimport { readFile } from \"node:fs\";\n",
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(["lint", "index.js"].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "resolves_imports_of_packages",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn does_not_report_without_project_rules() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(Path::new("src/utils.js").into(), "export const foo = 1;\n");
+    fs.insert(
+        Path::new("src/index.js").into(),
+        "import { baz } from \"./utils.js\";\n",
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(["lint", "src/index.js"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "does_not_report_without_project_rules",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn reports_project_rules_selected_with_only() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(Path::new("src/utils.js").into(), "export const foo = 1;\n");
+    fs.insert(
+        Path::new("src/index.js").into(),
+        "import { baz } from \"./utils.js\";\n",
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(["lint", "--only=nursery/noUnresolvedImports", "src/index.js"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "reports_project_rules_selected_with_only",
+        fs,
+        console,
+        result,
+    ));
+}
@@ -0,0 +1,297 @@
+use crate::run_cli;
+use crate::snap_test::{assert_cli_snapshot, assert_file_contents, SnapshotPayload};
+use biome_console::BufferConsole;
+use biome_fs::MemoryFileSystem;
+use biome_service::DynRef;
+use bpaf::Args;
+use std::path::Path;
+
+const USE_LOGGER_PLUGIN: &str = r#"`console.warn($message)` as $call where {
+    register_diagnostic(span=$call, message="Use the logger instead of the console."),
+    $call => `logger.warn($message)`
+}
+"#;
+
+const NO_DEBUG_LOG_PLUGIN: &str = r#"`console.log($message)` as $call where {
+    register_diagnostic(span=$call, message="Remove the debug logs.", severity="warn")
+}
+"#;
+
+#[test]
+fn reports_plugin_diagnostics() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{ "plugins": ["./plugins/useLogger.grit", "./plugins/noDebugLog.grit"] }"#,
+    );
+    fs.insert(
+        Path::new("plugins/useLogger.grit").into(),
+        USE_LOGGER_PLUGIN,
+    );
+    fs.insert(
+        Path::new("plugins/noDebugLog.grit").into(),
+        NO_DEBUG_LOG_PLUGIN,
+    );
+    fs.insert(
+        Path::new("file.js").into(),
+        "console.warn(\"careful\");\nconsole.log(\"debug\");\n",
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(["lint", "file.js"].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "reports_plugin_diagnostics",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn suppresses_plugin_diagnostics() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{ "plugins": ["./plugins/useLogger.grit"] }"#,
+    );
+    fs.insert(
+        Path::new("plugins/useLogger.grit").into(),
+        USE_LOGGER_PLUGIN,
+    );
+    fs.insert(
+        Path::new("file.js").into(),
+        "// biome-ignore lint/plugin(useLogger): the logger isn't loaded yet\nconsole.warn(\"careful\");\n",
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(["lint", "file.js"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "suppresses_plugin_diagnostics",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn applies_plugin_rewrites() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{ "plugins": ["./plugins/useLogger.grit"] }"#,
+    );
+    fs.insert(
+        Path::new("plugins/useLogger.grit").into(),
+        USE_LOGGER_PLUGIN,
+    );
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), "console.warn(\"careful\");\n");
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                "lint",
+                "--write",
+                "--unsafe",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, "logger.warn(\"careful\");\n");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "applies_plugin_rewrites",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn applies_plugin_severity() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{
+    "plugins": [
+        { "path": "./plugins/useLogger.grit", "severity": "off" },
+        { "path": "./plugins/noDebugLog.grit", "severity": "error" }
+    ]
+}"#,
+    );
+    fs.insert(
+        Path::new("plugins/useLogger.grit").into(),
+        USE_LOGGER_PLUGIN,
+    );
+    fs.insert(
+        Path::new("plugins/noDebugLog.grit").into(),
+        NO_DEBUG_LOG_PLUGIN,
+    );
+    fs.insert(
+        Path::new("file.js").into(),
+        "console.warn(\"careful\");\nconsole.log(\"debug\");\n",
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(["lint", "file.js"].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "applies_plugin_severity",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn rewrites_only_the_matched_nodes() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{ "plugins": ["./plugins/useLogger.grit"] }"#,
+    );
+    fs.insert(
+        Path::new("plugins/useLogger.grit").into(),
+        USE_LOGGER_PLUGIN,
+    );
+    let file_path = Path::new("file.js");
+    fs.insert(
+        file_path.into(),
+        "function check(value) {\n    // keep this comment\n    if (!value) {\n        console.warn(\"careful\");\n    }\n}\n",
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                "lint",
+                "--write",
+                "--unsafe",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(
+        &fs,
+        file_path,
+        "function check(value) {\n    // keep this comment\n    if (!value) {\n        logger.warn(\"careful\");\n    }\n}\n",
+    );
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "rewrites_only_the_matched_nodes",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn attaches_each_rewrite_to_its_diagnostic() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{ "plugins": ["./plugins/useLogger.grit"] }"#,
+    );
+    fs.insert(
+        Path::new("plugins/useLogger.grit").into(),
+        USE_LOGGER_PLUGIN,
+    );
+    let file_path = Path::new("file.js");
+    fs.insert(
+        file_path.into(),
+        "console.warn(\"first\");\nconsole.warn(\"second\");\n",
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(["lint", file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "attaches_each_rewrite_to_its_diagnostic",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn reports_invalid_plugins() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{ "plugins": ["./plugins/invalid.grit"] }"#,
+    );
+    fs.insert(
+        Path::new("plugins/invalid.grit").into(),
+        "`console.log($message)` where {",
+    );
+    fs.insert(Path::new("file.js").into(), "console.log(\"debug\");\n");
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(["lint", "file.js"].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "reports_invalid_plugins",
+        fs,
+        console,
+        result,
+    ));
+}
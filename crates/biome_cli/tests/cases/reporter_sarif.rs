@@ -0,0 +1,157 @@
+use crate::run_cli;
+use crate::snap_test::markup_to_string;
+use biome_console::{markup, BufferConsole, LogLevel};
+use biome_fs::MemoryFileSystem;
+use biome_service::DynRef;
+use bpaf::Args;
+use serde_json::Value;
+use std::path::Path;
+
+const MAIN: &str = r#"import { z} from "z"
+import { z, b , a} from "lodash"
+
+a ==b
+
+debugger
+
+let f;
+		let f;"#;
+
+fn sarif_log(console: &BufferConsole) -> Value {
+    let message = console
+        .out_buffer
+        .iter()
+        .find(|message| message.level == LogLevel::Log)
+        .expect("Console should have written the report");
+
+    let content = markup_to_string(markup! {
+        {message.content}
+    });
+
+    serde_json::from_str(&content).expect("The report should be valid JSON")
+}
+
+fn find_result<'a>(log: &'a Value, rule_id: &str) -> &'a Value {
+    log["runs"][0]["results"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|result| result["ruleId"] == rule_id)
+        .unwrap_or_else(|| panic!("Expected a result for {rule_id}"))
+}
+
+#[test]
+fn reports_diagnostics_sarif_lint_command() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("main.ts");
+    fs.insert(file_path.into(), MAIN.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                "--reporter=sarif",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    let log = sarif_log(&console);
+    assert_eq!(log["version"], "2.1.0");
+    assert_eq!(log["runs"].as_array().unwrap().len(), 1);
+
+    let driver = &log["runs"][0]["tool"]["driver"];
+    assert_eq!(driver["name"], "Biome");
+
+    let result = find_result(&log, "lint/suspicious/noDebugger");
+    assert_eq!(result["level"], "error");
+
+    let rule_index = result["ruleIndex"].as_u64().unwrap() as usize;
+    let rule = &driver["rules"][rule_index];
+    assert_eq!(rule["id"], "lint/suspicious/noDebugger");
+    assert_eq!(rule["name"], "noDebugger");
+    assert_eq!(
+        rule["helpUri"],
+        "https://biomejs.dev/linter/rules/no-debugger"
+    );
+    assert_eq!(rule["defaultConfiguration"]["enabled"], true);
+    assert_eq!(rule["defaultConfiguration"]["level"], "error");
+    assert_eq!(rule["properties"]["sources"][0]["rule"], "no-debugger");
+
+    let not_recommended_rule = driver["rules"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|rule| rule["id"] == "lint/style/useNamingConvention")
+        .unwrap();
+    assert_eq!(
+        not_recommended_rule["defaultConfiguration"]["enabled"],
+        false
+    );
+    assert_eq!(
+        not_recommended_rule["defaultConfiguration"]["level"],
+        "warning"
+    );
+
+    let region = &result["locations"][0]["physicalLocation"]["region"];
+    assert_eq!(
+        result["locations"][0]["physicalLocation"]["artifactLocation"]["uri"],
+        "main.ts"
+    );
+    assert_eq!(region["startLine"], 6);
+    assert_eq!(region["startColumn"], 1);
+    assert_eq!(region["endLine"], 6);
+    assert_eq!(region["endColumn"], 9);
+
+    let mut fixed = MAIN.to_string();
+    let replacements = result["fixes"][0]["artifactChanges"][0]["replacements"]
+        .as_array()
+        .unwrap();
+    for replacement in replacements.iter().rev() {
+        let offset = replacement["deletedRegion"]["byteOffset"].as_u64().unwrap() as usize;
+        let length = replacement["deletedRegion"]["byteLength"].as_u64().unwrap() as usize;
+        let text = replacement["insertedContent"]["text"].as_str().unwrap();
+        fixed.replace_range(offset..offset + length, text);
+    }
+    assert!(!fixed.contains("debugger"));
+    assert!(fixed.contains("a ==b"));
+}
+
+#[test]
+fn reports_diagnostics_sarif_format_command() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("main.ts");
+    fs.insert(file_path.into(), MAIN.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("format"),
+                "--reporter=sarif",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    let log = sarif_log(&console);
+    let result = find_result(&log, "format");
+    assert!(result["ruleIndex"].is_null());
+    assert_eq!(
+        result["locations"][0]["physicalLocation"]["artifactLocation"]["uri"],
+        "main.ts"
+    );
+}
@@ -315,3 +315,313 @@ fn custom_explanation_with_reason() {
         result,
     ));
 }
+
+#[test]
+fn policy_requires_reason() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let config_path = Path::new("biome.json");
+    fs.insert(
+        config_path.into(),
+        r#"{ "linter": { "suppressions": { "requireReason": true } } }"#.as_bytes(),
+    );
+
+    let file_path = Path::new("file.js");
+    fs.insert(
+        file_path.into(),
+        r#"// biome-ignore lint/suspicious/noDoubleEquals: <explanation>
+a == b;
+// biome-ignore lint/suspicious/noDoubleEquals:
+a == b;
+// biome-ignore lint/suspicious/noDoubleEquals: the values have different types
+a == b;
+"#
+        .as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(["lint", file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "policy_requires_reason",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn policy_reason_patterns() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let config_path = Path::new("biome.json");
+    fs.insert(
+        config_path.into(),
+        r#"{
+    "linter": {
+        "suppressions": {
+            "reasonPatterns": {
+                "security": "SEC-\\d+",
+                "security/noGlobalEval": "EVAL-\\d+"
+            }
+        }
+    }
+}"#
+        .as_bytes(),
+    );
+
+    let file_path = Path::new("file.js");
+    fs.insert(
+        file_path.into(),
+        r#"// biome-ignore lint/security/noGlobalEval: needed by the plugin system
+eval(code);
+// biome-ignore lint/security/noGlobalEval: SEC-42
+eval(code);
+// biome-ignore lint/security/noGlobalEval: see EVAL-42
+eval(code);
+"#
+        .as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(["lint", file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "policy_reason_patterns",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn policy_forbidden() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let config_path = Path::new("biome.json");
+    fs.insert(
+        config_path.into(),
+        r#"{ "linter": { "suppressions": { "forbidden": ["security/noGlobalEval"] } } }"#
+            .as_bytes(),
+    );
+
+    let file_path = Path::new("file.js");
+    fs.insert(
+        file_path.into(),
+        r#"// biome-ignore lint/security/noGlobalEval: SEC-42
+eval(code);
+// biome-ignore lint: everything is fine
+eval(code);
+"#
+        .as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(["lint", file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "policy_forbidden",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn policy_max_per_file() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let config_path = Path::new("biome.json");
+    fs.insert(
+        config_path.into(),
+        r#"{ "linter": { "suppressions": { "maxPerFile": 1 } } }"#.as_bytes(),
+    );
+
+    let file_path = Path::new("file.js");
+    fs.insert(
+        file_path.into(),
+        r#"// biome-ignore lint/suspicious/noDoubleEquals: the values have different types
+a == b;
+// biome-ignore lint/suspicious/noDoubleEquals: the values have different types
+a == b;
+"#
+        .as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(["lint", file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "policy_max_per_file",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn policy_with_invalid_configuration() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let config_path = Path::new("biome.json");
+    fs.insert(
+        config_path.into(),
+        r#"{
+    "linter": {
+        "suppressions": {
+            "reasonPatterns": { "security": "SEC-(" },
+            "forbidden": ["security/noUnknownRule"]
+        }
+    }
+}"#
+        .as_bytes(),
+    );
+
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), "a == b;\n".as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(["lint", file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "policy_with_invalid_configuration",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn suppress_css_graphql_and_jsonc() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let config_path = Path::new("biome.json");
+    fs.insert(
+        config_path.into(),
+        r#"{
+    "css": { "linter": { "enabled": true } },
+    "graphql": { "linter": { "enabled": true } },
+    "linter": {
+        "rules": {
+            "nursery": { "noDuplicatedFields": "error" }
+        }
+    }
+}"#
+        .as_bytes(),
+    );
+
+    let css_path = Path::new("file.css");
+    fs.insert(css_path.into(), "a {}\n".as_bytes());
+
+    let graphql_path = Path::new("file.graphql");
+    fs.insert(
+        graphql_path.into(),
+        "query test($v: String, $v: String) {\n  name\n}\n".as_bytes(),
+    );
+
+    let jsonc_path = Path::new("file.jsonc");
+    fs.insert(
+        jsonc_path.into(),
+        "{\n  \"name\": \"biome\",\n  \"name\": \"rome\"\n}\n".as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                "lint",
+                "--suppress",
+                css_path.as_os_str().to_str().unwrap(),
+                graphql_path.as_os_str().to_str().unwrap(),
+                jsonc_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    let mut buffer = String::new();
+    fs.open(css_path)
+        .unwrap()
+        .read_to_string(&mut buffer)
+        .unwrap();
+    assert_eq!(
+        buffer,
+        "/* biome-ignore lint/suspicious/noEmptyBlock: ignored using `--suppress` */\na {}\n"
+    );
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "suppress_css_graphql_and_jsonc",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn suppress_json_without_comments() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("file.json");
+    fs.insert(
+        file_path.into(),
+        "{\n  \"name\": \"biome\",\n  \"name\": \"rome\"\n}\n".as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                "lint",
+                "--suppress",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "suppress_json_without_comments",
+        fs,
+        console,
+        result,
+    ));
+}
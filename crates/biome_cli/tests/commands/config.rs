@@ -0,0 +1,191 @@
+use crate::run_cli;
+use crate::snap_test::{assert_cli_snapshot, SnapshotPayload};
+use biome_console::BufferConsole;
+use biome_fs::MemoryFileSystem;
+use biome_service::DynRef;
+use bpaf::Args;
+use std::path::Path;
+
+#[test]
+fn config_print_help() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(["config", "print", "--help"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "config_print_help",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn config_print_css_file_with_overrides() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{
+    "css": { "formatter": { "quoteStyle": "single" } },
+    "linter": { "rules": { "recommended": false, "correctness": { "noUnknownUnit": "warn" } } },
+    "overrides": [
+        { "include": ["*.js"], "formatter": { "indentWidth": 8 } },
+        {
+            "include": ["styles/**"],
+            "formatter": { "indentStyle": "space" },
+            "linter": { "rules": { "suspicious": { "noDuplicateFontNames": "off", "noImportantInKeyframe": "warn" } } }
+        }
+    ]
+}"#,
+    );
+
+    fs.insert(Path::new("styles/index.css").into(), r#"a { color: red; }"#);
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(["config", "print", "styles/index.css"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "config_print_css_file_with_overrides",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn config_print_nested_configuration_json() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{ "json": { "formatter": { "indentWidth": 4 } } }"#,
+    );
+
+    fs.insert(
+        Path::new("packages/a/biome.json").into(),
+        r#"{
+    "root": false,
+    "json": { "formatter": { "lineWidth": 120 } },
+    "linter": { "rules": { "suspicious": { "noDuplicateObjectKeys": "warn" } } }
+}"#,
+    );
+
+    fs.insert(Path::new("packages/a/data.json").into(), r#"{ "a": 1 }"#);
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(["config", "print", "--json", "packages/a/data.json"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "config_print_nested_configuration_json",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn config_print_editorconfig_and_command_line_sources() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{
+    "formatter": { "useEditorconfig": true, "lineWidth": 100 },
+    "javascript": { "formatter": { "quoteStyle": "single" } },
+    "linter": { "rules": { "recommended": false, "suspicious": { "noDebugger": "warn" } } }
+}"#,
+    );
+
+    fs.insert(
+        Path::new(".editorconfig").into(),
+        r#"
+[*]
+indent_style = space
+max_line_length = 120
+"#,
+    );
+
+    fs.insert(Path::new("src/index.js").into(), r#"const a = "a";"#);
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                "config",
+                "print",
+                "--line-ending=crlf",
+                "--javascript-formatter-indent-width=4",
+                "src/index.js",
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "config_print_editorconfig_and_command_line_sources",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn config_print_ignored_file() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{
+    "files": { "ignore": ["dist/**"] },
+    "formatter": { "enabled": false },
+    "linter": { "rules": { "recommended": false } }
+}"#,
+    );
+
+    fs.insert(Path::new("dist/data.json").into(), r#"{ "a": 1 }"#);
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(["config", "print", "dist/data.json"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "config_print_ignored_file",
+        fs,
+        console,
+        result,
+    ));
+}
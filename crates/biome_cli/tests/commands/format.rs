@@ -8,7 +8,7 @@ use crate::{
     assert_cli_snapshot, run_cli, CUSTOM_FORMAT_BEFORE, FORMATTED, LINT_ERROR, UNFORMATTED,
 };
 use biome_console::{markup, BufferConsole, MarkupBuf};
-use biome_fs::{FileSystemExt, MemoryFileSystem};
+use biome_fs::{ChangedLines, FileSystemExt, MemoryFileSystem};
 use biome_service::DynRef;
 use bpaf::Args;
 use std::path::{Path, PathBuf};
@@ -3775,6 +3775,57 @@ fn should_error_if_unchanged_files_only_with_changed_flag() {
     ));
 }
 
+#[test]
+fn should_only_format_changed_lines() {
+    let mut console = BufferConsole::default();
+    let mut fs = MemoryFileSystem::default();
+
+    fs.set_on_get_changed_files(Box::new(|| vec![String::from("file.js")]));
+    fs.set_on_get_changed_lines(Box::new(|| {
+        vec![ChangedLines {
+            path: String::from("file.js"),
+            lines: vec![2..3, 3..4],
+        }]
+    }));
+
+    let file_path = Path::new("file.js");
+    fs.insert(
+        file_path.into(),
+        "let  a  =  1\nlet b = 2;\nlet  c  =  3\nlet  d  =  4\n".as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("format"),
+                "--changed",
+                "--changed-lines-only",
+                "--since=main",
+                "--write",
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(
+        &fs,
+        file_path,
+        "let  a  =  1\nlet b = 2;\nlet c = 3;\nlet d = 4;\n",
+    );
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "should_only_format_changed_lines",
+        fs,
+        console,
+        result,
+    ));
+}
+
 #[test]
 fn applies_custom_bracket_spacing_for_graphql() {
     let mut fs = MemoryFileSystem::default();
@@ -142,3 +142,149 @@ fn does_not_create_config_file_if_jsonc_exists() {
         result,
     ));
 }
+
+#[test]
+fn does_not_create_config_file_if_package_json_has_configuration() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("package.json").into(),
+        r#"{ "name": "app", "biome": {} }"#,
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("init")].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "does_not_create_config_file_if_package_json_has_configuration",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn detect_creates_config_file_for_next_project() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("package.json").into(),
+        r#"{
+    "name": "app",
+    "dependencies": { "next": "14.2.3", "react": "^18.3.1", "react-dom": "^18.3.1" },
+    "devDependencies": { "typescript": "^5.4.5" }
+}"#,
+    );
+    fs.insert(
+        Path::new("tsconfig.json").into(),
+        r#"{
+    // Generated by Next.js
+    "compilerOptions": { "jsx": "preserve", "strict": true, },
+}"#,
+    );
+    fs.insert(Path::new(".git").into(), "gitdir: ../.git/worktrees/app");
+    fs.insert(Path::new(".next/server/app.js").into(), "");
+    fs.insert(Path::new("coverage/lcov.info").into(), "");
+    fs.insert(Path::new("src/app/page.tsx").into(), "");
+    fs.insert(Path::new(".eslintrc.json").into(), "{}");
+    fs.insert(Path::new(".prettierrc").into(), "{}");
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("init"), "--detect"].as_slice()),
+    );
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "detect_creates_config_file_for_next_project",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn detect_creates_config_file_for_vue_files() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(Path::new("src/App.vue").into(), "");
+    fs.insert(Path::new("src/main.ts").into(), "");
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("init"), "--detect"].as_slice()),
+    );
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "detect_creates_config_file_for_vue_files",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn detect_sets_classic_jsx_runtime_for_old_react() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("package.json").into(),
+        r#"{ "name": "app", "dependencies": { "react": "^16.14.0" } }"#,
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("init"), "--detect"].as_slice()),
+    );
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "detect_sets_classic_jsx_runtime_for_old_react",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn detect_sets_classic_jsx_runtime_from_tsconfig() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("tsconfig.json").into(),
+        r#"{ "compilerOptions": { "jsx": "react" } }"#,
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("init"), "--detect"].as_slice()),
+    );
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "detect_sets_classic_jsx_runtime_from_tsconfig",
+        fs,
+        console,
+        result,
+    ));
+}
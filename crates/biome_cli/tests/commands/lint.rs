@@ -18,7 +18,8 @@ use crate::configs::{
 use crate::snap_test::{assert_file_contents, markup_to_string, SnapshotPayload};
 use crate::{assert_cli_snapshot, run_cli, FORMATTED, LINT_ERROR, PARSE_ERROR};
 use biome_console::{markup, BufferConsole, LogLevel, MarkupBuf};
-use biome_fs::{ErrorEntry, FileSystemExt, MemoryFileSystem, OsFileSystem};
+use biome_diagnostics::PrintDiagnostic;
+use biome_fs::{ChangedLines, ErrorEntry, FileSystemExt, MemoryFileSystem, OsFileSystem};
 use biome_service::DynRef;
 
 const ERRORS: &str = r#"
@@ -2236,6 +2237,177 @@ file2.js
     ));
 }
 
+#[test]
+fn ignore_vcs_nested_ignored_file() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let biome_json = r#"{
+        "vcs": {
+            "enabled": true,
+            "clientKind": "git",
+            "useIgnoreFile": true
+        }
+    }"#;
+    fs.insert(Path::new("biome.json").into(), biome_json.as_bytes());
+    fs.insert(
+        Path::new(".gitignore").into(),
+        "*.generated.js\n".as_bytes(),
+    );
+    fs.insert(
+        Path::new("packages/a/.gitignore").into(),
+        "ignored.js\n!kept.generated.js\n".as_bytes(),
+    );
+
+    fs.insert(
+        Path::new("packages/a/ignored.js").into(),
+        "debugger;\n".as_bytes(),
+    );
+    fs.insert(
+        Path::new("packages/a/kept.generated.js").into(),
+        "debugger;\n".as_bytes(),
+    );
+    fs.insert(
+        Path::new("packages/a/other.generated.js").into(),
+        "debugger;\n".as_bytes(),
+    );
+    fs.insert(
+        Path::new("packages/b/ignored.js").into(),
+        "debugger;\n".as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), "packages"].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "ignore_vcs_nested_ignored_file",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn ignore_vcs_file_in_ignored_directory() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let biome_json = r#"{
+        "vcs": {
+            "enabled": true,
+            "clientKind": "git",
+            "useIgnoreFile": true
+        }
+    }"#;
+    fs.insert(Path::new("biome.json").into(), biome_json.as_bytes());
+    fs.insert(Path::new(".gitignore").into(), "dist/\n".as_bytes());
+    // A file can't be included again when its directory is ignored
+    fs.insert(Path::new("dist/.gitignore").into(), "!file.js\n".as_bytes());
+    fs.insert(Path::new("dist/file.js").into(), "debugger;\n".as_bytes());
+    fs.insert(Path::new("src/file.js").into(), "debugger;\n".as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), "dist/file.js", "src/file.js"].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "ignore_vcs_file_in_ignored_directory",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn ignore_vcs_excluded_file() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let biome_json = r#"{
+        "vcs": {
+            "enabled": true,
+            "clientKind": "git",
+            "useIgnoreFile": true
+        }
+    }"#;
+    fs.insert(Path::new("biome.json").into(), biome_json.as_bytes());
+    fs.insert(
+        Path::new(".git/info/exclude").into(),
+        "excluded.js\n".as_bytes(),
+    );
+    fs.insert(Path::new("excluded.js").into(), "debugger;\n".as_bytes());
+    fs.insert(Path::new("file.js").into(), "debugger;\n".as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), "excluded.js", "file.js"].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "ignore_vcs_excluded_file",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn ignore_vcs_global_excludes_file() {
+    let mut fs = MemoryFileSystem::default();
+    fs.set_home_directory(PathBuf::from("home"));
+    let mut console = BufferConsole::default();
+
+    let biome_json = r#"{
+        "vcs": {
+            "enabled": true,
+            "clientKind": "git",
+            "useIgnoreFile": true
+        }
+    }"#;
+    fs.insert(Path::new("biome.json").into(), biome_json.as_bytes());
+    fs.insert(
+        Path::new("home/.gitconfig").into(),
+        "[core]\n\texcludesFile = ~/.gitignore_global\n".as_bytes(),
+    );
+    fs.insert(
+        Path::new("home/.gitignore_global").into(),
+        "excluded.js\n".as_bytes(),
+    );
+    fs.insert(Path::new("excluded.js").into(), "debugger;\n".as_bytes());
+    fs.insert(Path::new("file.js").into(), "debugger;\n".as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), "excluded.js", "file.js"].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "ignore_vcs_global_excludes_file",
+        fs,
+        console,
+        result,
+    ));
+}
+
 #[test]
 fn check_stdin_write_successfully() {
     let mut fs = MemoryFileSystem::default();
@@ -3088,6 +3260,276 @@ fn should_error_if_changed_flag_and_staged_flag_are_active_at_the_same_time() {
     ));
 }
 
+#[test]
+fn should_only_report_diagnostics_of_changed_lines() {
+    let mut console = BufferConsole::default();
+    let mut fs = MemoryFileSystem::default();
+
+    fs.set_on_get_changed_files(Box::new(|| vec![String::from("file.js")]));
+    fs.set_on_get_changed_lines(Box::new(|| {
+        vec![ChangedLines {
+            path: String::from("file.js"),
+            lines: vec![2..3, 4..5],
+        }]
+    }));
+
+    let file_path = Path::new("file.js");
+    fs.insert(
+        file_path.into(),
+        "debugger;\nlet a = 1;\ndebugger;\nlet b = 2;\ndebugger;\n".as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                "--changed",
+                "--changed-lines-only",
+                "--since=main",
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "should_only_report_diagnostics_of_changed_lines",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+#[expect(clippy::single_range_in_vec_init)]
+fn should_report_diagnostics_of_changed_lines_beyond_max_diagnostics() {
+    let mut console = BufferConsole::default();
+    let mut fs = MemoryFileSystem::default();
+
+    fs.set_on_get_changed_files(Box::new(|| vec![String::from("file.js")]));
+    fs.set_on_get_changed_lines(Box::new(|| {
+        vec![ChangedLines {
+            path: String::from("file.js"),
+            lines: vec![2..3],
+        }]
+    }));
+
+    let file_path = Path::new("file.js");
+    fs.insert(
+        file_path.into(),
+        "debugger;\ndebugger;\ndebugger;\n".as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                "--changed",
+                "--changed-lines-only",
+                "--since=main",
+                "--max-diagnostics=1",
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "should_report_diagnostics_of_changed_lines_beyond_max_diagnostics",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn should_error_if_changed_lines_only_is_used_without_changed() {
+    let mut console = BufferConsole::default();
+    let mut fs = MemoryFileSystem::default();
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), "--changed-lines-only"].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "should_error_if_changed_lines_only_is_used_without_changed",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn should_error_if_the_base_of_changed_lines_is_unknown() {
+    let mut console = BufferConsole::default();
+
+    let root_path = temp_dir().join("lint_biome_test_unknown_base_of_changed_lines");
+    let _ = remove_dir_all(&root_path);
+    create_dir(&root_path).unwrap();
+    std::fs::write(root_path.join("file.js"), NO_DEBUGGER).unwrap();
+
+    let result = run_cli(
+        DynRef::Owned(Box::new(OsFileSystem::new(root_path.clone()))),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                "--changed",
+                "--changed-lines-only",
+                "--since=biome-unknown-base-ref",
+                root_path.display().to_string().as_str(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    remove_dir_all(root_path).unwrap();
+
+    let Err(error) = result else {
+        panic!("run_cli returned {result:?}");
+    };
+    // The message is the one printed by git, which depends on its version
+    let message = markup_to_string(markup! {{PrintDiagnostic::simple(&error)}});
+    assert!(
+        message.contains("biome-unknown-base-ref"),
+        "the error doesn't mention the base: {message}"
+    );
+}
+
+#[test]
+fn should_report_rule_profile_in_json_report() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("check.js");
+    fs.insert(file_path.into(), "debugger;\n".as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                "--profile-rules",
+                "--reporter=json",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    let message = console
+        .out_buffer
+        .iter()
+        .find(|message| message.level == LogLevel::Log)
+        .expect("Console should have written the report");
+    let content = markup_to_string(markup! {
+        {message.content}
+    });
+    let report: serde_json::Value =
+        serde_json::from_str(&content).expect("The report should be valid JSON");
+
+    // The timings change at every run, only the rules are checked
+    let no_debugger = report["ruleProfile"]
+        .as_array()
+        .expect("The report should contain the rule profile")
+        .iter()
+        .find(|timings| timings["rule"] == "suspicious/noDebugger")
+        .expect("The profile should contain noDebugger");
+    assert!(no_debugger["count"].as_u64().unwrap() > 0);
+}
+
+#[test]
+fn should_print_rule_profile() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("check.js");
+    fs.insert(file_path.into(), "debugger;\n".as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                "--profile-rules",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert!(console.out_buffer.iter().any(|message| {
+        let content = markup_to_string(markup! {
+            {message.content}
+        });
+        message.level == LogLevel::Error
+            && content.contains("Rules profile")
+            && content.contains("Action time")
+    }));
+}
+
+#[test]
+fn should_print_rule_profile_apart_from_sarif_report() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("check.js");
+    fs.insert(file_path.into(), "debugger;\n".as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                "--profile-rules",
+                "--reporter=sarif",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    let report: Vec<_> = console
+        .out_buffer
+        .iter()
+        .filter(|message| message.level == LogLevel::Log)
+        .map(|message| markup_to_string(markup! {{message.content}}))
+        .collect();
+    assert_eq!(
+        report.len(),
+        1,
+        "only the report should be logged: {report:?}"
+    );
+    serde_json::from_str::<serde_json::Value>(&report[0]).expect("The report should be valid JSON");
+
+    assert!(console.out_buffer.iter().any(|message| {
+        message.level == LogLevel::Error
+            && markup_to_string(markup! {{message.content}}).contains("Rules profile")
+    }));
+}
+
 #[test]
 fn should_only_processes_staged_files_when_staged_flag_is_set() {
     let mut console = BufferConsole::default();
@@ -4327,3 +4769,245 @@ fn should_error_if_unchanged_files_only_with_changed_flag() {
         result,
     ));
 }
+
+#[test]
+fn linter_domain_all_enables_rules() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{
+  "linter": {
+    "domains": {
+      "next": "all"
+    }
+  }
+}"#
+        .as_bytes(),
+    );
+
+    let file_path = Path::new("page.jsx");
+    fs.insert(
+        file_path.into(),
+        r#"export const Page = () => <img src="/logo.png" alt="Logo" />;
+"#
+        .as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "linter_domain_all_enables_rules",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn linter_domain_none_disables_rules() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{
+  "linter": {
+    "domains": {
+      "react": "none"
+    }
+  }
+}"#
+        .as_bytes(),
+    );
+
+    let file_path = Path::new("component.jsx");
+    fs.insert(
+        file_path.into(),
+        r#"export const Component = () => <div children="child" />;
+"#
+        .as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "linter_domain_none_disables_rules",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn linter_domain_auto_reads_dependencies() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{
+  "linter": {
+    "domains": {
+      "next": "auto",
+      "solid": "auto"
+    }
+  }
+}"#
+        .as_bytes(),
+    );
+    fs.insert(
+        Path::new("package.json").into(),
+        r#"{
+  "dependencies": { "next": "^14.0.0", "react": "^18.0.0" }
+}"#
+        .as_bytes(),
+    );
+
+    let file_path = Path::new("page.jsx");
+    fs.insert(
+        file_path.into(),
+        r#"export const Page = () => <img className="logo" src="/logo.png" alt="Logo" />;
+"#
+        .as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "linter_domain_auto_reads_dependencies",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn linter_rule_configuration_takes_precedence_over_domain() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{
+  "linter": {
+    "domains": {
+      "react": "none"
+    },
+    "rules": {
+      "correctness": {
+        "noChildrenProp": "error"
+      }
+    }
+  }
+}"#
+        .as_bytes(),
+    );
+
+    let file_path = Path::new("component.jsx");
+    fs.insert(
+        file_path.into(),
+        r#"export const Component = () => <div children="child" key="key" />;
+"#
+        .as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "linter_rule_configuration_takes_precedence_over_domain",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn linter_override_domains_apply_to_matching_files() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{
+  "overrides": [
+    {
+      "include": ["app/**"],
+      "linter": {
+        "domains": {
+          "next": "all"
+        }
+      }
+    }
+  ]
+}"#
+        .as_bytes(),
+    );
+
+    let app_file = Path::new("app/page.jsx");
+    fs.insert(
+        app_file.into(),
+        r#"export const Page = () => <img src="/logo.png" alt="Logo" />;
+"#
+        .as_bytes(),
+    );
+    let lib_file = Path::new("lib/logo.jsx");
+    fs.insert(
+        lib_file.into(),
+        r#"export const Logo = () => <img src="/logo.png" alt="Logo" />;
+"#
+        .as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                app_file.as_os_str().to_str().unwrap(),
+                lib_file.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "linter_override_domains_apply_to_matching_files",
+        fs,
+        console,
+        result,
+    ));
+}
@@ -158,3 +158,48 @@ fn should_emit_incompatible_arguments_error() {
         result,
     ));
 }
+
+#[test]
+fn migrate_package_json_configuration() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("package.json").into(),
+        r#"{
+    "name": "app",
+    "biome": {
+        "formatter": { "indentSize": 4 }
+    },
+    "dependencies": {}
+}"#,
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(["migrate", "--write"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(
+        &fs,
+        Path::new("package.json"),
+        r#"{
+    "name": "app",
+    "biome": {
+        "formatter": { "indentWidth": 4 }
+    },
+    "dependencies": {}
+}"#,
+    );
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "migrate_package_json_configuration",
+        fs,
+        console,
+        result,
+    ));
+}
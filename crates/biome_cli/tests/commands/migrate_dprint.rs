@@ -0,0 +1,213 @@
+use crate::run_cli;
+use crate::snap_test::{assert_cli_snapshot, SnapshotPayload};
+use biome_console::BufferConsole;
+use biome_fs::MemoryFileSystem;
+use biome_service::DynRef;
+use bpaf::Args;
+use std::path::Path;
+
+#[test]
+fn dprint_migrate() {
+    let biomejson = r#"{ "linter": { "enabled": true } }"#;
+    let dprintjson = r#"{
+        "$schema": "https://dprint.dev/schemas/v0.json",
+        "lineWidth": 100,
+        "indentWidth": 4,
+        "useTabs": false,
+        "newLineKind": "lf",
+        "typescript": {
+            "quoteStyle": "preferSingle",
+            "jsx.quoteStyle": "preferDouble",
+            "semiColons": "asi",
+            "trailingCommas": "onlyMultiLine",
+            "arrowFunction.useParentheses": "force",
+            "bracePosition": "sameLine"
+        },
+        "json": {
+            "indentWidth": 2,
+            "trailingCommas": "never"
+        },
+        "markup": {
+            "printWidth": 120
+        },
+        "excludes": ["**/node_modules", "dist"],
+        "plugins": ["https://plugins.dprint.dev/typescript-0.93.0.wasm"]
+    }"#;
+
+    let mut fs = MemoryFileSystem::default();
+    fs.insert(Path::new("biome.json").into(), biomejson.as_bytes());
+    fs.insert(Path::new("dprint.json").into(), dprintjson.as_bytes());
+
+    let mut console = BufferConsole::default();
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(["migrate", "dprint"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "dprint_migrate",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn dprint_migrate_write() {
+    let biomejson = r#"{ "linter": { "enabled": true } }"#;
+    let dprintjson = r#"{
+        "lineWidth": 100,
+        "typescript": { "quoteStyle": "alwaysSingle" }
+    }"#;
+
+    let mut fs = MemoryFileSystem::default();
+    fs.insert(Path::new("biome.json").into(), biomejson.as_bytes());
+    fs.insert(Path::new("dprint.json").into(), dprintjson.as_bytes());
+
+    let mut console = BufferConsole::default();
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(["migrate", "dprint", "--write"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "dprint_migrate_write",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn dprint_migrate_jsonc() {
+    let biomejson = r#"{ "linter": { "enabled": true } }"#;
+    let dprintjsonc = r#"{
+        // Comments are allowed
+        "useTabs": true,
+        "includes": ["src/**"],
+    }"#;
+
+    let mut fs = MemoryFileSystem::default();
+    fs.insert(Path::new("biome.json").into(), biomejson.as_bytes());
+    fs.insert(Path::new(".dprint.jsonc").into(), dprintjsonc.as_bytes());
+
+    let mut console = BufferConsole::default();
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(["migrate", "dprint"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "dprint_migrate_jsonc",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn dprint_migrate_unsupported_values() {
+    let biomejson = r#"{ "linter": { "enabled": true } }"#;
+    let dprintjson = r#"{
+        "newLineKind": "auto",
+        "incremental": true,
+        "typescript": {
+            "quoteProps": "consistent",
+            "trailingCommas": "always",
+            "arrowFunction.useParentheses": "maintain"
+        },
+        "json": {
+            "trailingCommas": "jsonc"
+        }
+    }"#;
+
+    let mut fs = MemoryFileSystem::default();
+    fs.insert(Path::new("biome.json").into(), biomejson.as_bytes());
+    fs.insert(Path::new("dprint.json").into(), dprintjson.as_bytes());
+
+    let mut console = BufferConsole::default();
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(["migrate", "dprint"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "dprint_migrate_unsupported_values",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn dprint_migrate_markup() {
+    let biomejson = r#"{ "linter": { "enabled": true } }"#;
+    let dprintjson = r#"{
+        "markup": {
+            "printWidth": 100,
+            "useTabs": true,
+            "lineBreak": "crlf",
+            "whitespaceSensitivity": "ignore",
+            "scriptIndent": true,
+            "styleIndent": false,
+            "htmlVoidSelfClosing": false,
+            "closingBracketSameLine": true
+        }
+    }"#;
+
+    let mut fs = MemoryFileSystem::default();
+    fs.insert(Path::new("biome.json").into(), biomejson.as_bytes());
+    fs.insert(Path::new("dprint.json").into(), dprintjson.as_bytes());
+
+    let mut console = BufferConsole::default();
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(["migrate", "dprint"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "dprint_migrate_markup",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn dprint_migrate_no_configuration() {
+    let biomejson = r#"{ "linter": { "enabled": true } }"#;
+
+    let mut fs = MemoryFileSystem::default();
+    fs.insert(Path::new("biome.json").into(), biomejson.as_bytes());
+
+    let mut console = BufferConsole::default();
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(["migrate", "dprint"].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "dprint_migrate_no_configuration",
+        fs,
+        console,
+        result,
+    ));
+}
@@ -0,0 +1,95 @@
+use crate::run_cli;
+use crate::snap_test::{assert_cli_snapshot, SnapshotPayload};
+use biome_console::BufferConsole;
+use biome_fs::MemoryFileSystem;
+use biome_service::DynRef;
+use bpaf::Args;
+use std::path::Path;
+
+#[test]
+fn editorconfig_migrate() {
+    let biomejson = r#"{ "linter": { "enabled": true } }"#;
+    let editorconfig = r#"
+[*]
+indent_style = space
+indent_size = 8
+max_line_length = 120
+
+[*.json]
+indent_size = 2
+"#;
+
+    let mut fs = MemoryFileSystem::default();
+    fs.insert(Path::new("biome.json").into(), biomejson.as_bytes());
+    fs.insert(Path::new(".editorconfig").into(), editorconfig.as_bytes());
+
+    let mut console = BufferConsole::default();
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(["migrate", "editorconfig"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "editorconfig_migrate",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn editorconfig_migrate_write() {
+    let biomejson = r#"{ "linter": { "enabled": true } }"#;
+    let editorconfig = r#"
+[*]
+indent_style = tab
+max_line_length = 100
+"#;
+
+    let mut fs = MemoryFileSystem::default();
+    fs.insert(Path::new("biome.json").into(), biomejson.as_bytes());
+    fs.insert(Path::new(".editorconfig").into(), editorconfig.as_bytes());
+
+    let mut console = BufferConsole::default();
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(["migrate", "editorconfig", "--write"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "editorconfig_migrate_write",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn editorconfig_migrate_no_configuration() {
+    let biomejson = r#"{ "linter": { "enabled": true } }"#;
+
+    let mut fs = MemoryFileSystem::default();
+    fs.insert(Path::new("biome.json").into(), biomejson.as_bytes());
+
+    let mut console = BufferConsole::default();
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(["migrate", "editorconfig"].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "editorconfig_migrate_no_configuration",
+        fs,
+        console,
+        result,
+    ));
+}
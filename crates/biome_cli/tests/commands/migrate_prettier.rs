@@ -441,3 +441,39 @@ fn prettier_migrate_overrides() {
         result,
     ));
 }
+
+#[test]
+fn prettier_migrate_package_json_configuration() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("package.json").into(),
+        r#"{
+    "name": "app",
+    "biome": { "linter": { "enabled": true } },
+    "dependencies": {}
+}"#,
+    );
+
+    fs.insert(
+        Path::new(".prettierrc").into(),
+        r#"{ "useTabs": false, "semi": true, "singleQuote": true }"#,
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(["migrate", "prettier"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "prettier_migrate_package_json_configuration",
+        fs,
+        console,
+        result,
+    ));
+}
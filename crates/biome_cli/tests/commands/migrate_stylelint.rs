@@ -0,0 +1,283 @@
+use crate::run_cli;
+use crate::snap_test::{assert_cli_snapshot, SnapshotPayload};
+use biome_console::BufferConsole;
+use biome_fs::MemoryFileSystem;
+use biome_service::DynRef;
+use bpaf::Args;
+use std::path::Path;
+
+#[test]
+fn migrate_stylelintrcjson() {
+    let biomejson = r#"{ "linter": { "enabled": true } }"#;
+    let stylelintrc = r#"{
+        "ignoreFiles": ["**/*.min.css", "vendor/**"],
+        "defaultSeverity": "warning",
+        "rules": {
+            "block-no-empty": true,
+            "color-no-invalid-hex": [true, { "severity": "error" }],
+            "unit-no-unknown": [true, { "ignoreUnits": ["x"], "severity": "error" }],
+            "property-no-unknown": null,
+            "font-family-no-duplicate-names": [null],
+            "color-hex-length": "short",
+            "no-descending-specificity": true,
+        },
+        "overrides": [{
+            "files": ["legacy/**/*.css"],
+            "rules": {
+                "block-no-empty": null
+            }
+        }],
+        "unknownField": "ignored"
+    }"#;
+
+    let mut fs = MemoryFileSystem::default();
+    fs.insert(Path::new("biome.json").into(), biomejson.as_bytes());
+    fs.insert(
+        Path::new(".stylelintrc.json").into(),
+        stylelintrc.as_bytes(),
+    );
+
+    let mut console = BufferConsole::default();
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(["migrate", "stylelint"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "migrate_stylelintrcjson",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn migrate_stylelintrcjson_write() {
+    let biomejson = r#"{ "linter": { "enabled": true } }"#;
+    let stylelintrc = r#"{
+        "rules": {
+            "block-no-empty": true,
+            "unit-no-unknown": [true, { "severity": "warning" }]
+        }
+    }"#;
+
+    let mut fs = MemoryFileSystem::default();
+    fs.insert(Path::new("biome.json").into(), biomejson.as_bytes());
+    fs.insert(
+        Path::new(".stylelintrc.json").into(),
+        stylelintrc.as_bytes(),
+    );
+
+    let mut console = BufferConsole::default();
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(["migrate", "stylelint", "--write"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "migrate_stylelintrcjson_write",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn migrate_stylelintrcjson_include_nursery() {
+    let biomejson = r#"{ "linter": { "enabled": true } }"#;
+    let stylelintrc = r#"{
+        "rules": {
+            "no-descending-specificity": true,
+            "at-rule-no-unknown": [true, { "severity": "warning" }]
+        }
+    }"#;
+
+    let mut fs = MemoryFileSystem::default();
+    fs.insert(Path::new("biome.json").into(), biomejson.as_bytes());
+    fs.insert(
+        Path::new(".stylelintrc.json").into(),
+        stylelintrc.as_bytes(),
+    );
+
+    let mut console = BufferConsole::default();
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(["migrate", "stylelint", "--include-nursery"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "migrate_stylelintrcjson_include_nursery",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn migrate_stylelintrcyaml() {
+    let biomejson = r#"{ "linter": { "enabled": true } }"#;
+    let stylelintrc = r#"
+ignoreFiles: "dist/**"
+rules:
+  block-no-empty: true
+  unit-no-unknown:
+    - true
+    - severity: warning
+  selector-max-id: 0
+"#;
+
+    let mut fs = MemoryFileSystem::default();
+    fs.insert(Path::new("biome.json").into(), biomejson.as_bytes());
+    fs.insert(Path::new(".stylelintrc.yml").into(), stylelintrc.as_bytes());
+
+    let mut console = BufferConsole::default();
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(["migrate", "stylelint"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "migrate_stylelintrcyaml",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn migrate_stylelintrc_without_extension() {
+    let biomejson = r#"{ "linter": { "enabled": true } }"#;
+    let stylelintrc = r#"
+rules:
+  block-no-empty: null
+"#;
+
+    let mut fs = MemoryFileSystem::default();
+    fs.insert(Path::new("biome.json").into(), biomejson.as_bytes());
+    fs.insert(Path::new(".stylelintrc").into(), stylelintrc.as_bytes());
+
+    let mut console = BufferConsole::default();
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(["migrate", "stylelint"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "migrate_stylelintrc_without_extension",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn migrate_stylelint_config_packagejson() {
+    let biomejson = r#"{ "linter": { "enabled": true } }"#;
+    let packagejson = r#"{
+        "name": "app",
+        "stylelint": {
+            "rules": {
+                "block-no-empty": true
+            }
+        }
+    }"#;
+
+    let mut fs = MemoryFileSystem::default();
+    fs.insert(Path::new("biome.json").into(), biomejson.as_bytes());
+    fs.insert(Path::new("package.json").into(), packagejson.as_bytes());
+
+    let mut console = BufferConsole::default();
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(["migrate", "stylelint"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "migrate_stylelint_config_packagejson",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn migrate_no_stylelint_config() {
+    let biomejson = r#"{ "linter": { "enabled": true } }"#;
+    let packagejson = r#"{ "name": "app" }"#;
+
+    let mut fs = MemoryFileSystem::default();
+    fs.insert(Path::new("biome.json").into(), biomejson.as_bytes());
+    fs.insert(Path::new("package.json").into(), packagejson.as_bytes());
+
+    let mut console = BufferConsole::default();
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(["migrate", "stylelint"].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "migrate_no_stylelint_config",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn migrate_stylelintignore() {
+    let biomejson = r#"{ "linter": { "enabled": true } }"#;
+    let stylelintrc = r#"{ "rules": { "block-no-empty": true } }"#;
+    let stylelintignore = r#"
+# Comment
+dist/
+!dist/index.css
+"#;
+
+    let mut fs = MemoryFileSystem::default();
+    fs.insert(Path::new("biome.json").into(), biomejson.as_bytes());
+    fs.insert(
+        Path::new(".stylelintrc.json").into(),
+        stylelintrc.as_bytes(),
+    );
+    fs.insert(
+        Path::new(".stylelintignore").into(),
+        stylelintignore.as_bytes(),
+    );
+
+    let mut console = BufferConsole::default();
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(["migrate", "stylelint"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "migrate_stylelintignore",
+        fs,
+        console,
+        result,
+    ));
+}
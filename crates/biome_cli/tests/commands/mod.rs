@@ -1,12 +1,18 @@
 mod check;
 mod ci;
+mod config;
 mod explain;
 mod format;
 mod init;
 mod lint;
 mod lsp_proxy;
 mod migrate;
+mod migrate_dprint;
+mod migrate_editorconfig;
 mod migrate_eslint;
 mod migrate_prettier;
+mod migrate_stylelint;
 mod rage;
+mod rules;
+mod search;
 mod version;
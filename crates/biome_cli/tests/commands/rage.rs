@@ -114,6 +114,37 @@ fn with_jsonc_configuration() {
     ));
 }
 
+#[test]
+fn with_user_configuration() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+    fs.set_user_config_directory(Path::new("user-config").to_path_buf());
+    fs.insert(
+        Path::new("user-config/biome.json").to_path_buf(),
+        r#"{
+  "formatter": {
+    "enabled": false
+  }
+}"#,
+    );
+
+    let result = run_rage(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("rage")].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_rage_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "with_user_configuration",
+        fs,
+        console,
+        result,
+    ));
+}
+
 #[test]
 fn with_malformed_configuration() {
     let mut fs = MemoryFileSystem::default();
@@ -350,7 +381,7 @@ fn assert_rage_snapshot(payload: SnapshotPayload<'_>) {
             .map(|line| match line.trim_start().split_once(':') {
                 Some((
                     "CPU Architecture" | "OS" | "NO_COLOR" | "TERM" | "BIOME_LOG_DIR"
-                    | "BIOME_LOG_PATH" | "Color support",
+                    | "BIOME_LOG_PATH" | "Color support" | "User configuration",
                     value,
                 )) => line.replace(value.trim_start(), "**PLACEHOLDER**"),
                 _ => line.to_string(),
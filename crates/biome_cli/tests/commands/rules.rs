@@ -0,0 +1,117 @@
+use bpaf::Args;
+
+use crate::assert_cli_snapshot;
+use crate::run_cli;
+use crate::snap_test::{markup_to_string, SnapshotPayload};
+use biome_console::{markup, BufferConsole, LogLevel};
+use biome_fs::MemoryFileSystem;
+use biome_service::DynRef;
+
+#[test]
+fn rules_list_help() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("rules"), "list", "--help"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "rules_list_help",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn rules_list_by_language_and_group() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("rules"), "list", "--language=json", "--group=suspicious"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "rules_list_by_language_and_group",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn rules_list_by_unknown_language() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("rules"), "list", "--language=javascript"].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "rules_list_by_unknown_language",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn rules_list_json_by_source() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("rules"),
+                "list",
+                "--json",
+                "--source=eslint-plugin-unicorn",
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    let message = console
+        .out_buffer
+        .iter()
+        .find(|message| message.level == LogLevel::Log)
+        .expect("Console should have written the rules");
+    let content = markup_to_string(markup! {
+        {message.content}
+    });
+    let list: serde_json::Value =
+        serde_json::from_str(&content).expect("The list should be valid JSON");
+
+    let rules = list["rules"].as_array().unwrap();
+    assert!(rules.iter().any(|rule| rule["name"] == "noForEach"));
+    assert!(rules.iter().all(|rule| {
+        rule["sources"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .any(|source| source["source"] == "eslint-plugin-unicorn")
+    }));
+}
@@ -0,0 +1,153 @@
+use crate::run_cli;
+use crate::snap_test::{assert_cli_snapshot, assert_file_contents, SnapshotPayload};
+use biome_console::BufferConsole;
+use biome_fs::MemoryFileSystem;
+use biome_service::DynRef;
+use bpaf::Args;
+use std::path::Path;
+
+const SOURCE: &str = r#"var a = 1;
+console.log(a);
+var b = 2;
+"#;
+
+const REWRITTEN: &str = r#"let a = 1;
+console.log(a);
+let b = 2;
+"#;
+
+#[test]
+fn search_prints_the_matches() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), SOURCE.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                "search",
+                "`var $name = $value`",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, SOURCE);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "search_prints_the_matches",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn search_prints_the_rewrites_as_a_diff() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), SOURCE.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                "search",
+                "`var $name = $value` => `let $name = $value`",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, SOURCE);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "search_prints_the_rewrites_as_a_diff",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn search_writes_the_rewrites() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), SOURCE.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                "search",
+                "--write",
+                "`var $name = $value` => `let $name = $value`",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, REWRITTEN);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "search_writes_the_rewrites",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn search_prints_the_rewrites_of_stdin() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    console
+        .in_buffer
+        .push("var a = 1;\nconsole.log(a);\n".to_string());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                "search",
+                "--stdin-file-path=file.js",
+                "`var $name = $value` => `let $name = $value`",
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "search_prints_the_rewrites_of_stdin",
+        fs,
+        console,
+        result,
+    ));
+}
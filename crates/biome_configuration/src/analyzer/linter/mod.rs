@@ -1,11 +1,15 @@
 #[rustfmt::skip]
 mod rules;
+mod suppressions;
 
+use biome_analyze::RuleDomain;
 use biome_deserialize::StringSet;
 use biome_deserialize_macros::{Deserializable, Merge, Partial};
 use bpaf::Bpaf;
 pub use rules::*;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+pub use suppressions::*;
 
 #[derive(Clone, Debug, Deserialize, Eq, Partial, PartialEq, Serialize)]
 #[partial(derive(Bpaf, Clone, Deserializable, Eq, Merge, PartialEq))]
@@ -29,6 +33,17 @@ pub struct LinterConfiguration {
     /// match these patterns.
     #[partial(bpaf(hide))]
     pub include: StringSet,
+
+    /// An object where the keys are the names of the domains, and the values are `all`, `none`
+    /// or `auto`. A domain groups the rules of a library, a framework or a runtime.
+    #[partial(bpaf(pure(Default::default()), optional, hide))]
+    pub domains: RuleDomains,
+
+    /// The policy that the suppression comments must follow: whether they require a reason,
+    /// the patterns of the reasons, the rules that can't be suppressed and the maximum number
+    /// of suppressions per file.
+    #[partial(bpaf(pure(Default::default()), optional, hide))]
+    pub suppressions: SuppressionsConfiguration,
 }
 
 impl LinterConfiguration {
@@ -44,6 +59,8 @@ impl Default for LinterConfiguration {
             rules: Default::default(),
             ignore: Default::default(),
             include: Default::default(),
+            domains: Default::default(),
+            suppressions: Default::default(),
         }
     }
 }
@@ -57,3 +74,21 @@ impl PartialLinterConfiguration {
         self.rules.clone().unwrap_or_default()
     }
 }
+
+/// The configuration of the rule domains, by domain
+pub type RuleDomains = BTreeMap<RuleDomain, RuleDomainValue>;
+
+#[derive(
+    Clone, Copy, Debug, Deserialize, Deserializable, Eq, Hash, Merge, PartialEq, Serialize,
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum RuleDomainValue {
+    /// Enables all the rules of the domain
+    All,
+    /// Disables all the rules of the domain
+    None,
+    /// Enables all the rules of the domain when one of its dependencies is listed in the
+    /// `dependencies` or the `devDependencies` of `package.json`, and disables them otherwise
+    Auto,
+}
@@ -0,0 +1,145 @@
+use crate::analyzer::RuleSelector;
+use biome_deserialize::{
+    Deserializable, DeserializableValidator, DeserializableValue, DeserializationDiagnostic, Merge,
+    StringSet,
+};
+use biome_deserialize_macros::{Deserializable, Merge};
+use biome_rowan::TextRange;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+/// The policy that the suppression comments must follow.
+///
+/// Rule groups and rules are referenced by name, e.g. `security` or `security/noGlobalEval`.
+#[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, Merge, PartialEq, Serialize)]
+#[deserializable(with_validator)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct SuppressionsConfiguration {
+    /// If `true`, the suppression comments must have a reason that isn't a placeholder
+    /// such as `<explanation>`. `false` by default.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub require_reason: Option<bool>,
+
+    /// An object where the keys are rule groups or rules, and the values are regular
+    /// expressions that the reason of their suppressions must match.
+    /// The pattern of a rule takes precedence over the pattern of its group. A suppression of
+    /// all the rules must match every pattern.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason_patterns: Option<BTreeMap<String, ReasonPattern>>,
+
+    /// A list of rule groups and rules that can't be suppressed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub forbidden: Option<StringSet>,
+
+    /// The maximum number of suppression comments allowed in a file.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_per_file: Option<u16>,
+}
+
+impl DeserializableValidator for SuppressionsConfiguration {
+    fn validate(
+        &mut self,
+        _name: &str,
+        range: TextRange,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> bool {
+        let reason_patterns = self
+            .reason_patterns
+            .iter()
+            .flat_map(|patterns| patterns.keys());
+        let forbidden = self.forbidden.iter().flat_map(|forbidden| forbidden.iter());
+        let mut is_valid = true;
+        for selector in reason_patterns.chain(forbidden) {
+            if let Err(error) = RuleSelector::from_str(selector) {
+                diagnostics.push(
+                    DeserializationDiagnostic::new(format_args!("{selector}: {error}"))
+                        .with_range(range),
+                );
+                is_valid = false;
+            }
+        }
+        is_valid
+    }
+}
+
+/// A regular expression that the reason of a suppression must match.
+/// The reason matches if the expression matches any part of it.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct ReasonPattern(regex::Regex);
+
+impl ReasonPattern {
+    /// Returns the compiled regular expression
+    pub fn regex(&self) -> &regex::Regex {
+        &self.0
+    }
+}
+
+impl FromStr for ReasonPattern {
+    type Err = regex::Error;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        regex::Regex::new(value).map(Self)
+    }
+}
+
+impl TryFrom<String> for ReasonPattern {
+    type Error = regex::Error;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl From<ReasonPattern> for String {
+    fn from(value: ReasonPattern) -> Self {
+        value.0.as_str().to_string()
+    }
+}
+
+impl Deserializable for ReasonPattern {
+    fn deserialize(
+        value: &impl DeserializableValue,
+        name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self> {
+        let pattern = <String as Deserializable>::deserialize(value, name, diagnostics)?;
+        match pattern.parse() {
+            Ok(pattern) => Some(pattern),
+            Err(error) => {
+                diagnostics.push(
+                    DeserializationDiagnostic::new(format_args!("{error}"))
+                        .with_range(value.range()),
+                );
+                None
+            }
+        }
+    }
+}
+
+impl Merge for ReasonPattern {
+    fn merge_with(&mut self, other: Self) {
+        *self = other;
+    }
+}
+
+impl Eq for ReasonPattern {}
+
+impl PartialEq for ReasonPattern {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.as_str() == other.0.as_str()
+    }
+}
+
+#[cfg(feature = "schema")]
+impl schemars::JsonSchema for ReasonPattern {
+    fn schema_name() -> String {
+        "ReasonPattern".to_string()
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        String::json_schema(gen)
+    }
+}
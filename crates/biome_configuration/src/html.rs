@@ -0,0 +1,164 @@
+use biome_deserialize_macros::{Deserializable, Merge, Partial};
+use biome_formatter::{AttributePosition, IndentStyle, IndentWidth, LineEnding, LineWidth};
+use biome_html_formatter::context::{SelfCloseVoidElements, WhitespaceSensitivity};
+use bpaf::Bpaf;
+use serde::{Deserialize, Serialize};
+
+/// Options applied to HTML files
+#[derive(Clone, Default, Debug, Deserialize, Eq, Partial, PartialEq, Serialize)]
+#[partial(derive(Bpaf, Clone, Deserializable, Eq, Merge, PartialEq))]
+#[partial(cfg_attr(feature = "schema", derive(schemars::JsonSchema)))]
+#[partial(serde(rename_all = "camelCase", default, deny_unknown_fields))]
+pub struct HtmlConfiguration {
+    /// HTML parsing options
+    #[partial(type, bpaf(external(partial_html_parser), optional))]
+    pub parser: HtmlParser,
+
+    /// HTML formatter options
+    #[partial(type, bpaf(external(partial_html_formatter), optional))]
+    pub formatter: HtmlFormatter,
+
+    /// HTML linter options
+    #[partial(type, bpaf(external(partial_html_linter), optional))]
+    pub linter: HtmlLinter,
+}
+
+/// Options that changes how the HTML parser behaves
+#[derive(Clone, Default, Debug, Deserialize, Eq, Partial, PartialEq, Serialize)]
+#[partial(derive(Bpaf, Clone, Deserializable, Eq, Merge, PartialEq))]
+#[partial(cfg_attr(feature = "schema", derive(schemars::JsonSchema)))]
+#[partial(serde(rename_all = "camelCase", default, deny_unknown_fields))]
+pub struct HtmlParser {}
+
+/// Options that changes how the HTML formatter behaves
+#[derive(Clone, Debug, Deserialize, Eq, Partial, PartialEq, Serialize)]
+#[partial(derive(Bpaf, Clone, Deserializable, Eq, Merge, PartialEq))]
+#[partial(cfg_attr(feature = "schema", derive(schemars::JsonSchema)))]
+#[partial(serde(rename_all = "camelCase", default, deny_unknown_fields))]
+pub struct HtmlFormatter {
+    /// Control the formatter for HTML files.
+    #[partial(bpaf(long("html-formatter-enabled"), argument("true|false"), optional))]
+    pub enabled: Option<bool>,
+
+    /// The indent style applied to HTML files.
+    #[partial(bpaf(long("html-formatter-indent-style"), argument("tab|space"), optional))]
+    pub indent_style: Option<IndentStyle>,
+
+    /// The size of the indentation applied to HTML files. Default to 2.
+    #[partial(bpaf(long("html-formatter-indent-width"), argument("NUMBER"), optional))]
+    pub indent_width: Option<IndentWidth>,
+
+    /// The type of line ending applied to HTML files.
+    #[partial(bpaf(long("html-formatter-line-ending"), argument("lf|crlf|cr"), optional))]
+    pub line_ending: Option<LineEnding>,
+
+    /// What's the max width of a line applied to HTML files. Defaults to 80.
+    #[partial(bpaf(long("html-formatter-line-width"), argument("NUMBER"), optional))]
+    pub line_width: Option<LineWidth>,
+
+    /// The attribute position style in HTML elements. Defaults to auto.
+    #[partial(bpaf(
+        long("html-formatter-attribute-position"),
+        argument("multiline|auto"),
+        optional
+    ))]
+    pub attribute_position: Option<AttributePosition>,
+
+    /// Whether the whitespace around elements is significant: `css` follows the default CSS
+    /// `display` of the elements, `strict` considers all the whitespace significant, and `ignore`
+    /// considers none of it significant. Defaults to "css".
+    #[partial(bpaf(
+        long("html-formatter-whitespace-sensitivity"),
+        argument("css|strict|ignore"),
+        optional
+    ))]
+    pub whitespace_sensitivity: Option<WhitespaceSensitivity>,
+
+    /// Whether to indent the content of `<script>` and `<style>` tags. Defaults to false.
+    #[partial(bpaf(
+        long("html-formatter-indent-script-and-style"),
+        argument("true|false"),
+        optional
+    ))]
+    pub indent_script_and_style: Option<bool>,
+
+    /// Whether void elements, such as `<br>` or `<img>`, are self-closed. Defaults to "always".
+    #[partial(bpaf(
+        long("html-formatter-self-close-void-elements"),
+        argument("always|never"),
+        optional
+    ))]
+    pub self_close_void_elements: Option<SelfCloseVoidElements>,
+}
+
+impl Default for HtmlFormatter {
+    fn default() -> Self {
+        Self {
+            enabled: Some(false),
+            indent_style: Default::default(),
+            indent_width: Default::default(),
+            line_ending: Default::default(),
+            line_width: Default::default(),
+            attribute_position: Default::default(),
+            whitespace_sensitivity: Default::default(),
+            indent_script_and_style: Default::default(),
+            self_close_void_elements: Default::default(),
+        }
+    }
+}
+
+impl PartialHtmlFormatter {
+    pub fn get_formatter_configuration(&self) -> HtmlFormatter {
+        HtmlFormatter {
+            enabled: self.enabled,
+            indent_style: self.indent_style,
+            indent_width: self.indent_width,
+            line_ending: self.line_ending,
+            line_width: self.line_width,
+            attribute_position: self.attribute_position,
+            whitespace_sensitivity: self.whitespace_sensitivity,
+            indent_script_and_style: self.indent_script_and_style,
+            self_close_void_elements: self.self_close_void_elements,
+        }
+    }
+}
+
+/// Options that changes how the HTML linter behaves
+#[derive(Clone, Debug, Default, Deserialize, Eq, Partial, PartialEq, Serialize)]
+#[partial(derive(Bpaf, Clone, Deserializable, Eq, Merge, PartialEq))]
+#[partial(cfg_attr(feature = "schema", derive(schemars::JsonSchema)))]
+#[partial(serde(rename_all = "camelCase", default, deny_unknown_fields))]
+pub struct HtmlLinter {
+    /// Control the linter for HTML files.
+    #[partial(bpaf(long("html-linter-enabled"), argument("true|false"), optional))]
+    pub enabled: bool,
+}
+
+impl PartialHtmlLinter {
+    pub fn get_linter_configuration(&self) -> HtmlLinter {
+        HtmlLinter {
+            enabled: self.enabled.unwrap_or_default(),
+        }
+    }
+}
+
+#[test]
+fn default_html_formatter() {
+    let html_configuration = HtmlFormatter::default();
+
+    assert_eq!(html_configuration.enabled, Some(false));
+    assert_eq!(html_configuration.indent_style, None);
+    assert_eq!(html_configuration.indent_width, None);
+    assert_eq!(html_configuration.line_ending, None);
+    assert_eq!(html_configuration.line_width, None);
+    assert_eq!(html_configuration.whitespace_sensitivity, None);
+    assert_eq!(html_configuration.indent_script_and_style, None);
+    assert_eq!(html_configuration.self_close_void_elements, None);
+}
+
+#[test]
+fn default_html_linter() {
+    let html_configuration = HtmlLinter::default();
+
+    assert!(!html_configuration.enabled);
+}
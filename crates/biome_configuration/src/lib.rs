@@ -9,10 +9,12 @@ pub mod editorconfig;
 pub mod formatter;
 pub mod generated;
 pub mod graphql;
+pub mod html;
 pub mod javascript;
 pub mod json;
 pub mod organize_imports;
 mod overrides;
+pub mod plugins;
 pub mod vcs;
 
 use crate::analyzer::assists::{
@@ -46,6 +48,10 @@ pub use graphql::{
     partial_graphql_configuration, GraphqlConfiguration, GraphqlFormatter, GraphqlLinter,
     PartialGraphqlConfiguration, PartialGraphqlFormatter, PartialGraphqlLinter,
 };
+pub use html::{
+    partial_html_configuration, HtmlConfiguration, HtmlFormatter, HtmlLinter,
+    PartialHtmlConfiguration, PartialHtmlFormatter, PartialHtmlLinter,
+};
 pub use javascript::{
     partial_javascript_configuration, JavascriptConfiguration, JavascriptFormatter,
     PartialJavascriptConfiguration, PartialJavascriptFormatter,
@@ -58,6 +64,7 @@ pub use overrides::{
     OverrideAssistsConfiguration, OverrideFormatterConfiguration, OverrideLinterConfiguration,
     OverrideOrganizeImportsConfiguration, OverridePattern, Overrides,
 };
+pub use plugins::{PluginConfiguration, PluginWithOptions, Plugins};
 use serde::{Deserialize, Serialize};
 use std::fmt::Debug;
 use std::num::NonZeroU64;
@@ -75,7 +82,7 @@ pub const DEFAULT_FILE_SIZE_LIMIT: NonZeroU64 =
     unsafe { NonZeroU64::new_unchecked(1024 * 1024) };
 
 /// The configuration that is contained inside the file `biome.json`
-#[derive(Clone, Debug, Default, Deserialize, Eq, Partial, PartialEq, Serialize)]
+#[derive(Clone, Debug, Deserialize, Eq, Partial, PartialEq, Serialize)]
 #[partial(derive(Bpaf, Clone, Deserializable, Eq, Merge, PartialEq))]
 #[partial(cfg_attr(feature = "schema", derive(schemars::JsonSchema)))]
 #[partial(serde(deny_unknown_fields, rename_all = "camelCase"))]
@@ -89,6 +96,13 @@ pub struct Configuration {
     #[partial(bpaf(hide))]
     pub extends: StringSet,
 
+    /// Whether this is the root configuration of the project. `true` by default.
+    ///
+    /// Set it to `false` in the configuration file of a subdirectory, so that it extends
+    /// the configuration of the parent directories instead of replacing it.
+    #[partial(bpaf(hide))]
+    pub root: bool,
+
     /// The configuration of the VCS integration
     #[partial(type, bpaf(external(partial_vcs_configuration), optional, hide_usage))]
     pub vcs: VcsConfiguration,
@@ -128,6 +142,10 @@ pub struct Configuration {
     #[partial(type, bpaf(external(partial_graphql_configuration), optional))]
     pub graphql: GraphqlConfiguration,
 
+    /// Specific configuration for the HTML language
+    #[partial(type, bpaf(external(partial_html_configuration), optional))]
+    pub html: HtmlConfiguration,
+
     /// A list of granular patterns that should be applied only to a sub set of files
     #[partial(bpaf(hide))]
     pub overrides: Overrides,
@@ -135,6 +153,35 @@ pub struct Configuration {
     /// Specific configuration for assists
     #[partial(type, bpaf(external(partial_assists_configuration), optional))]
     pub assists: AssistsConfiguration,
+
+    /// A list of GritQL files, which are run as lint rules alongside the rules of Biome. Each
+    /// entry is either the path of the file, relative to the configuration file, or an object
+    /// with its `path` and the `severity` of its diagnostics.
+    #[partial(bpaf(hide))]
+    pub plugins: Plugins,
+}
+
+impl Default for Configuration {
+    fn default() -> Self {
+        Self {
+            schema: String::default(),
+            extends: StringSet::default(),
+            root: true,
+            vcs: VcsConfiguration::default(),
+            files: FilesConfiguration::default(),
+            formatter: FormatterConfiguration::default(),
+            organize_imports: OrganizeImports::default(),
+            linter: LinterConfiguration::default(),
+            javascript: JavascriptConfiguration::default(),
+            json: JsonConfiguration::default(),
+            css: CssConfiguration::default(),
+            graphql: GraphqlConfiguration::default(),
+            html: HtmlConfiguration::default(),
+            overrides: Overrides::default(),
+            assists: AssistsConfiguration::default(),
+            plugins: Plugins::default(),
+        }
+    }
 }
 
 impl PartialConfiguration {
@@ -181,6 +228,12 @@ impl PartialConfiguration {
         }
     }
 
+    /// Whether this configuration is the root of the project, instead of extending the
+    /// configuration of the parent directories with `"root": false`
+    pub const fn is_root(&self) -> bool {
+        !matches!(self.root, Some(false))
+    }
+
     pub fn is_formatter_disabled(&self) -> bool {
         self.formatter.as_ref().map_or(false, |f| f.is_disabled())
     }
@@ -287,6 +340,30 @@ impl PartialConfiguration {
             .unwrap_or_default()
     }
 
+    pub fn get_html_linter_configuration(&self) -> HtmlLinter {
+        self.html
+            .as_ref()
+            .map(|f| {
+                f.linter
+                    .as_ref()
+                    .map(|f| f.get_linter_configuration())
+                    .unwrap_or_default()
+            })
+            .unwrap_or_default()
+    }
+
+    pub fn get_html_formatter_configuration(&self) -> HtmlFormatter {
+        self.html
+            .as_ref()
+            .map(|f| {
+                f.formatter
+                    .as_ref()
+                    .map(|f| f.get_formatter_configuration())
+                    .unwrap_or_default()
+            })
+            .unwrap_or_default()
+    }
+
     pub fn is_linter_disabled(&self) -> bool {
         self.linter.as_ref().map_or(false, |f| f.is_disabled())
     }
@@ -316,6 +393,14 @@ impl PartialConfiguration {
     pub fn use_editorconfig(&self) -> Option<bool> {
         self.formatter.as_ref().and_then(|f| f.use_editorconfig)
     }
+
+    /// Whether the results of the previous runs should be reused, see [FilesConfiguration::cache]
+    pub fn use_cache(&self) -> bool {
+        self.files
+            .as_ref()
+            .and_then(|files| files.cache)
+            .unwrap_or_default()
+    }
 }
 
 /// The configuration of the filesystem
@@ -342,6 +427,11 @@ pub struct FilesConfiguration {
     /// match these patterns.
     #[partial(bpaf(hide))]
     pub include: StringSet,
+
+    /// Whether the commands `check`, `lint` and `format` reuse the results of their previous runs
+    /// for the files that didn't change since then, like with the option `--cache`. Defaults to `false`
+    #[partial(bpaf(long("files-cache"), argument("true|false"), optional, hide))]
+    pub cache: bool,
 }
 
 impl Default for FilesConfiguration {
@@ -351,6 +441,7 @@ impl Default for FilesConfiguration {
             ignore: Default::default(),
             include: Default::default(),
             ignore_unknown: false,
+            cache: false,
         }
     }
 }
@@ -1,9 +1,9 @@
 use super::javascript::PartialJavascriptConfiguration;
 use super::json::PartialJsonConfiguration;
-use super::{PartialCssConfiguration, PartialGraphqlConfiguration};
+use super::{PartialCssConfiguration, PartialGraphqlConfiguration, PartialHtmlConfiguration};
 use crate::{
-    partial_css_configuration, partial_graphql_configuration, partial_javascript_configuration,
-    partial_json_configuration,
+    partial_css_configuration, partial_graphql_configuration, partial_html_configuration,
+    partial_javascript_configuration, partial_json_configuration,
 };
 use biome_deserialize::StringSet;
 use biome_deserialize_macros::{Deserializable, Merge};
@@ -67,6 +67,11 @@ pub struct OverridePattern {
     #[bpaf(external(partial_graphql_configuration), optional, hide)]
     pub graphql: Option<PartialGraphqlConfiguration>,
 
+    /// Specific configuration for the HTML language
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[bpaf(external(partial_html_configuration), optional, hide)]
+    pub html: Option<PartialHtmlConfiguration>,
+
     /// Specific configuration for the Json language
     #[serde(skip_serializing_if = "Option::is_none")]
     #[bpaf(external(override_formatter_configuration), optional, hide)]
@@ -160,6 +165,12 @@ pub struct OverrideLinterConfiguration {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[bpaf(pure(crate::analyzer::linter::Rules::default()), optional, hide)]
     pub rules: Option<crate::analyzer::linter::Rules>,
+
+    /// An object where the keys are the names of the domains, and the values are `all`, `none`
+    /// or `auto`. They take precedence over the domains of `linter.domains`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[bpaf(pure(Default::default()), optional, hide)]
+    pub domains: Option<crate::analyzer::RuleDomains>,
 }
 
 #[derive(
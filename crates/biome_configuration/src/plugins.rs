@@ -0,0 +1,81 @@
+use crate::analyzer::RulePlainConfiguration;
+use biome_deserialize::{
+    Deserializable, DeserializableType, DeserializableValue, DeserializationDiagnostic,
+};
+use biome_deserialize_macros::{Deserializable, Merge};
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+/// The lint plugins of the configuration
+#[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, Merge, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Plugins(pub Vec<PluginConfiguration>);
+
+impl FromStr for Plugins {
+    type Err = String;
+
+    fn from_str(_s: &str) -> Result<Self, Self::Err> {
+        Ok(Self::default())
+    }
+}
+
+impl Plugins {
+    pub fn iter(&self) -> impl Iterator<Item = &PluginConfiguration> {
+        self.0.iter()
+    }
+}
+
+/// A plugin, given as the path of its GritQL file, or as an object with its path and its options
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(untagged)]
+pub enum PluginConfiguration {
+    Path(String),
+    WithOptions(PluginWithOptions),
+}
+
+impl Deserializable for PluginConfiguration {
+    fn deserialize(
+        value: &impl DeserializableValue,
+        name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self> {
+        if value.visitable_type()? == DeserializableType::Str {
+            Deserializable::deserialize(value, name, diagnostics).map(Self::Path)
+        } else {
+            Deserializable::deserialize(value, name, diagnostics).map(Self::WithOptions)
+        }
+    }
+}
+
+impl PluginConfiguration {
+    /// The path of the GritQL file of the plugin, relative to the configuration file
+    pub fn path(&self) -> &str {
+        match self {
+            Self::Path(path) => path,
+            Self::WithOptions(plugin) => &plugin.path,
+        }
+    }
+
+    /// The severity of the diagnostics of the plugin, when it's configured
+    pub fn severity(&self) -> Option<RulePlainConfiguration> {
+        match self {
+            Self::Path(_) => None,
+            Self::WithOptions(plugin) => plugin.severity,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct PluginWithOptions {
+    /// The path of the GritQL file of the plugin, relative to the configuration file
+    #[deserializable(required)]
+    pub path: String,
+
+    /// The severity of the diagnostics of the plugin, which replaces the severity chosen by the
+    /// plugin. The plugin doesn't run when it's `off`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub severity: Option<RulePlainConfiguration>,
+}
@@ -10,8 +10,9 @@ mod utils;
 pub use crate::registry::visit_registry;
 use crate::suppression_action::CssSuppressionAction;
 use biome_analyze::{
-    AnalysisFilter, AnalyzerOptions, AnalyzerSignal, ControlFlow, LanguageRoot, MatchQueryParams,
-    MetadataRegistry, RuleAction, RuleRegistry, SuppressionKind,
+    AnalysisFilter, AnalyzerOptions, AnalyzerSignal, AnalyzerSuppression,
+    AnalyzerSuppressionVariant, ControlFlow, LanguageRoot, MatchQueryParams, MetadataRegistry,
+    RuleAction, RuleRegistry, SuppressionKind,
 };
 use biome_css_syntax::CssLanguage;
 use biome_diagnostics::{category, Error};
@@ -63,16 +64,21 @@ where
 {
     fn parse_linter_suppression_comment(
         text: &str,
-    ) -> Vec<Result<SuppressionKind, SuppressionDiagnostic>> {
+    ) -> Vec<Result<AnalyzerSuppression, SuppressionDiagnostic>> {
         let mut result = Vec::new();
 
         for comment in parse_suppression_comment(text) {
-            let categories = match comment {
+            let (categories, variant, reason) = match comment {
                 Ok(comment) => {
+                    let variant = AnalyzerSuppressionVariant::from(comment.kind);
                     if comment.is_legacy {
-                        result.push(Ok(SuppressionKind::Deprecated));
+                        result.push(Ok(AnalyzerSuppression::new(
+                            SuppressionKind::Deprecated,
+                            variant,
+                        )
+                        .with_reason(comment.reason)));
                     }
-                    comment.categories
+                    (comment.categories, variant, comment.reason)
                 }
                 Err(err) => {
                     result.push(Err(err));
@@ -83,14 +89,34 @@ where
             for (key, value) in categories {
                 if key == category!("lint") {
                     if let Some(value) = value {
-                        result.push(Ok(SuppressionKind::MaybeLegacy(value)));
+                        result.push(Ok(AnalyzerSuppression::new(
+                            SuppressionKind::MaybeLegacy(value),
+                            variant,
+                        )
+                        .with_reason(reason)));
                     } else {
-                        result.push(Ok(SuppressionKind::Everything));
+                        result.push(Ok(AnalyzerSuppression::new(
+                            SuppressionKind::Everything,
+                            variant,
+                        )
+                        .with_reason(reason)));
                     }
                 } else {
                     let category = key.name();
                     if let Some(rule) = category.strip_prefix("lint/") {
-                        result.push(Ok(SuppressionKind::Rule(rule)));
+                        if let Some(instance) = value {
+                            result.push(Ok(AnalyzerSuppression::new(
+                                SuppressionKind::RuleInstance(rule, instance),
+                                variant,
+                            )
+                            .with_reason(reason)));
+                        } else {
+                            result.push(Ok(AnalyzerSuppression::new(
+                                SuppressionKind::Rule(rule),
+                                variant,
+                            )
+                            .with_reason(reason)));
+                        }
                     }
                 }
             }
@@ -217,6 +217,13 @@ pub(crate) fn run_suppression_test(input: &'static str, _: &str, _: &str, _: &st
         .unwrap_or_else(|err| panic!("failed to read {input_file:?}: {err:?}"));
 
     let (group, rule) = parse_test_path(input_file);
+    if biome_css_analyze::METADATA
+        .deref()
+        .find_rule(group, rule)
+        .is_none()
+    {
+        panic!("could not find rule {group}/{rule}");
+    }
 
     let rule_filter = RuleFilter::Rule(group, rule);
     let filter = AnalysisFilter {
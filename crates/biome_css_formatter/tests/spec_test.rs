@@ -41,6 +41,9 @@ pub fn run(spec_input_file: &str, _expected_file: &str, test_directory: &str, _f
         vcs_base_path: None,
         gitignore_matches: vec![],
         workspace_directory: None,
+        nested: false,
+        ignore_files: vec![],
+        plugins: vec![],
     };
 
     let Some(test_file) = SpecTestFile::try_from_file(spec_input_file, root_path, Some(settings))
@@ -438,6 +438,14 @@ impl<T> Deserialized<T> {
             .any(|d| d.severity() == Severity::Warning)
     }
 
+    /// Maps the deserialized result with the given function, keeping the diagnostics.
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> Deserialized<U> {
+        Deserialized {
+            diagnostics: self.diagnostics,
+            deserialized: self.deserialized.map(f),
+        }
+    }
+
     /// Consume itself to return the deserialized result and its diagnostics.
     pub fn consume(self) -> (Option<T>, Vec<Error>) {
         (self.deserialized, self.diagnostics)
@@ -184,6 +184,7 @@ define_categories! {
     "lint/nursery/noUnknownTypeSelector": "https://biomejs.dev/linter/rules/no-unknown-type-selector",
     "lint/nursery/noUnknownUnit": "https://biomejs.dev/linter/rules/no-unknown-unit",
     "lint/nursery/noUnmatchableAnbSelector": "https://biomejs.dev/linter/rules/no-unmatchable-anb-selector",
+    "lint/nursery/noUnresolvedImports": "https://biomejs.dev/linter/rules/no-unresolved-imports",
     "lint/nursery/noUnusedFunctionParameters": "https://biomejs.dev/linter/rules/no-unused-function-parameters",
     "lint/nursery/noUselessEscapeInRegex": "https://biomejs.dev/linter/rules/no-useless-escape-in-regex",
     "lint/nursery/noUselessStringRaw": "https://biomejs.dev/linter/rules/no-useless-string-raw",
@@ -363,6 +364,8 @@ define_categories! {
     "deserialize",
     "project",
     "search",
+    "search/rewrite",
+    "baseline",
     "internalError/io",
     "internalError/fs",
     "internalError/panic",
@@ -380,6 +383,7 @@ define_categories! {
     "lint/correctness",
     "lint/nursery",
     "lint/performance",
+    "lint/plugin",
     "lint/security",
     "lint/style",
     "lint/suspicious",
@@ -390,6 +394,10 @@ define_categories! {
     "suppressions/unknownRule",
     "suppressions/unused",
     "suppressions/deprecatedSuppressionComment",
+    "suppressions/missingReason",
+    "suppressions/invalidReason",
+    "suppressions/forbidden",
+    "suppressions/tooMany",
 
     // Used in tests and examples
     "args/fileNotFound",
@@ -2,6 +2,20 @@ use directories::ProjectDirs;
 use std::{env, fs, path::PathBuf};
 use tracing::warn;
 
+/// Returns the user-level configuration directory of Biome, if the platform has one.
+///
+/// `$XDG_CONFIG_HOME/biome` takes precedence on every platform, otherwise:
+/// - Linux: /home/alice/.config/biome
+/// - Win: C:\Users\Alice\AppData\Roaming\biomejs\biome\config
+/// - Mac: /Users/Alice/Library/Application Support/dev.biomejs.biome
+pub fn user_config_dir() -> Option<PathBuf> {
+    if let Some(xdg_config_home) = env::var_os("XDG_CONFIG_HOME").filter(|dir| !dir.is_empty()) {
+        return Some(PathBuf::from(xdg_config_home).join("biome"));
+    }
+    ProjectDirs::from("dev", "biomejs", "biome")
+        .map(|proj_dirs| proj_dirs.config_dir().to_path_buf())
+}
+
 pub fn ensure_cache_dir() -> PathBuf {
     if let Some(proj_dirs) = ProjectDirs::from("dev", "biomejs", "biome") {
         // Linux: /home/alice/.cache/biome
@@ -2,11 +2,12 @@ use crate::{BiomePath, PathInterner};
 use biome_diagnostics::{console, Advices, Diagnostic, LogCategory, Visit};
 use biome_diagnostics::{Error, Severity};
 pub use memory::{ErrorEntry, MemoryFileSystem};
-pub use os::OsFileSystem;
+pub use os::{OsFileSystem, DEFAULT_IGNORE};
 use oxc_resolver::{Resolution, ResolveError};
 use serde::{Deserialize, Serialize};
 use std::collections::BTreeSet;
 use std::fmt::{Debug, Display, Formatter};
+use std::ops::Range;
 use std::panic::RefUnwindSafe;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
@@ -34,6 +35,21 @@ impl ConfigName {
     pub const fn file_names() -> [&'static str; 2] {
         Self::BIOME_JSON
     }
+
+    /// The manifest file that can contain the configuration in its `biome` key, when the
+    /// directory doesn't have a `biome.json` or `biome.jsonc` file
+    pub const fn package_json() -> &'static str {
+        "package.json"
+    }
+}
+
+/// The lines of a file that were added or modified
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ChangedLines {
+    /// The path of the file, relative to the working directory
+    pub path: String,
+    /// The ranges of the changed lines, as zero-based line indices
+    pub lines: Vec<Range<u32>>,
 }
 
 type AutoSearchResultAlias = Result<Option<AutoSearchResult>, FileSystemDiagnostic>;
@@ -57,6 +73,17 @@ pub trait FileSystem: Send + Sync + RefUnwindSafe {
     /// Return the path to the working directory
     fn working_directory(&self) -> Option<PathBuf>;
 
+    /// Return the path to the user-level configuration directory of Biome, whose
+    /// configuration file is used when a project doesn't have one
+    fn user_config_directory(&self) -> Option<PathBuf>;
+
+    /// Return the path to the home directory of the user
+    fn home_directory(&self) -> Option<PathBuf>;
+
+    /// Return the path to the configuration directory of the user, `$XDG_CONFIG_HOME` or
+    /// `~/.config`, where tools like Git read their global configuration
+    fn config_home_directory(&self) -> Option<PathBuf>;
+
     /// Checks if the given path exists in the file system
     fn path_exists(&self, path: &Path) -> bool;
 
@@ -69,6 +96,9 @@ pub trait FileSystem: Send + Sync + RefUnwindSafe {
     /// Checks if the given path is a symlink
     fn path_is_symlink(&self, path: &Path) -> bool;
 
+    /// Creates a directory and all its missing parents
+    fn create_dir_all(&self, path: &Path) -> io::Result<()>;
+
     /// This method accepts a directory path (`search_dir`) and a list of filenames (`file_names`),
     /// It looks for the files in the specified directory in the order they appear in the list.
     /// If a file is not found in the initial directory, the search may continue into the parent
@@ -182,6 +212,10 @@ pub trait FileSystem: Send + Sync + RefUnwindSafe {
 
     fn get_changed_files(&self, base: &str) -> io::Result<Vec<String>>;
 
+    /// Returns the lines of the files that changed compared to `base`, the same way
+    /// [FileSystem::get_changed_files] compares the files
+    fn get_changed_lines(&self, base: &str) -> io::Result<Vec<ChangedLines>>;
+
     fn get_staged_files(&self) -> io::Result<Vec<String>>;
 
     fn resolve_configuration(
@@ -357,6 +391,18 @@ where
         T::working_directory(self)
     }
 
+    fn user_config_directory(&self) -> Option<PathBuf> {
+        T::user_config_directory(self)
+    }
+
+    fn home_directory(&self) -> Option<PathBuf> {
+        T::home_directory(self)
+    }
+
+    fn config_home_directory(&self) -> Option<PathBuf> {
+        T::config_home_directory(self)
+    }
+
     fn path_exists(&self, path: &Path) -> bool {
         T::path_exists(self, path)
     }
@@ -373,10 +419,18 @@ where
         T::path_is_symlink(self, path)
     }
 
+    fn create_dir_all(&self, path: &Path) -> io::Result<()> {
+        T::create_dir_all(self, path)
+    }
+
     fn get_changed_files(&self, base: &str) -> io::Result<Vec<String>> {
         T::get_changed_files(self, base)
     }
 
+    fn get_changed_lines(&self, base: &str) -> io::Result<Vec<ChangedLines>> {
+        T::get_changed_lines(self, base)
+    }
+
     fn get_staged_files(&self) -> io::Result<Vec<String>> {
         T::get_staged_files(self)
     }
@@ -13,7 +13,7 @@ use parking_lot::{lock_api::ArcMutexGuard, Mutex, RawMutex, RwLock};
 use crate::fs::OpenOptions;
 use crate::{BiomePath, FileSystem, TraversalContext, TraversalScope};
 
-use super::{BoxedTraversal, ErrorKind, File, FileSystemDiagnostic};
+use super::{BoxedTraversal, ChangedLines, ErrorKind, File, FileSystemDiagnostic};
 
 type OnGetChangedFiles = Option<
     Arc<
@@ -23,6 +23,14 @@ type OnGetChangedFiles = Option<
     >,
 >;
 
+type OnGetChangedLines = Option<
+    Arc<
+        AssertUnwindSafe<
+            Mutex<Option<Box<dyn FnOnce() -> Vec<ChangedLines> + Send + 'static + RefUnwindSafe>>>,
+        >,
+    >,
+>;
+
 /// Fully in-memory file system, stores the content of all known files in a hashmap
 pub struct MemoryFileSystem {
     files: AssertUnwindSafe<RwLock<FxHashMap<PathBuf, FileEntry>>>,
@@ -30,6 +38,9 @@ pub struct MemoryFileSystem {
     allow_write: bool,
     on_get_staged_files: OnGetChangedFiles,
     on_get_changed_files: OnGetChangedFiles,
+    on_get_changed_lines: OnGetChangedLines,
+    user_config_directory: Option<PathBuf>,
+    home_directory: Option<PathBuf>,
 }
 
 impl Default for MemoryFileSystem {
@@ -44,6 +55,11 @@ impl Default for MemoryFileSystem {
             on_get_changed_files: Some(Arc::new(AssertUnwindSafe(Mutex::new(Some(Box::new(
                 Vec::new,
             )))))),
+            on_get_changed_lines: Some(Arc::new(AssertUnwindSafe(Mutex::new(Some(Box::new(
+                Vec::new,
+            )))))),
+            user_config_directory: None,
+            home_directory: None,
         }
     }
 }
@@ -115,6 +131,23 @@ impl MemoryFileSystem {
         self.on_get_changed_files = Some(Arc::new(AssertUnwindSafe(Mutex::new(Some(cfn)))));
     }
 
+    pub fn set_on_get_changed_lines(
+        &mut self,
+        cfn: Box<dyn FnOnce() -> Vec<ChangedLines> + Send + RefUnwindSafe + 'static>,
+    ) {
+        self.on_get_changed_lines = Some(Arc::new(AssertUnwindSafe(Mutex::new(Some(cfn)))));
+    }
+
+    /// Sets the user-level configuration directory, which the in-memory file system doesn't have by default
+    pub fn set_user_config_directory(&mut self, path: PathBuf) {
+        self.user_config_directory = Some(path);
+    }
+
+    /// Sets the home directory of the user, which the in-memory file system doesn't have by default
+    pub fn set_home_directory(&mut self, path: PathBuf) {
+        self.home_directory = Some(path);
+    }
+
     pub fn set_on_get_staged_files(
         &mut self,
         cfn: Box<dyn FnOnce() -> Vec<String> + Send + RefUnwindSafe + 'static>,
@@ -192,6 +225,20 @@ impl FileSystem for MemoryFileSystem {
         None
     }
 
+    fn user_config_directory(&self) -> Option<PathBuf> {
+        self.user_config_directory.clone()
+    }
+
+    fn home_directory(&self) -> Option<PathBuf> {
+        self.home_directory.clone()
+    }
+
+    fn config_home_directory(&self) -> Option<PathBuf> {
+        self.home_directory
+            .as_ref()
+            .map(|home| home.join(".config"))
+    }
+
     fn path_exists(&self, path: &Path) -> bool {
         self.path_is_file(path)
     }
@@ -209,6 +256,11 @@ impl FileSystem for MemoryFileSystem {
         false
     }
 
+    fn create_dir_all(&self, _path: &Path) -> io::Result<()> {
+        // The directories of the memory file system are implied by the paths of its files
+        Ok(())
+    }
+
     fn get_changed_files(&self, _base: &str) -> io::Result<Vec<String>> {
         let cb_arc = self.on_get_changed_files.as_ref().unwrap().clone();
 
@@ -219,6 +271,16 @@ impl FileSystem for MemoryFileSystem {
         Ok(cb())
     }
 
+    fn get_changed_lines(&self, _base: &str) -> io::Result<Vec<ChangedLines>> {
+        let cb_arc = self.on_get_changed_lines.as_ref().unwrap().clone();
+
+        let mut cb_guard = cb_arc.lock();
+
+        let cb = cb_guard.take().unwrap();
+
+        Ok(cb())
+    }
+
     fn get_staged_files(&self) -> io::Result<Vec<String>> {
         let cb_arc = self.on_get_staged_files.as_ref().unwrap().clone();
 
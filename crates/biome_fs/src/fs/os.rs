@@ -1,9 +1,9 @@
 //! Implementation of the [FileSystem] and related traits for the underlying OS filesystem
-use super::{BoxedTraversal, ErrorKind, File, FileSystemDiagnostic};
+use super::{BoxedTraversal, ChangedLines, ErrorKind, File, FileSystemDiagnostic};
 use crate::fs::OpenOptions;
 use crate::{
     fs::{TraversalContext, TraversalScope},
-    BiomePath, FileSystem,
+    user_config_dir, BiomePath, FileSystem,
 };
 use biome_diagnostics::{adapters::IoError, DiagnosticExt, Error, Severity};
 use oxc_resolver::{Resolution, ResolveError, ResolveOptions, Resolver};
@@ -20,6 +20,79 @@ use std::{
 
 const MAX_SYMLINK_DEPTH: u8 = 3;
 
+/// Runs a `git` command and returns its output, or the error it printed when it failed
+fn git_output(command: &mut Command) -> io::Result<String> {
+    let output = command.output()?;
+    if !output.status.success() {
+        return Err(io::Error::other(
+            String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        ));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// Collects the lines added or modified in each file from the output of `git diff --unified=0`.
+///
+/// Only the lines of the new version of the files are kept, so the hunks that only remove lines
+/// are ignored.
+fn parse_changed_lines(diff: &str) -> Vec<ChangedLines> {
+    let mut files: Vec<ChangedLines> = Vec::new();
+    // The lines of the old and the new version of the file that are left in the current hunk.
+    // The lines of a hunk are never headers, even when an added line starts with `++ `.
+    let mut old_remaining = 0u32;
+    let mut new_remaining = 0u32;
+    let mut follows_old_header = false;
+    for line in diff.lines() {
+        if old_remaining > 0 || new_remaining > 0 {
+            match line.as_bytes().first() {
+                Some(b'+') => new_remaining = new_remaining.saturating_sub(1),
+                Some(b'-') => old_remaining = old_remaining.saturating_sub(1),
+                // \ No newline at end of file
+                Some(b'\\') => {}
+                _ => {
+                    old_remaining = old_remaining.saturating_sub(1);
+                    new_remaining = new_remaining.saturating_sub(1);
+                }
+            }
+            follows_old_header = false;
+            continue;
+        }
+
+        if let Some(path) = line.strip_prefix("+++ ").filter(|_| follows_old_header) {
+            let path = path.strip_prefix("b/").unwrap_or(path);
+            files.push(ChangedLines {
+                path: path.to_string(),
+                lines: Vec::new(),
+            });
+        } else if let Some(hunk) = line.strip_prefix("@@ ") {
+            // @@ -start[,count] +start[,count] @@
+            let mut ranges = hunk.split(' ');
+            let old_range = ranges.next().and_then(|range| range.strip_prefix('-'));
+            let new_range = ranges.next().and_then(|range| range.strip_prefix('+'));
+            let (Some((_, old_count)), Some((new_start, new_count))) = (
+                old_range.and_then(parse_hunk_range),
+                new_range.and_then(parse_hunk_range),
+            ) else {
+                continue;
+            };
+            old_remaining = old_count;
+            new_remaining = new_count;
+            if let (Some(file), true) = (files.last_mut(), new_count > 0) {
+                let start = new_start.saturating_sub(1);
+                file.lines.push(start..start + new_count);
+            }
+        }
+        follows_old_header = line.starts_with("--- ");
+    }
+    files
+}
+
+/// Parses the `start[,count]` range of a hunk header
+fn parse_hunk_range(range: &str) -> Option<(u32, u32)> {
+    let (start, count) = range.split_once(',').unwrap_or((range, "1"));
+    Some((start.parse().ok()?, count.parse().ok()?))
+}
+
 /// Implementation of [FileSystem] that directly calls through to the underlying OS
 pub struct OsFileSystem {
     pub working_directory: Option<PathBuf>,
@@ -74,6 +147,24 @@ impl FileSystem for OsFileSystem {
         self.working_directory.clone()
     }
 
+    fn user_config_directory(&self) -> Option<PathBuf> {
+        user_config_dir()
+    }
+
+    fn home_directory(&self) -> Option<PathBuf> {
+        env::var_os("HOME")
+            .or_else(|| env::var_os("USERPROFILE"))
+            .filter(|dir| !dir.is_empty())
+            .map(PathBuf::from)
+    }
+
+    fn config_home_directory(&self) -> Option<PathBuf> {
+        env::var_os("XDG_CONFIG_HOME")
+            .filter(|dir| !dir.is_empty())
+            .map(PathBuf::from)
+            .or_else(|| self.home_directory().map(|home| home.join(".config")))
+    }
+
     fn path_exists(&self, path: &Path) -> bool {
         path.exists()
     }
@@ -90,6 +181,10 @@ impl FileSystem for OsFileSystem {
         path.is_symlink()
     }
 
+    fn create_dir_all(&self, path: &Path) -> io::Result<()> {
+        fs::create_dir_all(path)
+    }
+
     fn resolve_configuration(
         &self,
         specifier: &str,
@@ -99,43 +194,54 @@ impl FileSystem for OsFileSystem {
     }
 
     fn get_changed_files(&self, base: &str) -> io::Result<Vec<String>> {
-        let output = Command::new("git")
-            .arg("diff")
-            .arg("--name-only")
-            .arg("--relative")
-            // A: added
-            // C: copied
-            // M: modified
-            // R: renamed
-            // Source: https://git-scm.com/docs/git-diff#Documentation/git-diff.txt---diff-filterACDMRTUXB82308203
-            .arg("--diff-filter=ACMR")
-            .arg(format!("{base}...HEAD"))
-            .output()?;
-
-        Ok(String::from_utf8_lossy(&output.stdout)
-            .lines()
-            .map(|l| l.to_string())
-            .collect())
+        let output = git_output(
+            Command::new("git")
+                .arg("diff")
+                .arg("--name-only")
+                .arg("--relative")
+                // A: added
+                // C: copied
+                // M: modified
+                // R: renamed
+                // Source: https://git-scm.com/docs/git-diff#Documentation/git-diff.txt---diff-filterACDMRTUXB82308203
+                .arg("--diff-filter=ACMR")
+                .arg(format!("{base}...HEAD")),
+        )?;
+
+        Ok(output.lines().map(|l| l.to_string()).collect())
+    }
+
+    fn get_changed_lines(&self, base: &str) -> io::Result<Vec<ChangedLines>> {
+        let output = git_output(
+            Command::new("git")
+                .arg("diff")
+                .arg("--unified=0")
+                .arg("--relative")
+                .arg("--no-color")
+                .arg("--no-ext-diff")
+                .arg("--diff-filter=ACMR")
+                .arg(format!("{base}...HEAD")),
+        )?;
+
+        Ok(parse_changed_lines(&output))
     }
 
     fn get_staged_files(&self) -> io::Result<Vec<String>> {
-        let output = Command::new("git")
-            .arg("diff")
-            .arg("--name-only")
-            .arg("--relative")
-            .arg("--staged")
-            // A: added
-            // C: copied
-            // M: modified
-            // R: renamed
-            // Source: https://git-scm.com/docs/git-diff#Documentation/git-diff.txt---diff-filterACDMRTUXB82308203
-            .arg("--diff-filter=ACMR")
-            .output()?;
-
-        Ok(String::from_utf8_lossy(&output.stdout)
-            .lines()
-            .map(|l| l.to_string())
-            .collect())
+        let output = git_output(
+            Command::new("git")
+                .arg("diff")
+                .arg("--name-only")
+                .arg("--relative")
+                .arg("--staged")
+                // A: added
+                // C: copied
+                // M: modified
+                // R: renamed
+                // Source: https://git-scm.com/docs/git-diff#Documentation/git-diff.txt---diff-filterACDMRTUXB82308203
+                .arg("--diff-filter=ACMR"),
+        )?;
+
+        Ok(output.lines().map(|l| l.to_string()).collect())
     }
 }
 
@@ -219,7 +325,7 @@ impl<'scope> TraversalScope<'scope> for OsTraversalScope<'scope> {
 // TODO: remove in Biome 2.0, and directly use `.gitignore`
 /// Default list of ignored directories, in the future will be supplanted by
 /// detecting and parsing .ignore files
-const DEFAULT_IGNORE: &[&[u8]] = &[b".git", b".svn", b".hg", b".yarn", b"node_modules"];
+pub const DEFAULT_IGNORE: &[&[u8]] = &[b".git", b".svn", b".hg", b".yarn", b"node_modules"];
 
 /// Traverse a single directory
 fn handle_dir<'scope>(
@@ -448,3 +554,81 @@ impl From<FileType> for ErrorKind {
         Self::UnknownFileType
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::parse_changed_lines;
+    use crate::ChangedLines;
+
+    #[test]
+    #[expect(clippy::single_range_in_vec_init)]
+    fn parses_changed_lines() {
+        let diff = r#"diff --git a/src/file.js b/src/file.js
+index 1234567..89abcde 100644
+--- a/src/file.js
++++ b/src/file.js
+@@ -1,0 +2,2 @@ function f() {
++let a = 1;
++let b = 2;
+@@ -10 +12 @@ function g() {
+-old
++new
+@@ -20,3 +23,0 @@ function h() {
+-removed
+-removed
+-removed
+diff --git a/new.js b/new.js
+new file mode 100644
+index 0000000..1234567
+--- /dev/null
++++ b/new.js
+@@ -0,0 +1,3 @@
++a
++b
++c
+diff --git a/other.js b/other.js
+index 1234567..89abcde 100644
+--- a/other.js
++++ b/other.js
+@@ -1,0 +2 @@
++a
+@@ -3,0 +5 @@
++b
+diff --git a/counter.js b/counter.js
+index 1234567..89abcde 100644
+--- a/counter.js
++++ b/counter.js
+@@ -2,2 +2,3 @@ let i = 0;
+--- i;
+-++ i;
++++ i;
++++ i;
++i++;
+\ No newline at end of file
+@@ -10 +11 @@ let j = 0;
+-j;
++j++;
+"#;
+        assert_eq!(
+            parse_changed_lines(diff),
+            vec![
+                ChangedLines {
+                    path: "src/file.js".to_string(),
+                    lines: vec![1..3, 11..12],
+                },
+                ChangedLines {
+                    path: "new.js".to_string(),
+                    lines: vec![0..3],
+                },
+                ChangedLines {
+                    path: "other.js".to_string(),
+                    lines: vec![1..2, 4..5],
+                },
+                ChangedLines {
+                    path: "counter.js".to_string(),
+                    lines: vec![1..4, 10..11],
+                },
+            ]
+        );
+    }
+}
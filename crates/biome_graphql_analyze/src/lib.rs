@@ -6,8 +6,9 @@ mod suppression_action;
 pub use crate::registry::visit_registry;
 use crate::suppression_action::GraphqlSuppressionAction;
 use biome_analyze::{
-    AnalysisFilter, AnalyzerOptions, AnalyzerSignal, ControlFlow, LanguageRoot, MatchQueryParams,
-    MetadataRegistry, RuleAction, RuleRegistry, SuppressionKind,
+    AnalysisFilter, AnalyzerOptions, AnalyzerSignal, AnalyzerSuppression,
+    AnalyzerSuppressionVariant, ControlFlow, LanguageRoot, MatchQueryParams, MetadataRegistry,
+    RuleAction, RuleRegistry, SuppressionKind,
 };
 use biome_diagnostics::{category, Error};
 use biome_graphql_syntax::GraphqlLanguage;
@@ -59,16 +60,21 @@ where
 {
     fn parse_linter_suppression_comment(
         text: &str,
-    ) -> Vec<Result<SuppressionKind, SuppressionDiagnostic>> {
+    ) -> Vec<Result<AnalyzerSuppression, SuppressionDiagnostic>> {
         let mut result = Vec::new();
 
         for comment in parse_suppression_comment(text) {
-            let categories = match comment {
+            let (categories, variant, reason) = match comment {
                 Ok(comment) => {
+                    let variant = AnalyzerSuppressionVariant::from(comment.kind);
                     if comment.is_legacy {
-                        result.push(Ok(SuppressionKind::Deprecated));
+                        result.push(Ok(AnalyzerSuppression::new(
+                            SuppressionKind::Deprecated,
+                            variant,
+                        )
+                        .with_reason(comment.reason)));
                     }
-                    comment.categories
+                    (comment.categories, variant, comment.reason)
                 }
                 Err(err) => {
                     result.push(Err(err));
@@ -79,14 +85,34 @@ where
             for (key, value) in categories {
                 if key == category!("lint") {
                     if let Some(value) = value {
-                        result.push(Ok(SuppressionKind::MaybeLegacy(value)));
+                        result.push(Ok(AnalyzerSuppression::new(
+                            SuppressionKind::MaybeLegacy(value),
+                            variant,
+                        )
+                        .with_reason(reason)));
                     } else {
-                        result.push(Ok(SuppressionKind::Everything));
+                        result.push(Ok(AnalyzerSuppression::new(
+                            SuppressionKind::Everything,
+                            variant,
+                        )
+                        .with_reason(reason)));
                     }
                 } else {
                     let category = key.name();
                     if let Some(rule) = category.strip_prefix("lint/") {
-                        result.push(Ok(SuppressionKind::Rule(rule)));
+                        if let Some(instance) = value {
+                            result.push(Ok(AnalyzerSuppression::new(
+                                SuppressionKind::RuleInstance(rule, instance),
+                                variant,
+                            )
+                            .with_reason(reason)));
+                        } else {
+                            result.push(Ok(AnalyzerSuppression::new(
+                                SuppressionKind::Rule(rule),
+                                variant,
+                            )
+                            .with_reason(reason)));
+                        }
                     }
                 }
             }
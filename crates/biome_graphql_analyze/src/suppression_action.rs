@@ -71,6 +71,8 @@ impl SuppressionAction for GraphqlSuppressionAction {
             }
             new_token = new_token.with_leading_trivia(trivia);
         }
+        // Trim trailing trivia to prevent double insertion of trailing whitespaces in `replace_token_transfer_trivia`.
+        new_token = new_token.trim_trailing_trivia();
         mutation.replace_token_transfer_trivia(token_to_apply_suppression, new_token);
     }
 }
@@ -1,6 +1,7 @@
 use crate::{
     grit_context::GritQueryContext, grit_target_language::GritTargetLanguage,
-    grit_target_node::GritTargetNode, source_location_ext::SourceFileExt, util::TextRangeGritExt,
+    grit_target_node::GritTargetNode, linearization::linearize_binding,
+    source_location_ext::SourceFileExt, util::TextRangeGritExt,
 };
 use biome_diagnostics::{display::SourceFile, SourceCode};
 use biome_rowan::TextRange;
@@ -157,14 +158,41 @@ impl<'a> Binding<'a, GritQueryContext> for GritBinding<'a> {
 
     fn linearized_text(
         &self,
-        _language: &GritTargetLanguage,
-        _effects: &[Effect<'a, GritQueryContext>],
-        _files: &FileRegistry<'a, GritQueryContext>,
-        _memo: &mut HashMap<grit_util::CodeRange, Option<String>>,
-        _distributed_indent: Option<usize>,
-        _logs: &mut AnalysisLogs,
+        language: &GritTargetLanguage,
+        effects: &[Effect<'a, GritQueryContext>],
+        files: &FileRegistry<'a, GritQueryContext>,
+        memo: &mut HashMap<grit_util::CodeRange, Option<String>>,
+        distributed_indent: Option<usize>,
+        logs: &mut AnalysisLogs,
     ) -> GritResult<Cow<'a, str>> {
-        Err(GritPatternError::new("Not implemented")) // TODO: Implement rewriting
+        let text = match self {
+            Self::Node(node) => linearize_binding(
+                language,
+                effects,
+                files,
+                memo,
+                node.source(),
+                node.byte_range(),
+                logs,
+            )?,
+            Self::Range(range, source) => linearize_binding(
+                language,
+                effects,
+                files,
+                memo,
+                source,
+                range.to_byte_range(),
+                logs,
+            )?,
+            Self::File(path) => return Ok(path.to_string_lossy()),
+            Self::Empty(..) => return Ok("".into()),
+            Self::Constant(constant) => return Ok(constant.to_string().into()),
+        };
+
+        Ok(match distributed_indent {
+            Some(padding) => distribute_indent(&text, padding).into(),
+            None => text,
+        })
     }
 
     fn text(&self, _language: &GritTargetLanguage) -> GritResult<Cow<'a, str>> {
@@ -272,6 +300,32 @@ impl<'a> Binding<'a, GritQueryContext> for GritBinding<'a> {
     }
 }
 
+/// Indents the lines of the text, except the first one, by the given number of spaces.
+///
+/// The text of a binding inserted inside a list is indented like the list, the same way
+/// GritQL indents the texts that it distributes.
+fn distribute_indent(text: &str, padding: usize) -> String {
+    if text.trim().is_empty() {
+        return text.to_string();
+    }
+
+    let mut result = String::with_capacity(text.len());
+    if text.starts_with('\n') {
+        result.push('\n');
+    }
+    let separator = format!("\n{}", " ".repeat(padding));
+    for (index, line) in text.lines().enumerate() {
+        if index > 0 {
+            result.push_str(&separator);
+        }
+        result.push_str(line);
+    }
+    if text.ends_with('\n') {
+        result.push('\n');
+    }
+    result
+}
+
 /// Checks whether two nodes are equivalent.
 ///
 /// We define two nodes to be equivalent if they have the same sort (kind) and
@@ -1,7 +1,10 @@
 use crate::{
     grit_context::{GritExecContext, GritQueryContext},
+    grit_query::RegisteredDiagnostic,
     grit_resolved_pattern::GritResolvedPattern,
 };
+use biome_diagnostics::Severity;
+use biome_rowan::TextRange;
 use biome_string_case::StrOnlyExtension;
 use grit_pattern_matcher::{
     binding::Binding,
@@ -99,6 +102,11 @@ impl Default for BuiltIns {
             BuiltInFunction::new("shuffle", vec!["list"], Box::new(shuffle_fn)),
             BuiltInFunction::new("random", vec!["floor", "ceiling"], Box::new(random_fn)),
             BuiltInFunction::new("split", vec!["string", "separator"], Box::new(split_fn)),
+            BuiltInFunction::new(
+                "register_diagnostic",
+                vec!["span", "message", "severity"],
+                Box::new(register_diagnostic_fn),
+            ),
         ]
         .into()
     }
@@ -343,6 +351,46 @@ fn random_fn<'a>(
     }
 }
 
+/// Registers a diagnostic with a message and an optional severity at the range
+/// of the given code snippet
+fn register_diagnostic_fn<'a>(
+    args: &'a [Option<Pattern<GritQueryContext>>],
+    context: &'a GritExecContext<'a>,
+    state: &mut State<'a, GritQueryContext>,
+    logs: &mut AnalysisLogs,
+) -> GritResult<GritResolvedPattern<'a>> {
+    let args = GritResolvedPattern::from_patterns(args, state, context, logs)?;
+    let mut args = args.into_iter();
+    let (Some(Some(span)), Some(Some(message))) = (args.next(), args.next()) else {
+        return Err(GritPatternError::new(
+            "register_diagnostic() takes 2 or 3 arguments: span, message and an optional severity",
+        ));
+    };
+
+    let Some(range) = span.position(context.language()) else {
+        return Err(GritPatternError::new(
+            "register_diagnostic() requires a code snippet as the span",
+        ));
+    };
+
+    let message = message.text(&state.files, context.language())?.into_owned();
+    let severity = match args.next().flatten() {
+        Some(severity) => severity
+            .text(&state.files, context.language())?
+            .parse()
+            .map_err(GritPatternError::new)?,
+        None => Severity::Error,
+    };
+
+    context.add_diagnostic(RegisteredDiagnostic {
+        message,
+        range: TextRange::new(range.start_byte.into(), range.end_byte.into()),
+        severity,
+    });
+
+    Ok(GritResolvedPattern::undefined())
+}
+
 /// Turns an arbitrary path into a resolved and normalized absolute path
 fn resolve_path_fn<'a>(
     args: &'a [Option<Pattern<GritQueryContext>>],
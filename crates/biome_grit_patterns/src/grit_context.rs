@@ -3,10 +3,12 @@ use crate::grit_built_in_functions::BuiltIns;
 use crate::grit_code_snippet::GritCodeSnippet;
 use crate::grit_file::GritFile;
 use crate::grit_node_patterns::{GritLeafNodePattern, GritNodePattern};
+use crate::grit_query::RegisteredDiagnostic;
 use crate::grit_resolved_pattern::GritResolvedPattern;
 use crate::grit_target_language::GritTargetLanguage;
 use crate::grit_target_node::GritTargetNode;
 use crate::grit_tree::GritTargetTree;
+use crate::linearization::apply_effects;
 use biome_parser::AnyParse;
 use grit_pattern_matcher::constants::{GLOBAL_VARS_SCOPE_INDEX, NEW_FILES_INDEX};
 use grit_pattern_matcher::context::{ExecContext, QueryContext};
@@ -19,6 +21,7 @@ use grit_util::error::GritPatternError;
 use grit_util::{error::GritResult, AnalysisLogs, FileOrigin, InputRanges, MatchRanges};
 use path_absolutize::Absolutize;
 use std::path::PathBuf;
+use std::sync::Mutex;
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct GritQueryContext;
@@ -49,6 +52,15 @@ pub struct GritExecContext<'a> {
     pub functions: &'a [GritFunctionDefinition<GritQueryContext>],
     pub patterns: &'a [PatternDefinition<GritQueryContext>],
     pub predicates: &'a [PredicateDefinition<GritQueryContext>],
+
+    /// Diagnostics registered with the `register_diagnostic()` built-in.
+    pub diagnostics: Mutex<Vec<RegisteredDiagnostic>>,
+}
+
+impl GritExecContext<'_> {
+    pub(crate) fn add_diagnostic(&self, diagnostic: RegisteredDiagnostic) {
+        self.diagnostics.lock().unwrap().push(diagnostic);
+    }
 }
 
 impl<'a> ExecContext<'a, GritQueryContext> for GritExecContext<'a> {
@@ -144,13 +156,37 @@ impl<'a> ExecContext<'a, GritQueryContext> for GritExecContext<'a> {
         };
         for file_ptr in files {
             let file = state.files.get_file_owner(file_ptr);
-            let mut match_log = file.matches.borrow_mut();
-
-            if match_log.input_matches.is_none() {
-                match_log.input_matches = Some(input_ranges.clone());
+            {
+                let mut match_log = file.matches.borrow_mut();
+                if match_log.input_matches.is_none() {
+                    match_log.input_matches = Some(input_ranges.clone());
+                }
             }
 
-            // TODO: Implement effect application
+            let Some(new_source) =
+                apply_effects(&file.tree, &state.effects, &state.files, &self.lang, logs)?
+            else {
+                continue;
+            };
+
+            let owned_file = new_file_owner(
+                file.name.clone(),
+                &new_source,
+                FileOrigin::Mutated,
+                &self.lang,
+                logs,
+            )?
+            .ok_or_else(|| {
+                GritPatternError::Builder(format!(
+                    "failed to construct rewritten file for file {}",
+                    file.name.to_string_lossy()
+                ))
+            })?;
+            self.files().push(owned_file);
+            // SAFETY: We just pushed to the list of files, so there must be one.
+            state
+                .files
+                .push_revision(&file_ptr, self.files().last().unwrap());
         }
 
         let new_files_binding = &mut state.bindings[GLOBAL_VARS_SCOPE_INDEX as usize]
@@ -180,12 +216,13 @@ impl<'a> ExecContext<'a, GritQueryContext> for GritExecContext<'a> {
                 .into();
             let body = file.body(&state.files).text(&state.files, &self.lang)?;
             let owned_file =
-                new_file_owner(name.clone(), &body, &self.lang, logs)?.ok_or_else(|| {
-                    GritPatternError::Builder(format!(
-                        "failed to construct new file for file {}",
-                        name.to_string_lossy()
-                    ))
-                })?;
+                new_file_owner(name.clone(), &body, FileOrigin::New, &self.lang, logs)?
+                    .ok_or_else(|| {
+                        GritPatternError::Builder(format!(
+                            "failed to construct new file for file {}",
+                            name.to_string_lossy()
+                        ))
+                    })?;
             self.files().push(owned_file);
             // SAFETY: We just pushed to the list of files, so there must be one.
             let _ = state.files.push_new_file(self.files().last().unwrap());
@@ -269,14 +306,16 @@ fn file_owner_from_matches(
 fn new_file_owner(
     name: impl Into<PathBuf>,
     source: &str,
+    origin: FileOrigin<'_, GritTargetTree>,
     language: &GritTargetLanguage,
     logs: &mut AnalysisLogs,
 ) -> GritResult<Option<FileOwner<GritTargetTree>>> {
     let name = name.into();
+    let new = matches!(origin, FileOrigin::New);
 
     let Some(tree) = language
         .get_parser()
-        .parse_file(source, Some(&name), logs, FileOrigin::New)
+        .parse_file(source, Some(&name), logs, origin)
     else {
         return Ok(None);
     };
@@ -287,7 +326,7 @@ fn new_file_owner(
         absolute_path,
         tree,
         matches: Default::default(),
-        new: true,
+        new,
     }))
 }
 
@@ -13,7 +13,9 @@ use crate::pattern_compiler::{
 };
 use crate::variables::{VarRegistry, VariableLocations};
 use crate::CompileError;
+use biome_diagnostics::Severity;
 use biome_grit_syntax::{GritRoot, GritRootExt};
+use biome_rowan::TextRange;
 use grit_pattern_matcher::constants::{
     ABSOLUTE_PATH_INDEX, FILENAME_INDEX, NEW_FILES_INDEX, PROGRAM_INDEX,
 };
@@ -26,7 +28,7 @@ use grit_util::{AnalysisLogs, Ast, ByteRange, InputRanges, Range, VariableMatch}
 use std::collections::{BTreeMap, BTreeSet};
 use std::ffi::OsStr;
 use std::path::{Path, PathBuf};
-use std::sync::LazyLock;
+use std::sync::{LazyLock, Mutex};
 
 static BUILT_INS: LazyLock<BuiltIns> = LazyLock::new(BuiltIns::default);
 
@@ -78,6 +80,7 @@ impl GritQuery {
             functions: &self.definitions.functions,
             patterns: &self.definitions.patterns,
             predicates: &self.definitions.predicates,
+            diagnostics: Mutex::default(),
         };
 
         let var_registry = VarRegistry::from_locations(&self.variable_locations);
@@ -99,6 +102,9 @@ impl GritQuery {
                     results.push(result)
                 }
             }
+
+            let diagnostics = std::mem::take(&mut *context.diagnostics.lock().unwrap());
+            results.extend(diagnostics.into_iter().map(GritQueryResult::Diagnostic));
         }
 
         Ok((results, logs))
@@ -191,6 +197,7 @@ pub enum GritQueryResult {
     Match(Match),
     Rewrite(Rewrite),
     CreateFile(CreateFile),
+    Diagnostic(RegisteredDiagnostic),
 }
 
 impl GritQueryResult {
@@ -364,3 +371,12 @@ pub struct Message {
     pub range: Vec<Range>,
     pub variable_runtime_id: String,
 }
+
+/// A diagnostic registered by the query with the `register_diagnostic()`
+/// built-in.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RegisteredDiagnostic {
+    pub message: String,
+    pub range: TextRange,
+    pub severity: Severity,
+}
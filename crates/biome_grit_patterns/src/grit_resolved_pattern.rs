@@ -15,7 +15,7 @@ use grit_pattern_matcher::pattern::{
     ResolvedPattern, ResolvedSnippet, State,
 };
 use grit_util::error::{GritPatternError, GritResult};
-use grit_util::{AnalysisLogs, Ast, CodeRange, Range};
+use grit_util::{AnalysisLogs, Ast, CodeRange, EffectKind, Range};
 use std::borrow::Cow;
 use std::collections::{BTreeMap, HashMap};
 
@@ -345,11 +345,56 @@ impl<'a> ResolvedPattern<'a, GritQueryContext> for GritResolvedPattern<'a> {
 
     fn extend(
         &mut self,
-        _with: Self,
-        _effects: &mut Vec<Effect<'a, GritQueryContext>>,
-        _language: &<GritQueryContext as QueryContext>::Language<'a>,
+        mut with: Self,
+        effects: &mut Vec<Effect<'a, GritQueryContext>>,
+        language: &<GritQueryContext as QueryContext>::Language<'a>,
     ) -> GritResult<()> {
-        Err(GritPatternError::new("Not implemented")) // TODO: Implement rewriting
+        match self {
+            Self::Binding(bindings) => {
+                for binding in bindings.iter() {
+                    let is_first = !effects.iter().any(|effect| effect.binding == *binding);
+                    with.normalize_insert(binding, is_first, language)?;
+                    effects.push(Effect {
+                        binding: binding.clone(),
+                        pattern: with.clone(),
+                        kind: EffectKind::Insert,
+                    });
+                }
+                Ok(())
+            }
+            Self::Snippets(snippets) => {
+                snippets.extend(with.to_snippets()?);
+                Ok(())
+            }
+            Self::List(list) => {
+                list.push(with);
+                Ok(())
+            }
+            Self::Constant(Constant::Integer(integer)) => match with {
+                Self::Constant(Constant::Integer(other)) => {
+                    *integer += other;
+                    Ok(())
+                }
+                _ => Err(GritPatternError::new(
+                    "can only add an integer to an integer",
+                )),
+            },
+            Self::Constant(Constant::Float(float)) => match with {
+                Self::Constant(Constant::Float(other)) => {
+                    *float += other;
+                    Ok(())
+                }
+                _ => Err(GritPatternError::new("can only add a float to a float")),
+            },
+            Self::Constant(constant) => {
+                let mut snippets = vec![ResolvedSnippet::Text(constant.to_string().into())];
+                snippets.extend(with.to_snippets()?);
+                *self = Self::Snippets(snippets);
+                Ok(())
+            }
+            Self::Map(_) => Err(GritPatternError::new("cannot extend a map")),
+            Self::File(_) | Self::Files(_) => Err(GritPatternError::new("cannot extend a file")),
+        }
     }
 
     fn float(
@@ -518,14 +563,59 @@ impl<'a> ResolvedPattern<'a, GritQueryContext> for GritResolvedPattern<'a> {
 
     fn linearized_text(
         &self,
-        _language: &GritTargetLanguage,
-        _effects: &[Effect<'a, GritQueryContext>],
-        _files: &FileRegistry<'a, GritQueryContext>,
-        _memo: &mut HashMap<CodeRange, Option<String>>,
+        language: &GritTargetLanguage,
+        effects: &[Effect<'a, GritQueryContext>],
+        files: &FileRegistry<'a, GritQueryContext>,
+        memo: &mut HashMap<CodeRange, Option<String>>,
         _should_pad_snippet: bool,
-        _logs: &mut AnalysisLogs,
+        logs: &mut AnalysisLogs,
     ) -> GritResult<Cow<'a, str>> {
-        Err(GritPatternError::new("Not implemented")) // TODO: Implement rewriting
+        match self {
+            Self::Binding(bindings) => bindings
+                .last()
+                .ok_or_else(|| {
+                    GritPatternError::new("cannot linearize resolved_pattern with no binding")
+                })?
+                .linearized_text(language, effects, files, memo, None, logs),
+            Self::Snippets(snippets) => Ok(snippets
+                .iter()
+                .try_fold(String::new(), |mut text, snippet| {
+                    text.push_str(
+                        &snippet.linearized_text(language, effects, files, memo, None, logs)?,
+                    );
+                    Ok::<String, GritPatternError>(text)
+                })?
+                .into()),
+            Self::List(list) => Ok(list
+                .iter()
+                .map(|pattern| pattern.linearized_text(language, effects, files, memo, false, logs))
+                .collect::<GritResult<Vec<_>>>()?
+                .join(",")
+                .into()),
+            Self::Map(map) => {
+                let entries = map
+                    .iter()
+                    .map(|(key, value)| {
+                        let value =
+                            value.linearized_text(language, effects, files, memo, false, logs)?;
+                        Ok(format!("\"{key}\": {value}"))
+                    })
+                    .collect::<GritResult<Vec<_>>>()?;
+                Ok(format!("{{{}}}", entries.join(", ")).into())
+            }
+            Self::File(file) => Ok(format!(
+                "{}:\n{}",
+                file.name(files)
+                    .linearized_text(language, effects, files, memo, false, logs)?,
+                file.body(files)
+                    .linearized_text(language, effects, files, memo, false, logs)?
+            )
+            .into()),
+            Self::Files(files_pattern) => {
+                files_pattern.linearized_text(language, effects, files, memo, false, logs)
+            }
+            Self::Constant(constant) => Ok(constant.to_string().into()),
+        }
     }
 
     fn matches_undefined(&self) -> bool {
@@ -551,11 +641,22 @@ impl<'a> ResolvedPattern<'a, GritQueryContext> for GritResolvedPattern<'a> {
 
     fn normalize_insert(
         &mut self,
-        _binding: &GritBinding,
-        _is_first: bool,
-        _language: &GritTargetLanguage,
+        binding: &GritBinding,
+        is_first: bool,
+        language: &GritTargetLanguage,
     ) -> GritResult<()> {
-        Err(GritPatternError::new("Not implemented")) // TODO: Implement insertion padding
+        let Self::Snippets(snippets) = self else {
+            return Ok(());
+        };
+        let Some(ResolvedSnippet::Text(text)) = snippets.first() else {
+            return Ok(());
+        };
+        if let Some(padding) = binding.get_insertion_padding(text, is_first, language) {
+            if padding.chars().next() != binding.text(language)?.chars().last() {
+                snippets.insert(0, ResolvedSnippet::Text(padding.into()));
+            }
+        }
+        Ok(())
     }
 
     fn position(&self, language: &GritTargetLanguage) -> Option<Range> {
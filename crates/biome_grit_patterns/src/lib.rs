@@ -15,6 +15,7 @@ mod grit_resolved_pattern;
 mod grit_target_language;
 mod grit_target_node;
 mod grit_tree;
+mod linearization;
 mod pattern_compiler;
 mod source_location_ext;
 mod util;
@@ -22,7 +23,9 @@ mod variables;
 
 pub use errors::*;
 pub use grit_context::GritTargetFile;
-pub use grit_query::{CreateFile, GritQuery, GritQueryResult, Message, OutputFile};
+pub use grit_query::{
+    CreateFile, GritQuery, GritQueryResult, Message, OutputFile, RegisteredDiagnostic, Rewrite,
+};
 pub use grit_target_language::{GritTargetLanguage, JsTargetLanguage};
 
 use biome_grit_parser::parse_grit;
@@ -0,0 +1,203 @@
+use crate::grit_binding::GritBinding;
+use crate::grit_context::GritQueryContext;
+use crate::grit_target_language::GritTargetLanguage;
+use crate::grit_tree::GritTargetTree;
+use grit_pattern_matcher::binding::Binding;
+use grit_pattern_matcher::effects::Effect;
+use grit_pattern_matcher::pattern::{FileRegistry, ResolvedPattern};
+use grit_util::error::GritResult;
+use grit_util::{AnalysisLogs, ByteRange, CodeRange, EffectKind};
+use std::borrow::Cow;
+use std::cmp::Reverse;
+use std::collections::HashMap;
+
+/// A replacement of the source text, resulting from an effect.
+struct Edit {
+    /// The range of the binding to which the effect applies.
+    range: ByteRange,
+
+    /// The linearized text of the effect's pattern.
+    text: String,
+
+    /// Whether the text is inserted after the binding, instead of replacing
+    /// it.
+    is_insert: bool,
+
+    /// The position of the effect, which determines the order of insertions
+    /// at the same offset.
+    index: usize,
+}
+
+impl Edit {
+    /// The offset at which the edit starts.
+    fn offset(&self) -> usize {
+        if self.is_insert {
+            self.range.end
+        } else {
+            self.range.start
+        }
+    }
+}
+
+/// Applies the effects that target the given tree, and returns the rewritten
+/// source.
+///
+/// Returns `None` if none of the effects target the tree.
+pub(crate) fn apply_effects<'a>(
+    tree: &'a GritTargetTree,
+    effects: &[Effect<'a, GritQueryContext>],
+    files: &FileRegistry<'a, GritQueryContext>,
+    language: &GritTargetLanguage,
+    logs: &mut AnalysisLogs,
+) -> GritResult<Option<String>> {
+    let source = tree.text();
+    if !effects
+        .iter()
+        .any(|effect| is_from_source(&effect.binding, source))
+    {
+        return Ok(None);
+    }
+
+    let mut memo = HashMap::new();
+    let text = linearize_binding(
+        language,
+        effects,
+        files,
+        &mut memo,
+        source,
+        ByteRange::new(0, source.len()),
+        logs,
+    )?;
+    Ok(Some(text.into_owned()))
+}
+
+/// Returns the text of the given range of the source, with the effects that
+/// target it inlined.
+///
+/// The effects are linearized recursively, so that the rewrite of a binding
+/// includes the rewrites of the bindings it contains, if it refers to them.
+/// The `memo` keeps the linearized text of the rewrites that were already
+/// computed, and a `None` for the rewrites that are being computed, which
+/// are then left out to avoid infinite recursion.
+pub(crate) fn linearize_binding<'a>(
+    language: &GritTargetLanguage,
+    effects: &[Effect<'a, GritQueryContext>],
+    files: &FileRegistry<'a, GritQueryContext>,
+    memo: &mut HashMap<CodeRange, Option<String>>,
+    source: &'a str,
+    range: ByteRange,
+    logs: &mut AnalysisLogs,
+) -> GritResult<Cow<'a, str>> {
+    let mut edits = Vec::new();
+    for (index, effect) in effects.iter().enumerate() {
+        if !is_from_source(&effect.binding, source) {
+            continue;
+        }
+        let Some(effect_range) = effect.binding.range(language) else {
+            continue;
+        };
+        if effect_range.start < range.start || effect_range.end > range.end {
+            continue;
+        }
+
+        let text = match effect.kind {
+            EffectKind::Rewrite => {
+                let key =
+                    CodeRange::new(effect_range.start as u32, effect_range.end as u32, source);
+                match memo.get(&key) {
+                    Some(Some(text)) => text.clone(),
+                    Some(None) => continue,
+                    None => {
+                        memo.insert(key.clone(), None);
+                        let text = effect
+                            .pattern
+                            .linearized_text(language, effects, files, memo, false, logs)?
+                            .into_owned();
+                        memo.insert(key, Some(text.clone()));
+                        text
+                    }
+                }
+            }
+            EffectKind::Insert => {
+                // The inserted text may refer to the binding it's inserted
+                // after, so the insertion itself is left out.
+                let other_effects: Vec<_> = effects
+                    .iter()
+                    .enumerate()
+                    .filter(|(other_index, _)| *other_index != index)
+                    .map(|(_, effect)| effect.clone())
+                    .collect();
+                effect
+                    .pattern
+                    .linearized_text(language, &other_effects, files, memo, false, logs)?
+                    .into_owned()
+            }
+        };
+
+        edits.push(Edit {
+            range: effect_range,
+            text,
+            is_insert: matches!(effect.kind, EffectKind::Insert),
+            index,
+        });
+    }
+
+    let edits = remove_nested_edits(edits);
+    let text = &source[range.start..range.end];
+    if edits.is_empty() {
+        return Ok(text.into());
+    }
+
+    let mut text = text.to_owned();
+    for edit in edits {
+        let start = edit.offset() - range.start;
+        if edit.is_insert {
+            text.insert_str(start, &edit.text);
+        } else {
+            text.replace_range(start..edit.range.end - range.start, &edit.text);
+        }
+    }
+    Ok(text.into())
+}
+
+/// Removes the edits of the bindings that are inside a rewritten binding,
+/// since the rewrite already includes them when it refers to them.
+///
+/// The remaining edits are sorted so that they can be applied from the end of
+/// the text.
+fn remove_nested_edits(mut edits: Vec<Edit>) -> Vec<Edit> {
+    edits.sort_by_key(|edit| (edit.range.start, Reverse(edit.range.end), edit.index));
+
+    let mut rewrites: Vec<Edit> = Vec::new();
+    let mut inserts = Vec::new();
+    for edit in edits {
+        if edit.is_insert {
+            inserts.push(edit);
+        } else if rewrites
+            .last()
+            .map_or(true, |last| edit.range.start >= last.range.end)
+        {
+            rewrites.push(edit);
+        }
+    }
+
+    inserts.retain(|insert| {
+        let offset = insert.offset();
+        !rewrites.iter().any(|rewrite| {
+            let is_inside = rewrite.range.start <= insert.range.start
+                && insert.range.end <= rewrite.range.end
+                && rewrite.range != insert.range;
+            is_inside || (rewrite.range.start < offset && offset < rewrite.range.end)
+        })
+    });
+
+    let mut edits: Vec<_> = rewrites.into_iter().chain(inserts).collect();
+    edits.sort_by_key(|edit| (Reverse(edit.offset()), edit.is_insert, Reverse(edit.index)));
+    edits
+}
+
+fn is_from_source(binding: &GritBinding, source: &str) -> bool {
+    binding
+        .source()
+        .is_some_and(|binding_source| std::ptr::eq(binding_source, source))
+}
@@ -82,7 +82,7 @@ pub(super) fn call_pattern_from_node_with_name(
     }
 }
 
-fn call_built_in_from_args(
+pub(super) fn call_built_in_from_args(
     mut args: BTreeMap<String, Pattern<GritQueryContext>>,
     params: &[&str],
     index: usize,
@@ -5,7 +5,7 @@ use crate::NodeLikeArgumentError;
 use crate::{grit_context::GritQueryContext, CompileError};
 use biome_grit_syntax::GritPredicateCall;
 use biome_rowan::AstNode;
-use grit_pattern_matcher::pattern::{PrCall, Predicate};
+use grit_pattern_matcher::pattern::{Container, Match, PrCall, Predicate};
 
 pub(crate) struct PrCallCompiler;
 
@@ -21,6 +21,12 @@ impl PrCallCompiler {
             return LogCompiler::from_named_args(node.named_args(), context).map(Predicate::Log);
         }
 
+        // `register_diagnostic()` is a built-in function, but it's called as
+        // a predicate that always matches.
+        if name == "register_diagnostic" {
+            return Self::built_in_predicate(node, name, context);
+        }
+
         let info = if let Some(info) = context.compilation.predicate_definition_info.get(&name) {
             info
         } else if let Some(info) = context.compilation.function_definition_info.get(&name) {
@@ -44,4 +50,42 @@ impl PrCallCompiler {
         let args = match_args_to_params(&name, args, &params, &context.compilation.lang)?;
         Ok(Predicate::Call(Box::new(PrCall::new(info.index, args))))
     }
+
+    /// Compiles the call of a built-in function that returns nothing into a
+    /// predicate that matches the undefined result of the call.
+    fn built_in_predicate(
+        node: &GritPredicateCall,
+        name: String,
+        context: &mut NodeCompilationContext,
+    ) -> Result<Predicate<GritQueryContext>, CompileError> {
+        let Some((index, built_in)) = context
+            .compilation
+            .built_ins
+            .get_built_ins()
+            .iter()
+            .enumerate()
+            .find(|(_, built_in)| built_in.name == name)
+        else {
+            return Err(CompileError::UnknownFunctionOrPredicate(name));
+        };
+
+        let params = built_in.params.clone();
+        let expected_params = Some(params.iter().map(|param| (*param).to_string()).collect());
+        let named_args = node_to_args_pairs(
+            &name,
+            node.named_args(),
+            &context.compilation.lang,
+            &expected_params,
+        )?;
+        let args = named_args_to_map(named_args, context)?;
+        if args.len() != node.named_args().into_iter().count() {
+            Err(NodeLikeArgumentError::DuplicateArguments { name: name.clone() })?
+        }
+
+        let call = call_built_in_from_args(args, &params, index, &context.compilation.lang, &name)?;
+        Ok(Predicate::Match(Box::new(Match::new(
+            Container::FunctionCall(Box::new(call)),
+            None,
+        ))))
+    }
 }
@@ -70,9 +70,12 @@ fn run_test(input: &'static str, _: &str, _: &str, _: &str) {
     let (results, logs) = query
         .execute(target_file)
         .unwrap_or_else(|err| panic!("cannot execute query from {query_path:?}: {err:?}"));
+    let (diagnostics, results): (Vec<_>, Vec<_>) = results
+        .into_iter()
+        .partition(|result| matches!(result, GritQueryResult::Diagnostic(_)));
     let snapshot_result = SnapshotResult::from_query_results(results);
 
-    let snapshot = if logs.is_empty() {
+    let mut snapshot = if logs.is_empty() {
         format!("{snapshot_result:#?}")
     } else {
         let logs = logs
@@ -89,6 +92,18 @@ fn run_test(input: &'static str, _: &str, _: &str, _: &str) {
         format!("{snapshot_result:#?}\n\n## Logs\n\n{logs}")
     };
 
+    if !diagnostics.is_empty() {
+        let diagnostics = diagnostics
+            .iter()
+            .filter_map(|result| match result {
+                GritQueryResult::Diagnostic(diagnostic) => Some(format!("{diagnostic:#?}")),
+                _ => None,
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        snapshot.push_str(&format!("\n\n## Diagnostics\n\n{diagnostics}"));
+    }
+
     insta::with_settings!({
         prepend_module_to_snapshot => false,
         snapshot_path => query_path.parent().unwrap(),
@@ -189,6 +204,7 @@ impl SnapshotResult {
                     }
                     snapshot_result.created_files.push(create_file.rewritten);
                 }
+                GritQueryResult::Diagnostic(_) => {}
             }
         }
 
@@ -0,0 +1,2 @@
+console.log("hello");
+console.warn("careful");
@@ -1,5 +1,6 @@
-use std::{fmt, rc::Rc};
+use std::{fmt, rc::Rc, str::FromStr};
 
+use biome_deserialize_macros::{Deserializable, Merge};
 use biome_formatter::{
     printer::PrinterOptions, AttributePosition, BracketSpacing, CstFormatContext, FormatContext,
     FormatOptions, IndentStyle, IndentWidth, LineEnding, LineWidth, TransformSourceMap,
@@ -24,6 +25,15 @@ pub struct HtmlFormatOptions {
 
     /// Attribute position style. By default auto.
     attribute_position: AttributePosition,
+
+    /// How the whitespace inside and around elements is handled. By default css.
+    whitespace_sensitivity: WhitespaceSensitivity,
+
+    /// Whether the content of `<script>` and `<style>` tags is indented. By default false.
+    indent_script_and_style: IndentScriptAndStyle,
+
+    /// Whether void elements are self-closed, e.g. `<br />`. By default always.
+    self_close_void_elements: SelfCloseVoidElements,
 }
 
 impl HtmlFormatOptions {
@@ -58,6 +68,30 @@ impl HtmlFormatOptions {
         self
     }
 
+    pub fn with_whitespace_sensitivity(
+        mut self,
+        whitespace_sensitivity: WhitespaceSensitivity,
+    ) -> Self {
+        self.whitespace_sensitivity = whitespace_sensitivity;
+        self
+    }
+
+    pub fn with_indent_script_and_style(
+        mut self,
+        indent_script_and_style: IndentScriptAndStyle,
+    ) -> Self {
+        self.indent_script_and_style = indent_script_and_style;
+        self
+    }
+
+    pub fn with_self_close_void_elements(
+        mut self,
+        self_close_void_elements: SelfCloseVoidElements,
+    ) -> Self {
+        self.self_close_void_elements = self_close_void_elements;
+        self
+    }
+
     pub fn indent_style(&self) -> IndentStyle {
         self.indent_style
     }
@@ -78,6 +112,18 @@ impl HtmlFormatOptions {
         self.attribute_position
     }
 
+    pub fn whitespace_sensitivity(&self) -> WhitespaceSensitivity {
+        self.whitespace_sensitivity
+    }
+
+    pub fn indent_script_and_style(&self) -> IndentScriptAndStyle {
+        self.indent_script_and_style
+    }
+
+    pub fn self_close_void_elements(&self) -> SelfCloseVoidElements {
+        self.self_close_void_elements
+    }
+
     pub fn set_indent_style(&mut self, indent_style: IndentStyle) {
         self.indent_style = indent_style;
     }
@@ -97,6 +143,21 @@ impl HtmlFormatOptions {
     pub fn set_attribute_position(&mut self, attribute_position: AttributePosition) {
         self.attribute_position = attribute_position;
     }
+
+    pub fn set_whitespace_sensitivity(&mut self, whitespace_sensitivity: WhitespaceSensitivity) {
+        self.whitespace_sensitivity = whitespace_sensitivity;
+    }
+
+    pub fn set_indent_script_and_style(&mut self, indent_script_and_style: IndentScriptAndStyle) {
+        self.indent_script_and_style = indent_script_and_style;
+    }
+
+    pub fn set_self_close_void_elements(
+        &mut self,
+        self_close_void_elements: SelfCloseVoidElements,
+    ) {
+        self.self_close_void_elements = self_close_void_elements;
+    }
 }
 
 impl fmt::Display for HtmlFormatOptions {
@@ -105,7 +166,143 @@ impl fmt::Display for HtmlFormatOptions {
         writeln!(f, "Indent width: {}", self.indent_width.value())?;
         writeln!(f, "Line ending: {}", self.line_ending)?;
         writeln!(f, "Line width: {}", self.line_width.value())?;
-        writeln!(f, "Attribute Position: {}", self.attribute_position)
+        writeln!(f, "Attribute Position: {}", self.attribute_position)?;
+        writeln!(f, "Whitespace sensitivity: {}", self.whitespace_sensitivity)?;
+        writeln!(
+            f,
+            "Indent script and style: {}",
+            self.indent_script_and_style.value()
+        )?;
+        writeln!(
+            f,
+            "Self close void elements: {}",
+            self.self_close_void_elements
+        )
+    }
+}
+
+/// Whitespace sensitivity for HTML formatting.
+///
+/// The formatter may add or remove whitespace between elements and text only where it doesn't
+/// change how the document is rendered.
+#[derive(Clone, Copy, Debug, Default, Deserializable, Eq, Hash, Merge, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize, schemars::JsonSchema),
+    serde(rename_all = "camelCase")
+)]
+pub enum WhitespaceSensitivity {
+    /// The whitespace around inline elements, such as `<span>` or `<a>`, is significant, while
+    /// the whitespace around the other elements isn't.
+    #[default]
+    Css,
+    /// The whitespace around all the elements is significant.
+    Strict,
+    /// The whitespace around all the elements is insignificant.
+    Ignore,
+}
+
+impl WhitespaceSensitivity {
+    pub const fn is_css(&self) -> bool {
+        matches!(self, Self::Css)
+    }
+
+    pub const fn is_strict(&self) -> bool {
+        matches!(self, Self::Strict)
+    }
+
+    pub const fn is_ignore(&self) -> bool {
+        matches!(self, Self::Ignore)
+    }
+}
+
+impl FromStr for WhitespaceSensitivity {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "css" | "Css" => Ok(Self::Css),
+            "strict" | "Strict" => Ok(Self::Strict),
+            "ignore" | "Ignore" => Ok(Self::Ignore),
+            _ => Err("Value not supported for WhitespaceSensitivity. Supported values are 'css', 'strict' and 'ignore'."),
+        }
+    }
+}
+
+impl fmt::Display for WhitespaceSensitivity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WhitespaceSensitivity::Css => write!(f, "Css"),
+            WhitespaceSensitivity::Strict => write!(f, "Strict"),
+            WhitespaceSensitivity::Ignore => write!(f, "Ignore"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Merge, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize, schemars::JsonSchema),
+    serde(rename_all = "camelCase")
+)]
+pub struct IndentScriptAndStyle(bool);
+
+impl IndentScriptAndStyle {
+    /// Return the boolean value for this [IndentScriptAndStyle]
+    pub fn value(&self) -> bool {
+        self.0
+    }
+}
+
+impl From<bool> for IndentScriptAndStyle {
+    fn from(value: bool) -> Self {
+        Self(value)
+    }
+}
+
+/// Controls whether void elements, such as `<br>` or `<img>`, are self-closed.
+#[derive(Clone, Copy, Debug, Default, Deserializable, Eq, Hash, Merge, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize, schemars::JsonSchema),
+    serde(rename_all = "camelCase")
+)]
+pub enum SelfCloseVoidElements {
+    /// The `/` is added to void elements: `<br />`.
+    #[default]
+    Always,
+    /// The `/` is removed from void elements: `<br>`.
+    Never,
+}
+
+impl SelfCloseVoidElements {
+    pub const fn is_always(&self) -> bool {
+        matches!(self, Self::Always)
+    }
+
+    pub const fn is_never(&self) -> bool {
+        matches!(self, Self::Never)
+    }
+}
+
+impl FromStr for SelfCloseVoidElements {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "always" | "Always" => Ok(Self::Always),
+            "never" | "Never" => Ok(Self::Never),
+            _ => Err("Value not supported for SelfCloseVoidElements. Supported values are 'always' and 'never'."),
+        }
+    }
+}
+
+impl fmt::Display for SelfCloseVoidElements {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SelfCloseVoidElements::Always => write!(f, "Always"),
+            SelfCloseVoidElements::Never => write!(f, "Never"),
+        }
     }
 }
 
@@ -1,6 +1,9 @@
 use crate::prelude::*;
-use biome_formatter::write;
-use biome_html_syntax::{HtmlElement, HtmlElementFields};
+use biome_formatter::{format_args, write, CstFormatContext};
+use biome_html_syntax::{
+    HtmlContent, HtmlElement, HtmlElementFields, HtmlElementList, HtmlSyntaxToken,
+};
+use biome_rowan::TextSize;
 #[derive(Debug, Clone, Default)]
 pub(crate) struct FormatHtmlElement;
 impl FormatNodeRule<HtmlElement> for FormatHtmlElement {
@@ -11,19 +14,33 @@ impl FormatNodeRule<HtmlElement> for FormatHtmlElement {
             closing_element,
         } = node.as_fields();
 
-        let tag_name = opening_element
-            .clone()
-            .and_then(|e| e.name())
-            .map(|e| e.text())
-            .unwrap_or_default();
+        let tag_name = node.tag_name();
+        let tag_name = tag_name.as_ref().map_or("", |name| name.text());
         // `pre` tags are "preformatted", so we should not format the content inside them. https://developer.mozilla.org/en-US/docs/Web/HTML/Element/pre
-        // We ignore the `script` and `style` tags as well, since embedded language parsing/formatting is not yet implemented.
-        let should_be_verbatim = ["script", "style", "pre"]
+        let is_pre = tag_name.eq_ignore_ascii_case("pre");
+        // The content of `script` and `style` tags is kept verbatim, since embedded language parsing/formatting is not yet implemented.
+        // It's only re-indented when `indentScriptAndStyle` is enabled.
+        let is_script_or_style = ["script", "style"]
             .iter()
             .any(|tag| tag_name.eq_ignore_ascii_case(tag));
 
         write!(f, [opening_element.format()])?;
-        if should_be_verbatim {
+        if let Some((content, token)) = is_script_or_style
+            .then(|| embedded_content(&children))
+            .flatten()
+        {
+            // You can't suppress the content of an embedded language
+            f.context()
+                .comments()
+                .mark_suppression_checked(content.syntax());
+            write!(
+                f,
+                [FormatEmbeddedContent {
+                    token: &token,
+                    indent: f.options().indent_script_and_style().value(),
+                }]
+            )?;
+        } else if is_pre || is_script_or_style {
             format_verbatim_skipped(children.syntax()).fmt(f)?;
             write!(f, [hard_line_break()])?;
         } else {
@@ -34,3 +51,157 @@ impl FormatNodeRule<HtmlElement> for FormatHtmlElement {
         Ok(())
     }
 }
+
+/// Returns the node holding the raw content of a `script` or `style` element, and its token.
+fn embedded_content(children: &HtmlElementList) -> Option<(HtmlContent, HtmlSyntaxToken)> {
+    let mut children = children.iter();
+    let content = children.next()?.as_html_content()?.clone();
+    if children.next().is_some() {
+        return None;
+    }
+    let token = content.value_token().ok()?;
+    Some((content, token))
+}
+
+/// Formats the raw content of a `script` or `style` element.
+///
+/// The content is kept as is, apart from the whitespace before the closing tag. When
+/// `indentScriptAndStyle` is enabled, the indentation that is common to all the lines is replaced
+/// by one level of indentation. The lines that start inside a template literal are never
+/// re-indented, because their indentation is part of the string.
+struct FormatEmbeddedContent<'a> {
+    token: &'a HtmlSyntaxToken,
+    indent: bool,
+}
+
+impl Format<HtmlFormatContext> for FormatEmbeddedContent<'_> {
+    fn fmt(&self, f: &mut Formatter<HtmlFormatContext>) -> FormatResult<()> {
+        let text = self.token.text_trimmed();
+        let start = self.token.text_trimmed_range().start();
+
+        let mut lines = Vec::new();
+        let mut offset = 0;
+        let mut is_in_template = false;
+        for line in text.split('\n') {
+            lines.push(EmbeddedLine {
+                text: line.strip_suffix('\r').unwrap_or(line),
+                offset,
+                is_in_template,
+            });
+            offset += line.len() + 1;
+            is_in_template ^= count_template_delimiters(line) % 2 == 1;
+        }
+        while lines
+            .last()
+            .is_some_and(|line| line.is_blank() && !line.is_in_template)
+        {
+            lines.pop();
+        }
+
+        if !self.indent {
+            if lines.iter().all(EmbeddedLine::is_blank) {
+                return write!(f, [format_removed(self.token)]);
+            }
+            let content = lines
+                .iter()
+                .map(|line| line.text)
+                .collect::<Vec<_>>()
+                .join("\n");
+            return write!(
+                f,
+                [format_replaced(
+                    self.token,
+                    &format_args![dynamic_text(&content, start), hard_line_break()]
+                )]
+            );
+        }
+
+        let first_line = lines
+            .iter()
+            .position(|line| !line.is_blank())
+            .unwrap_or(lines.len());
+        let lines = &lines[first_line..];
+
+        if lines.is_empty() {
+            return write!(f, [format_removed(self.token)]);
+        }
+
+        let indentation = lines
+            .iter()
+            .filter(|line| !line.is_blank() && !line.is_in_template)
+            .map(|line| {
+                line.text
+                    .bytes()
+                    .take_while(|byte| matches!(byte, b' ' | b'\t'))
+                    .count()
+            })
+            .min()
+            .unwrap_or_default();
+
+        let content = format_with(|f| {
+            let mut has_empty_line = false;
+            let mut index = 0;
+            while index < lines.len() {
+                let line = &lines[index];
+                index += 1;
+                if line.is_blank() {
+                    has_empty_line = true;
+                    continue;
+                }
+                if index > 1 {
+                    if has_empty_line {
+                        write!(f, [empty_line()])?;
+                    } else {
+                        write!(f, [hard_line_break()])?;
+                    }
+                }
+                has_empty_line = false;
+                // The lines inside a template literal are printed with the line that opens it
+                let mut text = line.text[indentation..].to_string();
+                while let Some(next_line) = lines.get(index).filter(|line| line.is_in_template) {
+                    text.push('\n');
+                    text.push_str(next_line.text);
+                    index += 1;
+                }
+                let position = start + TextSize::from((line.offset + indentation) as u32);
+                write!(f, [dynamic_text(&text, position)])?;
+            }
+            Ok(())
+        });
+
+        write!(f, [format_replaced(self.token, &block_indent(&content))])
+    }
+}
+
+/// A line of the content of a `script` or `style` element
+struct EmbeddedLine<'a> {
+    text: &'a str,
+    /// The offset of the line in the content
+    offset: usize,
+    /// Whether the line starts inside a template literal
+    is_in_template: bool,
+}
+
+impl EmbeddedLine<'_> {
+    fn is_blank(&self) -> bool {
+        self.text.trim().is_empty()
+    }
+}
+
+/// Counts the backticks of the line that aren't escaped
+fn count_template_delimiters(line: &str) -> usize {
+    let mut count = 0;
+    let mut is_escaped = false;
+    for byte in line.bytes() {
+        match byte {
+            b'\\' => {
+                is_escaped = !is_escaped;
+                continue;
+            }
+            b'`' if !is_escaped => count += 1,
+            _ => {}
+        }
+        is_escaped = false;
+    }
+    count
+}
@@ -13,6 +13,21 @@ impl FormatNodeRule<HtmlSelfClosingElement> for FormatHtmlSelfClosingElement {
             r_angle_token,
         } = node.as_fields();
 
+        // Only void elements, such as `<br>`, can omit the `/`
+        let should_self_close =
+            !node.is_void_element() || f.options().self_close_void_elements().is_always();
+
+        if !should_self_close {
+            write!(f, [l_angle_token.format(), name.format()])?;
+            if attributes.len() > 0 {
+                write!(f, [space(), attributes.format()])?;
+            }
+            if let Some(slash_token) = slash_token {
+                write!(f, [format_removed(&slash_token)])?;
+            }
+            return write!(f, [r_angle_token.format()]);
+        }
+
         write!(
             f,
             [
@@ -4,6 +4,7 @@ use std::cell::RefCell;
 
 use crate::{
     comments::HtmlComments,
+    context::WhitespaceSensitivity,
     prelude::*,
     utils::children::{
         html_split_children, is_meaningful_html_text, HtmlChild, HtmlChildrenIterator, HtmlSpace,
@@ -87,6 +88,7 @@ impl FormatHtmlElementList {
         let mut multiline = MultilineBuilder::new(multiline_layout, is_root_parent);
 
         let mut force_multiline = layout.is_multiline();
+        let whitespace_sensitivity = f.options().whitespace_sensitivity();
 
         let mut children = html_split_children(list.iter(), f.context().comments())?;
 
@@ -115,12 +117,19 @@ impl FormatHtmlElementList {
                         }
 
                         // Last word or last word before an element without any whitespace in between
-                        Some(HtmlChild::NonText(next_child)) => Some(WordSeparator::EndOfText {
-                            is_soft_line_break: !matches!(
-                                next_child,
-                                AnyHtmlElement::HtmlSelfClosingElement(_)
-                            ) || word.is_single_character(),
-                        }),
+                        Some(HtmlChild::NonText(next_child))
+                            if can_break_next_to(next_child, whitespace_sensitivity) =>
+                        {
+                            Some(WordSeparator::EndOfText {
+                                is_soft_line_break: !matches!(
+                                    next_child,
+                                    AnyHtmlElement::HtmlSelfClosingElement(_)
+                                ) || word.is_single_character(),
+                            })
+                        }
+
+                        // Adding a line break would add whitespace that is rendered
+                        Some(HtmlChild::NonText(_)) => None,
 
                         Some(HtmlChild::Newline | HtmlChild::Whitespace | HtmlChild::EmptyLine) => {
                             None
@@ -222,6 +231,19 @@ impl FormatHtmlElementList {
                 // Any child that isn't text
                 HtmlChild::NonText(non_text) => {
                     let line_mode = match children_iter.peek() {
+                        // Adding a line break would add whitespace that is rendered
+                        Some(HtmlChild::Word(_))
+                            if !can_break_next_to(non_text, whitespace_sensitivity) =>
+                        {
+                            None
+                        }
+                        Some(HtmlChild::NonText(next_non_text))
+                            if !can_break_next_to(non_text, whitespace_sensitivity)
+                                || !can_break_next_to(next_non_text, whitespace_sensitivity) =>
+                        {
+                            None
+                        }
+
                         Some(HtmlChild::Word(word)) => {
                             // Break if the current or next element is a self closing element
                             // ```javascript
@@ -368,6 +390,19 @@ impl FormatHtmlElementList {
     }
 }
 
+/// Whether a line break can be inserted between `element` and a sibling that isn't separated
+/// from it by any whitespace.
+fn can_break_next_to(
+    element: &AnyHtmlElement,
+    whitespace_sensitivity: WhitespaceSensitivity,
+) -> bool {
+    match whitespace_sensitivity {
+        WhitespaceSensitivity::Css => !element.is_inline_element(),
+        WhitespaceSensitivity::Strict => false,
+        WhitespaceSensitivity::Ignore => true,
+    }
+}
+
 #[derive(Debug, Default, Copy, Clone)]
 pub enum HtmlChildListLayout {
     /// Prefers to format the children on a single line if possible.
@@ -35,10 +35,11 @@ impl TestFormatLanguage for HtmlTestFormatLanguage {
         settings: &Settings,
         file_source: &DocumentFileSource,
     ) -> Self::FormatLanguage {
+        let language_settings = &settings.languages.html.formatter;
         let options = Self::ServiceLanguage::resolve_format_options(
             Some(&settings.formatter),
             Some(&settings.override_settings),
-            None,
+            Some(language_settings),
             &BiomePath::new(""),
             file_source,
         );
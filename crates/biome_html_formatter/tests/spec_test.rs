@@ -1,6 +1,8 @@
+use biome_configuration::{PartialConfiguration, PartialHtmlConfiguration, PartialHtmlFormatter};
 use biome_formatter_test::spec::{SpecSnapshot, SpecTestFile};
 use biome_html_formatter::{context::HtmlFormatOptions, HtmlFormatLanguage};
 use biome_html_syntax::HtmlFileSource;
+use biome_service::workspace::UpdateSettingsParams;
 use std::path::Path;
 
 mod language {
@@ -26,8 +28,27 @@ mod language {
 /// * `null` -> input: `tests/specs/null.json`, expected output: `tests/specs/null.json.snap`
 pub fn run(spec_input_file: &str, _expected_file: &str, test_directory: &str, _file_type: &str) {
     let root_path = Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/specs/html"));
+    let settings = UpdateSettingsParams {
+        configuration: PartialConfiguration {
+            html: Some(PartialHtmlConfiguration {
+                formatter: Some(PartialHtmlFormatter {
+                    enabled: Some(true),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }),
+            ..Default::default()
+        },
+        vcs_base_path: None,
+        gitignore_matches: vec![],
+        workspace_directory: None,
+        nested: false,
+        ignore_files: vec![],
+        plugins: vec![],
+    };
 
-    let Some(test_file) = SpecTestFile::try_from_file(spec_input_file, root_path, None) else {
+    let Some(test_file) = SpecTestFile::try_from_file(spec_input_file, root_path, Some(settings))
+    else {
         panic!("Failed to set up snapshot test");
     };
 
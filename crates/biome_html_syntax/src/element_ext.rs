@@ -0,0 +1,73 @@
+use crate::{AnyHtmlElement, HtmlElement, HtmlSelfClosingElement};
+use biome_rowan::TokenText;
+
+/// These elements are effectively always self-closing. They should not have a closing tag (if they do, it should be a parsing error). They might not contain a `/` like in `<img />`.
+pub static VOID_ELEMENTS: &[&str] = &[
+    "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "param", "source",
+    "track", "wbr",
+];
+
+/// These elements are displayed inline by default, so the whitespace around them is rendered.
+/// https://developer.mozilla.org/en-US/docs/Glossary/Inline-level_content
+static INLINE_ELEMENTS: &[&str] = &[
+    "a", "abbr", "acronym", "audio", "b", "bdi", "bdo", "big", "br", "button", "canvas", "cite",
+    "code", "data", "del", "dfn", "em", "embed", "font", "i", "iframe", "img", "input", "ins",
+    "kbd", "label", "map", "mark", "meter", "object", "output", "picture", "q", "ruby", "s",
+    "samp", "select", "small", "span", "strike", "strong", "sub", "sup", "svg", "textarea", "time",
+    "tt", "u", "var", "video", "wbr",
+];
+
+impl HtmlElement {
+    /// Returns the name of the element, e.g. `div` for `<div></div>`
+    pub fn tag_name(&self) -> Option<TokenText> {
+        self.opening_element()
+            .ok()?
+            .name()
+            .ok()?
+            .value_token()
+            .ok()
+            .map(|token| token.token_text_trimmed())
+    }
+}
+
+impl HtmlSelfClosingElement {
+    /// Returns the name of the element, e.g. `img` for `<img />`
+    pub fn tag_name(&self) -> Option<TokenText> {
+        self.name()
+            .ok()?
+            .value_token()
+            .ok()
+            .map(|token| token.token_text_trimmed())
+    }
+
+    /// Whether the element is a void element, e.g. `<br>`, which can't have children.
+    pub fn is_void_element(&self) -> bool {
+        self.tag_name().is_some_and(|name| {
+            VOID_ELEMENTS
+                .iter()
+                .any(|tag| tag.eq_ignore_ascii_case(name.text()))
+        })
+    }
+}
+
+impl AnyHtmlElement {
+    /// Returns the name of the element, or [None] if the node isn't an element.
+    pub fn tag_name(&self) -> Option<TokenText> {
+        match self {
+            AnyHtmlElement::HtmlElement(element) => element.tag_name(),
+            AnyHtmlElement::HtmlSelfClosingElement(element) => element.tag_name(),
+            AnyHtmlElement::HtmlBogusElement(_)
+            | AnyHtmlElement::HtmlComment(_)
+            | AnyHtmlElement::HtmlContent(_) => None,
+        }
+    }
+
+    /// Whether the node is an element displayed inline by default, e.g. `<span>`.
+    pub fn is_inline_element(&self) -> bool {
+        self.tag_name().is_some_and(|name| {
+            INLINE_ELEMENTS
+                .iter()
+                .any(|tag| tag.eq_ignore_ascii_case(name.text()))
+        })
+    }
+}
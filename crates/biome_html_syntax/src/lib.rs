@@ -1,10 +1,12 @@
 #[macro_use]
 mod generated;
+mod element_ext;
 mod file_source;
 mod syntax_node;
 
 pub use self::generated::*;
 pub use biome_rowan::{TextLen, TextRange, TextSize, TokenAtOffset, TriviaPieceKind, WalkEvent};
+pub use element_ext::VOID_ELEMENTS;
 pub use file_source::HtmlFileSource;
 pub use syntax_node::*;
 
@@ -2,14 +2,15 @@
 
 use crate::suppression_action::JsSuppressionAction;
 use biome_analyze::{
-    AnalysisFilter, Analyzer, AnalyzerContext, AnalyzerOptions, AnalyzerSignal, ControlFlow,
-    InspectMatcher, LanguageRoot, MatchQueryParams, MetadataRegistry, RuleAction, RuleRegistry,
+    AnalysisFilter, Analyzer, AnalyzerContext, AnalyzerOptions, AnalyzerSignal,
+    AnalyzerSuppression, AnalyzerSuppressionVariant, ControlFlow, InspectMatcher, LanguageRoot,
+    MatchQueryParams, MetadataRegistry, Phases, PluginVisitor, RuleAction, RuleRegistry,
     SuppressionKind,
 };
 use biome_aria::AriaRoles;
 use biome_diagnostics::{category, Error as DiagnosticError};
 use biome_js_syntax::{JsFileSource, JsLanguage};
-use biome_project::PackageJson;
+use biome_project::{ModuleGraph, PackageJson};
 use biome_suppression::{parse_suppression_comment, SuppressionDiagnostic};
 use std::ops::Deref;
 use std::sync::{Arc, LazyLock};
@@ -43,6 +44,7 @@ pub static METADATA: LazyLock<MetadataRegistry> = LazyLock::new(|| {
 /// Additionally, this function takes a `inspect_matcher` function that can be
 /// used to inspect the "query matches" emitted by the analyzer before they are
 /// processed by the lint rules registry
+#[expect(clippy::too_many_arguments)]
 pub fn analyze_with_inspect_matcher<'a, V, F, B>(
     root: &LanguageRoot<JsLanguage>,
     filter: AnalysisFilter,
@@ -50,6 +52,7 @@ pub fn analyze_with_inspect_matcher<'a, V, F, B>(
     options: &'a AnalyzerOptions,
     source_type: JsFileSource,
     manifest: Option<PackageJson>,
+    module_graph: Arc<ModuleGraph>,
     mut emit_signal: F,
 ) -> (Option<B>, Vec<DiagnosticError>)
 where
@@ -59,16 +62,21 @@ where
 {
     fn parse_linter_suppression_comment(
         text: &str,
-    ) -> Vec<Result<SuppressionKind, SuppressionDiagnostic>> {
+    ) -> Vec<Result<AnalyzerSuppression, SuppressionDiagnostic>> {
         let mut result = Vec::new();
 
         for comment in parse_suppression_comment(text) {
-            let categories = match comment {
+            let (categories, variant, reason) = match comment {
                 Ok(comment) => {
+                    let variant = AnalyzerSuppressionVariant::from(comment.kind);
                     if comment.is_legacy {
-                        result.push(Ok(SuppressionKind::Deprecated));
+                        result.push(Ok(AnalyzerSuppression::new(
+                            SuppressionKind::Deprecated,
+                            variant,
+                        )
+                        .with_reason(comment.reason)));
                     }
-                    comment.categories
+                    (comment.categories, variant, comment.reason)
                 }
                 Err(err) => {
                     result.push(Err(err));
@@ -79,17 +87,33 @@ where
             for (key, value) in categories {
                 if key == category!("lint") {
                     if let Some(value) = value {
-                        result.push(Ok(SuppressionKind::MaybeLegacy(value)));
+                        result.push(Ok(AnalyzerSuppression::new(
+                            SuppressionKind::MaybeLegacy(value),
+                            variant,
+                        )
+                        .with_reason(reason)));
                     } else {
-                        result.push(Ok(SuppressionKind::Everything));
+                        result.push(Ok(AnalyzerSuppression::new(
+                            SuppressionKind::Everything,
+                            variant,
+                        )
+                        .with_reason(reason)));
                     }
                 } else {
                     let category = key.name();
                     if let Some(rule) = category.strip_prefix("lint/") {
                         if let Some(instance) = value {
-                            result.push(Ok(SuppressionKind::RuleInstance(rule, instance)));
+                            result.push(Ok(AnalyzerSuppression::new(
+                                SuppressionKind::RuleInstance(rule, instance),
+                                variant,
+                            )
+                            .with_reason(reason)));
                         } else {
-                            result.push(Ok(SuppressionKind::Rule(rule)));
+                            result.push(Ok(AnalyzerSuppression::new(
+                                SuppressionKind::Rule(rule),
+                                variant,
+                            )
+                            .with_reason(reason)));
                         }
                     }
                 }
@@ -121,8 +145,18 @@ where
         analyzer.add_visitor(phase, visitor);
     }
 
+    if filter.match_plugins() {
+        for plugin in &options.configuration.plugins {
+            analyzer.add_visitor(
+                Phases::Syntax,
+                Box::new(PluginVisitor::new(Arc::clone(plugin))),
+            );
+        }
+    }
+
     services.insert_service(Arc::new(AriaRoles));
     services.insert_service(Arc::new(manifest));
+    services.insert_service(module_graph);
     services.insert_service(source_type);
     (
         analyzer.run(AnalyzerContext {
@@ -144,6 +178,7 @@ pub fn analyze<'a, F, B>(
     options: &'a AnalyzerOptions,
     source_type: JsFileSource,
     manifest: Option<PackageJson>,
+    module_graph: Arc<ModuleGraph>,
     emit_signal: F,
 ) -> (Option<B>, Vec<DiagnosticError>)
 where
@@ -157,6 +192,7 @@ where
         options,
         source_type,
         manifest,
+        module_graph,
         emit_signal,
     )
 }
@@ -210,6 +246,7 @@ mod tests {
                 dependencies,
                 ..Default::default()
             }),
+            Default::default(),
             |signal| {
                 if let Some(diag) = signal.diagnostic() {
                     error_ranges.push(diag.location().span.unwrap());
@@ -302,6 +339,7 @@ mod tests {
             &options,
             JsFileSource::js_module(),
             None,
+            Default::default(),
             |signal| {
                 if let Some(diag) = signal.diagnostic() {
                     let span = diag.get_span();
@@ -363,6 +401,53 @@ mod tests {
         );
     }
 
+    #[test]
+    fn too_many_suppressions_outside_of_the_range() {
+        const SOURCE: &str =
+            "// biome-ignore lint/suspicious/noDoubleEquals: the values have different types
+a == b;
+// biome-ignore lint/suspicious/noDoubleEquals: the values have different types
+a == b;
+";
+
+        let parsed = parse(
+            SOURCE,
+            JsFileSource::js_module(),
+            JsParserOptions::default(),
+        );
+
+        let filter = AnalysisFilter {
+            range: Some(TextRange::new(TextSize::from(0), TextSize::from(87))),
+            ..AnalysisFilter::default()
+        };
+        let mut options = AnalyzerOptions::default();
+        options.configuration.suppression_policy.max_per_file = Some(1);
+
+        let mut too_many_ranges = Vec::new();
+        analyze(
+            &parsed.tree(),
+            filter,
+            &options,
+            JsFileSource::js_module(),
+            None,
+            Default::default(),
+            |signal| {
+                if let Some(diag) = signal.diagnostic() {
+                    if diag.category() == Some(category!("suppressions/tooMany")) {
+                        too_many_ranges.push(diag.get_span().unwrap());
+                    }
+                }
+
+                ControlFlow::<Never>::Continue(())
+            },
+        );
+
+        assert_eq!(
+            too_many_ranges.as_slice(),
+            &[TextRange::new(TextSize::from(88), TextSize::from(167))]
+        );
+    }
+
     #[test]
     fn suppression_syntax() {
         const SOURCE: &str = "
@@ -388,6 +473,7 @@ mod tests {
             &options,
             JsFileSource::js_module(),
             None,
+            Default::default(),
             |signal| {
                 if let Some(diag) = signal.diagnostic() {
                     let code = diag.category().unwrap();
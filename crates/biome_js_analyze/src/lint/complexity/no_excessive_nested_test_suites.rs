@@ -1,6 +1,6 @@
 use biome_analyze::{
     context::RuleContext, declare_lint_rule, AddVisitor, Phases, QueryMatch, Queryable, Rule,
-    RuleDiagnostic, RuleSource, RuleSourceKind, ServiceBag, Visitor, VisitorContext,
+    RuleDiagnostic, RuleDomain, RuleSource, RuleSourceKind, ServiceBag, Visitor, VisitorContext,
 };
 use biome_console::markup;
 use biome_js_syntax::{JsCallExpression, JsLanguage, JsStaticMemberExpression};
@@ -58,6 +58,7 @@ declare_lint_rule! {
         recommended: true,
         sources: &[RuleSource::EslintJest("max-nested-describe")],
         source_kind: RuleSourceKind::SameLogic,
+        domains: &[RuleDomain::Test],
     }
 }
 
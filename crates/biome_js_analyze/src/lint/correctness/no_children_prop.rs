@@ -1,7 +1,7 @@
 use crate::react::{ReactApiCall, ReactCreateElementCall};
 use crate::services::semantic::Semantic;
 use biome_analyze::context::RuleContext;
-use biome_analyze::{declare_lint_rule, Rule, RuleDiagnostic, RuleSource};
+use biome_analyze::{declare_lint_rule, Rule, RuleDiagnostic, RuleDomain, RuleSource};
 use biome_console::markup;
 use biome_js_syntax::{JsCallExpression, JsxAttribute};
 use biome_rowan::{declare_node_union, AstNode, TextRange};
@@ -28,6 +28,7 @@ declare_lint_rule! {
         language: "jsx",
         sources: &[RuleSource::EslintReact("no-children-prop")],
         recommended: true,
+        domains: &[RuleDomain::React],
     }
 }
 
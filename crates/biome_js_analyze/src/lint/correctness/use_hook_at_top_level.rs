@@ -3,8 +3,8 @@ use crate::services::semantic::{SemanticModelBuilderVisitor, SemanticServices};
 use biome_analyze::RuleSource;
 use biome_analyze::{
     context::RuleContext, declare_lint_rule, AddVisitor, FromServices, MissingServicesDiagnostic,
-    Phase, Phases, QueryMatch, Queryable, Rule, RuleDiagnostic, RuleKey, ServiceBag, Visitor,
-    VisitorContext, VisitorFinishContext,
+    Phase, Phases, QueryMatch, Queryable, Rule, RuleDiagnostic, RuleDomain, RuleKey, ServiceBag,
+    Visitor, VisitorContext, VisitorFinishContext,
 };
 use biome_console::markup;
 use biome_deserialize::{
@@ -70,6 +70,7 @@ declare_lint_rule! {
         language: "jsx",
         sources: &[RuleSource::EslintReactHooks("rules-of-hooks")],
         recommended: false,
+        domains: &[RuleDomain::React],
     }
 }
 
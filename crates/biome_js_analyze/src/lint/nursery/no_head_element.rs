@@ -1,6 +1,6 @@
 use biome_analyze::RuleSourceKind;
 use biome_analyze::{
-    context::RuleContext, declare_lint_rule, Ast, Rule, RuleDiagnostic, RuleSource,
+    context::RuleContext, declare_lint_rule, Ast, Rule, RuleDiagnostic, RuleDomain, RuleSource,
 };
 use biome_console::markup;
 use biome_js_syntax::JsxOpeningElement;
@@ -50,6 +50,7 @@ declare_lint_rule! {
         sources: &[RuleSource::EslintNext("no-head-element")],
         source_kind: RuleSourceKind::SameLogic,
         recommended: false,
+        domains: &[RuleDomain::Next],
     }
 }
 
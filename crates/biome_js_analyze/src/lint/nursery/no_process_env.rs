@@ -1,4 +1,6 @@
-use biome_analyze::{context::RuleContext, declare_lint_rule, Rule, RuleDiagnostic, RuleSource};
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, Rule, RuleDiagnostic, RuleDomain, RuleSource,
+};
 use biome_console::markup;
 use biome_js_syntax::{global_identifier, JsStaticMemberExpression};
 use biome_rowan::AstNode;
@@ -41,6 +43,7 @@ declare_lint_rule! {
         language: "js",
         sources: &[RuleSource::EslintN("no-process-env")],
         recommended: false,
+        domains: &[RuleDomain::Node],
     }
 }
 
@@ -0,0 +1,110 @@
+use biome_analyze::{context::RuleContext, declare_lint_rule, Rule, RuleDiagnostic, RuleSource};
+use biome_console::markup;
+use biome_js_syntax::{inner_string_text, AnyJsImportClause, AnyJsImportSpecifier};
+use biome_project::ResolvedImport;
+use biome_rowan::AstNode;
+
+use crate::services::module_graph::ProjectModules;
+
+declare_lint_rule! {
+    /// Disallow importing names that the imported module doesn't export.
+    ///
+    /// The rule looks at the module the import resolves to in the module graph of the project,
+    /// and reports the named and the default imports that this module doesn't export,
+    /// including through its `export * from` declarations.
+    ///
+    /// The imports are resolved the way Node.js and TypeScript do: relative paths,
+    /// the `paths` and the `baseUrl` of `tsconfig.json`, the `exports` and the `main` fields of
+    /// `package.json` and the packages of `node_modules`.
+    /// The imports of modules whose exports can't be known statically,
+    /// such as CommonJS modules, are ignored.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// Given a module `foo.js` that exports `foo`:
+    ///
+    /// ```js,ignore
+    /// import { bar } from "./foo.js";
+    /// ```
+    ///
+    /// ```js,ignore
+    /// import foo from "./foo.js";
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js,ignore
+    /// import { foo } from "./foo.js";
+    /// import * as ns from "./foo.js";
+    /// ```
+    ///
+    pub NoUnresolvedImports {
+        version: "next",
+        name: "noUnresolvedImports",
+        language: "js",
+        sources: &[RuleSource::EslintImport("named")],
+        recommended: false,
+    }
+}
+
+impl Rule for NoUnresolvedImports {
+    type Query = ProjectModules<AnyJsImportSpecifier>;
+    type State = Box<str>;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let specifier = ctx.query();
+        let imported_name: Box<str> = match specifier {
+            AnyJsImportSpecifier::JsNamedImportSpecifier(specifier) => {
+                inner_string_text(&specifier.name().ok()?.value().ok()?)
+                    .text()
+                    .into()
+            }
+            AnyJsImportSpecifier::JsShorthandNamedImportSpecifier(specifier) => specifier
+                .local_name()
+                .ok()?
+                .as_js_identifier_binding()?
+                .name_token()
+                .ok()?
+                .text_trimmed()
+                .into(),
+            AnyJsImportSpecifier::JsDefaultImportSpecifier(_) => "default".into(),
+            AnyJsImportSpecifier::JsNamespaceImportSpecifier(_) => return None,
+        };
+
+        let source = specifier
+            .syntax()
+            .ancestors()
+            .find_map(AnyJsImportClause::cast)?
+            .source()
+            .ok()?
+            .inner_string_text()
+            .ok()?;
+        let ResolvedImport::Module(module_path) =
+            ctx.resolve_import(ctx.file_path(), source.text())?
+        else {
+            return None;
+        };
+
+        (ctx.is_exported(module_path, &imported_name) == Some(false)).then_some(imported_name)
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, imported_name: &Self::State) -> Option<RuleDiagnostic> {
+        let specifier = ctx.query();
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                specifier.range(),
+                markup! {
+                    "The imported module doesn't export "<Emphasis>{imported_name}</Emphasis>"."
+                },
+            )
+            .note(markup! {
+                "Import one of the names that the module exports, or export this name from the module."
+            }),
+        )
+    }
+}
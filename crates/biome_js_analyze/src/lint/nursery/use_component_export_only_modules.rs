@@ -1,5 +1,6 @@
 use biome_analyze::{
-    context::RuleContext, declare_lint_rule, Ast, Rule, RuleDiagnostic, RuleSource, RuleSourceKind,
+    context::RuleContext, declare_lint_rule, Ast, Rule, RuleDiagnostic, RuleDomain, RuleSource,
+    RuleSourceKind,
 };
 use biome_console::markup;
 use biome_deserialize_macros::Deserializable;
@@ -106,6 +107,7 @@ declare_lint_rule! {
         sources: &[RuleSource::EslintReactRefresh("only-export-components")],
         source_kind: RuleSourceKind::Inspired,
         recommended: false,
+        domains: &[RuleDomain::React],
     }
 }
 
@@ -1,5 +1,6 @@
 use biome_analyze::{
-    context::RuleContext, declare_lint_rule, Ast, Rule, RuleDiagnostic, RuleSource, RuleSourceKind,
+    context::RuleContext, declare_lint_rule, Ast, Rule, RuleDiagnostic, RuleDomain, RuleSource,
+    RuleSourceKind,
 };
 use biome_console::markup;
 use biome_js_syntax::jsx_ext::AnyJsxElement;
@@ -55,6 +56,7 @@ declare_lint_rule! {
         sources: &[RuleSource::EslintNext("google-font-display")],
         source_kind: RuleSourceKind::SameLogic,
         recommended: false,
+        domains: &[RuleDomain::Next],
     }
 }
 
@@ -1,5 +1,5 @@
 use biome_analyze::{
-    context::RuleContext, declare_lint_rule, Ast, Rule, RuleDiagnostic, RuleSource,
+    context::RuleContext, declare_lint_rule, Ast, Rule, RuleDiagnostic, RuleDomain, RuleSource,
 };
 use biome_console::markup;
 use biome_js_syntax::{
@@ -49,6 +49,7 @@ declare_lint_rule! {
         language: "js",
         recommended: false,
         sources: &[RuleSource::EslintJest("no-done-callback")],
+        domains: &[RuleDomain::Test],
     }
 }
 
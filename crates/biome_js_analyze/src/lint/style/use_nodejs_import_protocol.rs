@@ -1,5 +1,5 @@
 use biome_analyze::{
-    context::RuleContext, declare_lint_rule, FixKind, Rule, RuleDiagnostic, RuleSource,
+    context::RuleContext, declare_lint_rule, FixKind, Rule, RuleDiagnostic, RuleDomain, RuleSource,
 };
 use biome_console::markup;
 use biome_js_syntax::{inner_string_text, AnyJsImportLike, JsSyntaxKind, JsSyntaxToken};
@@ -54,6 +54,7 @@ declare_lint_rule! {
         sources: &[RuleSource::EslintUnicorn("prefer-node-protocol")],
         recommended: true,
         fix_kind: FixKind::Unsafe,
+        domains: &[RuleDomain::Node],
     }
 }
 
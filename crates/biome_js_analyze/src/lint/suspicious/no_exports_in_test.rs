@@ -1,6 +1,6 @@
 use biome_analyze::{
     context::RuleContext, declare_lint_rule, AddVisitor, Phases, QueryMatch, Queryable, Rule,
-    RuleDiagnostic, RuleSource, RuleSourceKind, ServiceBag, Visitor,
+    RuleDiagnostic, RuleDomain, RuleSource, RuleSourceKind, ServiceBag, Visitor,
 };
 use biome_console::markup;
 use biome_js_syntax::{
@@ -43,6 +43,7 @@ declare_lint_rule! {
         recommended: true,
         sources: &[RuleSource::EslintJest("no-export")],
         source_kind: RuleSourceKind::Inspired,
+        domains: &[RuleDomain::Test],
     }
 }
 
@@ -1,6 +1,7 @@
 use crate::services::semantic::Semantic;
 use biome_analyze::{
-    context::RuleContext, declare_lint_rule, Rule, RuleDiagnostic, RuleSource, RuleSourceKind,
+    context::RuleContext, declare_lint_rule, Rule, RuleDiagnostic, RuleDomain, RuleSource,
+    RuleSourceKind,
 };
 use biome_console::markup;
 use biome_deserialize::TextRange;
@@ -109,6 +110,7 @@ declare_lint_rule! {
         recommended: false,
         sources: &[RuleSource::EslintJest("no-standalone-expect")],
         source_kind: RuleSourceKind::Inspired,
+        domains: &[RuleDomain::Test],
     }
 }
 
@@ -233,6 +233,8 @@ pub type NoUnreachable =
     <lint::correctness::no_unreachable::NoUnreachable as biome_analyze::Rule>::Options;
 pub type NoUnreachableSuper =
     <lint::correctness::no_unreachable_super::NoUnreachableSuper as biome_analyze::Rule>::Options;
+pub type NoUnresolvedImports =
+    <lint::nursery::no_unresolved_imports::NoUnresolvedImports as biome_analyze::Rule>::Options;
 pub type NoUnsafeDeclarationMerging = < lint :: suspicious :: no_unsafe_declaration_merging :: NoUnsafeDeclarationMerging as biome_analyze :: Rule > :: Options ;
 pub type NoUnsafeFinally =
     <lint::correctness::no_unsafe_finally::NoUnsafeFinally as biome_analyze::Rule>::Options;
@@ -0,0 +1,76 @@
+use biome_analyze::{
+    AddVisitor, FromServices, MissingServicesDiagnostic, Phase, Phases, QueryKey, Queryable,
+    RuleKey, ServiceBag, SyntaxVisitor,
+};
+use biome_js_syntax::{AnyJsRoot, JsLanguage, JsSyntaxNode};
+use biome_project::{ModuleGraph, ResolvedImport};
+use biome_rowan::AstNode;
+use std::path::Path;
+use std::sync::Arc;
+
+#[derive(Debug, Clone)]
+pub struct ModuleGraphServices {
+    pub(crate) module_graph: Arc<ModuleGraph>,
+}
+
+impl ModuleGraphServices {
+    /// Returns what the import `specifier` of the module at `path` resolves to, if the module is
+    /// part of the module graph
+    pub(crate) fn resolve_import(&self, path: &Path, specifier: &str) -> Option<&ResolvedImport> {
+        self.module_graph.resolve_import(path, specifier)
+    }
+
+    /// Returns whether the module at `path` exports `name`, or `None` when it isn't known
+    pub(crate) fn is_exported(&self, path: &Path, name: &str) -> Option<bool> {
+        self.module_graph.is_exported(path, name)
+    }
+}
+
+impl FromServices for ModuleGraphServices {
+    fn from_services(
+        rule_key: &RuleKey,
+        services: &ServiceBag,
+    ) -> biome_diagnostics::Result<Self, MissingServicesDiagnostic> {
+        let module_graph: &Arc<ModuleGraph> = services.get_service().ok_or_else(|| {
+            MissingServicesDiagnostic::new(rule_key.rule_name(), &["ModuleGraph"])
+        })?;
+
+        Ok(Self {
+            module_graph: module_graph.clone(),
+        })
+    }
+}
+
+impl Phase for ModuleGraphServices {
+    fn phase() -> Phases {
+        Phases::Project
+    }
+}
+
+/// Query type usable by lint rules **that use the module graph of the project** to match on
+/// specific [AstNode] types
+#[derive(Clone)]
+pub struct ProjectModules<N>(pub N);
+
+impl<N> Queryable for ProjectModules<N>
+where
+    N: AstNode<Language = JsLanguage> + 'static,
+{
+    type Input = JsSyntaxNode;
+    type Output = N;
+
+    type Language = JsLanguage;
+    type Services = ModuleGraphServices;
+
+    fn build_visitor(analyzer: &mut impl AddVisitor<JsLanguage>, _: &AnyJsRoot) {
+        analyzer.add_visitor(Phases::Project, SyntaxVisitor::default);
+    }
+
+    fn key() -> QueryKey<Self::Language> {
+        QueryKey::Syntax(N::KIND_SET)
+    }
+
+    fn unwrap_match(_: &ServiceBag, node: &Self::Input) -> Self::Output {
+        N::unwrap_cast(node.clone())
+    }
+}
@@ -0,0 +1,4 @@
+import { bar } from "./modules/foo.js";
+import { foo, missing } from "./modules/reexports.js";
+// `export * from` doesn't re-export the default export
+import reexported from "./modules/reexports.js";
@@ -0,0 +1 @@
+module.exports = { anything: 1 };
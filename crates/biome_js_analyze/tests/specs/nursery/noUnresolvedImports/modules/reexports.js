@@ -0,0 +1,2 @@
+export * from "./foo.js";
+export { other } from "./other.js";
@@ -0,0 +1,10 @@
+/* should not generate diagnostics */
+import { foo } from "./modules/foo.js";
+import bar from "./modules/foo.js";
+import * as ns from "./modules/foo.js";
+import { foo as renamed, other } from "./modules/reexports.js";
+// The exports of a CommonJS module aren't known
+import { anything } from "./modules/commonjs.cjs";
+// The imports that don't resolve to a module are ignored
+import { readFile } from "node:fs";
+import { missing } from "./modules/missing.js";
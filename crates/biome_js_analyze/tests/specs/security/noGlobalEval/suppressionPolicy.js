@@ -0,0 +1,11 @@
+// biome-ignore lint: legacy code
+eval("a");
+
+// biome-ignore lint: SEC-12 reviewed by the security team
+eval("b");
+
+// biome-ignore lint/security: legacy code
+eval("c");
+
+// biome-ignore lint/security/noGlobalEval: SEC-13 reviewed by the security team
+eval("d");
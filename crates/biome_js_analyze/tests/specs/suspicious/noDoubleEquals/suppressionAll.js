@@ -0,0 +1,9 @@
+// biome-ignore-all lint/suspicious/noDoubleEquals: vendored file
+
+a == b;
+
+function f() {
+	return a == b;
+}
+
+// biome-ignore-all lint/suspicious/noDoubleEquals: not at the top of the file
@@ -0,0 +1,12 @@
+// biome-ignore lint/suspicious/noDoubleEquals: <explanation>
+a == b;
+
+// biome-ignore lint/suspicious/noDoubleEquals: the values have different types
+a == b;
+
+// biome-ignore-start lint/suspicious/noDoubleEquals: TICKET-42 generated code
+a == b;
+// biome-ignore-end lint/suspicious/noDoubleEquals: generated code
+
+// biome-ignore lint/suspicious/noDoubleEquals: TICKET-43
+a == b;
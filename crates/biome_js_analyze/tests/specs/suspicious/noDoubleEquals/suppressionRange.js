@@ -0,0 +1,17 @@
+a == b;
+
+// biome-ignore-start lint/suspicious/noDoubleEquals: generated code
+a == b;
+a == b;
+// biome-ignore-end lint/suspicious/noDoubleEquals: generated code
+
+a == b;
+
+// biome-ignore-start lint/suspicious/noDoubleEquals: stale range
+a === b;
+// biome-ignore-end lint/suspicious/noDoubleEquals: stale range
+
+// biome-ignore-end lint/suspicious/noDoubleEquals: no matching start
+
+// biome-ignore-start lint/suspicious/noDoubleEquals: never closed
+a == b;
@@ -0,0 +1,6 @@
+// biome-ignore lint/suspicious/noDoubleEquals: the values have different types
+a == b;
+
+// biome-ignore-start lint/suspicious/noDoubleEquals: generated code
+a == b;
+// biome-ignore-end lint/suspicious/noDoubleEquals: generated code
@@ -9,11 +9,13 @@ pub mod utils;
 pub use crate::registry::visit_registry;
 use crate::suppression_action::JsonSuppressionAction;
 use biome_analyze::{
-    AnalysisFilter, AnalyzerOptions, AnalyzerSignal, ControlFlow, LanguageRoot, MatchQueryParams,
-    MetadataRegistry, RuleAction, RuleRegistry, SuppressionDiagnostic, SuppressionKind,
+    AnalysisFilter, AnalyzerOptions, AnalyzerSignal, AnalyzerSuppression,
+    AnalyzerSuppressionVariant, ControlFlow, LanguageRoot, MatchQueryParams, MetadataRegistry,
+    RuleAction, RuleRegistry, SuppressionKind,
 };
-use biome_diagnostics::Error;
+use biome_diagnostics::{category, Error};
 use biome_json_syntax::{JsonFileSource, JsonLanguage};
+use biome_suppression::{parse_suppression_comment, SuppressionDiagnostic};
 use std::ops::Deref;
 use std::sync::LazyLock;
 
@@ -62,10 +64,68 @@ where
     B: 'a,
 {
     fn parse_linter_suppression_comment(
-        _text: &str,
-    ) -> Vec<Result<SuppressionKind, SuppressionDiagnostic>> {
-        vec![]
+        text: &str,
+    ) -> Vec<Result<AnalyzerSuppression, SuppressionDiagnostic>> {
+        let mut result = Vec::new();
+
+        for comment in parse_suppression_comment(text) {
+            let (categories, variant, reason) = match comment {
+                Ok(comment) => {
+                    let variant = AnalyzerSuppressionVariant::from(comment.kind);
+                    if comment.is_legacy {
+                        result.push(Ok(AnalyzerSuppression::new(
+                            SuppressionKind::Deprecated,
+                            variant,
+                        )
+                        .with_reason(comment.reason)));
+                    }
+                    (comment.categories, variant, comment.reason)
+                }
+                Err(err) => {
+                    result.push(Err(err));
+                    continue;
+                }
+            };
+
+            for (key, value) in categories {
+                if key == category!("lint") {
+                    if let Some(value) = value {
+                        result.push(Ok(AnalyzerSuppression::new(
+                            SuppressionKind::MaybeLegacy(value),
+                            variant,
+                        )
+                        .with_reason(reason)));
+                    } else {
+                        result.push(Ok(AnalyzerSuppression::new(
+                            SuppressionKind::Everything,
+                            variant,
+                        )
+                        .with_reason(reason)));
+                    }
+                } else {
+                    let category = key.name();
+                    if let Some(rule) = category.strip_prefix("lint/") {
+                        if let Some(instance) = value {
+                            result.push(Ok(AnalyzerSuppression::new(
+                                SuppressionKind::RuleInstance(rule, instance),
+                                variant,
+                            )
+                            .with_reason(reason)));
+                        } else {
+                            result.push(Ok(AnalyzerSuppression::new(
+                                SuppressionKind::Rule(rule),
+                                variant,
+                            )
+                            .with_reason(reason)));
+                        }
+                    }
+                }
+            }
+        }
+
+        result
     }
+
     let mut registry = RuleRegistry::builder(&filter, root);
     visit_registry(&mut registry);
 
@@ -80,7 +140,7 @@ where
         METADATA.deref(),
         biome_analyze::InspectMatcher::new(registry, inspect_matcher),
         parse_linter_suppression_comment,
-        Box::new(JsonSuppressionAction),
+        Box::new(JsonSuppressionAction::new(file_source)),
         &mut emit_signal,
     );
 
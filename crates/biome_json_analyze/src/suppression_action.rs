@@ -1,27 +1,94 @@
 use biome_analyze::{ApplySuppression, SuppressionAction};
-use biome_json_syntax::JsonLanguage;
-use biome_rowan::{BatchMutation, SyntaxToken};
+use biome_json_syntax::{JsonFileSource, JsonLanguage, JsonSyntaxToken};
+use biome_rowan::{BatchMutation, TriviaPieceKind};
 
-pub(crate) struct JsonSuppressionAction;
+pub(crate) struct JsonSuppressionAction {
+    /// Whether the file supports comments, e.g. a JSONC file
+    allow_comments: bool,
+}
+
+impl JsonSuppressionAction {
+    pub(crate) fn new(file_source: JsonFileSource) -> Self {
+        Self {
+            allow_comments: file_source.allow_comments(),
+        }
+    }
+}
 
 impl SuppressionAction for JsonSuppressionAction {
     type Language = JsonLanguage;
 
     fn find_token_to_apply_suppression(
         &self,
-        _original_token: SyntaxToken<Self::Language>,
+        token: JsonSyntaxToken,
     ) -> Option<ApplySuppression<Self::Language>> {
-        // TODO: property implement. Look for the JsSuppressionAction for an example
-        None
+        // A suppression comment would make the file invalid
+        if !self.allow_comments {
+            return None;
+        }
+
+        let mut apply_suppression = ApplySuppression {
+            token_has_trailing_comments: false,
+            token_to_apply_suppression: token.clone(),
+            should_insert_leading_newline: false,
+        };
+
+        // Find the token at the start of suppressed token's line
+        let mut current_token = token;
+        loop {
+            let trivia = current_token.leading_trivia();
+            if trivia.pieces().any(|trivia| trivia.kind().is_newline()) {
+                break;
+            } else if let Some(prev_token) = current_token.prev_token() {
+                current_token = prev_token
+            } else {
+                break;
+            }
+        }
+
+        apply_suppression.token_to_apply_suppression = current_token;
+        Some(apply_suppression)
     }
 
     fn apply_suppression(
         &self,
-        _mutation: &mut BatchMutation<Self::Language>,
-        _apply_suppression: ApplySuppression<Self::Language>,
-        _suppression_text: &str,
-        _suppression_reason: &str,
+        mutation: &mut BatchMutation<Self::Language>,
+        apply_suppression: ApplySuppression<Self::Language>,
+        suppression_text: &str,
+        suppression_reason: &str,
     ) {
-        unreachable!("find_token_to_apply_suppression return None")
+        let ApplySuppression {
+            token_to_apply_suppression,
+            ..
+        } = apply_suppression;
+
+        let mut new_token = token_to_apply_suppression.clone();
+        let leading_whitespaces: Vec<_> = new_token
+            .leading_trivia()
+            .pieces()
+            .filter(|trivia| trivia.is_whitespace())
+            .collect();
+
+        let suppression_comment = format!("// {suppression_text}: {suppression_reason}");
+        let suppression_comment = suppression_comment.as_str();
+        let trivia = [
+            (TriviaPieceKind::SingleLineComment, suppression_comment),
+            (TriviaPieceKind::Newline, "\n"),
+        ];
+        if leading_whitespaces.is_empty() {
+            new_token = new_token.with_leading_trivia(trivia);
+        }
+        // Token is indented
+        else {
+            let mut trivia = trivia.to_vec();
+
+            for w in leading_whitespaces.iter() {
+                trivia.push((TriviaPieceKind::Whitespace, w.text()));
+            }
+            new_token = new_token.with_leading_trivia(trivia);
+        }
+        // Trim trailing trivia to prevent double insertion of trailing whitespaces in `replace_token_transfer_trivia`.
+        new_token = new_token.trim_trailing_trivia();
+        mutation.replace_token_transfer_trivia(token_to_apply_suppression, new_token);
     }
 }
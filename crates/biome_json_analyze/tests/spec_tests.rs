@@ -7,12 +7,13 @@ use biome_rowan::AstNode;
 use biome_test_utils::{
     assert_errors_are_absent, code_fix_to_string, create_analyzer_options, diagnostic_to_string,
     has_bogus_nodes_or_empty_slots, parse_test_path, register_leak_checker,
-    write_analyzer_snapshot,
+    write_analyzer_snapshot, CheckActionType,
 };
 use std::ops::Deref;
 use std::{ffi::OsStr, fs::read_to_string, path::Path, slice};
 
-tests_macros::gen_tests! {"tests/specs/**/*.{json}", crate::run_test, "module"}
+tests_macros::gen_tests! {"tests/specs/**/*.{json,jsonc}", crate::run_test, "module"}
+tests_macros::gen_tests! {"tests/suppression/**/*.{json,jsonc}", crate::run_suppression_test, "module"}
 
 fn run_test(input: &'static str, _: &str, _: &str, _: &str) {
     register_leak_checker();
@@ -58,6 +59,7 @@ fn run_test(input: &'static str, _: &str, _: &str, _: &str) {
         filter,
         file_name,
         input_file,
+        CheckActionType::Lint,
     );
 
     insta::with_settings!({
@@ -79,8 +81,10 @@ pub(crate) fn analyze_and_snap(
     filter: AnalysisFilter,
     file_name: &str,
     input_file: &Path,
+    check_action_type: CheckActionType,
 ) -> usize {
-    let parsed = parse_json(input_code, JsonParserOptions::default());
+    let parser_options = JsonParserOptions::from(&file_source);
+    let parsed = parse_json(input_code, parser_options);
     let root = parsed.tree();
 
     let mut diagnostics = Vec::new();
@@ -90,8 +94,13 @@ pub(crate) fn analyze_and_snap(
     let (_, errors) = biome_json_analyze::analyze(&root, filter, &options, file_source, |event| {
         if let Some(mut diag) = event.diagnostic() {
             for action in event.actions() {
-                if !action.is_suppression() {
-                    check_code_action(input_file, input_code, &action);
+                if check_action_type.is_suppression() {
+                    if action.is_suppression() {
+                        check_code_action(input_file, input_code, &action, parser_options);
+                        diag = diag.add_code_suggestion(CodeSuggestionAdvice::from(action));
+                    }
+                } else if !action.is_suppression() {
+                    check_code_action(input_file, input_code, &action, parser_options);
                     diag = diag.add_code_suggestion(CodeSuggestionAdvice::from(action));
                 }
             }
@@ -102,8 +111,13 @@ pub(crate) fn analyze_and_snap(
         }
 
         for action in event.actions() {
-            if !action.is_suppression() {
-                check_code_action(input_file, input_code, &action);
+            if check_action_type.is_suppression() {
+                if action.is_suppression() {
+                    check_code_action(input_file, input_code, &action, parser_options);
+                    code_fixes.push(code_fix_to_string(input_code, action));
+                }
+            } else if !action.is_suppression() {
+                check_code_action(input_file, input_code, &action, parser_options);
                 code_fixes.push(code_fix_to_string(input_code, action));
             }
         }
@@ -125,7 +139,12 @@ pub(crate) fn analyze_and_snap(
     diagnostics.len()
 }
 
-fn check_code_action(path: &Path, source: &str, action: &AnalyzerAction<JsonLanguage>) {
+fn check_code_action(
+    path: &Path,
+    source: &str,
+    action: &AnalyzerAction<JsonLanguage>,
+    options: JsonParserOptions,
+) {
     let (new_tree, text_edit) = match action
         .mutation
         .clone()
@@ -151,6 +170,52 @@ fn check_code_action(path: &Path, source: &str, action: &AnalyzerAction<JsonLang
     }
 
     // Re-parse the modified code and panic if the resulting tree has syntax errors
-    let re_parse = parse_json(&output, JsonParserOptions::default());
+    let re_parse = parse_json(&output, options);
     assert_errors_are_absent(re_parse.tree().syntax(), re_parse.diagnostics(), path);
 }
+
+pub(crate) fn run_suppression_test(input: &'static str, _: &str, _: &str, _: &str) {
+    register_leak_checker();
+
+    let input_file = Path::new(input);
+    let file_name = input_file.file_name().and_then(OsStr::to_str).unwrap();
+    let input_code = read_to_string(input_file)
+        .unwrap_or_else(|err| panic!("failed to read {input_file:?}: {err:?}"));
+
+    let (group, rule) = parse_test_path(input_file);
+    if biome_json_analyze::METADATA
+        .deref()
+        .find_rule(group, rule)
+        .is_none()
+    {
+        panic!("could not find rule {group}/{rule}");
+    }
+
+    let rule_filter = RuleFilter::Rule(group, rule);
+    let filter = AnalysisFilter {
+        enabled_rules: Some(slice::from_ref(&rule_filter)),
+        ..AnalysisFilter::default()
+    };
+
+    let Ok(file_source) = input_file.try_into() else {
+        return;
+    };
+
+    let mut snapshot = String::new();
+    analyze_and_snap(
+        &mut snapshot,
+        &input_code,
+        file_source,
+        filter,
+        file_name,
+        input_file,
+        CheckActionType::Suppression,
+    );
+
+    insta::with_settings!({
+        prepend_module_to_snapshot => false,
+        snapshot_path => input_file.parent().unwrap(),
+    }, {
+        insta::assert_snapshot!(file_name, snapshot, file_name);
+    });
+}
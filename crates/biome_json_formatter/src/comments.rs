@@ -68,6 +68,7 @@ impl CommentStyle for JsonCommentStyle {
     fn is_suppression(text: &str) -> bool {
         parse_suppression_comment(text)
             .filter_map(Result::ok)
+            .filter(|suppression| suppression.kind.is_classic())
             .flat_map(|suppression| suppression.categories)
             .any(|(key, _)| key == category!("format"))
     }
@@ -156,6 +156,10 @@ impl JsonFormatOptions {
         self.trailing_commas = trailing_commas;
     }
 
+    pub fn trailing_commas(&self) -> TrailingCommas {
+        self.trailing_commas
+    }
+
     pub(crate) fn to_trailing_separator(&self) -> TrailingSeparator {
         match self.trailing_commas {
             TrailingCommas::None => TrailingSeparator::Omit,
@@ -247,6 +247,7 @@ fn fix_all(
         only: vec![],
         skip: vec![],
         suppression_reason: None,
+        profile_rules: false,
         rule_categories: RuleCategoriesBuilder::default()
             .with_syntax()
             .with_lint()
@@ -138,14 +138,21 @@ impl LSPServer {
                     watchers: vec![
                         FileSystemWatcher {
                             glob_pattern: GlobPattern::String(format!(
-                                "{}/biome.json",
+                                "{}/**/biome.json",
                                 base_path.display()
                             )),
                             kind: Some(WatchKind::all()),
                         },
                         FileSystemWatcher {
                             glob_pattern: GlobPattern::String(format!(
-                                "{}/biome.jsonc",
+                                "{}/**/biome.jsonc",
+                                base_path.display()
+                            )),
+                            kind: Some(WatchKind::all()),
+                        },
+                        FileSystemWatcher {
+                            glob_pattern: GlobPattern::String(format!(
+                                "{}/**/package.json",
                                 base_path.display()
                             )),
                             kind: Some(WatchKind::all()),
@@ -157,6 +164,21 @@ impl LSPServer {
                             )),
                             kind: Some(WatchKind::all()),
                         },
+                        FileSystemWatcher {
+                            glob_pattern: GlobPattern::String(format!(
+                                "{}/**/.gitignore",
+                                base_path.display()
+                            )),
+                            kind: Some(WatchKind::all()),
+                        },
+                        // The modules of the module graph are read again when they change on disk
+                        FileSystemWatcher {
+                            glob_pattern: GlobPattern::String(format!(
+                                "{}/**/*.{{js,jsx,mjs,cjs,ts,tsx,mts,cts}}",
+                                base_path.display()
+                            )),
+                            kind: Some(WatchKind::all()),
+                        },
                         // TODO: Biome 2.0 remove it
                         FileSystemWatcher {
                             glob_pattern: GlobPattern::String(format!(
@@ -322,6 +344,16 @@ impl LanguageServer for LSPServer {
 
     #[tracing::instrument(level = "trace", skip(self))]
     async fn did_change_watched_files(&self, params: DidChangeWatchedFilesParams) {
+        // The modules that changed on disk are read again by the next update of the diagnostics
+        let changed_paths: Vec<_> = params
+            .changes
+            .iter()
+            .filter_map(|change| change.uri.to_file_path().ok())
+            .collect();
+        if let Err(error) = self.session.invalidate_modules(&changed_paths) {
+            error!("Failed to update the module graph: {}", error);
+        }
+
         let file_paths = params
             .changes
             .iter()
@@ -334,9 +366,19 @@ impl LanguageServer for LSPServer {
                         let possible_rome_json = file_path.strip_prefix(&base_path);
                         if let Ok(watched_file) = possible_rome_json {
                             if watched_file.display().to_string() == ROME_JSON
-                                || ConfigName::file_names()
-                                    .contains(&&*watched_file.display().to_string())
+                                || watched_file
+                                    .file_name()
+                                    .and_then(|file_name| file_name.to_str())
+                                    .is_some_and(|file_name| {
+                                        ConfigName::file_names().contains(&file_name)
+                                    })
+                                // The configuration can be in the `biome` key of a `package.json` file
+                                || (watched_file.ends_with(ConfigName::package_json())
+                                    && !watched_file
+                                        .components()
+                                        .any(|component| component.as_os_str() == "node_modules"))
                                 || watched_file.ends_with(".editorconfig")
+                                || watched_file.ends_with(".gitignore")
                             {
                                 self.session.load_workspace_settings().await;
                                 self.session.load_manifest().await;
@@ -626,9 +668,13 @@ impl ServerFactory {
         workspace_method!(builder, unregister_project_folder);
         workspace_method!(builder, open_file);
         workspace_method!(builder, set_manifest_for_project);
+        workspace_method!(builder, register_vcs_ignore_files);
+        workspace_method!(builder, update_module_graph);
+        workspace_method!(builder, get_module_graph_paths);
         workspace_method!(builder, get_syntax_tree);
         workspace_method!(builder, get_control_flow_graph);
         workspace_method!(builder, get_formatter_ir);
+        workspace_method!(builder, get_resolved_settings);
         workspace_method!(builder, change_file);
         workspace_method!(builder, get_file_content);
         workspace_method!(builder, close_file);
@@ -640,6 +686,9 @@ impl ServerFactory {
         workspace_method!(builder, fix_file);
         workspace_method!(builder, rename);
         workspace_method!(builder, organize_imports);
+        workspace_method!(builder, parse_pattern);
+        workspace_method!(builder, search_pattern);
+        workspace_method!(builder, drop_pattern);
 
         let (service, socket) = builder.finish();
         ServerConnection { socket, service }
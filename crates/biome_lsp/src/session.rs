@@ -11,15 +11,20 @@ use biome_deserialize::Merge;
 use biome_diagnostics::{DiagnosticExt, Error, PrintDescription};
 use biome_fs::{BiomePath, FileSystem};
 use biome_lsp_converters::{negotiated_encoding, PositionEncoding, WideEncoding};
+use biome_project::ModuleGraph;
 use biome_service::configuration::{
-    load_configuration, load_editorconfig, LoadedConfiguration, PartialConfigurationExt,
+    find_nested_configuration_files, load_configuration, load_editorconfig,
+    load_nested_configuration, LoadedConfiguration, PartialConfigurationExt, VcsIgnoreFileLoader,
 };
 use biome_service::file_handlers::{AstroFileHandler, SvelteFileHandler, VueFileHandler};
+use biome_service::module_graph::ModuleGraphBuilder;
 use biome_service::workspace::{
     FeaturesBuilder, GetFileContentParams, PullDiagnosticsParams, RegisterProjectFolderParams,
     SetManifestForProjectParams, SupportsFeatureParams,
 };
-use biome_service::workspace::{RageEntry, RageParams, RageResult, UpdateSettingsParams};
+use biome_service::workspace::{
+    RageEntry, RageParams, RageResult, UpdateModuleGraphParams, UpdateSettingsParams, VcsIgnoreFile,
+};
 use biome_service::Workspace;
 use biome_service::{DynRef, WorkspaceError};
 use futures::stream::futures_unordered::FuturesUnordered;
@@ -27,13 +32,14 @@ use futures::StreamExt;
 use rustc_hash::FxHashMap;
 use serde_json::Value;
 use std::ffi::OsStr;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::atomic::Ordering;
 use std::sync::atomic::{AtomicBool, AtomicU8};
 use std::sync::Arc;
 use std::sync::RwLock;
 use tokio::sync::Notify;
 use tokio::sync::OnceCell;
+use tokio::task::spawn_blocking;
 use tower_lsp::lsp_types;
 use tower_lsp::lsp_types::{Diagnostic, Url};
 use tower_lsp::lsp_types::{MessageType, Registration};
@@ -83,6 +89,18 @@ pub(crate) struct Session {
 
     pub(crate) config_path: Option<PathBuf>,
     pub(crate) manifest_path: Option<PathBuf>,
+
+    /// The directory of the project whose module graph is built when the diagnostics are
+    /// updated, set when the configuration enables rules that analyze the other files of the project
+    module_graph_path: RwLock<Option<PathBuf>>,
+
+    /// The modules sent to the module graph of the workspace, kept between the updates of the
+    /// diagnostics so that only the changed modules are parsed again
+    module_graph: RwLock<ModuleGraph>,
+
+    /// Loads the `.gitignore` files of the directories of the opened documents, set when the
+    /// VCS integration uses them
+    vcs_ignore_files: RwLock<Option<VcsIgnoreFileLoader>>,
 }
 
 /// The parameters provided by the client in the "initialize" request
@@ -180,6 +198,9 @@ impl Session {
             cancellation,
             config_path: None,
             manifest_path: None,
+            module_graph_path: RwLock::default(),
+            module_graph: RwLock::default(),
+            vcs_ignore_files: RwLock::default(),
             notified_broken_configuration: AtomicBool::new(false),
         }
     }
@@ -296,6 +317,83 @@ impl Session {
         Ok(BiomePath::new(path_to_file))
     }
 
+    /// Updates the module graph with the module of the file at `biome_path` and the modules it
+    /// imports, when the configuration enables rules that need it.
+    ///
+    /// Only the file and the modules that aren't part of the graph yet are parsed, then the
+    /// modules that the open documents don't import anymore are removed from the graph.
+    fn update_module_graph(&self, biome_path: &BiomePath) -> Result<(), LspError> {
+        let Some(project_path) = self.module_graph_path.read().unwrap().clone() else {
+            return Ok(());
+        };
+        let content = self.workspace.get_file_content(GetFileContentParams {
+            path: biome_path.clone(),
+        })?;
+        let open_paths: Vec<_> = self
+            .documents
+            .read()
+            .unwrap()
+            .keys()
+            .filter_map(|url| self.file_path(url).ok())
+            .collect();
+
+        let mut module_graph = self.module_graph.write().unwrap();
+        let mut builder =
+            ModuleGraphBuilder::new(&*self.fs, &project_path).with_known_modules(&module_graph);
+        builder.add_module(biome_path, &content);
+        let changed_modules = builder.build();
+        module_graph.extend(changed_modules.clone());
+        let removed_paths = module_graph.retain_reachable(
+            open_paths
+                .iter()
+                .map(|path| path.as_path())
+                .chain([biome_path.as_path()]),
+        );
+
+        self.workspace
+            .update_module_graph(UpdateModuleGraphParams {
+                module_graph: changed_modules,
+                removed_paths,
+            })?;
+        Ok(())
+    }
+
+    /// Removes the modules at `paths`, which changed on disk, from the module graph, so that
+    /// they are read again by the next update of the diagnostics
+    pub(crate) fn invalidate_modules(&self, paths: &[PathBuf]) -> Result<(), LspError> {
+        let mut module_graph = self.module_graph.write().unwrap();
+        let removed_paths: Vec<_> = paths
+            .iter()
+            .filter(|path| module_graph.remove_module(path).is_some())
+            .cloned()
+            .collect();
+        if removed_paths.is_empty() {
+            return Ok(());
+        }
+
+        self.workspace
+            .update_module_graph(UpdateModuleGraphParams {
+                module_graph: ModuleGraph::default(),
+                removed_paths,
+            })?;
+        Ok(())
+    }
+
+    /// Sets the directory of the project whose module graph is built, and empties the module
+    /// graph, whose modules may be resolved differently with the new configuration
+    fn set_module_graph_path(&self, project_path: Option<PathBuf>) -> Result<(), WorkspaceError> {
+        *self.module_graph_path.write().unwrap() = project_path;
+        let module_graph = std::mem::take(&mut *self.module_graph.write().unwrap());
+        if module_graph.is_empty() {
+            return Ok(());
+        }
+
+        self.workspace.update_module_graph(UpdateModuleGraphParams {
+            module_graph: ModuleGraph::default(),
+            removed_paths: module_graph.paths().map(Path::to_path_buf).collect(),
+        })
+    }
+
     /// Computes diagnostics for the file matching the provided url and publishes
     /// them to the client. Called from [`handlers::text_document`] when a file's
     /// contents changes.
@@ -309,6 +407,9 @@ impl Session {
                     .show_message(MessageType::WARNING, "The configuration file has errors. Biome will report only parsing errors until the configuration is fixed.")
                     .await;
         }
+        if let Some(loader) = self.vcs_ignore_files.read().unwrap().as_ref() {
+            loader.load_parent_directories(&*self.fs, self.workspace.as_ref(), &biome_path)?;
+        }
         let file_features = self.workspace.file_features(SupportsFeatureParams {
             features: FeaturesBuilder::new()
                 .with_linter()
@@ -338,12 +439,16 @@ impl Session {
                     categories = categories.with_action();
                 }
             }
+            if file_features.supports_lint() && self.configuration_status().is_loaded() {
+                self.update_module_graph(&biome_path)?;
+            }
             let result = self.workspace.pull_diagnostics(PullDiagnosticsParams {
                 path: biome_path.clone(),
                 categories: categories.build(),
                 max_diagnostics: u64::MAX,
                 only: Vec::new(),
                 skip: Vec::new(),
+                profile_rules: false,
             })?;
 
             tracing::trace!("biome diagnostics: {:#?}", result.diagnostics);
@@ -445,7 +550,7 @@ impl Session {
     /// This function attempts to read the `biome.json` configuration file from
     /// the root URI and update the workspace settings accordingly
     #[tracing::instrument(level = "trace", skip(self))]
-    pub(crate) async fn load_workspace_settings(&self) {
+    pub(crate) async fn load_workspace_settings(self: &Arc<Self>) {
         // Providing a custom configuration path will not allow to support workspaces
         if let Some(config_path) = &self.config_path {
             let base_path = ConfigurationPathHint::FromUser(config_path.clone());
@@ -485,7 +590,7 @@ impl Session {
     }
 
     async fn load_biome_configuration_file(
-        &self,
+        self: &Arc<Self>,
         base_path: ConfigurationPathHint,
     ) -> ConfigurationStatus {
         match load_configuration(&self.fs, base_path.clone()) {
@@ -501,9 +606,19 @@ impl Session {
                     let LoadedConfiguration {
                         configuration: fs_configuration,
                         directory_path: configuration_path,
+                        file_path,
+                        is_user_configuration,
                         ..
                     } = loaded_configuration;
                     info!("Configuration loaded successfully from disk.");
+                    if let (true, Some(file_path)) = (is_user_configuration, file_path) {
+                        let message = format!(
+                            "The project doesn't have a configuration file, using the user configuration {}",
+                            file_path.display()
+                        );
+                        info!("{message}");
+                        self.client.log_message(MessageType::INFO, message).await;
+                    }
                     info!("Update workspace settings.");
 
                     let fs = &self.fs;
@@ -566,11 +681,51 @@ impl Session {
                                 self.client.log_message(MessageType::ERROR, &error).await;
                                 return ConfigurationStatus::Error;
                             }
+                            let root = match &base_path {
+                                ConfigurationPathHint::FromWorkspace(path) => Some(path.clone()),
+                                _ => fs.working_directory(),
+                            }
+                            .unwrap_or_default();
+                            let ignore_files = match vcs_base_path.as_deref() {
+                                Some(vcs_base_path) => {
+                                    configuration.retrieve_vcs_ignore_files(fs, vcs_base_path)
+                                }
+                                None => Ok(Vec::new()),
+                            };
+                            *self.vcs_ignore_files.write().unwrap() =
+                                vcs_base_path.as_deref().and_then(|vcs_base_path| {
+                                    configuration.vcs_ignore_file_loader(vcs_base_path)
+                                });
+                            let ignore_files = match ignore_files {
+                                Ok(ignore_files) => ignore_files,
+                                Err(error) => {
+                                    error!("Couldn't load the ignore files, reason:\n {}", error);
+                                    self.client.log_message(MessageType::ERROR, &error).await;
+                                    return ConfigurationStatus::Error;
+                                }
+                            };
+                            let plugins = configuration.retrieve_plugins(
+                                fs,
+                                configuration_path.as_deref().unwrap_or(&root),
+                            );
+                            let plugins = match plugins {
+                                Ok(plugins) => plugins,
+                                Err(error) => {
+                                    error!("Couldn't load the plugins, reason:\n {}", error);
+                                    self.client.log_message(MessageType::ERROR, &error).await;
+                                    return ConfigurationStatus::Error;
+                                }
+                            };
+                            let project_path = configuration_path.clone().unwrap_or(root.clone());
+                            let requires_module_graph = configuration.requires_module_graph(&[]);
                             let result = self.workspace.update_settings(UpdateSettingsParams {
                                 workspace_directory: fs.working_directory(),
                                 configuration,
-                                vcs_base_path,
-                                gitignore_matches,
+                                vcs_base_path: vcs_base_path.clone(),
+                                gitignore_matches: gitignore_matches.clone(),
+                                nested: false,
+                                ignore_files: ignore_files.clone(),
+                                plugins,
                             });
 
                             if let Err(error) = result {
@@ -578,7 +733,15 @@ impl Session {
                                 self.client.log_message(MessageType::ERROR, &error).await;
                                 ConfigurationStatus::Error
                             } else {
-                                ConfigurationStatus::Loaded
+                                self.load_nested_configuration_files(
+                                    root,
+                                    vcs_base_path,
+                                    gitignore_matches,
+                                    ignore_files,
+                                    project_path,
+                                    requires_module_graph,
+                                )
+                                .await
                             }
                         }
                         Err(err) => {
@@ -598,6 +761,113 @@ impl Session {
         }
     }
 
+    /// Loads the configuration files of the subdirectories of `root`, and updates the settings
+    /// of the files of these subdirectories.
+    ///
+    /// The module graph of the project at `project_path` is built when the root configuration
+    /// (`requires_module_graph`) or one of the nested configurations enables rules that need it.
+    async fn load_nested_configuration_files(
+        self: &Arc<Self>,
+        root: PathBuf,
+        vcs_base_path: Option<PathBuf>,
+        gitignore_matches: Vec<String>,
+        ignore_files: Vec<VcsIgnoreFile>,
+        project_path: PathBuf,
+        mut requires_module_graph: bool,
+    ) -> ConfigurationStatus {
+        let features = FeaturesBuilder::new()
+            .with_formatter()
+            .with_linter()
+            .with_organize_imports()
+            .with_assists()
+            .build();
+        // The scan walks the whole project, so it runs on the blocking pool to
+        // leave the runtime free for the requests of the client
+        let session = Arc::clone(self);
+        let scan_root = root.clone();
+        let configuration_files = match spawn_blocking(move || {
+            find_nested_configuration_files(
+                &*session.fs,
+                session.workspace.as_ref(),
+                features,
+                &scan_root,
+                &[PathBuf::new()],
+            )
+        })
+        .await
+        {
+            Ok(configuration_files) => configuration_files,
+            Err(error) => {
+                error!(
+                    "Couldn't scan the nested configuration files, reason:\n {}",
+                    error
+                );
+                self.client.log_message(MessageType::ERROR, &error).await;
+                return ConfigurationStatus::Error;
+            }
+        };
+        for configuration_file in configuration_files {
+            info!("Loading the configuration file {:?}", configuration_file);
+            let loaded_configuration =
+                match load_nested_configuration(&self.fs, &configuration_file) {
+                    Ok(loaded_configuration) => loaded_configuration,
+                    Err(error) => {
+                        error!("Couldn't load the configuration file, reason:\n {}", error);
+                        self.client.log_message(MessageType::ERROR, &error).await;
+                        return ConfigurationStatus::Error;
+                    }
+                };
+            if loaded_configuration.has_errors() {
+                error!("Couldn't load the configuration file, reasons:");
+                for diagnostic in loaded_configuration.as_diagnostics_iter() {
+                    let message = PrintDescription(diagnostic).to_string();
+                    self.client.log_message(MessageType::ERROR, message).await;
+                }
+                return ConfigurationStatus::Error;
+            }
+            let LoadedConfiguration {
+                configuration,
+                directory_path,
+                ..
+            } = loaded_configuration;
+            let plugins = match configuration
+                .retrieve_plugins(&self.fs, directory_path.as_deref().unwrap_or(&root))
+            {
+                Ok(plugins) => plugins,
+                Err(error) => {
+                    error!("Couldn't load the plugins, reason:\n {}", error);
+                    self.client.log_message(MessageType::ERROR, &error).await;
+                    return ConfigurationStatus::Error;
+                }
+            };
+            requires_module_graph |= configuration.requires_module_graph(&[]);
+            let result = self.workspace.update_settings(UpdateSettingsParams {
+                workspace_directory: directory_path,
+                configuration,
+                vcs_base_path: vcs_base_path.clone(),
+                gitignore_matches: gitignore_matches.clone(),
+                nested: true,
+                ignore_files: ignore_files.clone(),
+                plugins,
+            });
+            if let Err(error) = result {
+                error!("Failed to set workspace settings: {}", error);
+                self.client.log_message(MessageType::ERROR, &error).await;
+                return ConfigurationStatus::Error;
+            }
+        }
+
+        if let Err(error) =
+            self.set_module_graph_path(requires_module_graph.then_some(project_path))
+        {
+            error!("Failed to reset the module graph: {}", error);
+            self.client.log_message(MessageType::ERROR, &error).await;
+            return ConfigurationStatus::Error;
+        }
+
+        ConfigurationStatus::Loaded
+    }
+
     #[tracing::instrument(level = "trace", skip(self))]
     pub(crate) async fn load_manifest(&self) {
         let base_path = self
@@ -1,5 +1,6 @@
 mod diagnostics;
 mod license;
+mod module_graph;
 mod node_js_project;
 
 pub use crate::diagnostics::{ProjectAnalyzeDiagnostic, ProjectDiagnostic};
@@ -8,7 +9,8 @@ use biome_diagnostics::serde::Diagnostic;
 use biome_parser::diagnostic::ParseDiagnostic;
 use biome_rowan::Language;
 pub use license::generated::*;
-pub use node_js_project::{Dependencies, NodeJsProject, PackageJson, PackageType};
+pub use module_graph::{ModuleGraph, ModuleInfo, ResolvedImport};
+pub use node_js_project::{Dependencies, NodeJsProject, PackageJson, PackageType, Version};
 use std::any::TypeId;
 use std::fmt::Debug;
 use std::path::Path;
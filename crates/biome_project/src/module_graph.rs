@@ -0,0 +1,328 @@
+use rustc_hash::FxHashSet;
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Component, Path, PathBuf};
+
+/// The modules of a project, with the modules that their imports resolve to and the names that
+/// they export.
+///
+/// It lets the rules of the project phase look at the other files of the project.
+#[derive(Clone, Debug, Default, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ModuleGraph {
+    modules: BTreeMap<PathBuf, ModuleInfo>,
+}
+
+impl ModuleGraph {
+    /// Returns the module at `path`, if it's part of the graph
+    pub fn module(&self, path: &Path) -> Option<&ModuleInfo> {
+        self.modules.get(&normalize_path(path))
+    }
+
+    /// Adds the module at `path` to the graph, replacing the previous one
+    pub fn insert_module(&mut self, path: PathBuf, module: ModuleInfo) {
+        self.modules.insert(normalize_path(&path), module);
+    }
+
+    /// Adds the modules of `other` to the graph, replacing the ones with the same path
+    pub fn extend(&mut self, other: Self) {
+        self.modules.extend(other.modules);
+    }
+
+    /// Removes the module at `path` from the graph
+    pub fn remove_module(&mut self, path: &Path) -> Option<ModuleInfo> {
+        self.modules.remove(&normalize_path(path))
+    }
+
+    /// Returns the paths of the modules of the graph
+    pub fn paths(&self) -> impl Iterator<Item = &Path> {
+        self.modules.keys().map(PathBuf::as_path)
+    }
+
+    /// Removes the modules that can't be reached from the modules at `roots` by following their
+    /// imports, and returns their paths
+    pub fn retain_reachable<'a>(
+        &mut self,
+        roots: impl IntoIterator<Item = &'a Path>,
+    ) -> Vec<PathBuf> {
+        let mut reachable = FxHashSet::default();
+        let mut queue: Vec<_> = roots.into_iter().map(normalize_path).collect();
+        while let Some(path) = queue.pop() {
+            let Some(module) = self.modules.get(&path) else {
+                continue;
+            };
+            if !reachable.insert(path) {
+                continue;
+            }
+            queue.extend(
+                module
+                    .imports
+                    .values()
+                    .filter_map(|resolved| match resolved {
+                        ResolvedImport::Module(path) => Some(normalize_path(path)),
+                        _ => None,
+                    }),
+            );
+        }
+
+        let unreachable: Vec<_> = self
+            .paths()
+            .filter(|path| !reachable.contains(*path))
+            .map(Path::to_path_buf)
+            .collect();
+        for path in &unreachable {
+            self.modules.remove(path);
+        }
+        unreachable
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.modules.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.modules.len()
+    }
+
+    /// Returns what the import `specifier` of the module at `path` resolves to
+    pub fn resolve_import(&self, path: &Path, specifier: &str) -> Option<&ResolvedImport> {
+        self.module(path)?.imports.get(specifier)
+    }
+
+    /// Returns whether the module at `path` exports `name`, either with its own exports or with
+    /// its `export * from` declarations.
+    ///
+    /// It returns `None` when the exports of the module aren't known.
+    pub fn is_exported(&self, path: &Path, name: &str) -> Option<bool> {
+        self.is_exported_from(path, name, &mut FxHashSet::default())
+    }
+
+    fn is_exported_from(
+        &self,
+        path: &Path,
+        name: &str,
+        visited: &mut FxHashSet<PathBuf>,
+    ) -> Option<bool> {
+        // A cycle of re-exports doesn't export anything more
+        if !visited.insert(normalize_path(path)) {
+            return Some(false);
+        }
+
+        let module = self.module(path)?;
+        if module.exports.contains(name) {
+            return Some(true);
+        }
+        if module.has_dynamic_exports {
+            return None;
+        }
+        // `export * from` doesn't re-export the default export
+        if name == "default" {
+            return Some(false);
+        }
+
+        let mut is_known = true;
+        for specifier in &module.reexports {
+            let Some(ResolvedImport::Module(reexported_path)) = module.imports.get(specifier)
+            else {
+                is_known = false;
+                continue;
+            };
+            match self.is_exported_from(reexported_path, name, visited) {
+                Some(true) => return Some(true),
+                Some(false) => {}
+                None => is_known = false,
+            }
+        }
+
+        is_known.then_some(false)
+    }
+}
+
+/// Removes the `.` and the `..` components of `path`, so that `./src/../index.js` and `index.js`
+/// are the same module
+fn normalize_path(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir
+                if matches!(
+                    normalized.components().next_back(),
+                    Some(Component::Normal(_))
+                ) =>
+            {
+                normalized.pop();
+            }
+            component => normalized.push(component),
+        }
+    }
+    normalized
+}
+
+/// A module of the [ModuleGraph]
+#[derive(Clone, Debug, Default, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ModuleInfo {
+    /// What the specifiers of the imports and re-exports of the module resolve to
+    pub imports: BTreeMap<String, ResolvedImport>,
+    /// The names exported by the module, `default` being the name of the default export
+    pub exports: BTreeSet<String>,
+    /// The specifiers of the `export * from` declarations of the module
+    pub reexports: BTreeSet<String>,
+    /// Whether the exports of the module can't be known statically, e.g. because it's
+    /// a CommonJS module
+    pub has_dynamic_exports: bool,
+}
+
+/// What the specifier of an import resolves to
+#[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum ResolvedImport {
+    /// A module of the project or of its dependencies
+    Module(PathBuf),
+    /// A built-in module of Node.js, e.g. `node:fs`
+    Builtin(String),
+    /// The specifier doesn't resolve to any module
+    Unresolved,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn module(exports: &[&str], reexports: &[(&str, &str)]) -> ModuleInfo {
+        ModuleInfo {
+            imports: reexports
+                .iter()
+                .map(|(specifier, path)| {
+                    (
+                        (*specifier).to_string(),
+                        ResolvedImport::Module(PathBuf::from(path)),
+                    )
+                })
+                .collect(),
+            exports: exports.iter().map(|name| (*name).to_string()).collect(),
+            reexports: reexports
+                .iter()
+                .map(|(specifier, _)| (*specifier).to_string())
+                .collect(),
+            has_dynamic_exports: false,
+        }
+    }
+
+    #[test]
+    fn is_exported_follows_reexports() {
+        let mut graph = ModuleGraph::default();
+        graph.insert_module("index.js".into(), module(&["a"], &[("./b", "b.js")]));
+        graph.insert_module("b.js".into(), module(&["b", "default"], &[]));
+
+        assert_eq!(graph.is_exported(Path::new("index.js"), "a"), Some(true));
+        assert_eq!(graph.is_exported(Path::new("index.js"), "b"), Some(true));
+        assert_eq!(graph.is_exported(Path::new("index.js"), "c"), Some(false));
+        assert_eq!(graph.is_exported(Path::new("unknown.js"), "a"), None);
+    }
+
+    #[test]
+    fn is_exported_stops_at_reexport_cycles() {
+        let mut graph = ModuleGraph::default();
+        graph.insert_module("a.js".into(), module(&["a"], &[("./b", "b.js")]));
+        graph.insert_module("b.js".into(), module(&["b"], &[("./a", "a.js")]));
+
+        assert_eq!(graph.is_exported(Path::new("a.js"), "b"), Some(true));
+        assert_eq!(graph.is_exported(Path::new("b.js"), "a"), Some(true));
+        assert_eq!(graph.is_exported(Path::new("a.js"), "c"), Some(false));
+    }
+
+    #[test]
+    fn is_exported_doesnt_reexport_default() {
+        let mut graph = ModuleGraph::default();
+        graph.insert_module("index.js".into(), module(&[], &[("./b", "b.js")]));
+        graph.insert_module("b.js".into(), module(&["default"], &[]));
+
+        assert_eq!(
+            graph.is_exported(Path::new("index.js"), "default"),
+            Some(false)
+        );
+    }
+
+    #[test]
+    fn is_exported_with_dynamic_exports() {
+        let mut graph = ModuleGraph::default();
+        graph.insert_module("index.js".into(), module(&["a"], &[("./b", "b.js")]));
+        graph.insert_module(
+            "b.js".into(),
+            ModuleInfo {
+                has_dynamic_exports: true,
+                ..ModuleInfo::default()
+            },
+        );
+
+        assert_eq!(graph.is_exported(Path::new("index.js"), "a"), Some(true));
+        assert_eq!(graph.is_exported(Path::new("index.js"), "b"), None);
+        assert_eq!(graph.is_exported(Path::new("b.js"), "b"), None);
+    }
+
+    #[test]
+    fn is_exported_with_unresolved_reexports() {
+        let mut graph = ModuleGraph::default();
+        let mut index = module(&["a"], &[]);
+        index.reexports.insert("./missing".to_string());
+        index
+            .imports
+            .insert("./missing".to_string(), ResolvedImport::Unresolved);
+        graph.insert_module("index.js".into(), index);
+
+        assert_eq!(graph.is_exported(Path::new("index.js"), "a"), Some(true));
+        assert_eq!(graph.is_exported(Path::new("index.js"), "b"), None);
+    }
+
+    #[test]
+    fn normalize_path_removes_dot_components() {
+        assert_eq!(
+            normalize_path(Path::new("./src/../index.js")),
+            PathBuf::from("index.js")
+        );
+        assert_eq!(
+            normalize_path(Path::new("src/utils/../lib/./index.js")),
+            PathBuf::from("src/lib/index.js")
+        );
+        assert_eq!(
+            normalize_path(Path::new("../shared/index.js")),
+            PathBuf::from("../shared/index.js")
+        );
+        assert_eq!(
+            normalize_path(Path::new("../../shared/../index.js")),
+            PathBuf::from("../../index.js")
+        );
+    }
+
+    #[test]
+    fn is_exported_normalizes_the_reexported_paths() {
+        let mut graph = ModuleGraph::default();
+        graph.insert_module(
+            "src/index.js".into(),
+            module(&[], &[("../lib", "src/../lib/index.js")]),
+        );
+        graph.insert_module("lib/index.js".into(), module(&["lib"], &[]));
+
+        assert_eq!(
+            graph.is_exported(Path::new("./src/index.js"), "lib"),
+            Some(true)
+        );
+    }
+
+    #[test]
+    fn retain_reachable_removes_the_unreachable_modules() {
+        let mut graph = ModuleGraph::default();
+        graph.insert_module("index.js".into(), module(&[], &[("./a", "a.js")]));
+        graph.insert_module("a.js".into(), module(&[], &[("./index", "index.js")]));
+        graph.insert_module("b.js".into(), module(&[], &[("./a", "a.js")]));
+
+        let removed = graph.retain_reachable([Path::new("./index.js")]);
+
+        assert_eq!(removed, vec![PathBuf::from("b.js")]);
+        assert_eq!(
+            graph.paths().collect::<Vec<_>>(),
+            vec![Path::new("a.js"), Path::new("index.js")]
+        );
+    }
+}
@@ -41,6 +41,10 @@ impl Dependencies {
         self.0.contains_key(specifier)
     }
 
+    pub fn get(&self, specifier: &str) -> Option<&Version> {
+        self.0.get(specifier)
+    }
+
     pub fn add(&mut self, dependency: impl Into<String>, version: impl Into<Version>) {
         self.0.insert(dependency.into(), version.into());
     }
@@ -1,30 +1,43 @@
 use crate::matcher::Pattern;
+use crate::module_graph::ProjectRules;
 use crate::settings::Settings;
-use crate::{DynRef, WorkspaceError};
-use biome_analyze::AnalyzerRules;
+use crate::workspace::{
+    FeatureName, IsPathIgnoredParams, PluginFile, RegisterVcsIgnoreFilesParams, VcsIgnoreFile,
+};
+use crate::{DynRef, Workspace, WorkspaceError};
+use biome_analyze::{AnalyzerRules, SuppressionPolicy};
+use biome_configuration::analyzer::{RuleDomains, RuleSelector};
 use biome_configuration::diagnostics::{CantLoadExtendFile, EditorConfigDiagnostic};
 use biome_configuration::{push_to_analyzer_assists, VERSION};
 use biome_configuration::{
     push_to_analyzer_rules, BiomeDiagnostic, ConfigurationPathHint, ConfigurationPayload,
-    PartialConfiguration,
+    PartialConfiguration, Rules,
 };
 use biome_console::markup;
 use biome_css_analyze::METADATA as css_lint_metadata;
 use biome_deserialize::json::deserialize_from_json_str;
 use biome_deserialize::{Deserialized, Merge};
+use biome_deserialize_macros::Deserializable;
 use biome_diagnostics::{DiagnosticExt, Error, Severity};
-use biome_fs::{AutoSearchResult, ConfigName, FileSystem, OpenOptions};
+use biome_fs::{
+    AutoSearchResult, BiomePath, ConfigName, FileSystem, FileSystemDiagnostic, OpenOptions,
+    PathInterner, TraversalContext, TraversalScope,
+};
 use biome_graphql_analyze::METADATA as graphql_lint_metadata;
 use biome_js_analyze::METADATA as js_lint_metadata;
 use biome_json_analyze::METADATA as json_lint_metadata;
 use biome_json_formatter::context::JsonFormatOptions;
 use biome_json_parser::{parse_json, JsonParserOptions};
+use biome_rowan::AstSeparatedList;
+use rustc_hash::FxHashSet;
+use std::collections::BTreeSet;
 use std::ffi::OsStr;
 use std::fmt::Debug;
 use std::io::ErrorKind;
 use std::iter::FusedIterator;
 use std::ops::Deref;
-use std::path::{Path, PathBuf};
+use std::path::{Component, Path, PathBuf};
+use std::sync::{Mutex, RwLock};
 
 /// Information regarding the configuration that was found.
 ///
@@ -40,6 +53,12 @@ pub struct LoadedConfiguration {
     pub configuration: PartialConfiguration,
     /// All diagnostics that were emitted during parsing and deserialization
     pub diagnostics: Vec<Error>,
+    /// Whether the configuration was loaded from the user-level configuration directory,
+    /// because the project doesn't have a configuration file.
+    ///
+    /// In this case, [LoadedConfiguration::directory_path] is `None`: the configuration
+    /// doesn't belong to a project directory.
+    pub is_user_configuration: bool,
 }
 
 impl LoadedConfiguration {
@@ -136,17 +155,219 @@ impl LoadedConfiguration {
                 .collect(),
             directory_path: configuration_file_path.parent().map(PathBuf::from),
             file_path: Some(configuration_file_path),
+            is_user_configuration: false,
         })
     }
 }
 
 /// Load the partial configuration for this session of the CLI.
+///
+/// When the configuration found has `"root": false`, the configurations of the parent
+/// directories are loaded too, and the configuration is merged on top of them.
+///
+/// When no configuration is found, the configuration of the user-level configuration
+/// directory is loaded instead, see [load_user_configuration].
 pub fn load_configuration(
     fs: &DynRef<'_, dyn FileSystem>,
     config_path: ConfigurationPathHint,
 ) -> Result<LoadedConfiguration, WorkspaceError> {
-    let config = load_config(fs, config_path)?;
-    LoadedConfiguration::try_from_payload(config, fs)
+    let Some(config) = load_config(fs, config_path)? else {
+        return load_user_configuration(fs);
+    };
+    let mut loaded_configuration = LoadedConfiguration::try_from_payload(Some(config), fs)?;
+
+    let mut is_root = loaded_configuration.configuration.is_root();
+    let mut search_path = loaded_configuration
+        .directory_path()
+        .and_then(Path::parent)
+        .map(PathBuf::from);
+    while !is_root {
+        let Some(parent_directory) = search_path.take() else {
+            break;
+        };
+        let Some(parent_config) =
+            load_config(fs, ConfigurationPathHint::FromWorkspace(parent_directory))?
+        else {
+            break;
+        };
+        let mut parent = LoadedConfiguration::try_from_payload(Some(parent_config), fs)?;
+        is_root = parent.configuration.is_root();
+        search_path = parent
+            .directory_path()
+            .and_then(Path::parent)
+            .map(PathBuf::from);
+        // The configuration that was found first keeps its paths, and is applied on top of its parent
+        let configuration = std::mem::replace(
+            &mut loaded_configuration.configuration,
+            parent.configuration,
+        );
+        loaded_configuration.configuration.merge_with(configuration);
+        loaded_configuration
+            .diagnostics
+            .append(&mut parent.diagnostics);
+    }
+
+    Ok(loaded_configuration)
+}
+
+/// Loads the `biome.json` or `biome.jsonc` file of the user-level configuration directory,
+/// e.g. `~/.config/biome/biome.json`.
+///
+/// This configuration is only used when the project doesn't have one: it's never merged
+/// with the configuration of a project.
+fn load_user_configuration(
+    fs: &DynRef<'_, dyn FileSystem>,
+) -> Result<LoadedConfiguration, WorkspaceError> {
+    let Some(user_config_directory) = fs.user_config_directory() else {
+        return Ok(LoadedConfiguration::default());
+    };
+    let Some(configuration_file_path) = ConfigName::file_names()
+        .into_iter()
+        .map(|file_name| user_config_directory.join(file_name))
+        .find(|file_path| fs.path_is_file(file_path))
+    else {
+        return Ok(LoadedConfiguration::default());
+    };
+
+    let config = read_config_file(fs, &configuration_file_path, user_config_directory)?;
+    let mut loaded_configuration = LoadedConfiguration::try_from_payload(Some(config), fs)?;
+    loaded_configuration.directory_path = None;
+    loaded_configuration.is_user_configuration = true;
+    Ok(loaded_configuration)
+}
+
+/// Loads the configuration file of a subdirectory of the project, found by [find_nested_configuration_files].
+///
+/// Unlike [load_configuration], the parent directories aren't searched: the workspace merges the
+/// configuration with the one of its parent directory when it has `"root": false`.
+pub fn load_nested_configuration(
+    fs: &DynRef<'_, dyn FileSystem>,
+    configuration_file_path: &Path,
+) -> Result<LoadedConfiguration, WorkspaceError> {
+    let external_resolution_base_path = configuration_file_path
+        .parent()
+        .map(PathBuf::from)
+        .unwrap_or_default();
+    let config = read_config_file(fs, configuration_file_path, external_resolution_base_path)?;
+    LoadedConfiguration::try_from_payload(Some(config), fs)
+}
+
+/// Returns the configuration files of the subdirectories of `root` that apply to the given `paths`:
+/// the ones in the directories between `root` and each path, and the ones inside the directories
+/// of `paths`.
+///
+/// The directories ignored by the workspace aren't traversed. A configuration file always comes
+/// before the configuration files of its subdirectories.
+pub fn find_nested_configuration_files(
+    fs: &dyn FileSystem,
+    workspace: &dyn Workspace,
+    features: FeatureName,
+    root: &Path,
+    paths: &[PathBuf],
+) -> Vec<PathBuf> {
+    let (interner, _) = PathInterner::new();
+    let scanner = NestedConfigurationScanner {
+        fs,
+        workspace,
+        features,
+        interner,
+        directories: Mutex::default(),
+    };
+
+    fs.traversal(Box::new(|scope: &dyn TraversalScope| {
+        for path in paths {
+            scope.evaluate(&scanner, root.join(path));
+        }
+    }));
+
+    let mut directories = scanner.directories.into_inner().unwrap();
+    directories.extend(paths.iter().map(|path| root.join(path)));
+    let directories: BTreeSet<PathBuf> = directories
+        .iter()
+        .flat_map(|directory| {
+            directory
+                .ancestors()
+                .take_while(|ancestor| ancestor.starts_with(root) && *ancestor != root)
+        })
+        // `./packages` and `packages` are the same directory
+        .map(|directory| {
+            directory
+                .components()
+                .filter(|component| !matches!(component, Component::CurDir))
+                .collect()
+        })
+        .filter(|directory: &PathBuf| directory.as_path() != root)
+        .collect();
+
+    let mut configuration_files: Vec<_> = directories
+        .into_iter()
+        .filter_map(|directory| {
+            ConfigName::file_names()
+                .into_iter()
+                .map(|file_name| directory.join(file_name))
+                .find(|file_path| fs.path_is_file(file_path))
+                .or_else(|| {
+                    let file_path = directory.join(ConfigName::package_json());
+                    fs.read_file_from_path(&file_path)
+                        .is_ok_and(|content| has_package_json_configuration(&content))
+                        .then_some(file_path)
+                })
+        })
+        .collect();
+    configuration_files.sort_by_key(|path| path.components().count());
+    configuration_files
+}
+
+/// Collects the directories found while traversing the paths of [find_nested_configuration_files]
+struct NestedConfigurationScanner<'a> {
+    fs: &'a dyn FileSystem,
+    workspace: &'a dyn Workspace,
+    features: FeatureName,
+    interner: PathInterner,
+    /// The directories that aren't ignored, and the directories of the traversed files
+    directories: Mutex<BTreeSet<PathBuf>>,
+}
+
+impl TraversalContext for NestedConfigurationScanner<'_> {
+    fn interner(&self) -> &PathInterner {
+        &self.interner
+    }
+
+    fn push_diagnostic(&self, _error: Error) {
+        // The directories that can't be read are reported by the traversal of the command
+    }
+
+    fn can_handle(&self, path: &BiomePath) -> bool {
+        if !self.fs.path_is_dir(path) {
+            // Some file systems only traverse files
+            if let Some(directory) = path.parent() {
+                self.directories
+                    .lock()
+                    .unwrap()
+                    .insert(directory.to_path_buf());
+            }
+            return false;
+        }
+        let is_ignored = self
+            .workspace
+            .is_path_ignored(IsPathIgnoredParams {
+                biome_path: path.clone(),
+                features: self.features,
+            })
+            .unwrap_or_default();
+        if !is_ignored {
+            self.directories.lock().unwrap().insert(path.to_path_buf());
+        }
+        !is_ignored
+    }
+
+    fn handle_path(&self, _path: BiomePath) {}
+
+    fn store_path(&self, _path: BiomePath) {}
+
+    fn evaluated_paths(&self) -> BTreeSet<BiomePath> {
+        BTreeSet::new()
+    }
 }
 
 /// - [Result]: if an error occurred while loading the configuration file.
@@ -189,20 +410,8 @@ fn load_config(
     // we'll load it directly
     if let ConfigurationPathHint::FromUser(ref config_file_path) = base_path {
         if file_system.path_is_file(config_file_path) {
-            let content = file_system.read_file_from_path(config_file_path)?;
-            let parser_options = match config_file_path.extension().map(OsStr::as_encoded_bytes) {
-                Some(b"json") => JsonParserOptions::default(),
-                _ => JsonParserOptions::default()
-                    .with_allow_comments()
-                    .with_allow_trailing_commas(),
-            };
-            let deserialized =
-                deserialize_from_json_str::<PartialConfiguration>(&content, parser_options, "");
-            return Ok(Some(ConfigurationPayload {
-                deserialized,
-                configuration_file_path: PathBuf::from(config_file_path),
-                external_resolution_base_path,
-            }));
+            return read_config_file(file_system, config_file_path, external_resolution_base_path)
+                .map(Some);
         }
     }
 
@@ -216,42 +425,32 @@ fn load_config(
         ConfigurationPathHint::None => file_system.working_directory().unwrap_or_default(),
     };
 
-    // We first search for `biome.json` or `biome.jsonc` files
-    if let Some(auto_search_result) = match file_system.auto_search(
-        &configuration_directory,
-        ConfigName::file_names().as_slice(),
-        should_error,
-    ) {
-        Ok(Some(auto_search_result)) => Some(auto_search_result),
-        // We then search for the deprecated `rome.json` file
-        // if neither `biome.json` nor `biome.jsonc` is found
-        // TODO: The following arms should be removed in v2.0.0
-        Ok(None) => file_system.auto_search(
-            &configuration_directory,
-            [file_system.deprecated_config_name()].as_slice(),
-            should_error,
-        )?,
-        Err(error) => file_system
-            .auto_search(
+    // We first search for `biome.json` or `biome.jsonc` files, or a `package.json` file with a `biome` key
+    if let Some(auto_search_result) =
+        match auto_search_configuration(file_system, &configuration_directory, should_error) {
+            Ok(Some(auto_search_result)) => Some(auto_search_result),
+            // We then search for the deprecated `rome.json` file
+            // if neither `biome.json` nor `biome.jsonc` is found
+            // TODO: The following arms should be removed in v2.0.0
+            Ok(None) => file_system.auto_search(
                 &configuration_directory,
                 [file_system.deprecated_config_name()].as_slice(),
                 should_error,
-            )
-            // Map the error so users won't see error messages
-            // that contains `rome.json`
-            .map_err(|_| error)?,
-    } {
+            )?,
+            Err(error) => file_system
+                .auto_search(
+                    &configuration_directory,
+                    [file_system.deprecated_config_name()].as_slice(),
+                    should_error,
+                )
+                // Map the error so users won't see error messages
+                // that contains `rome.json`
+                .map_err(|_| error)?,
+        }
+    {
         let AutoSearchResult { content, file_path } = auto_search_result;
 
-        let parser_options = match file_path.extension().map(OsStr::as_encoded_bytes) {
-            Some(b"json") => JsonParserOptions::default(),
-            _ => JsonParserOptions::default()
-                .with_allow_comments()
-                .with_allow_trailing_commas(),
-        };
-
-        let deserialized =
-            deserialize_from_json_str::<PartialConfiguration>(&content, parser_options, "");
+        let deserialized = deserialize_configuration(&content, &file_path);
 
         Ok(Some(ConfigurationPayload {
             deserialized,
@@ -263,6 +462,109 @@ fn load_config(
     }
 }
 
+/// Searches the configuration of `search_dir`, then the one of its parent directories, and returns
+/// the first one found. The configuration of a directory is its `biome.json` or `biome.jsonc` file,
+/// or else the `biome` key of its `package.json` file.
+///
+/// When `should_error_if_file_not_found` is `true`, only `search_dir` is searched, and an error
+/// is returned if it doesn't have a configuration.
+fn auto_search_configuration(
+    file_system: &DynRef<'_, dyn FileSystem>,
+    search_dir: &Path,
+    should_error_if_file_not_found: bool,
+) -> Result<Option<AutoSearchResult>, FileSystemDiagnostic> {
+    for directory in search_dir.ancestors() {
+        let mut error = None;
+        for file_name in ConfigName::file_names() {
+            let file_path = directory.join(file_name);
+            match file_system.read_file_from_path(&file_path) {
+                Ok(content) => return Ok(Some(AutoSearchResult { content, file_path })),
+                Err(diagnostic) => {
+                    error.get_or_insert(diagnostic);
+                }
+            }
+        }
+
+        let file_path = directory.join(ConfigName::package_json());
+        if let Ok(content) = file_system.read_file_from_path(&file_path) {
+            if has_package_json_configuration(&content) {
+                return Ok(Some(AutoSearchResult { content, file_path }));
+            }
+        }
+
+        if should_error_if_file_not_found {
+            return error.map_or(Ok(None), Err);
+        }
+    }
+
+    Ok(None)
+}
+
+/// The fields of a `package.json` file that Biome reads its configuration from
+#[derive(Debug, Default, Deserializable)]
+#[deserializable(unknown_fields = "allow")]
+struct BiomePackageJson {
+    biome: Option<PartialConfiguration>,
+}
+
+/// Whether the content of a `package.json` file has a `biome` key
+fn has_package_json_configuration(content: &str) -> bool {
+    parse_json(content, JsonParserOptions::default())
+        .tree()
+        .value()
+        .ok()
+        .and_then(|value| value.as_json_object_value().cloned())
+        .is_some_and(|object| {
+            object.json_member_list().iter().flatten().any(|member| {
+                member
+                    .name()
+                    .and_then(|name| name.inner_string_text())
+                    .is_ok_and(|name| name.text() == "biome")
+            })
+        })
+}
+
+/// Deserializes the content of the configuration file at the given path.
+///
+/// The configuration of a `package.json` file is its `biome` key. The other files are
+/// deserialized as `.jsonc` files when they don't end with `.json`.
+fn deserialize_configuration(
+    content: &str,
+    config_file_path: &Path,
+) -> Deserialized<PartialConfiguration> {
+    if config_file_path.file_name() == Some(OsStr::new(ConfigName::package_json())) {
+        return deserialize_from_json_str::<BiomePackageJson>(
+            content,
+            JsonParserOptions::default(),
+            "",
+        )
+        .map(|package_json| package_json.biome.unwrap_or_default());
+    }
+
+    let parser_options = match config_file_path.extension().map(OsStr::as_encoded_bytes) {
+        Some(b"json") => JsonParserOptions::default(),
+        _ => JsonParserOptions::default()
+            .with_allow_comments()
+            .with_allow_trailing_commas(),
+    };
+    deserialize_from_json_str::<PartialConfiguration>(content, parser_options, "")
+}
+
+/// Reads the configuration file at the given path, see [deserialize_configuration].
+fn read_config_file(
+    file_system: &DynRef<'_, dyn FileSystem>,
+    config_file_path: &Path,
+    external_resolution_base_path: PathBuf,
+) -> Result<ConfigurationPayload, WorkspaceError> {
+    let content = file_system.read_file_from_path(&config_file_path.to_path_buf())?;
+    let deserialized = deserialize_configuration(&content, config_file_path);
+    Ok(ConfigurationPayload {
+        deserialized,
+        configuration_file_path: PathBuf::from(config_file_path),
+        external_resolution_base_path,
+    })
+}
+
 pub fn load_editorconfig(
     file_system: &DynRef<'_, dyn FileSystem>,
     workspace_root: PathBuf,
@@ -311,7 +613,7 @@ pub fn load_editorconfig(
 /// ## Errors
 ///
 /// It fails if:
-/// - the configuration file already exists
+/// - the configuration file already exists, or the configuration is in the `biome` key of `package.json`
 /// - the program doesn't have the write rights
 pub fn create_config(
     fs: &mut DynRef<dyn FileSystem>,
@@ -321,7 +623,10 @@ pub fn create_config(
     let json_path = PathBuf::from(ConfigName::biome_json());
     let jsonc_path = PathBuf::from(ConfigName::biome_jsonc());
 
-    if fs.path_exists(&json_path) || fs.path_exists(&jsonc_path) {
+    let has_package_json_configuration = fs
+        .read_file_from_path(&PathBuf::from(ConfigName::package_json()))
+        .is_ok_and(|content| has_package_json_configuration(&content));
+    if fs.path_exists(&json_path) || fs.path_exists(&jsonc_path) || has_package_json_configuration {
         return Err(BiomeDiagnostic::new_already_exists().into());
     }
 
@@ -383,6 +688,26 @@ pub fn to_analyzer_rules(settings: &Settings, path: &Path) -> AnalyzerRules {
     overrides.override_analyzer_rules(path, analyzer_rules)
 }
 
+/// Returns the policy that the suppression comments must follow, given the [Settings]
+pub fn to_suppression_policy(settings: &Settings) -> SuppressionPolicy {
+    let suppressions = &settings.linter.suppressions;
+    SuppressionPolicy {
+        require_reason: suppressions.require_reason.unwrap_or_default(),
+        reason_patterns: suppressions
+            .reason_patterns
+            .iter()
+            .flatten()
+            .map(|(name, pattern)| (name.clone(), pattern.regex().clone()))
+            .collect(),
+        forbidden: suppressions
+            .forbidden
+            .iter()
+            .flat_map(|forbidden| forbidden.iter().cloned())
+            .collect(),
+        max_per_file: suppressions.max_per_file.map(usize::from),
+    }
+}
+
 pub trait PartialConfigurationExt {
     fn apply_extends(
         &mut self,
@@ -406,6 +731,22 @@ pub trait PartialConfigurationExt {
         file_system: &DynRef<'_, dyn FileSystem>,
         vcs_base_path: Option<&Path>,
     ) -> Result<(Option<PathBuf>, Vec<String>), WorkspaceError>;
+
+    fn retrieve_vcs_ignore_files(
+        &self,
+        file_system: &DynRef<'_, dyn FileSystem>,
+        vcs_root: &Path,
+    ) -> Result<Vec<VcsIgnoreFile>, WorkspaceError>;
+
+    fn vcs_ignore_file_loader(&self, vcs_root: &Path) -> Option<VcsIgnoreFileLoader>;
+
+    fn retrieve_plugins(
+        &self,
+        file_system: &DynRef<'_, dyn FileSystem>,
+        base_path: &Path,
+    ) -> Result<Vec<PluginFile>, WorkspaceError>;
+
+    fn requires_module_graph(&self, only: &[RuleSelector]) -> bool;
 }
 
 impl PartialConfigurationExt for PartialConfiguration {
@@ -605,9 +946,305 @@ impl PartialConfigurationExt for PartialConfiguration {
                                 .collect::<Vec<String>>(),
                         ));
                     }
+                    // The other ignore files of the VCS can still apply
+                    return Ok((Some(vcs_base_path), vec![]));
                 }
             }
         }
         Ok((None, vec![]))
     }
+
+    /// This function retrieves the ignore files of the VCS that apply to the whole repository,
+    /// other than the `.gitignore` file of its root directory, when the VCS integration uses them:
+    /// - the global ignore file of the user, `core.excludesFile` in the configuration of Git;
+    /// - the `.git/info/exclude` file of the repository.
+    ///
+    /// The `.gitignore` files of the subdirectories are loaded by [VcsIgnoreFileLoader] when
+    /// the directories are reached.
+    ///
+    /// ## Returns
+    ///
+    /// The ignore files, from the lowest to the highest precedence
+    fn retrieve_vcs_ignore_files(
+        &self,
+        file_system: &DynRef<'_, dyn FileSystem>,
+        vcs_root: &Path,
+    ) -> Result<Vec<VcsIgnoreFile>, WorkspaceError> {
+        if !uses_vcs_ignore_files(self) {
+            return Ok(vec![]);
+        }
+
+        let mut ignore_files = Vec::new();
+        let global_ignore_file = git_excludes_file(&**file_system, vcs_root);
+        let exclude_file = vcs_root.join(".git/info/exclude");
+        for file_path in global_ignore_file.into_iter().chain([exclude_file]) {
+            if file_system.path_is_file(&file_path) {
+                let content = file_system.read_file_from_path(&file_path)?;
+                ignore_files.push(VcsIgnoreFile {
+                    directory: vcs_root.to_path_buf(),
+                    patterns: content.lines().map(String::from).collect(),
+                });
+            }
+        }
+        Ok(ignore_files)
+    }
+
+    /// Returns the loader of the `.gitignore` files of the subdirectories of `vcs_root`, when the
+    /// VCS integration uses them
+    fn vcs_ignore_file_loader(&self, vcs_root: &Path) -> Option<VcsIgnoreFileLoader> {
+        uses_vcs_ignore_files(self).then(|| VcsIgnoreFileLoader::new(vcs_root.to_path_buf()))
+    }
+
+    /// Reads the GritQL files listed in the `plugins` field, whose paths are relative to
+    /// `base_path`, the directory of the configuration file
+    fn retrieve_plugins(
+        &self,
+        file_system: &DynRef<'_, dyn FileSystem>,
+        base_path: &Path,
+    ) -> Result<Vec<PluginFile>, WorkspaceError> {
+        let Some(plugins) = &self.plugins else {
+            return Ok(vec![]);
+        };
+
+        plugins
+            .iter()
+            .map(|plugin| {
+                // `./plugins/plugin.grit` is read as `plugins/plugin.grit`
+                let path = Path::new(plugin.path())
+                    .components()
+                    .filter(|component| !matches!(component, Component::CurDir))
+                    .fold(base_path.to_path_buf(), |path, component| {
+                        path.join(component)
+                    });
+                let content = file_system.read_file_from_path(&path)?;
+                Ok(PluginFile {
+                    path,
+                    content,
+                    severity: plugin.severity(),
+                })
+            })
+            .collect()
+    }
+
+    /// Returns whether the linter enables rules of the project phase, which need the module
+    /// graph of the project, either globally or in one of the overrides. When rules are selected
+    /// with `--only`, only these rules are considered.
+    fn requires_module_graph(&self, only: &[RuleSelector]) -> bool {
+        if self.is_linter_disabled() {
+            return false;
+        }
+        let project_rules = ProjectRules::new();
+        if !only.is_empty() {
+            return project_rules.any_selected(only);
+        }
+        let rules = self.get_linter_rules();
+        let requires_module_graph = |rules: &Rules, domains: Option<&RuleDomains>| {
+            project_rules.any_enabled(&rules.as_enabled_rules())
+                || domains
+                    .is_some_and(|domains| project_rules.any_enabled_by_domains(domains, rules))
+        };
+        let domains = self
+            .linter
+            .as_ref()
+            .and_then(|linter| linter.domains.as_ref());
+        if requires_module_graph(&rules, domains) {
+            return true;
+        }
+        self.overrides
+            .iter()
+            .flat_map(|overrides| overrides.0.iter())
+            .any(|pattern| {
+                pattern.linter.as_ref().is_some_and(|linter| {
+                    linter.enabled != Some(false)
+                        && requires_module_graph(
+                            linter.rules.as_ref().unwrap_or(&rules),
+                            linter.domains.as_ref(),
+                        )
+                })
+            })
+    }
+}
+
+/// Whether the VCS integration is enabled and uses the ignore files of the VCS
+fn uses_vcs_ignore_files(configuration: &PartialConfiguration) -> bool {
+    configuration.vcs.as_ref().is_some_and(|vcs| {
+        vcs.is_enabled() && vcs.client_kind.is_some() && !vcs.ignore_file_disabled()
+    })
+}
+
+/// Returns the path of the global ignore file of Git: the last `core.excludesFile` of the
+/// configuration files of Git, or `$XDG_CONFIG_HOME/git/ignore` by default.
+fn git_excludes_file(file_system: &dyn FileSystem, vcs_root: &Path) -> Option<PathBuf> {
+    let home = file_system.home_directory();
+    let config_home = file_system.config_home_directory();
+
+    let config_files = [
+        config_home
+            .as_ref()
+            .map(|config_home| config_home.join("git/config")),
+        home.as_ref().map(|home| home.join(".gitconfig")),
+        Some(vcs_root.join(".git/config")),
+    ];
+    let mut excludes_file = None;
+    for config_file in config_files.into_iter().flatten() {
+        if !file_system.path_is_file(&config_file) {
+            continue;
+        }
+        let Ok(content) = file_system.read_file_from_path(&config_file) else {
+            continue;
+        };
+        if let Some(value) = parse_git_excludes_file(&content) {
+            excludes_file = Some(value);
+        }
+    }
+
+    match excludes_file {
+        Some(excludes_file) => match (excludes_file.strip_prefix("~/"), home) {
+            (Some(path), Some(home)) => Some(home.join(path)),
+            _ => Some(PathBuf::from(excludes_file)),
+        },
+        None => config_home.map(|config_home| config_home.join("git/ignore")),
+    }
+}
+
+/// Returns the last value of `core.excludesFile` in the content of a configuration file of Git
+fn parse_git_excludes_file(content: &str) -> Option<String> {
+    let mut in_core_section = false;
+    let mut excludes_file = None;
+    for line in content.lines() {
+        let line = line.trim();
+        if let Some(section) = line.strip_prefix('[') {
+            let name = section
+                .split(|c: char| c == ']' || c.is_whitespace())
+                .next()
+                .unwrap_or_default();
+            in_core_section = name.eq_ignore_ascii_case("core");
+            continue;
+        }
+        if !in_core_section {
+            continue;
+        }
+        if let Some((key, value)) = line.split_once('=') {
+            if key.trim().eq_ignore_ascii_case("excludesFile") {
+                let value = value.trim();
+                let value = value
+                    .strip_prefix('"')
+                    .and_then(|value| value.strip_suffix('"'))
+                    .unwrap_or(value);
+                excludes_file = Some(value.to_string());
+            }
+        }
+    }
+    excludes_file
+}
+
+/// Loads the `.gitignore` files of the subdirectories of the root directory of the VCS when the
+/// traversal, or the editor, reaches them, and registers them in the workspace.
+///
+/// Each directory is only visited once. The `.gitignore` file of the root directory isn't loaded,
+/// it's part of the settings of the VCS.
+#[derive(Debug)]
+pub struct VcsIgnoreFileLoader {
+    vcs_root: PathBuf,
+    visited_directories: RwLock<FxHashSet<PathBuf>>,
+}
+
+impl VcsIgnoreFileLoader {
+    fn new(vcs_root: PathBuf) -> Self {
+        Self {
+            vcs_root,
+            visited_directories: RwLock::default(),
+        }
+    }
+
+    /// Loads the `.gitignore` files of the directories between the root directory and `path`,
+    /// from top to bottom
+    pub fn load_parent_directories(
+        &self,
+        fs: &dyn FileSystem,
+        workspace: &dyn Workspace,
+        path: &Path,
+    ) -> Result<(), WorkspaceError> {
+        let path = self.resolve(path);
+        // The parents of a visited directory are visited before it
+        if path.parent().is_some_and(|parent| {
+            !parent.starts_with(&self.vcs_root)
+                || parent == self.vcs_root
+                || self.visited_directories.read().unwrap().contains(parent)
+        }) {
+            return Ok(());
+        }
+        let mut visited_directories = self.visited_directories.write().unwrap();
+        let mut directories: Vec<_> = path
+            .ancestors()
+            .skip(1)
+            .take_while(|directory| {
+                directory.starts_with(&self.vcs_root)
+                    && *directory != self.vcs_root
+                    && !visited_directories.contains(*directory)
+            })
+            .collect();
+        directories.reverse();
+        let mut ignore_files = Vec::new();
+        for directory in directories {
+            visited_directories.insert(directory.to_path_buf());
+            ignore_files.extend(read_ignore_file(fs, directory)?);
+        }
+        register_ignore_files(workspace, ignore_files)
+    }
+
+    /// Loads the `.gitignore` file of the directory. The directory should not be ignored.
+    pub fn load_directory(
+        &self,
+        fs: &dyn FileSystem,
+        workspace: &dyn Workspace,
+        directory: &Path,
+    ) -> Result<(), WorkspaceError> {
+        let directory = self.resolve(directory);
+        if !directory.starts_with(&self.vcs_root) || directory == self.vcs_root {
+            return Ok(());
+        }
+        let mut visited_directories = self.visited_directories.write().unwrap();
+        if !visited_directories.insert(directory.clone()) {
+            return Ok(());
+        }
+        let ignore_files = read_ignore_file(fs, &directory)?;
+        register_ignore_files(workspace, ignore_files.into_iter().collect())
+    }
+
+    /// The relative paths are relative to the root directory. `./packages` and `packages` are the
+    /// same directory.
+    fn resolve(&self, path: &Path) -> PathBuf {
+        self.vcs_root
+            .join(path)
+            .components()
+            .filter(|component| !matches!(component, Component::CurDir))
+            .collect()
+    }
+}
+
+/// Reads the `.gitignore` file of the directory, if it has one
+fn read_ignore_file(
+    fs: &dyn FileSystem,
+    directory: &Path,
+) -> Result<Option<VcsIgnoreFile>, WorkspaceError> {
+    let file_path = directory.join(".gitignore");
+    if !fs.path_is_file(&file_path) {
+        return Ok(None);
+    }
+    let content = fs.read_file_from_path(&file_path)?;
+    Ok(Some(VcsIgnoreFile {
+        directory: directory.to_path_buf(),
+        patterns: content.lines().map(String::from).collect(),
+    }))
+}
+
+fn register_ignore_files(
+    workspace: &dyn Workspace,
+    ignore_files: Vec<VcsIgnoreFile>,
+) -> Result<(), WorkspaceError> {
+    if ignore_files.is_empty() {
+        return Ok(());
+    }
+    workspace.register_vcs_ignore_files(RegisterVcsIgnoreFilesParams { ignore_files })
 }
@@ -1,4 +1,6 @@
-use biome_analyze::{GroupCategory, Queryable, RegistryVisitor, Rule, RuleCategory, RuleMetadata};
+use biome_analyze::{
+    GroupCategory, Queryable, RegistryVisitor, Rule, RuleCategory, RuleGroup, RuleMetadata,
+};
 use biome_css_syntax::CssLanguage;
 use biome_graphql_syntax::GraphqlLanguage;
 use biome_js_syntax::JsLanguage;
@@ -29,8 +31,26 @@ impl FromStr for Doc {
     }
 }
 
+/// The metadata of a lint rule, along with the name of the group it belongs to
+#[derive(Debug, Clone)]
+pub struct LintRuleMetadata {
+    pub group: &'static str,
+    pub metadata: RuleMetadata,
+}
+
+/// Returns the metadata of the lint rules of all languages, sorted by group, name and language.
+///
+/// A rule that exists for several languages, like `noDuplicateObjectKeys`, is returned once per language.
+pub fn lint_rules_metadata() -> Vec<LintRuleMetadata> {
+    LintRulesVisitor::new()
+        .rules_metadata
+        .into_iter()
+        .map(|((group, ..), metadata)| LintRuleMetadata { group, metadata })
+        .collect()
+}
+
 struct LintRulesVisitor {
-    rules_metadata: BTreeMap<&'static str, RuleMetadata>,
+    rules_metadata: BTreeMap<(&'static str, &'static str, &'static str), RuleMetadata>,
 }
 
 impl LintRulesVisitor {
@@ -47,8 +67,10 @@ impl LintRulesVisitor {
         visitor
     }
 
-    fn get_metadata(&mut self, name: &str) -> Option<RuleMetadata> {
-        self.rules_metadata.remove(name)
+    fn get_metadata(self, name: &str) -> Option<RuleMetadata> {
+        self.rules_metadata
+            .into_values()
+            .find(|metadata| metadata.name == name)
     }
 }
 
@@ -57,7 +79,14 @@ impl RegistryVisitor<JsLanguage> for LintRulesVisitor {
     where
         R: Rule<Options: Default, Query: Queryable<Language = JsLanguage, Output: Clone>> + 'static,
     {
-        self.rules_metadata.insert(R::METADATA.name, R::METADATA);
+        self.rules_metadata.insert(
+            (
+                <R::Group as RuleGroup>::NAME,
+                R::METADATA.name,
+                R::METADATA.language,
+            ),
+            R::METADATA,
+        );
     }
 
     fn record_category<C: biome_analyze::GroupCategory<Language = JsLanguage>>(&mut self) {
@@ -73,7 +102,14 @@ impl RegistryVisitor<JsonLanguage> for LintRulesVisitor {
         R: Rule<Options: Default, Query: Queryable<Language = JsonLanguage, Output: Clone>>
             + 'static,
     {
-        self.rules_metadata.insert(R::METADATA.name, R::METADATA);
+        self.rules_metadata.insert(
+            (
+                <R::Group as RuleGroup>::NAME,
+                R::METADATA.name,
+                R::METADATA.language,
+            ),
+            R::METADATA,
+        );
     }
 
     fn record_category<C: biome_analyze::GroupCategory<Language = JsonLanguage>>(&mut self) {
@@ -89,7 +125,14 @@ impl RegistryVisitor<CssLanguage> for LintRulesVisitor {
         R: Rule<Options: Default, Query: Queryable<Language = CssLanguage, Output: Clone>>
             + 'static,
     {
-        self.rules_metadata.insert(R::METADATA.name, R::METADATA);
+        self.rules_metadata.insert(
+            (
+                <R::Group as RuleGroup>::NAME,
+                R::METADATA.name,
+                R::METADATA.language,
+            ),
+            R::METADATA,
+        );
     }
 
     fn record_category<C: GroupCategory<Language = CssLanguage>>(&mut self) {
@@ -105,7 +148,14 @@ impl RegistryVisitor<GraphqlLanguage> for LintRulesVisitor {
         R: Rule<Options: Default, Query: Queryable<Language = GraphqlLanguage, Output: Clone>>
             + 'static,
     {
-        self.rules_metadata.insert(R::METADATA.name, R::METADATA);
+        self.rules_metadata.insert(
+            (
+                <R::Group as RuleGroup>::NAME,
+                R::METADATA.name,
+                R::METADATA.language,
+            ),
+            R::METADATA,
+        );
     }
 
     fn record_category<C: GroupCategory<Language = GraphqlLanguage>>(&mut self) {
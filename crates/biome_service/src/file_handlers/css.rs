@@ -2,7 +2,7 @@ use super::{
     is_diagnostic_error, AnalyzerVisitorBuilder, CodeActionsParams, ExtensionHandler, FixAllParams,
     LintParams, LintResults, ParseResult, SearchCapabilities,
 };
-use crate::configuration::to_analyzer_rules;
+use crate::configuration::{to_analyzer_rules, to_suppression_policy};
 use crate::file_handlers::DebugCapabilities;
 use crate::file_handlers::{
     AnalyzerCapabilities, Capabilities, FormatterCapabilities, ParserCapabilities,
@@ -149,12 +149,16 @@ impl ServiceLanguage for CssLanguage {
             preferred_quote,
             preferred_jsx_quote: Default::default(),
             jsx_runtime: None,
+            suppression_policy: global.map(to_suppression_policy).unwrap_or_default(),
+            // The plugins only support JavaScript for now
+            plugins: Vec::new(),
         };
 
         AnalyzerOptions {
             configuration,
             file_path: file_path.to_path_buf(),
             suppression_reason,
+            profiler: None,
         }
     }
 }
@@ -315,11 +319,14 @@ fn lint(params: LintParams) -> LintResults {
     debug_span!("Linting CSS file", path =? params.path, language =? params.language).in_scope(
         move || {
             let workspace_settings = &params.workspace;
-            let analyzer_options = workspace_settings.analyzer_options::<CssLanguage>(
-                params.path,
-                &params.language,
-                params.suppression_reason,
-            );
+            let analyzer_options = AnalyzerOptions {
+                profiler: params.profiler,
+                ..workspace_settings.analyzer_options::<CssLanguage>(
+                    params.path,
+                    &params.language,
+                    params.suppression_reason,
+                )
+            };
             let tree = params.parse.tree();
 
             let has_only_filter = !params.only.is_empty();
@@ -331,6 +338,7 @@ fn lint(params: LintParams) -> LintResults {
 
             let (enabled_rules, disabled_rules) =
                 AnalyzerVisitorBuilder::new(params.workspace.settings())
+                    .with_manifest(params.manifest.as_ref())
                     .with_syntax_rules()
                     .with_linter_rules(&params.only, &params.skip, params.path.as_path())
                     .with_assists_rules(&params.only, &params.skip, params.path.as_path())
@@ -434,6 +442,7 @@ pub(crate) fn code_actions(params: CodeActionsParams) -> PullActionsResult {
         workspace,
         path,
         manifest: _,
+        module_graph: _,
         language,
         only,
         skip,
@@ -454,6 +463,7 @@ pub(crate) fn code_actions(params: CodeActionsParams) -> PullActionsResult {
             let mut actions = Vec::new();
             let (enabled_rules, disabled_rules) =
                 AnalyzerVisitorBuilder::new(params.workspace.settings())
+                    .with_manifest(params.manifest.as_ref())
                     .with_syntax_rules()
                     .with_linter_rules(&only, &skip, params.path.as_path())
                     .with_assists_rules(&only, &skip, params.path.as_path())
@@ -500,12 +510,14 @@ pub(crate) fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceEr
             errors: 0,
             skipped_suggested_fixes: 0,
             code: tree.syntax().to_string(),
+            rule_durations: Vec::new(),
         });
     };
 
     // Compute final rules (taking `overrides` into account)
     let rules = settings.as_linter_rules(params.biome_path.as_path());
     let (enabled_rules, disabled_rules) = AnalyzerVisitorBuilder::new(params.workspace.settings())
+        .with_manifest(params.manifest.as_ref())
         .with_syntax_rules()
         .with_linter_rules(&params.only, &params.skip, params.biome_path.as_path())
         .with_assists_rules(&params.only, &params.skip, params.biome_path.as_path())
@@ -524,29 +536,35 @@ pub(crate) fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceEr
     let mut actions = Vec::new();
     let mut skipped_suggested_fixes = 0;
     let mut errors: u16 = 0;
-    let analyzer_options = params.workspace.analyzer_options::<CssLanguage>(
-        params.biome_path,
-        &params.document_file_source,
-        params.suppression_reason,
-    );
+    let analyzer_options = AnalyzerOptions {
+        profiler: params.profiler,
+        ..params.workspace.analyzer_options::<CssLanguage>(
+            params.biome_path,
+            &params.document_file_source,
+            params.suppression_reason,
+        )
+    };
     loop {
         let (action, _) = analyze(&tree, filter, &analyzer_options, |signal| {
             let current_diagnostic = signal.diagnostic();
 
             if let Some(diagnostic) = current_diagnostic.as_ref() {
-                if is_diagnostic_error(diagnostic, rules.as_deref()) {
+                if is_diagnostic_error(diagnostic, rules.as_deref(), &[]) {
                     errors += 1;
                 }
             }
 
             for action in signal.actions() {
-                // suppression actions should not be part of the fixes (safe or suggested)
-                if action.is_suppression() {
-                    continue;
-                }
-
                 match params.fix_file_mode {
+                    FixFileMode::ApplySuppressions => {
+                        if action.is_suppression() {
+                            return ControlFlow::Break(action);
+                        }
+                    }
                     FixFileMode::SafeFixes => {
+                        if action.is_suppression() {
+                            continue;
+                        }
                         if action.applicability == Applicability::MaybeIncorrect {
                             skipped_suggested_fixes += 1;
                         }
@@ -556,6 +574,9 @@ pub(crate) fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceEr
                         }
                     }
                     FixFileMode::SafeAndUnsafeFixes => {
+                        if action.is_suppression() {
+                            continue;
+                        }
                         if matches!(
                             action.applicability,
                             Applicability::Always | Applicability::MaybeIncorrect
@@ -564,9 +585,6 @@ pub(crate) fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceEr
                             return ControlFlow::Break(action);
                         }
                     }
-                    FixFileMode::ApplySuppressions => {
-                        // TODO: to implement
-                    }
                 }
             }
 
@@ -617,6 +635,7 @@ pub(crate) fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceEr
                     skipped_suggested_fixes,
                     actions,
                     errors: errors.into(),
+                    rule_durations: Vec::new(),
                 });
             }
         }
@@ -2,6 +2,7 @@ use super::{
     is_diagnostic_error, AnalyzerVisitorBuilder, CodeActionsParams, DocumentFileSource,
     ExtensionHandler, FixAllParams, LintParams, LintResults, ParseResult, SearchCapabilities,
 };
+use crate::configuration::to_suppression_policy;
 use crate::file_handlers::DebugCapabilities;
 use crate::file_handlers::{
     AnalyzerCapabilities, Capabilities, FormatterCapabilities, ParserCapabilities,
@@ -135,7 +136,7 @@ impl ServiceLanguage for GraphqlLanguage {
     }
 
     fn resolve_analyzer_options(
-        _global: Option<&Settings>,
+        global: Option<&Settings>,
         _linter: Option<&LinterSettings>,
         _overrides: Option<&OverrideSettings>,
         _language: Option<&Self::LinterSettings>,
@@ -144,9 +145,13 @@ impl ServiceLanguage for GraphqlLanguage {
         suppression_reason: Option<String>,
     ) -> AnalyzerOptions {
         AnalyzerOptions {
-            configuration: AnalyzerConfiguration::default(),
+            configuration: AnalyzerConfiguration {
+                suppression_policy: global.map(to_suppression_policy).unwrap_or_default(),
+                ..Default::default()
+            },
             file_path: path.to_path_buf(),
             suppression_reason,
+            profiler: None,
         }
     }
 }
@@ -294,11 +299,14 @@ fn lint(params: LintParams) -> LintResults {
     debug_span!("Linting GraphQL file", path =? params.path, language =? params.language).in_scope(
         move || {
             let workspace_settings = &params.workspace;
-            let analyzer_options = workspace_settings.analyzer_options::<GraphqlLanguage>(
-                params.path,
-                &params.language,
-                params.suppression_reason,
-            );
+            let analyzer_options = AnalyzerOptions {
+                profiler: params.profiler,
+                ..workspace_settings.analyzer_options::<GraphqlLanguage>(
+                    params.path,
+                    &params.language,
+                    params.suppression_reason,
+                )
+            };
             let tree = params.parse.tree();
 
             let has_only_filter = !params.only.is_empty();
@@ -310,6 +318,7 @@ fn lint(params: LintParams) -> LintResults {
 
             let (enabled_rules, disabled_rules) =
                 AnalyzerVisitorBuilder::new(params.workspace.settings())
+                    .with_manifest(params.manifest.as_ref())
                     .with_syntax_rules()
                     .with_linter_rules(&params.only, &params.skip, params.path.as_path())
                     .with_assists_rules(&params.only, &params.skip, params.path.as_path())
@@ -407,6 +416,7 @@ pub(crate) fn code_actions(params: CodeActionsParams) -> PullActionsResult {
         workspace,
         path,
         manifest: _,
+        module_graph: _,
         language,
         only,
         skip,
@@ -427,6 +437,7 @@ pub(crate) fn code_actions(params: CodeActionsParams) -> PullActionsResult {
             let mut actions = Vec::new();
             let (enabled_rules, disabled_rules) =
                 AnalyzerVisitorBuilder::new(params.workspace.settings())
+                    .with_manifest(params.manifest.as_ref())
                     .with_syntax_rules()
                     .with_linter_rules(&only, &skip, params.path.as_path())
                     .with_assists_rules(&only, &skip, params.path.as_path())
@@ -473,6 +484,7 @@ pub(crate) fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceEr
             errors: 0,
             skipped_suggested_fixes: 0,
             code: tree.syntax().to_string(),
+            rule_durations: Vec::new(),
         });
     };
 
@@ -480,6 +492,7 @@ pub(crate) fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceEr
     let rules = settings.as_linter_rules(params.biome_path.as_path());
 
     let (enabled_rules, disabled_rules) = AnalyzerVisitorBuilder::new(params.workspace.settings())
+        .with_manifest(params.manifest.as_ref())
         .with_syntax_rules()
         .with_linter_rules(&params.only, &params.skip, params.biome_path.as_path())
         .with_assists_rules(&params.only, &params.skip, params.biome_path.as_path())
@@ -498,29 +511,35 @@ pub(crate) fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceEr
     let mut actions = Vec::new();
     let mut skipped_suggested_fixes = 0;
     let mut errors: u16 = 0;
-    let analyzer_options = params.workspace.analyzer_options::<GraphqlLanguage>(
-        params.biome_path,
-        &params.document_file_source,
-        params.suppression_reason,
-    );
+    let analyzer_options = AnalyzerOptions {
+        profiler: params.profiler,
+        ..params.workspace.analyzer_options::<GraphqlLanguage>(
+            params.biome_path,
+            &params.document_file_source,
+            params.suppression_reason,
+        )
+    };
     loop {
         let (action, _) = analyze(&tree, filter, &analyzer_options, |signal| {
             let current_diagnostic = signal.diagnostic();
 
             if let Some(diagnostic) = current_diagnostic.as_ref() {
-                if is_diagnostic_error(diagnostic, rules.as_deref()) {
+                if is_diagnostic_error(diagnostic, rules.as_deref(), &[]) {
                     errors += 1;
                 }
             }
 
             for action in signal.actions() {
-                // suppression actions should not be part of the fixes (safe or suggested)
-                if action.is_suppression() {
-                    continue;
-                }
-
                 match params.fix_file_mode {
+                    FixFileMode::ApplySuppressions => {
+                        if action.is_suppression() {
+                            return ControlFlow::Break(action);
+                        }
+                    }
                     FixFileMode::SafeFixes => {
+                        if action.is_suppression() {
+                            continue;
+                        }
                         if action.applicability == Applicability::MaybeIncorrect {
                             skipped_suggested_fixes += 1;
                         }
@@ -530,6 +549,9 @@ pub(crate) fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceEr
                         }
                     }
                     FixFileMode::SafeAndUnsafeFixes => {
+                        if action.is_suppression() {
+                            continue;
+                        }
                         if matches!(
                             action.applicability,
                             Applicability::Always | Applicability::MaybeIncorrect
@@ -538,9 +560,6 @@ pub(crate) fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceEr
                             return ControlFlow::Break(action);
                         }
                     }
-                    FixFileMode::ApplySuppressions => {
-                        // TODO: implement once a GraphQL suppression action is available
-                    }
                 }
             }
 
@@ -589,6 +608,7 @@ pub(crate) fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceEr
                     skipped_suggested_fixes,
                     actions,
                     errors: errors.into(),
+                    rule_durations: Vec::new(),
                 });
             }
         }
@@ -104,6 +104,7 @@ impl ServiceLanguage for GritLanguage {
             configuration: AnalyzerConfiguration::default(),
             file_path: path.to_path_buf(),
             suppression_reason,
+            profiler: None,
         }
     }
 }
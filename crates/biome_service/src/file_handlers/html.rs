@@ -1,7 +1,12 @@
 use biome_analyze::{AnalyzerConfiguration, AnalyzerOptions};
-use biome_formatter::{IndentStyle, IndentWidth, LineEnding, LineWidth, Printed};
+use biome_formatter::{
+    AttributePosition, IndentStyle, IndentWidth, LineEnding, LineWidth, Printed,
+};
 use biome_fs::BiomePath;
-use biome_html_formatter::{format_node, HtmlFormatOptions};
+use biome_html_formatter::{
+    context::{IndentScriptAndStyle, SelfCloseVoidElements, WhitespaceSensitivity},
+    format_node, HtmlFormatOptions,
+};
 use biome_html_parser::parse_html_with_cache;
 use biome_html_syntax::{HtmlLanguage, HtmlRoot, HtmlSyntaxNode};
 use biome_parser::AnyParse;
@@ -25,6 +30,10 @@ pub struct HtmlFormatterSettings {
     pub line_width: Option<LineWidth>,
     pub indent_width: Option<IndentWidth>,
     pub indent_style: Option<IndentStyle>,
+    pub attribute_position: Option<AttributePosition>,
+    pub whitespace_sensitivity: Option<WhitespaceSensitivity>,
+    pub indent_script_and_style: Option<IndentScriptAndStyle>,
+    pub self_close_void_elements: Option<SelfCloseVoidElements>,
     pub enabled: Option<bool>,
 }
 
@@ -36,13 +45,31 @@ impl Default for HtmlFormatterSettings {
             indent_width: Default::default(),
             line_ending: Default::default(),
             line_width: Default::default(),
+            attribute_position: Default::default(),
+            whitespace_sensitivity: Default::default(),
+            indent_script_and_style: Default::default(),
+            self_close_void_elements: Default::default(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct HtmlLinterSettings {
+    pub enabled: Option<bool>,
+}
+
+impl Default for HtmlLinterSettings {
+    fn default() -> Self {
+        Self {
+            enabled: Some(false),
         }
     }
 }
 
 impl ServiceLanguage for HtmlLanguage {
     type FormatterSettings = HtmlFormatterSettings;
-    type LinterSettings = ();
+    type LinterSettings = HtmlLinterSettings;
     type OrganizeImportsSettings = ();
     type FormatOptions = HtmlFormatOptions;
     type ParserSettings = ();
@@ -79,11 +106,29 @@ impl ServiceLanguage for HtmlLanguage {
             .or(global.and_then(|g| g.line_ending))
             .unwrap_or_default();
 
+        let attribute_position = language
+            .and_then(|l| l.attribute_position)
+            .or(global.and_then(|g| g.attribute_position))
+            .unwrap_or_default();
+        let whitespace_sensitivity = language
+            .and_then(|l| l.whitespace_sensitivity)
+            .unwrap_or_default();
+        let indent_script_and_style = language
+            .and_then(|l| l.indent_script_and_style)
+            .unwrap_or_default();
+        let self_close_void_elements = language
+            .and_then(|l| l.self_close_void_elements)
+            .unwrap_or_default();
+
         let options = HtmlFormatOptions::new(file_source.to_html_file_source().unwrap_or_default())
             .with_indent_style(indent_style)
             .with_indent_width(indent_width)
             .with_line_width(line_width)
-            .with_line_ending(line_ending);
+            .with_line_ending(line_ending)
+            .with_attribute_position(attribute_position)
+            .with_whitespace_sensitivity(whitespace_sensitivity)
+            .with_indent_script_and_style(indent_script_and_style)
+            .with_self_close_void_elements(self_close_void_elements);
         if let Some(overrides) = overrides {
             overrides.to_override_html_format_options(path, options)
         } else {
@@ -104,6 +149,7 @@ impl ServiceLanguage for HtmlLanguage {
             configuration: AnalyzerConfiguration::default(),
             file_path: path.to_path_buf(),
             suppression_reason,
+            profiler: None,
         }
     }
 }
@@ -3,9 +3,9 @@ use super::{
     ExtensionHandler, FormatterCapabilities, LintParams, LintResults, ParseResult,
     ParserCapabilities, SearchCapabilities,
 };
-use crate::configuration::to_analyzer_rules;
+use crate::configuration::{to_analyzer_rules, to_suppression_policy};
 use crate::diagnostics::extension_error;
-use crate::file_handlers::{is_diagnostic_error, FixAllParams};
+use crate::file_handlers::{diagnostic_severity, is_diagnostic_error, FixAllParams};
 use crate::settings::{LinterSettings, OverrideSettings, Settings};
 use crate::workspace::{DocumentFileSource, OrganizeImportsResult};
 use crate::{
@@ -291,12 +291,17 @@ impl ServiceLanguage for JsLanguage {
             preferred_quote,
             preferred_jsx_quote,
             jsx_runtime,
+            suppression_policy: global.map(to_suppression_policy).unwrap_or_default(),
+            plugins: global
+                .map(|global| global.plugins.clone())
+                .unwrap_or_default(),
         };
 
         AnalyzerOptions {
             configuration,
             file_path: path.to_path_buf(),
             suppression_reason,
+            profiler: None,
         }
     }
 }
@@ -410,6 +415,7 @@ fn debug_control_flow(parse: AnyParse, cursor: TextSize) -> String {
         &options,
         JsFileSource::default(),
         None,
+        Default::default(),
         |_| ControlFlow::<Never>::Continue(()),
     );
 
@@ -446,11 +452,14 @@ pub(crate) fn lint(params: LintParams) -> LintResults {
                 };
             };
             let tree = params.parse.tree();
-            let analyzer_options = &params.workspace.analyzer_options::<JsLanguage>(
-                params.path,
-                &params.language,
-                params.suppression_reason,
-            );
+            let analyzer_options = &AnalyzerOptions {
+                profiler: params.profiler,
+                ..params.workspace.analyzer_options::<JsLanguage>(
+                    params.path,
+                    &params.language,
+                    params.suppression_reason,
+                )
+            };
 
             let rules = params
                 .workspace
@@ -460,6 +469,7 @@ pub(crate) fn lint(params: LintParams) -> LintResults {
 
             let (enabled_rules, disabled_rules) =
                 AnalyzerVisitorBuilder::new(params.workspace.settings())
+                    .with_manifest(params.manifest.as_ref())
                     .with_syntax_rules()
                     .with_linter_rules(&params.only, &params.skip, params.path.as_path())
                     .with_assists_rules(&params.only, &params.skip, params.path.as_path())
@@ -489,6 +499,7 @@ pub(crate) fn lint(params: LintParams) -> LintResults {
                 analyzer_options,
                 file_source,
                 params.manifest,
+                params.module_graph,
                 |signal| {
                     if let Some(mut diagnostic) = signal.diagnostic() {
                         if ignores_suppression_comment
@@ -501,18 +512,11 @@ pub(crate) fn lint(params: LintParams) -> LintResults {
 
                         // We do now check if the severity of the diagnostics should be changed.
                         // The configuration allows to change the severity of the diagnostics emitted by rules.
-                        let severity = diagnostic
-                            .category()
-                            .filter(|category| category.name().starts_with("lint/"))
-                            .map_or_else(
-                                || diagnostic.severity(),
-                                |category| {
-                                    rules
-                                        .as_ref()
-                                        .and_then(|rules| rules.get_severity_from_code(category))
-                                        .unwrap_or(Severity::Warning)
-                                },
-                            );
+                        let severity = diagnostic_severity(
+                            &diagnostic,
+                            rules.as_deref(),
+                            &analyzer_options.configuration.plugins,
+                        );
 
                         if severity >= Severity::Error {
                             errors += 1;
@@ -559,6 +563,7 @@ pub(crate) fn code_actions(params: CodeActionsParams) -> PullActionsResult {
         workspace,
         path,
         manifest,
+        module_graph,
         language,
         only,
         skip,
@@ -572,6 +577,7 @@ pub(crate) fn code_actions(params: CodeActionsParams) -> PullActionsResult {
             let mut actions = Vec::new();
             let (enabled_rules, disabled_rules) =
                 AnalyzerVisitorBuilder::new(params.workspace.settings())
+                    .with_manifest(manifest.as_ref())
                     .with_syntax_rules()
                     .with_linter_rules(&only, &skip, params.path.as_path())
                     .with_assists_rules(&only, &skip, params.path.as_path())
@@ -602,6 +608,7 @@ pub(crate) fn code_actions(params: CodeActionsParams) -> PullActionsResult {
                 &analyzer_options,
                 source_type,
                 manifest,
+                module_graph,
                 |signal| {
                     actions.extend(signal.actions().into_code_action_iter().map(|item| {
                         CodeAction {
@@ -631,6 +638,7 @@ pub(crate) fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceEr
             errors: 0,
             skipped_suggested_fixes: 0,
             code: tree.syntax().to_string(),
+            rule_durations: Vec::new(),
         });
     };
 
@@ -638,6 +646,7 @@ pub(crate) fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceEr
     let rules = settings.as_linter_rules(params.biome_path.as_path());
 
     let (enabled_rules, disabled_rules) = AnalyzerVisitorBuilder::new(params.workspace.settings())
+        .with_manifest(params.manifest.as_ref())
         .with_syntax_rules()
         .with_linter_rules(&params.only, &params.skip, params.biome_path.as_path())
         .with_assists_rules(&params.only, &params.skip, params.biome_path.as_path())
@@ -661,11 +670,14 @@ pub(crate) fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceEr
     let mut actions = Vec::new();
     let mut skipped_suggested_fixes = 0;
     let mut errors: u16 = 0;
-    let analyzer_options = params.workspace.analyzer_options::<JsLanguage>(
-        params.biome_path,
-        &params.document_file_source,
-        params.suppression_reason,
-    );
+    let analyzer_options = AnalyzerOptions {
+        profiler: params.profiler,
+        ..params.workspace.analyzer_options::<JsLanguage>(
+            params.biome_path,
+            &params.document_file_source,
+            params.suppression_reason,
+        )
+    };
     loop {
         let (action, _) = analyze(
             &tree,
@@ -673,11 +685,16 @@ pub(crate) fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceEr
             &analyzer_options,
             file_source,
             params.manifest.clone(),
+            params.module_graph.clone(),
             |signal| {
                 let current_diagnostic = signal.diagnostic();
 
                 if let Some(diagnostic) = current_diagnostic.as_ref() {
-                    if is_diagnostic_error(diagnostic, rules.as_deref()) {
+                    if is_diagnostic_error(
+                        diagnostic,
+                        rules.as_deref(),
+                        &analyzer_options.configuration.plugins,
+                    ) {
                         errors += 1;
                     }
                 }
@@ -764,6 +781,7 @@ pub(crate) fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceEr
                     skipped_suggested_fixes,
                     actions,
                     errors: errors.into(),
+                    rule_durations: Vec::new(),
                 });
             }
         }
@@ -902,6 +920,7 @@ pub(crate) fn organize_imports(parse: AnyParse) -> Result<OrganizeImportsResult,
         &AnalyzerOptions::default(),
         JsFileSource::default(),
         None,
+        Default::default(),
         |signal| {
             for action in signal.actions() {
                 if action.is_suppression() {
@@ -5,7 +5,7 @@ use super::{
     is_diagnostic_error, AnalyzerVisitorBuilder, CodeActionsParams, DocumentFileSource,
     ExtensionHandler, ParseResult, SearchCapabilities,
 };
-use crate::configuration::to_analyzer_rules;
+use crate::configuration::{to_analyzer_rules, to_suppression_policy};
 use crate::file_handlers::DebugCapabilities;
 use crate::file_handlers::{
     AnalyzerCapabilities, Capabilities, FixAllParams, FormatterCapabilities, LintParams,
@@ -142,11 +142,15 @@ impl ServiceLanguage for JsonLanguage {
             preferred_quote: PreferredQuote::Double,
             preferred_jsx_quote: Default::default(),
             jsx_runtime: Default::default(),
+            suppression_policy: global.map(to_suppression_policy).unwrap_or_default(),
+            // The plugins only support JavaScript for now
+            plugins: vec![],
         };
         AnalyzerOptions {
             configuration,
             file_path: path.to_path_buf(),
             suppression_reason,
+            profiler: None,
         }
     }
 }
@@ -187,7 +191,21 @@ fn parse(
     settings: Option<&Settings>,
     cache: &mut NodeCache,
 ) -> ParseResult {
-    let options = if biome_path.ends_with(ConfigName::biome_jsonc()) {
+    let options = parser_options(biome_path, &file_source, settings);
+    let parse = biome_json_parser::parse_json_with_cache(text, cache, options);
+
+    ParseResult {
+        any_parse: parse.into(),
+        language: Some(file_source),
+    }
+}
+
+fn parser_options(
+    biome_path: &BiomePath,
+    file_source: &DocumentFileSource,
+    settings: Option<&Settings>,
+) -> JsonParserOptions {
+    if biome_path.ends_with(ConfigName::biome_jsonc()) {
         JsonParserOptions::default()
             .with_allow_comments()
             .with_allow_trailing_commas()
@@ -210,13 +228,21 @@ fn parse(
         } else {
             options
         }
-    };
-
-    let parse = biome_json_parser::parse_json_with_cache(text, cache, options);
+    }
+}
 
-    ParseResult {
-        any_parse: parse.into(),
-        language: Some(file_source),
+/// Returns the file source given to the analyzer: it allows comments when the parser
+/// allows them, so that the analyzer can emit suppression comments
+fn to_analyzer_file_source(
+    file_source: JsonFileSource,
+    biome_path: &BiomePath,
+    document_file_source: &DocumentFileSource,
+    settings: Option<&Settings>,
+) -> JsonFileSource {
+    if parser_options(biome_path, document_file_source, settings).allow_comments {
+        file_source.with_allow_comments()
+    } else {
+        file_source
     }
 }
 
@@ -329,13 +355,22 @@ fn lint(params: LintParams) -> LintResults {
                     skipped_diagnostics: 0,
                 };
             };
-            let root: JsonRoot = params.parse.tree();
-
-            let analyzer_options = &params.workspace.analyzer_options::<JsonLanguage>(
+            let file_source = to_analyzer_file_source(
+                file_source,
                 params.path,
                 &params.language,
-                params.suppression_reason,
+                params.workspace.settings(),
             );
+            let root: JsonRoot = params.parse.tree();
+
+            let analyzer_options = &AnalyzerOptions {
+                profiler: params.profiler,
+                ..params.workspace.analyzer_options::<JsonLanguage>(
+                    params.path,
+                    &params.language,
+                    params.suppression_reason,
+                )
+            };
 
             let has_only_filter = !params.only.is_empty();
             let rules = params
@@ -346,6 +381,7 @@ fn lint(params: LintParams) -> LintResults {
 
             let (enabled_rules, disabled_rules) =
                 AnalyzerVisitorBuilder::new(params.workspace.settings())
+                    .with_manifest(params.manifest.as_ref())
                     .with_syntax_rules()
                     .with_linter_rules(&params.only, &params.skip, params.path.as_path())
                     .with_assists_rules(&params.only, &params.skip, params.path.as_path())
@@ -455,6 +491,7 @@ fn code_actions(params: CodeActionsParams) -> PullActionsResult {
         workspace,
         path,
         manifest: _,
+        module_graph: _,
         language,
         skip,
         only,
@@ -469,6 +506,7 @@ fn code_actions(params: CodeActionsParams) -> PullActionsResult {
             let mut actions = Vec::new();
             let (enabled_rules, disabled_rules) =
                 AnalyzerVisitorBuilder::new(params.workspace.settings())
+                    .with_manifest(params.manifest.as_ref())
                     .with_syntax_rules()
                     .with_linter_rules(&only, &skip, params.path.as_path())
                     .with_assists_rules(&only, &skip, params.path.as_path())
@@ -489,6 +527,8 @@ fn code_actions(params: CodeActionsParams) -> PullActionsResult {
                 error!("Could not determine the file source of the file");
                 return PullActionsResult { actions: vec![] };
             };
+            let file_source =
+                to_analyzer_file_source(file_source, path, &language, workspace.settings());
 
             trace!("JSON runs the analyzer");
             analyze(&tree, filter, &analyzer_options, file_source, |signal| {
@@ -518,6 +558,7 @@ fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceError> {
             errors: 0,
             skipped_suggested_fixes: 0,
             code: tree.syntax().to_string(),
+            rule_durations: Vec::new(),
         });
     };
 
@@ -525,6 +566,7 @@ fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceError> {
     let rules = settings.as_linter_rules(params.biome_path.as_path());
 
     let (enabled_rules, disabled_rules) = AnalyzerVisitorBuilder::new(params.workspace.settings())
+        .with_manifest(params.manifest.as_ref())
         .with_syntax_rules()
         .with_linter_rules(&params.only, &params.skip, params.biome_path.as_path())
         .with_assists_rules(&params.only, &params.skip, params.biome_path.as_path())
@@ -548,33 +590,45 @@ fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceError> {
     else {
         return Err(extension_error(params.biome_path));
     };
+    let file_source = to_analyzer_file_source(
+        file_source,
+        params.biome_path,
+        &params.document_file_source,
+        Some(settings),
+    );
 
     let mut actions = Vec::new();
     let mut skipped_suggested_fixes = 0;
     let mut errors: u16 = 0;
-    let analyzer_options = params.workspace.analyzer_options::<JsonLanguage>(
-        params.biome_path,
-        &params.document_file_source,
-        params.suppression_reason,
-    );
+    let analyzer_options = AnalyzerOptions {
+        profiler: params.profiler,
+        ..params.workspace.analyzer_options::<JsonLanguage>(
+            params.biome_path,
+            &params.document_file_source,
+            params.suppression_reason,
+        )
+    };
     loop {
         let (action, _) = analyze(&tree, filter, &analyzer_options, file_source, |signal| {
             let current_diagnostic = signal.diagnostic();
 
             if let Some(diagnostic) = current_diagnostic.as_ref() {
-                if is_diagnostic_error(diagnostic, rules.as_deref()) {
+                if is_diagnostic_error(diagnostic, rules.as_deref(), &[]) {
                     errors += 1;
                 }
             }
 
             for action in signal.actions() {
-                // suppression actions should not be part of the fixes (safe or suggested)
-                if action.is_suppression() {
-                    continue;
-                }
-
                 match params.fix_file_mode {
+                    FixFileMode::ApplySuppressions => {
+                        if action.is_suppression() {
+                            return ControlFlow::Break(action);
+                        }
+                    }
                     FixFileMode::SafeFixes => {
+                        if action.is_suppression() {
+                            continue;
+                        }
                         if action.applicability == Applicability::MaybeIncorrect {
                             skipped_suggested_fixes += 1;
                         }
@@ -584,6 +638,9 @@ fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceError> {
                         }
                     }
                     FixFileMode::SafeAndUnsafeFixes => {
+                        if action.is_suppression() {
+                            continue;
+                        }
                         if matches!(
                             action.applicability,
                             Applicability::Always | Applicability::MaybeIncorrect
@@ -592,9 +649,6 @@ fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceError> {
                             return ControlFlow::Break(action);
                         }
                     }
-                    FixFileMode::ApplySuppressions => {
-                        // TODO: implement once a JSON suppression action is available
-                    }
                 }
             }
 
@@ -645,6 +699,7 @@ fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceError> {
                     skipped_suggested_fixes,
                     actions,
                     errors: errors.into(),
+                    rule_durations: Vec::new(),
                 });
             }
         }
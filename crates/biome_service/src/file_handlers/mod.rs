@@ -7,23 +7,23 @@ pub use crate::file_handlers::astro::{AstroFileHandler, ASTRO_FENCE};
 use crate::file_handlers::graphql::GraphqlFileHandler;
 pub use crate::file_handlers::svelte::{SvelteFileHandler, SVELTE_FENCE};
 pub use crate::file_handlers::vue::{VueFileHandler, VUE_FENCE};
-use crate::settings::Settings;
-use crate::workspace::{FixFileMode, OrganizeImportsResult};
+use crate::settings::{is_rule_enabled_by_domains, DomainRules, Settings};
+use crate::workspace::{FixFileMode, OrganizeImportsResult, SearchResults};
 use crate::{
     settings::WorkspaceSettingsHandle,
     workspace::{FixFileResult, GetSyntaxTreeResult, PullActionsResult, RenameResult},
     WorkspaceError,
 };
 use biome_analyze::{
-    AnalyzerDiagnostic, GroupCategory, Queryable, RegistryVisitor, Rule, RuleCategories,
-    RuleCategory, RuleFilter, RuleGroup,
+    AnalyzerDiagnostic, AnalyzerPlugin, GroupCategory, Queryable, RegistryVisitor, Rule,
+    RuleCategories, RuleCategory, RuleFilter, RuleGroup, RuleProfiler, PLUGIN_GROUP,
 };
-use biome_configuration::analyzer::RuleSelector;
+use biome_configuration::analyzer::{RuleDomains, RuleSelector};
 use biome_configuration::Rules;
 use biome_console::fmt::Formatter;
 use biome_console::markup;
 use biome_css_syntax::{CssFileSource, CssLanguage};
-use biome_diagnostics::{Diagnostic, Severity};
+use biome_diagnostics::{category, Diagnostic, Severity};
 use biome_formatter::Printed;
 use biome_fs::BiomePath;
 use biome_graphql_syntax::{GraphqlFileSource, GraphqlLanguage};
@@ -36,7 +36,7 @@ use biome_js_syntax::{
 };
 use biome_json_syntax::{JsonFileSource, JsonLanguage};
 use biome_parser::AnyParse;
-use biome_project::PackageJson;
+use biome_project::{ModuleGraph, PackageJson};
 use biome_rowan::{FileSourceError, NodeCache};
 use biome_string_case::StrLikeExtension;
 
@@ -47,6 +47,7 @@ use rustc_hash::FxHashSet;
 use std::borrow::Cow;
 use std::ffi::OsStr;
 use std::path::Path;
+use std::sync::Arc;
 use tracing::instrument;
 
 mod astro;
@@ -294,6 +295,10 @@ impl DocumentFileSource {
         matches!(self, DocumentFileSource::Css(_))
     }
 
+    pub const fn is_html_like(&self) -> bool {
+        matches!(self, DocumentFileSource::Html(_))
+    }
+
     pub fn to_js_file_source(&self) -> Option<JsFileSource> {
         match self {
             DocumentFileSource::Js(file_source) => Some(*file_source),
@@ -396,11 +401,14 @@ pub struct FixAllParams<'a> {
     pub(crate) should_format: bool,
     pub(crate) biome_path: &'a BiomePath,
     pub(crate) manifest: Option<PackageJson>,
+    pub(crate) module_graph: Arc<ModuleGraph>,
     pub(crate) document_file_source: DocumentFileSource,
     pub(crate) only: Vec<RuleSelector>,
     pub(crate) skip: Vec<RuleSelector>,
     pub(crate) rule_categories: RuleCategories,
     pub(crate) suppression_reason: Option<String>,
+    /// Receives the time spent in each rule, when the rules are measured
+    pub(crate) profiler: Option<Arc<dyn RuleProfiler>>,
 }
 
 #[derive(Default)]
@@ -458,7 +466,10 @@ pub(crate) struct LintParams<'a> {
     pub(crate) skip: Vec<RuleSelector>,
     pub(crate) categories: RuleCategories,
     pub(crate) manifest: Option<PackageJson>,
+    pub(crate) module_graph: Arc<ModuleGraph>,
     pub(crate) suppression_reason: Option<String>,
+    /// Receives the time spent in each rule, when the rules are measured
+    pub(crate) profiler: Option<Arc<dyn RuleProfiler>>,
 }
 
 pub(crate) struct LintResults {
@@ -473,6 +484,7 @@ pub(crate) struct CodeActionsParams<'a> {
     pub(crate) workspace: &'a WorkspaceSettingsHandle<'a>,
     pub(crate) path: &'a BiomePath,
     pub(crate) manifest: Option<PackageJson>,
+    pub(crate) module_graph: Arc<ModuleGraph>,
     pub(crate) language: DocumentFileSource,
     pub(crate) only: Vec<RuleSelector>,
     pub(crate) skip: Vec<RuleSelector>,
@@ -536,7 +548,7 @@ type Search = fn(
     AnyParse,
     &GritQuery,
     WorkspaceSettingsHandle,
-) -> Result<Vec<TextRange>, WorkspaceError>;
+) -> Result<SearchResults, WorkspaceError>;
 
 #[derive(Default)]
 pub(crate) struct SearchCapabilities {
@@ -607,14 +619,35 @@ impl Features {
 
 /// Checks whether a diagnostic coming from the analyzer is an [error](Severity::Error)
 ///
-/// The function checks the diagnostic against the current configured rules.
+/// The function checks the diagnostic against the current configured rules and plugins.
 pub(crate) fn is_diagnostic_error(
     diagnostic: &'_ AnalyzerDiagnostic,
     rules: Option<&'_ Rules>,
+    plugins: &[Arc<dyn AnalyzerPlugin>],
 ) -> bool {
-    let severity = diagnostic
+    diagnostic_severity(diagnostic, rules, plugins) >= Severity::Error
+}
+
+/// Returns the severity of a diagnostic emitted by the analyzer. The severity of the diagnostics
+/// of the lint rules comes from the configuration, while the plugins choose their own unless
+/// their severity is configured.
+pub(crate) fn diagnostic_severity(
+    diagnostic: &'_ AnalyzerDiagnostic,
+    rules: Option<&'_ Rules>,
+    plugins: &[Arc<dyn AnalyzerPlugin>],
+) -> Severity {
+    if let Some(plugin_name) = diagnostic.plugin_name() {
+        return plugins
+            .iter()
+            .find(|plugin| plugin.name() == plugin_name)
+            .and_then(|plugin| plugin.severity())
+            .unwrap_or_else(|| diagnostic.severity());
+    }
+    diagnostic
         .category()
-        .filter(|category| category.name().starts_with("lint/"))
+        .filter(|category| {
+            category.name().starts_with("lint/") && *category != category!("lint/plugin")
+        })
         .map_or_else(
             || diagnostic.severity(),
             |category| {
@@ -622,9 +655,7 @@ pub(crate) fn is_diagnostic_error(
                     .and_then(|rules| rules.get_severity_from_code(category))
                     .unwrap_or(Severity::Warning)
             },
-        );
-
-    severity >= Severity::Error
+        )
 }
 
 /// Parse the "lang" attribute from the opening tag of the "\<script\>" block in Svelte or Vue files.
@@ -685,7 +716,7 @@ pub(crate) fn search(
     parse: AnyParse,
     query: &GritQuery,
     _settings: WorkspaceSettingsHandle,
-) -> Result<Vec<TextRange>, WorkspaceError> {
+) -> Result<SearchResults, WorkspaceError> {
     let (query_result, _logs) = query
         .execute(GritTargetFile {
             path: path.to_path_buf(),
@@ -695,16 +726,27 @@ pub(crate) fn search(
             WorkspaceError::SearchError(SearchError::QueryError(QueryDiagnostic(err.to_string())))
         })?;
 
-    let matches = query_result
-        .into_iter()
-        .flat_map(|result| match result {
-            GritQueryResult::Match(m) => m.ranges,
-            _ => Vec::new(),
-        })
-        .map(|range| TextRange::new(range.start_byte.into(), range.end_byte.into()))
-        .collect();
+    let mut matches = Vec::new();
+    let mut rewritten = None;
+    for result in query_result {
+        match result {
+            GritQueryResult::Match(m) => matches.extend(m.ranges),
+            GritQueryResult::Rewrite(rewrite) => {
+                matches.extend(rewrite.original.ranges);
+                rewritten = Some(rewrite.rewritten.content);
+            }
+            GritQueryResult::CreateFile(_) | GritQueryResult::Diagnostic(_) => {}
+        }
+    }
 
-    Ok(matches)
+    Ok(SearchResults {
+        file: path.clone(),
+        matches: matches
+            .into_iter()
+            .map(|range| TextRange::new(range.start_byte.into(), range.end_byte.into()))
+            .collect(),
+        rewritten,
+    })
 }
 
 #[test]
@@ -830,8 +872,12 @@ struct LintVisitor<'a, 'b> {
     // lint_params: &'b LintParams<'a>,
     only: &'b [RuleSelector],
     skip: &'b [RuleSelector],
-    settings: Option<&'b Settings>,
-    path: &'b Path,
+    /// The rules configured for the file, taking overrides into account
+    rules: Option<Cow<'b, Rules>>,
+    /// The rule domains configured for the file, taking overrides into account
+    domains: Option<Cow<'b, RuleDomains>>,
+    /// The manifest of the project, used to detect the rule domains set to `auto`
+    manifest: Option<&'b PackageJson>,
 }
 
 impl<'a, 'b> LintVisitor<'a, 'b> {
@@ -846,8 +892,9 @@ impl<'a, 'b> LintVisitor<'a, 'b> {
             disabled_rules: Default::default(),
             only,
             skip,
-            settings,
-            path,
+            rules: settings.and_then(|settings| settings.as_linter_rules(path)),
+            domains: settings.map(|settings| settings.as_linter_domains(path)),
+            manifest: None,
         }
     }
 
@@ -855,16 +902,65 @@ impl<'a, 'b> LintVisitor<'a, 'b> {
         let has_only_filter = !self.only.is_empty();
         if !has_only_filter {
             let enabled_rules = self
-                .settings
-                .and_then(|settings| settings.as_linter_rules(self.path))
+                .rules
                 .as_ref()
                 .map(|rules| rules.as_enabled_rules())
                 .unwrap_or_default();
             self.enabled_rules.extend(enabled_rules);
+            // The plugins run alongside the lint rules, unless specific rules are selected
+            self.enabled_rules.insert(RuleFilter::Group(PLUGIN_GROUP));
         }
         (self.enabled_rules, self.disabled_rules)
     }
 
+    /// Whether the rules of the group must be visited, because they're matched by a selector
+    /// or because some of them belong to a configured domain
+    fn should_record_group<G: RuleGroup>(&self) -> bool {
+        self.only
+            .iter()
+            .chain(self.skip)
+            .any(|selector| RuleFilter::from(selector).match_group::<G>())
+            || self.has_configured_domain::<G>()
+    }
+
+    /// Whether some rules of the group belong to a domain configured for the file
+    fn has_configured_domain<G: RuleGroup>(&self) -> bool {
+        let Some(domains) = self.domains.as_deref() else {
+            return false;
+        };
+        if domains.is_empty() {
+            return false;
+        }
+        let mut domain_rules = DomainRules::default();
+        G::record_rules(&mut domain_rules);
+        domain_rules.has_configured_domain(domains)
+    }
+
+    /// Enables or disables the rule according to the configuration of its domains, unless the
+    /// rule itself is configured
+    fn push_domain_rule<R: Rule>(&mut self) {
+        let Some(domains) = self.domains.as_deref() else {
+            return;
+        };
+        let group = <R::Group as RuleGroup>::NAME;
+        let Some(enabled) = is_rule_enabled_by_domains(
+            domains,
+            self.rules.as_deref(),
+            group,
+            R::METADATA.name,
+            R::METADATA.domains,
+            self.manifest,
+        ) else {
+            return;
+        };
+        let filter = RuleFilter::Rule(group, R::METADATA.name);
+        if enabled {
+            self.enabled_rules.insert(filter);
+        } else {
+            self.disabled_rules.insert(filter);
+        }
+    }
+
     fn push_rule<R, L>(&mut self)
     where
         R: Rule<Options: Default, Query: Queryable<Language = L, Output: Clone>> + 'static,
@@ -884,6 +980,9 @@ impl<'a, 'b> LintVisitor<'a, 'b> {
                 self.disabled_rules.insert(filter);
             }
         }
+        if self.only.is_empty() {
+            self.push_domain_rule::<R>();
+        }
     }
 }
 
@@ -895,16 +994,8 @@ impl<'a, 'b> RegistryVisitor<JsLanguage> for LintVisitor<'a, 'b> {
     }
 
     fn record_group<G: RuleGroup<Language = JsLanguage>>(&mut self) {
-        for selector in self.only {
-            if RuleFilter::from(selector).match_group::<G>() {
-                G::record_rules(self)
-            }
-        }
-
-        for selector in self.skip {
-            if RuleFilter::from(selector).match_group::<G>() {
-                G::record_rules(self)
-            }
+        if self.should_record_group::<G>() {
+            G::record_rules(self)
         }
     }
 
@@ -923,16 +1014,8 @@ impl<'a, 'b> RegistryVisitor<JsonLanguage> for LintVisitor<'a, 'b> {
     }
 
     fn record_group<G: RuleGroup<Language = JsonLanguage>>(&mut self) {
-        for selector in self.only {
-            if RuleFilter::from(selector).match_group::<G>() {
-                G::record_rules(self)
-            }
-        }
-
-        for selector in self.skip {
-            if RuleFilter::from(selector).match_group::<G>() {
-                G::record_rules(self)
-            }
+        if self.should_record_group::<G>() {
+            G::record_rules(self)
         }
     }
 
@@ -953,16 +1036,8 @@ impl<'a, 'b> RegistryVisitor<CssLanguage> for LintVisitor<'a, 'b> {
     }
 
     fn record_group<G: RuleGroup<Language = CssLanguage>>(&mut self) {
-        for selector in self.only {
-            if RuleFilter::from(selector).match_group::<G>() {
-                G::record_rules(self)
-            }
-        }
-
-        for selector in self.skip {
-            if RuleFilter::from(selector).match_group::<G>() {
-                G::record_rules(self)
-            }
+        if self.should_record_group::<G>() {
+            G::record_rules(self)
         }
     }
 
@@ -983,16 +1058,8 @@ impl<'a, 'b> RegistryVisitor<GraphqlLanguage> for LintVisitor<'a, 'b> {
     }
 
     fn record_group<G: RuleGroup<Language = GraphqlLanguage>>(&mut self) {
-        for selector in self.only {
-            if RuleFilter::from(selector).match_group::<G>() {
-                G::record_rules(self)
-            }
-        }
-
-        for selector in self.skip {
-            if RuleFilter::from(selector).match_group::<G>() {
-                G::record_rules(self)
-            }
+        if self.should_record_group::<G>() {
+            G::record_rules(self)
         }
     }
 
@@ -1148,6 +1215,7 @@ pub(crate) struct AnalyzerVisitorBuilder<'a, 'b> {
     lint: Option<LintVisitor<'a, 'b>>,
     assists: Option<AssistsVisitor<'a, 'b>>,
     settings: Option<&'b Settings>,
+    manifest: Option<&'b PackageJson>,
 }
 
 impl<'a, 'b> AnalyzerVisitorBuilder<'a, 'b> {
@@ -1157,9 +1225,16 @@ impl<'a, 'b> AnalyzerVisitorBuilder<'a, 'b> {
             syntax: None,
             lint: None,
             assists: None,
+            manifest: None,
         }
     }
 
+    #[must_use]
+    pub(crate) fn with_manifest(mut self, manifest: Option<&'b PackageJson>) -> Self {
+        self.manifest = manifest;
+        self
+    }
+
     #[must_use]
     pub(crate) fn with_syntax_rules(mut self) -> Self {
         self.syntax = Some(SyntaxVisitor::default());
@@ -1200,6 +1275,7 @@ impl<'a, 'b> AnalyzerVisitorBuilder<'a, 'b> {
         }
 
         if let Some(mut lint) = self.lint {
+            lint.manifest = self.manifest;
             biome_js_analyze::visit_registry(&mut lint);
             biome_css_analyze::visit_registry(&mut lint);
             biome_json_analyze::visit_registry(&mut lint);
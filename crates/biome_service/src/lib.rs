@@ -7,6 +7,8 @@ pub mod documentation;
 pub mod file_handlers;
 
 pub mod matcher;
+pub mod module_graph;
+pub mod plugins;
 pub mod settings;
 pub mod workspace;
 
@@ -0,0 +1,678 @@
+use biome_analyze::{
+    GroupCategory, Phases, Queryable, RegistryVisitor, Rule, RuleCategory, RuleDomain, RuleFilter,
+    RuleGroup,
+};
+use biome_configuration::analyzer::{RuleDomainValue, RuleDomains, RuleSelector};
+use biome_configuration::Rules;
+use biome_fs::{FileSystem, OpenOptions};
+use biome_js_parser::{parse, JsParserOptions};
+use biome_js_syntax::{
+    inner_string_text, AnyJsDeclarationClause, AnyJsExportClause, AnyJsExportNamedSpecifier,
+    AnyJsImportLike, AnyJsModuleItem, AnyJsModuleSource, AnyJsRoot, JsFileSource,
+    JsIdentifierBinding, JsLanguage, JsLiteralExportName,
+};
+use biome_project::{ModuleGraph, ModuleInfo, ResolvedImport};
+use biome_rowan::{AstNode, AstNodeList, AstSeparatedList, SyntaxNode};
+use oxc_resolver::{
+    FileMetadata, ResolveError, ResolveOptions, ResolverGeneric, TsconfigOptions,
+    TsconfigReferences,
+};
+use rayon::prelude::*;
+use rustc_hash::FxHashSet;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Builds the [ModuleGraph] of a project.
+///
+/// It parses the JavaScript and TypeScript files to collect their imports and their exports, and
+/// it resolves the imports the way Node.js and TypeScript do: relative paths, the `paths` and the
+/// `baseUrl` of the `tsconfig.json` of the project, the `exports` and the `main` fields of
+/// `package.json` and the packages of `node_modules`.
+///
+/// The modules imported by the files of the project are added to the graph as well, so that their
+/// exports are known. Only the re-exports of the modules of `node_modules` are followed.
+pub struct ModuleGraphBuilder<'a> {
+    fs: &'a dyn FileSystem,
+    resolver: ResolverGeneric<ResolverFileSystem<'a>>,
+    /// The working directory, used to resolve the imports of the modules with a relative path
+    working_directory: Option<PathBuf>,
+    graph: ModuleGraph,
+    visited: FxHashSet<PathBuf>,
+}
+
+impl<'a> ModuleGraphBuilder<'a> {
+    /// Creates a builder for the project at `project_path`, whose `tsconfig.json` is used to
+    /// resolve the imports
+    pub fn new(fs: &'a dyn FileSystem, project_path: &Path) -> Self {
+        let tsconfig_path = project_path.join("tsconfig.json");
+        let tsconfig = fs.path_is_file(&tsconfig_path).then_some(TsconfigOptions {
+            config_file: tsconfig_path,
+            references: TsconfigReferences::Auto,
+        });
+        let options = ResolveOptions {
+            tsconfig,
+            condition_names: ["types", "import", "default"].map(String::from).into(),
+            extension_alias: [
+                (".js", [".ts", ".tsx", ".js"].as_slice()),
+                (".jsx", &[".tsx", ".jsx"]),
+                (".mjs", &[".mts", ".mjs"]),
+                (".cjs", &[".cts", ".cjs"]),
+            ]
+            .map(|(extension, aliases)| {
+                (
+                    extension.to_string(),
+                    aliases.iter().copied().map(String::from).collect(),
+                )
+            })
+            .into(),
+            extensions: [
+                ".ts", ".tsx", ".d.ts", ".js", ".jsx", ".mts", ".mjs", ".cts", ".cjs", ".json",
+            ]
+            .map(String::from)
+            .into(),
+            main_fields: ["types", "module", "main"].map(String::from).into(),
+            builtin_modules: true,
+            // The file system of Biome can't read the target of the symbolic links
+            symlinks: false,
+            ..ResolveOptions::default()
+        };
+
+        Self {
+            fs,
+            resolver: ResolverGeneric::new_with_file_system(ResolverFileSystem(fs), options),
+            working_directory: fs.working_directory(),
+            graph: ModuleGraph::default(),
+            visited: FxHashSet::default(),
+        }
+    }
+
+    /// Skips the modules of `module_graph`, which are already known, unless they are added
+    /// with [Self::add_module]. It lets the graph be updated with the changed modules only.
+    pub fn with_known_modules(mut self, module_graph: &ModuleGraph) -> Self {
+        self.visited
+            .extend(module_graph.paths().map(Path::to_path_buf));
+        self
+    }
+
+    /// Adds the module at `path`, which is read from the file system, and the modules it imports
+    pub fn add_path(&mut self, path: &Path) {
+        self.add_paths([path.to_path_buf()]);
+    }
+
+    /// Adds the modules at `paths`, which are read from the file system, and the modules they
+    /// import. The modules imported at the same depth are read and parsed in parallel.
+    pub fn add_paths(&mut self, paths: impl IntoIterator<Item = PathBuf>) {
+        let mut queue: Vec<_> = paths.into_iter().collect();
+        while !queue.is_empty() {
+            let pending: Vec<_> = queue
+                .drain(..)
+                .filter(|path| self.visited.insert(path.clone()))
+                .collect();
+            let modules: Vec<_> = pending
+                .into_par_iter()
+                .filter_map(|path| {
+                    let content = read_to_string(self.fs, &path).ok()?;
+                    let (module, imported_paths) = self.analyze_module(&path, &content)?;
+                    Some((path, module, imported_paths))
+                })
+                .collect();
+            for (path, module, imported_paths) in modules {
+                self.graph.insert_module(path, module);
+                queue.extend(imported_paths);
+            }
+        }
+    }
+
+    /// Adds the module at `path` with the given content, e.g. the content of a document open in
+    /// an editor, and the modules it imports
+    pub fn add_module(&mut self, path: &Path, content: &str) {
+        self.visited.insert(path.to_path_buf());
+        if let Some((module, imported_paths)) = self.analyze_module(path, content) {
+            self.graph.insert_module(path.to_path_buf(), module);
+            self.add_paths(imported_paths);
+        }
+    }
+
+    pub fn build(self) -> ModuleGraph {
+        self.graph
+    }
+
+    /// Parses the module and resolves its imports. It returns the module, and the paths of the
+    /// modules to add next.
+    fn analyze_module(&self, path: &Path, content: &str) -> Option<(ModuleInfo, Vec<PathBuf>)> {
+        let file_source = JsFileSource::try_from(path).ok()?;
+        let parse = parse(content, file_source, JsParserOptions::default());
+        let (mut module, specifiers) = collect_module(&parse.tree());
+
+        let directory = path.parent().unwrap_or(Path::new(""));
+        for specifier in specifiers {
+            let resolved = self.resolve(directory, &specifier);
+            module.imports.insert(specifier, resolved);
+        }
+
+        // The imports of the dependencies aren't analyzed, only their re-exports are followed
+        let is_dependency = is_in_node_modules(path);
+        let imported_paths = module
+            .imports
+            .iter()
+            .filter(|(specifier, _)| !is_dependency || module.reexports.contains(*specifier))
+            .filter_map(|(_, resolved)| match resolved {
+                ResolvedImport::Module(path) if JsFileSource::try_from(path.as_path()).is_ok() => {
+                    Some(path.clone())
+                }
+                _ => None,
+            })
+            .collect();
+
+        Some((module, imported_paths))
+    }
+
+    /// Resolves `specifier` from `directory`
+    fn resolve(&self, directory: &Path, specifier: &str) -> ResolvedImport {
+        let absolute_directory = match &self.working_directory {
+            Some(working_directory) => working_directory.join(directory),
+            None => directory.to_path_buf(),
+        };
+        match self.resolver.resolve(&absolute_directory, specifier) {
+            Ok(resolution) => {
+                let resolved_path = resolution.into_path_buf();
+                // The paths of the modules are relative to the working directory when the paths
+                // of the files are
+                let resolved_path = match &self.working_directory {
+                    Some(working_directory) if directory.is_relative() => resolved_path
+                        .strip_prefix(working_directory)
+                        .map(Path::to_path_buf)
+                        .unwrap_or(resolved_path),
+                    _ => resolved_path,
+                };
+                ResolvedImport::Module(resolved_path)
+            }
+            Err(ResolveError::Builtin { resolved, .. }) => ResolvedImport::Builtin(resolved),
+            Err(_) => ResolvedImport::Unresolved,
+        }
+    }
+}
+
+/// Collects the exports of the module, and the specifiers of its imports and re-exports
+fn collect_module(root: &AnyJsRoot) -> (ModuleInfo, FxHashSet<String>) {
+    let mut module = ModuleInfo::default();
+    let specifiers = root
+        .syntax()
+        .descendants()
+        .filter_map(AnyJsImportLike::cast)
+        .filter(|import| !import.is_in_ts_module_declaration())
+        .filter_map(|import| Some(import.inner_string_text()?.to_string()))
+        .collect();
+
+    let Some(items) = root.as_js_module().map(|module| module.items()) else {
+        // A script has no exports, e.g. a CommonJS module
+        module.has_dynamic_exports = true;
+        return (module, specifiers);
+    };
+
+    let mut has_exports = false;
+    for export in items.iter().filter_map(|item| match item {
+        AnyJsModuleItem::JsExport(export) => Some(export),
+        _ => None,
+    }) {
+        has_exports = true;
+        let Ok(clause) = export.export_clause() else {
+            continue;
+        };
+        match clause {
+            AnyJsExportClause::AnyJsDeclarationClause(declaration) => {
+                collect_declaration_names(&declaration, &mut module.exports);
+            }
+            AnyJsExportClause::TsExportDeclareClause(clause) => {
+                if let Ok(declaration) = clause.declaration() {
+                    collect_declaration_names(&declaration, &mut module.exports);
+                }
+            }
+            AnyJsExportClause::JsExportDefaultDeclarationClause(_)
+            | AnyJsExportClause::JsExportDefaultExpressionClause(_) => {
+                module.exports.insert("default".to_string());
+            }
+            // export * from "mod";
+            // export * as ns from "mod";
+            AnyJsExportClause::JsExportFromClause(clause) => match clause.export_as() {
+                Some(export_as) => {
+                    module
+                        .exports
+                        .extend(export_as.exported_name().ok().map(export_name));
+                }
+                None => {
+                    if let Some(specifier) = clause.source().ok().and_then(source_specifier) {
+                        module.reexports.insert(specifier);
+                    }
+                }
+            },
+            // export { a, b as c };
+            AnyJsExportClause::JsExportNamedClause(clause) => {
+                for specifier in clause.specifiers().iter().flatten() {
+                    let name = match specifier {
+                        AnyJsExportNamedSpecifier::JsExportNamedShorthandSpecifier(specifier) => {
+                            specifier
+                                .name()
+                                .ok()
+                                .and_then(|name| {
+                                    Some(name.value_token().ok()?.token_text_trimmed())
+                                })
+                                .map(|name| name.to_string())
+                        }
+                        AnyJsExportNamedSpecifier::JsExportNamedSpecifier(specifier) => {
+                            specifier.exported_name().ok().map(export_name)
+                        }
+                    };
+                    module.exports.extend(name);
+                }
+            }
+            // export { a, b as c } from "mod";
+            AnyJsExportClause::JsExportNamedFromClause(clause) => {
+                for specifier in clause.specifiers().iter().flatten() {
+                    let name = match specifier.export_as() {
+                        Some(export_as) => export_as.exported_name().ok(),
+                        None => specifier.source_name().ok(),
+                    };
+                    module.exports.extend(name.map(export_name));
+                }
+            }
+            // export = value;
+            AnyJsExportClause::TsExportAssignmentClause(_) => {
+                module.has_dynamic_exports = true;
+            }
+            AnyJsExportClause::TsExportAsNamespaceClause(_) => {}
+        }
+    }
+
+    // Without any export, the module is likely a CommonJS module
+    if !has_exports {
+        module.has_dynamic_exports = true;
+    }
+
+    (module, specifiers)
+}
+
+/// Collects the names of the bindings of an exported declaration
+fn collect_declaration_names(
+    declaration: &AnyJsDeclarationClause,
+    names: &mut impl Extend<String>,
+) {
+    let id = match declaration {
+        // export const { a, b: [c] } = value;
+        AnyJsDeclarationClause::JsVariableDeclarationClause(clause) => {
+            if let Ok(declaration) = clause.declaration() {
+                for declarator in declaration.declarators().iter().flatten() {
+                    if let Ok(id) = declarator.id() {
+                        names.extend(
+                            id.syntax()
+                                .descendants()
+                                .filter_map(JsIdentifierBinding::cast)
+                                .filter_map(|binding| binding.name_token().ok())
+                                .map(|token| token.text_trimmed().to_string()),
+                        );
+                    }
+                }
+            }
+            return;
+        }
+        AnyJsDeclarationClause::JsClassDeclaration(declaration) => {
+            declaration.id().ok().map(|id| id.into_syntax())
+        }
+        AnyJsDeclarationClause::JsFunctionDeclaration(declaration) => {
+            declaration.id().ok().map(|id| id.into_syntax())
+        }
+        AnyJsDeclarationClause::TsDeclareFunctionDeclaration(declaration) => {
+            declaration.id().ok().map(|id| id.into_syntax())
+        }
+        AnyJsDeclarationClause::TsEnumDeclaration(declaration) => {
+            declaration.id().ok().map(|id| id.into_syntax())
+        }
+        AnyJsDeclarationClause::TsImportEqualsDeclaration(declaration) => {
+            declaration.id().ok().map(|id| id.into_syntax())
+        }
+        AnyJsDeclarationClause::TsInterfaceDeclaration(declaration) => {
+            declaration.id().ok().map(|id| id.into_syntax())
+        }
+        // export namespace A.B {}
+        AnyJsDeclarationClause::TsModuleDeclaration(declaration) => {
+            declaration.name().ok().map(|name| name.into_syntax())
+        }
+        AnyJsDeclarationClause::TsTypeAliasDeclaration(declaration) => declaration
+            .binding_identifier()
+            .ok()
+            .map(|id| id.into_syntax()),
+        AnyJsDeclarationClause::TsExternalModuleDeclaration(_)
+        | AnyJsDeclarationClause::TsGlobalDeclaration(_) => None,
+    };
+
+    names.extend(
+        id.as_ref()
+            .and_then(SyntaxNode::<JsLanguage>::first_token)
+            .map(|token| token.text_trimmed().to_string()),
+    );
+}
+
+/// Returns the name of an export, which can be a string literal: `export { a as "b" }`
+fn export_name(name: JsLiteralExportName) -> String {
+    name.value()
+        .map(|token| inner_string_text(&token).to_string())
+        .unwrap_or_default()
+}
+
+fn source_specifier(source: AnyJsModuleSource) -> Option<String> {
+    let source = source.as_js_module_source()?;
+    Some(source.inner_string_text().ok()?.to_string())
+}
+
+fn is_in_node_modules(path: &Path) -> bool {
+    path.components()
+        .any(|component| component.as_os_str() == "node_modules")
+}
+
+fn read_to_string(fs: &dyn FileSystem, path: &Path) -> io::Result<String> {
+    let mut file = fs.open_with_options(path, OpenOptions::default().read(true))?;
+    let mut content = String::new();
+    file.read_to_string(&mut content)?;
+    Ok(content)
+}
+
+/// Lets the resolver read the files through the [FileSystem] of Biome
+struct ResolverFileSystem<'a>(&'a dyn FileSystem);
+
+impl oxc_resolver::FileSystem for ResolverFileSystem<'_> {
+    fn read_to_string(&self, path: &Path) -> io::Result<String> {
+        read_to_string(self.0, path)
+    }
+
+    fn metadata(&self, path: &Path) -> io::Result<FileMetadata> {
+        if self.0.path_is_file(path) {
+            Ok(FileMetadata::new(true, false, false))
+        } else if self.0.path_is_dir(path) {
+            Ok(FileMetadata::new(false, true, false))
+        } else {
+            Err(io::Error::from(io::ErrorKind::NotFound))
+        }
+    }
+
+    fn symlink_metadata(&self, path: &Path) -> io::Result<FileMetadata> {
+        self.metadata(path)
+    }
+
+    fn read_link(&self, _path: &Path) -> io::Result<PathBuf> {
+        Err(io::Error::from(io::ErrorKind::Unsupported))
+    }
+}
+
+/// The lint rules of the [project phase](Phases::Project), which need the module graph of the
+/// project, with their domains
+#[derive(Default)]
+pub struct ProjectRules(Vec<(RuleFilter<'static>, &'static [RuleDomain])>);
+
+impl ProjectRules {
+    pub fn new() -> Self {
+        let mut rules = Self::default();
+        biome_js_analyze::visit_registry(&mut rules);
+        rules
+    }
+
+    /// Returns whether the rule `rule_name` of the group `group` is one of the rules
+    pub fn contains(&self, group: &str, rule_name: &str) -> bool {
+        self.0
+            .iter()
+            .any(|(rule, _)| *rule == RuleFilter::Rule(group, rule_name))
+    }
+
+    /// Returns whether `enabled_rules` include one of the rules
+    pub(crate) fn any_enabled(&self, enabled_rules: &FxHashSet<RuleFilter<'static>>) -> bool {
+        self.0.iter().any(|(rule, _)| enabled_rules.contains(rule))
+    }
+
+    /// Returns whether one of the rules is selected by `--only`
+    pub(crate) fn any_selected(&self, only: &[RuleSelector]) -> bool {
+        self.0.iter().any(|(rule, _)| {
+            only.iter().any(|selector| match selector {
+                RuleSelector::Group(group) => group.as_str() == rule.group(),
+                RuleSelector::Rule(group, name) => *rule == RuleFilter::Rule(group.as_str(), name),
+            })
+        })
+    }
+
+    /// Returns whether one of the rules may be enabled by its domains, unless the rule is
+    /// configured in `rules`. The domains set to `auto` count as enabled, because the manifest
+    /// isn't known yet.
+    pub(crate) fn any_enabled_by_domains(&self, domains: &RuleDomains, rules: &Rules) -> bool {
+        self.0.iter().any(|(rule, rule_domains)| {
+            let is_configured = rule.group().parse().is_ok_and(|group| match rule {
+                RuleFilter::Rule(_, name) => rules.get_rule_level(group, name).is_some(),
+                RuleFilter::Group(_) => false,
+            });
+            !is_configured
+                && rule_domains.iter().any(|domain| {
+                    domains
+                        .get(domain)
+                        .is_some_and(|value| *value != RuleDomainValue::None)
+                })
+        })
+    }
+}
+
+impl RegistryVisitor<JsLanguage> for ProjectRules {
+    fn record_category<C: GroupCategory<Language = JsLanguage>>(&mut self) {
+        if matches!(C::CATEGORY, RuleCategory::Lint) {
+            C::record_groups(self);
+        }
+    }
+
+    fn record_rule<R>(&mut self)
+    where
+        R: Rule<Query: Queryable<Language = JsLanguage, Output: Clone>> + 'static,
+    {
+        if R::phase() == Phases::Project {
+            self.0.push((
+                RuleFilter::Rule(<R::Group as RuleGroup>::NAME, R::METADATA.name),
+                R::METADATA.domains,
+            ));
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use biome_fs::MemoryFileSystem;
+
+    fn resolved_module(path: &str) -> ResolvedImport {
+        ResolvedImport::Module(PathBuf::from(path))
+    }
+
+    #[test]
+    fn resolves_relative_imports() {
+        let mut fs = MemoryFileSystem::default();
+        fs.insert("src/index.ts".into(), "import { a } from './a.js';\nimport { b } from './b';\nimport { c } from './c';\nimport { d } from './missing';\n");
+        fs.insert("src/a.ts".into(), "export const a = 1;\n");
+        fs.insert("src/b.tsx".into(), "export const b = 1;\n");
+        fs.insert("src/c/index.js".into(), "export const c = 1;\n");
+
+        let mut builder = ModuleGraphBuilder::new(&fs, Path::new(""));
+        builder.add_path(Path::new("src/index.ts"));
+        let graph = builder.build();
+
+        let index = Path::new("src/index.ts");
+        assert_eq!(
+            graph.resolve_import(index, "./a.js"),
+            Some(&resolved_module("src/a.ts"))
+        );
+        assert_eq!(
+            graph.resolve_import(index, "./b"),
+            Some(&resolved_module("src/b.tsx"))
+        );
+        assert_eq!(
+            graph.resolve_import(index, "./c"),
+            Some(&resolved_module("src/c/index.js"))
+        );
+        assert_eq!(
+            graph.resolve_import(index, "./missing"),
+            Some(&ResolvedImport::Unresolved)
+        );
+        assert_eq!(graph.len(), 4);
+    }
+
+    #[test]
+    fn resolves_builtin_modules() {
+        let mut fs = MemoryFileSystem::default();
+        fs.insert(
+            "index.js".into(),
+            "import fs from 'node:fs';\nimport path from 'path';\n",
+        );
+
+        let mut builder = ModuleGraphBuilder::new(&fs, Path::new(""));
+        builder.add_path(Path::new("index.js"));
+        let graph = builder.build();
+
+        let index = Path::new("index.js");
+        assert_eq!(
+            graph.resolve_import(index, "node:fs"),
+            Some(&ResolvedImport::Builtin("node:fs".to_string()))
+        );
+        assert_eq!(
+            graph.resolve_import(index, "path"),
+            Some(&ResolvedImport::Builtin("node:path".to_string()))
+        );
+    }
+
+    #[test]
+    fn resolves_tsconfig_paths() {
+        let mut fs = MemoryFileSystem::default();
+        fs.insert(
+            "tsconfig.json".into(),
+            r#"{ "compilerOptions": { "baseUrl": ".", "paths": { "@lib/*": ["lib/*"] } } }"#,
+        );
+        fs.insert("src/index.ts".into(), "import { add } from '@lib/math';\n");
+        fs.insert("lib/math.ts".into(), "export function add() {}\n");
+
+        let mut builder = ModuleGraphBuilder::new(&fs, Path::new(""));
+        builder.add_path(Path::new("src/index.ts"));
+        let graph = builder.build();
+
+        assert_eq!(
+            graph.resolve_import(Path::new("src/index.ts"), "@lib/math"),
+            Some(&resolved_module("lib/math.ts"))
+        );
+        assert_eq!(
+            graph.is_exported(Path::new("lib/math.ts"), "add"),
+            Some(true)
+        );
+    }
+
+    #[test]
+    fn follows_only_the_reexports_of_dependencies() {
+        let mut fs = MemoryFileSystem::default();
+        fs.insert(
+            "node_modules/pkg/package.json".into(),
+            r#"{ "name": "pkg", "exports": { ".": "./dist/index.mjs" } }"#,
+        );
+        fs.insert(
+            "node_modules/pkg/dist/index.mjs".into(),
+            "import './side-effect.mjs';\nexport * from './helpers.mjs';\n",
+        );
+        fs.insert(
+            "node_modules/pkg/dist/helpers.mjs".into(),
+            "export const helper = 1;\n",
+        );
+        fs.insert(
+            "node_modules/pkg/dist/side-effect.mjs".into(),
+            "export {};\n",
+        );
+        fs.insert("index.js".into(), "import { helper } from 'pkg';\n");
+
+        let mut builder = ModuleGraphBuilder::new(&fs, Path::new(""));
+        builder.add_path(Path::new("index.js"));
+        let graph = builder.build();
+
+        assert_eq!(
+            graph.resolve_import(Path::new("index.js"), "pkg"),
+            Some(&resolved_module("node_modules/pkg/dist/index.mjs"))
+        );
+        assert_eq!(
+            graph.is_exported(Path::new("node_modules/pkg/dist/index.mjs"), "helper"),
+            Some(true)
+        );
+        assert!(graph
+            .module(Path::new("node_modules/pkg/dist/side-effect.mjs"))
+            .is_none());
+    }
+
+    #[test]
+    fn collects_the_exports_of_the_modules() {
+        let mut fs = MemoryFileSystem::default();
+        fs.insert(
+            "index.ts".into(),
+            "export const { a, b: [c] } = value;\nexport function d() {}\nexport default 1;\nexport { e as f, g };\nexport * as ns from './other';\nexport type H = string;\n",
+        );
+        fs.insert("script.js".into(), "module.exports = {};\n");
+
+        let mut builder = ModuleGraphBuilder::new(&fs, Path::new(""));
+        builder.add_path(Path::new("index.ts"));
+        builder.add_path(Path::new("script.js"));
+        let graph = builder.build();
+
+        let index = graph.module(Path::new("index.ts")).unwrap();
+        assert_eq!(
+            index.exports.iter().map(String::as_str).collect::<Vec<_>>(),
+            ["H", "a", "c", "d", "default", "f", "g", "ns"]
+        );
+        assert!(!index.has_dynamic_exports);
+        assert!(
+            graph
+                .module(Path::new("script.js"))
+                .unwrap()
+                .has_dynamic_exports
+        );
+    }
+
+    #[test]
+    fn adds_the_modules_of_several_paths() {
+        let mut fs = MemoryFileSystem::default();
+        fs.insert("a.js".into(), "import { c } from './c.js';\n");
+        fs.insert("b.js".into(), "import { c } from './c.js';\n");
+        fs.insert("c.js".into(), "export { d as c } from './d.js';\n");
+        fs.insert("d.js".into(), "export const d = 1;\n");
+
+        let mut builder = ModuleGraphBuilder::new(&fs, Path::new(""));
+        builder.add_paths([PathBuf::from("a.js"), PathBuf::from("b.js")]);
+        let graph = builder.build();
+
+        assert_eq!(
+            graph.paths().collect::<Vec<_>>(),
+            ["a.js", "b.js", "c.js", "d.js"].map(Path::new)
+        );
+        assert_eq!(graph.is_exported(Path::new("c.js"), "c"), Some(true));
+    }
+
+    #[test]
+    fn skips_the_known_modules() {
+        let mut fs = MemoryFileSystem::default();
+        fs.insert("index.js".into(), "import { a } from './a.js';\n");
+        fs.insert("a.js".into(), "export const a = 1;\n");
+
+        let mut builder = ModuleGraphBuilder::new(&fs, Path::new(""));
+        builder.add_path(Path::new("index.js"));
+        let graph = builder.build();
+
+        let mut builder = ModuleGraphBuilder::new(&fs, Path::new("")).with_known_modules(&graph);
+        builder.add_module(
+            Path::new("index.js"),
+            "import { a } from './a.js';\nexport const b = a;\n",
+        );
+        let changed_modules = builder.build();
+
+        assert_eq!(
+            changed_modules.paths().collect::<Vec<_>>(),
+            [Path::new("index.js")]
+        );
+        assert_eq!(
+            changed_modules.is_exported(Path::new("index.js"), "b"),
+            Some(true)
+        );
+    }
+}
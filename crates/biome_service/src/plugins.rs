@@ -0,0 +1,139 @@
+use crate::workspace::PluginFile;
+use crate::WorkspaceError;
+use biome_analyze::{AnalyzerPlugin, PluginDiagnostic, PluginEvaluation};
+use biome_configuration::analyzer::RulePlainConfiguration;
+use biome_configuration::BiomeDiagnostic;
+use biome_console::markup;
+use biome_diagnostics::{Diagnostic, Severity};
+use biome_grit_patterns::{
+    compile_pattern, CompileError, GritQuery, GritQueryResult, GritTargetFile, JsTargetLanguage,
+};
+use biome_js_parser::{parse, JsParserOptions};
+use biome_js_syntax::{JsFileSource, JsLanguage};
+use biome_parser::AnyParse;
+use biome_rowan::{SendNode, TextRange};
+use std::fmt::{Debug, Formatter};
+use std::path::Path;
+use tracing::error;
+
+/// A lint plugin written in GritQL.
+///
+/// The diagnostics of the plugin are registered with the `register_diagnostic()` built-in, and
+/// its rewrites are offered as a code action.
+pub struct GritPlugin {
+    name: String,
+    query: GritQuery,
+    severity: Option<Severity>,
+}
+
+impl GritPlugin {
+    /// Compiles the GritQL file of a plugin, which is named after the file
+    pub fn compile(file: &PluginFile) -> Result<Self, WorkspaceError> {
+        let query = compile_pattern(
+            &file.content,
+            Some(file.path.as_path()),
+            JsTargetLanguage.into(),
+        )
+        .map_err(|error| {
+            BiomeDiagnostic::invalid_configuration(markup! {
+                "The plugin "{file.path.display().to_string()}" can't be compiled: "{CompileErrorMessage(&error)}
+            })
+        })?;
+        let name = file
+            .path
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().to_string())
+            .unwrap_or_default();
+
+        Ok(Self {
+            name,
+            query,
+            severity: file
+                .severity
+                .filter(|severity| *severity != RulePlainConfiguration::Off)
+                .map(Severity::from),
+        })
+    }
+}
+
+impl Debug for GritPlugin {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("GritPlugin")
+            .field("name", &self.name)
+            .finish_non_exhaustive()
+    }
+}
+
+impl AnalyzerPlugin for GritPlugin {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn severity(&self) -> Option<Severity> {
+        self.severity
+    }
+
+    fn evaluate(&self, root: SendNode, path: &Path) -> PluginEvaluation {
+        // The queries of the plugins target JavaScript
+        if root.clone().into_node::<JsLanguage>().is_none() {
+            return PluginEvaluation::default();
+        }
+
+        let file = GritTargetFile {
+            path: path.to_path_buf(),
+            parse: AnyParse::new(root, Vec::new()),
+        };
+        let results = match self.query.execute(file) {
+            Ok((results, _logs)) => results,
+            Err(err) => {
+                error!("The plugin {} failed to run: {}", self.name, err);
+                // The error is reported on the file, which can't be checked by the plugin
+                return PluginEvaluation {
+                    diagnostics: vec![PluginDiagnostic {
+                        span: TextRange::default(),
+                        message: format!("The plugin {} failed to run: {err}", self.name),
+                        severity: Severity::Error,
+                    }],
+                    rewritten: None,
+                };
+            }
+        };
+
+        let mut evaluation = PluginEvaluation::default();
+        for result in results {
+            match result {
+                GritQueryResult::Diagnostic(diagnostic) => {
+                    evaluation.diagnostics.push(PluginDiagnostic {
+                        span: diagnostic.range,
+                        message: diagnostic.message,
+                        severity: diagnostic.severity,
+                    });
+                }
+                GritQueryResult::Rewrite(rewrite) => {
+                    let file_source = JsFileSource::try_from(path).unwrap_or_default();
+                    let parse = parse(
+                        &rewrite.rewritten.content,
+                        file_source,
+                        JsParserOptions::default(),
+                    );
+                    // A rewrite that doesn't parse can't be applied to the file
+                    if !parse.has_errors() {
+                        evaluation.rewritten = parse.syntax().as_send();
+                    }
+                }
+                GritQueryResult::Match(_) | GritQueryResult::CreateFile(_) => {}
+            }
+        }
+
+        evaluation
+    }
+}
+
+/// Prints the message of a [CompileError]
+struct CompileErrorMessage<'a>(&'a CompileError);
+
+impl biome_console::fmt::Display for CompileErrorMessage<'_> {
+    fn fmt(&self, fmt: &mut biome_console::fmt::Formatter) -> std::io::Result<()> {
+        self.0.message(fmt)
+    }
+}
@@ -1,7 +1,17 @@
-use crate::workspace::{DocumentFileSource, ProjectKey, WorkspaceData};
+use crate::plugins::GritPlugin;
+use crate::workspace::{
+    DocumentFileSource, PluginFile, ProjectKey, ResolvedFormatterOption, ResolvedRule,
+    ResolvedSettingSource, ResolvedSettings, VcsIgnoreFile, WorkspaceData,
+};
 use crate::{Matcher, WorkspaceError};
-use biome_analyze::{AnalyzerOptions, AnalyzerRules};
+use biome_analyze::{
+    AnalyzerOptions, AnalyzerPlugin, AnalyzerRules, GroupCategory, MetadataRegistry, Queryable,
+    RegistryVisitor, Rule, RuleCategory, RuleDomain, RuleFilter, RuleGroup,
+};
 use biome_configuration::analyzer::assists::AssistsConfiguration;
+use biome_configuration::analyzer::{
+    RuleDomainValue, RuleDomains, RulePlainConfiguration, SuppressionsConfiguration,
+};
 use biome_configuration::diagnostics::InvalidIgnorePattern;
 use biome_configuration::javascript::JsxRuntime;
 use biome_configuration::organize_imports::OrganizeImports;
@@ -9,8 +19,9 @@ use biome_configuration::{
     push_to_analyzer_rules, BiomeDiagnostic, FilesConfiguration, FormatterConfiguration,
     JavascriptConfiguration, LinterConfiguration, OverrideAssistsConfiguration,
     OverrideFormatterConfiguration, OverrideLinterConfiguration,
-    OverrideOrganizeImportsConfiguration, Overrides, PartialConfiguration, PartialCssConfiguration,
-    PartialGraphqlConfiguration, PartialJavascriptConfiguration, PartialJsonConfiguration,
+    OverrideOrganizeImportsConfiguration, OverridePattern, Overrides, PartialConfiguration,
+    PartialCssConfiguration, PartialGraphqlConfiguration, PartialHtmlConfiguration,
+    PartialJavascriptConfiguration, PartialJsonConfiguration,
 };
 use biome_css_formatter::context::CssFormatOptions;
 use biome_css_parser::CssParserOptions;
@@ -18,7 +29,8 @@ use biome_css_syntax::CssLanguage;
 use biome_deserialize::{Merge, StringSet};
 use biome_diagnostics::Category;
 use biome_formatter::{
-    AttributePosition, BracketSpacing, IndentStyle, IndentWidth, LineEnding, LineWidth,
+    AttributePosition, BracketSpacing, FormatOptions, IndentStyle, IndentWidth, LineEnding,
+    LineWidth,
 };
 use biome_fs::BiomePath;
 use biome_graphql_formatter::context::GraphqlFormatOptions;
@@ -33,14 +45,16 @@ use biome_js_syntax::{JsFileSource, JsLanguage};
 use biome_json_formatter::context::JsonFormatOptions;
 use biome_json_parser::JsonParserOptions;
 use biome_json_syntax::JsonLanguage;
-use biome_project::{NodeJsProject, PackageJson};
-use ignore::gitignore::{Gitignore, GitignoreBuilder};
+use biome_project::{ModuleGraph, NodeJsProject, PackageJson};
+use biome_rowan::Language;
+use ignore::gitignore::{Gitignore, GitignoreBuilder, Glob};
+use ignore::Match;
 use indexmap::IndexSet;
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHashSet};
 use std::borrow::Cow;
 use std::ops::Deref;
 use std::path::{Path, PathBuf};
-use std::sync::RwLockWriteGuard;
+use std::sync::{Arc, RwLockWriteGuard};
 use std::{
     num::NonZeroU64,
     sync::{RwLock, RwLockReadGuard},
@@ -56,6 +70,33 @@ pub struct ProjectData {
     settings: Settings,
     /// Information relative to the current project
     project: Option<NodeJsProject>,
+    /// The configuration the settings were computed from, extended by the nested configurations
+    configuration: PartialConfiguration,
+    /// The settings of the configuration files of the subdirectories, the deepest directories first
+    nested_settings: Vec<NestedSettings>,
+    /// The modules of the project, used by the rules of the project phase
+    module_graph: Arc<ModuleGraph>,
+}
+
+/// The settings of a configuration file in a subdirectory of a project, that apply to the files
+/// of this subdirectory instead of the settings of the project
+#[derive(Debug)]
+struct NestedSettings {
+    /// The directory of the configuration file
+    directory: PathBuf,
+    /// The configuration of the file, merged with the configuration of its parent directory
+    /// when it has `"root": false`
+    configuration: PartialConfiguration,
+    settings: Settings,
+}
+
+impl ProjectData {
+    /// Retrieves the settings of the closest nested configuration of the file at the given path
+    fn get_nested_settings(&self, path: &Path) -> Option<&NestedSettings> {
+        self.nested_settings
+            .iter()
+            .find(|nested_settings| path.starts_with(&nested_settings.directory))
+    }
 }
 
 #[derive(Debug, Default)]
@@ -89,6 +130,151 @@ impl WorkspaceSettings {
         }
     }
 
+    /// Retrieves the settings that apply to the file at the given path: the settings of the
+    /// closest nested configuration, or the settings of the current project.
+    pub fn get_settings_for_path(&self, path: &Path) -> Option<&Settings> {
+        let data = self.data.get(self.current_project)?;
+        let nested_settings = data.get_nested_settings(path);
+        Some(nested_settings.map_or(&data.settings, |nested_settings| &nested_settings.settings))
+    }
+
+    /// Retrieves the directory and the configuration of the closest configuration file of the
+    /// file at the given path, the nested ones or the one of the project
+    pub fn get_configuration_for_path(
+        &self,
+        path: &Path,
+    ) -> Option<(&Path, &PartialConfiguration)> {
+        let data = self.data.get(self.current_project)?;
+        Some(match data.get_nested_settings(path) {
+            Some(nested_settings) => (
+                nested_settings.directory.as_path(),
+                &nested_settings.configuration,
+            ),
+            None => (data.path.as_path(), &data.configuration),
+        })
+    }
+
+    /// Retrieves the directory of the closest nested configuration of the file at the given path, if any.
+    pub fn get_nested_configuration_directory(&self, path: &Path) -> Option<&Path> {
+        let data = self.data.get(self.current_project)?;
+        data.get_nested_settings(path)
+            .map(|nested_settings| nested_settings.directory.as_path())
+    }
+
+    /// Stores the configuration the settings of the current project were computed from.
+    ///
+    /// The settings of the nested configurations are removed, since they may depend on it.
+    pub fn merge_current_configuration(&mut self, configuration: PartialConfiguration) {
+        let project_data = self.get_current_project_data_mut();
+        project_data.configuration.merge_with(configuration);
+        project_data.nested_settings.clear();
+    }
+
+    /// Computes the settings of a configuration file found in a subdirectory of the current project.
+    ///
+    /// When the configuration has `"root": false`, it's merged with the configuration of the
+    /// closest parent directory, so that the parent configuration must be inserted first.
+    pub fn insert_nested_configuration(
+        &mut self,
+        directory: PathBuf,
+        configuration: PartialConfiguration,
+        vcs_path: Option<PathBuf>,
+        gitignore_matches: &[String],
+        ignore_files: &[VcsIgnoreFile],
+        plugins: &[PluginFile],
+    ) -> Result<(), WorkspaceError> {
+        let project_data = self.get_current_project_data_mut();
+        // Without plugins of its own, a configuration with `"root": false` keeps the plugins of
+        // its parent, whose paths are relative to the parent directory
+        let mut parent_plugins = Vec::new();
+        let configuration = if configuration.is_root() {
+            configuration
+        } else {
+            let parent_settings = project_data.nested_settings.iter().find(|nested_settings| {
+                directory.starts_with(&nested_settings.directory)
+                    && directory != nested_settings.directory
+            });
+            if configuration.plugins.is_none() {
+                parent_plugins.clone_from(
+                    &parent_settings
+                        .map_or(&project_data.settings, |nested_settings| {
+                            &nested_settings.settings
+                        })
+                        .plugins,
+                );
+            }
+            let mut parent_configuration = parent_settings
+                .map_or(&project_data.configuration, |nested_settings| {
+                    &nested_settings.configuration
+                })
+                .clone();
+            // The overrides of the parent apply before the values of the nested configuration,
+            // which are then repeated in an override matching all its files
+            let mut configuration = configuration;
+            let overrides = configuration.overrides.take();
+            if let Some(parent_overrides) = parent_configuration.overrides.as_mut() {
+                parent_overrides.0.push(as_override_pattern(&configuration));
+            }
+            parent_configuration.merge_with(configuration);
+            parent_configuration.merge_with(PartialConfiguration {
+                overrides,
+                ..Default::default()
+            });
+            parent_configuration
+        };
+
+        let mut settings = Settings::default();
+        settings.merge_with_configuration(
+            configuration.clone(),
+            Some(directory.clone()),
+            vcs_path,
+            gitignore_matches,
+        )?;
+        settings.merge_with_vcs_ignore_files(ignore_files)?;
+        if plugins.is_empty() {
+            settings.plugins = parent_plugins;
+        } else {
+            settings.set_plugins(plugins)?;
+        }
+
+        project_data
+            .nested_settings
+            .retain(|nested_settings| nested_settings.directory != directory);
+        let depth = directory.components().count();
+        let index = project_data
+            .nested_settings
+            .iter()
+            .position(|nested_settings| nested_settings.directory.components().count() <= depth)
+            .unwrap_or(project_data.nested_settings.len());
+        project_data.nested_settings.insert(
+            index,
+            NestedSettings {
+                directory,
+                configuration,
+                settings,
+            },
+        );
+        Ok(())
+    }
+
+    /// Adds the ignore files of the VCS found while traversing the project to the settings of
+    /// the current project and of its nested configurations
+    pub fn register_vcs_ignore_files(
+        &mut self,
+        ignore_files: &[VcsIgnoreFile],
+    ) -> Result<(), WorkspaceError> {
+        let project_data = self.get_current_project_data_mut();
+        project_data
+            .settings
+            .merge_with_vcs_ignore_files(ignore_files)?;
+        for nested_settings in &mut project_data.nested_settings {
+            nested_settings
+                .settings
+                .merge_with_vcs_ignore_files(ignore_files)?;
+        }
+        Ok(())
+    }
+
     pub fn get_current_manifest(&self) -> Option<&PackageJson> {
         let data = self.data.get(self.current_project);
         if let Some(data) = data {
@@ -98,6 +284,25 @@ impl WorkspaceSettings {
         }
     }
 
+    /// Returns the module graph of the current project, empty when none was built
+    pub fn get_current_module_graph(&self) -> Arc<ModuleGraph> {
+        self.data
+            .get(self.current_project)
+            .map(|data| data.module_graph.clone())
+            .unwrap_or_default()
+    }
+
+    /// Removes the modules at `removed_paths` from the module graph of the current project, then
+    /// adds the modules of `module_graph`
+    pub fn update_module_graph(&mut self, module_graph: ModuleGraph, removed_paths: &[PathBuf]) {
+        let project_data = self.get_current_project_data_mut();
+        let project_module_graph = Arc::make_mut(&mut project_data.module_graph);
+        for path in removed_paths {
+            project_module_graph.remove_module(path);
+        }
+        project_module_graph.extend(module_graph);
+    }
+
     /// Retrieves a mutable reference of the settings of the current project
     pub fn get_current_settings_mut(&mut self) -> &mut Settings {
         &mut self
@@ -121,6 +326,9 @@ impl WorkspaceSettings {
             path,
             settings: Settings::default(),
             project: None,
+            configuration: PartialConfiguration::default(),
+            nested_settings: Vec::new(),
+            module_graph: Arc::default(),
         })
     }
 
@@ -201,9 +409,43 @@ pub struct Settings {
     pub assists: AssistsSettings,
     /// overrides
     pub override_settings: OverrideSettings,
+    /// The lint plugins compiled from the `plugins` of the configuration
+    pub plugins: Vec<Arc<dyn AnalyzerPlugin>>,
 }
 
 impl Settings {
+    /// Compiles the GritQL files of the plugins, which replace the current plugins
+    pub fn set_plugins(&mut self, plugins: &[PluginFile]) -> Result<(), WorkspaceError> {
+        self.plugins = plugins
+            .iter()
+            // The plugins turned off don't run
+            .filter(|plugin| plugin.severity != Some(RulePlainConfiguration::Off))
+            .map(|plugin| {
+                GritPlugin::compile(plugin)
+                    .map(|plugin| Arc::new(plugin) as Arc<dyn AnalyzerPlugin>)
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(())
+    }
+
+    /// Adds the other ignore files of the VCS to the `.gitignore` file of its root directory.
+    ///
+    /// They are ignored when the VCS integration isn't enabled.
+    pub fn merge_with_vcs_ignore_files(
+        &mut self,
+        ignore_files: &[VcsIgnoreFile],
+    ) -> Result<(), WorkspaceError> {
+        if let Some(git_ignore) = self.files.git_ignore.as_mut() {
+            for ignore_file in ignore_files {
+                git_ignore.insert(to_git_ignore(
+                    ignore_file.directory.clone(),
+                    &ignore_file.patterns,
+                )?)
+            }
+        }
+        Ok(())
+    }
+
     /// The [PartialConfiguration] is merged into the workspace
     #[tracing::instrument(level = "trace", skip(self))]
     pub fn merge_with_configuration(
@@ -268,6 +510,10 @@ impl Settings {
         if let Some(graphql) = configuration.graphql {
             self.languages.graphql = graphql.into()
         }
+        // html settings
+        if let Some(html) = configuration.html {
+            self.languages.html = html.into()
+        }
 
         // NOTE: keep this last. Computing the overrides require reading the settings computed by the parent settings.
         if let Some(overrides) = configuration.overrides {
@@ -301,6 +547,12 @@ impl Settings {
         enabled == Some(&false)
     }
 
+    /// Whether the formatter is disabled for HTML files
+    pub fn html_formatter_disabled(&self) -> bool {
+        let enabled = self.languages.html.formatter.enabled.as_ref();
+        enabled == Some(&false)
+    }
+
     /// Whether the linter is disabled for CSS files
     pub fn javascript_linter_disabled(&self) -> bool {
         let enabled = self.languages.javascript.linter.enabled.as_ref();
@@ -320,6 +572,12 @@ impl Settings {
         enabled == Some(&false)
     }
 
+    /// Whether the linter is disabled for HTML files
+    pub fn html_linter_disabled(&self) -> bool {
+        let enabled = self.languages.html.linter.enabled.as_ref();
+        enabled == Some(&false)
+    }
+
     /// Retrieves the settings of the linter
     pub fn linter(&self) -> &LinterSettings {
         &self.linter
@@ -376,6 +634,113 @@ impl Settings {
         result
     }
 
+    /// Returns the configuration of the rule domains for the file at the given path, taking
+    /// overrides into account
+    pub fn as_linter_domains(&self, path: &Path) -> Cow<RuleDomains> {
+        let mut result = Cow::Borrowed(&self.linter.domains);
+        for pattern in self.override_settings.patterns.iter() {
+            if let Some(pattern_domains) = pattern.linter.domains.as_ref() {
+                if pattern.include.matches_path(path) && !pattern.exclude.matches_path(path) {
+                    result.to_mut().extend(
+                        pattern_domains
+                            .iter()
+                            .map(|(domain, value)| (*domain, *value)),
+                    );
+                }
+            }
+        }
+        result
+    }
+
+    /// Returns the lint rules of the given registry that are enabled for the file at the given
+    /// path, taking overrides into account.
+    pub fn as_resolved_linter_rules(
+        &self,
+        path: &Path,
+        metadata: &MetadataRegistry,
+        domain_rules: &DomainRules,
+        manifest: Option<&PackageJson>,
+    ) -> Vec<ResolvedRule> {
+        let rules = self
+            .as_linter_rules(path)
+            .map(Cow::into_owned)
+            .unwrap_or_default();
+        let configured_domains = self.as_linter_domains(path);
+        let mut enabled_rules = rules.as_enabled_rules();
+        let mut enabled_by_domains = FxHashSet::default();
+        for (filter, domains) in &domain_rules.0 {
+            let RuleFilter::Rule(group, name) = *filter else {
+                continue;
+            };
+            match is_rule_enabled_by_domains(
+                &configured_domains,
+                Some(&rules),
+                group,
+                name,
+                domains,
+                manifest,
+            ) {
+                Some(true) => {
+                    enabled_rules.insert(*filter);
+                    enabled_by_domains.insert(*filter);
+                }
+                Some(false) => {
+                    enabled_rules.remove(filter);
+                }
+                None => {}
+            }
+        }
+        let resolved_rules = serde_json::to_value(&rules).unwrap_or_default();
+        let configured_rules = serde_json::to_value(&self.linter.rules).unwrap_or_default();
+        let overridden_rules: Vec<_> = self
+            .override_settings
+            .patterns
+            .iter()
+            .filter(|pattern| {
+                pattern.include.matches_path(path) && !pattern.exclude.matches_path(path)
+            })
+            .filter_map(|pattern| pattern.linter.rules.as_ref())
+            .map(|rules| serde_json::to_value(rules).unwrap_or_default())
+            .collect();
+
+        let mut result: Vec<_> = enabled_rules
+            .into_iter()
+            .filter_map(|filter| {
+                let RuleFilter::Rule(group, name) = filter else {
+                    return None;
+                };
+                metadata.find_rule(group, name)?;
+                let category: &Category = format!("lint/{group}/{name}").parse().ok()?;
+                let severity = rules.get_severity_from_code(category)?;
+                let options = resolved_rules
+                    .get(group)
+                    .and_then(|group| group.get(name))
+                    .and_then(|rule| rule.get("options"))
+                    .map(|options| options.to_string());
+                let is_configured_in = |rules: &serde_json::Value| {
+                    rules.get(group).and_then(|group| group.get(name)).is_some()
+                };
+                let source = if overridden_rules.iter().any(is_configured_in) {
+                    ResolvedSettingSource::Override
+                } else if is_configured_in(&configured_rules)
+                    || enabled_by_domains.contains(&filter)
+                {
+                    ResolvedSettingSource::Configuration
+                } else {
+                    ResolvedSettingSource::Default
+                };
+                Some(ResolvedRule {
+                    name: format!("{group}/{name}"),
+                    severity,
+                    options,
+                    source,
+                })
+            })
+            .collect();
+        result.sort_by(|a, b| a.name.cmp(&b.name));
+        result
+    }
+
     /// Returns assists rules taking overrides into account.
     pub fn as_assists_rules(
         &self,
@@ -468,6 +833,12 @@ pub struct LinterSettings {
 
     /// List of included paths/files to match
     pub included_files: Matcher,
+
+    /// The configuration of the rule domains
+    pub domains: RuleDomains,
+
+    /// The policy that the suppression comments must follow
+    pub suppressions: SuppressionsConfiguration,
 }
 
 impl Default for LinterSettings {
@@ -477,10 +848,62 @@ impl Default for LinterSettings {
             rules: Some(biome_configuration::analyzer::linter::Rules::default()),
             ignored_files: Matcher::empty(),
             included_files: Matcher::empty(),
+            domains: RuleDomains::default(),
+            suppressions: SuppressionsConfiguration::default(),
         }
     }
 }
 
+/// Returns `Some(true)` if the domains of the rule enable it, `Some(false)` if they disable it,
+/// and [None] if they don't apply. The configuration of the rule takes precedence over its
+/// domains.
+pub fn is_rule_enabled_by_domains(
+    configured_domains: &RuleDomains,
+    rules: Option<&biome_configuration::analyzer::linter::Rules>,
+    group: &str,
+    rule_name: &str,
+    domains: &[RuleDomain],
+    manifest: Option<&PackageJson>,
+) -> Option<bool> {
+    let is_configured = rules.is_some_and(|rules| {
+        group
+            .parse()
+            .is_ok_and(|group| rules.get_rule_level(group, rule_name).is_some())
+    });
+    if is_configured {
+        return None;
+    }
+    is_domain_enabled(configured_domains, domains, manifest)
+}
+
+/// Returns `Some(true)` if the configured domains enable one of the given domains, `Some(false)`
+/// if they disable them, and [None] if none of them is configured.
+///
+/// A domain set to `auto` is enabled when one of its dependencies is listed in the
+/// `dependencies` or the `devDependencies` of the manifest.
+fn is_domain_enabled(
+    configured_domains: &RuleDomains,
+    domains: &[RuleDomain],
+    manifest: Option<&PackageJson>,
+) -> Option<bool> {
+    domains
+        .iter()
+        .filter_map(|domain| {
+            let enabled = match configured_domains.get(domain)? {
+                RuleDomainValue::All => true,
+                RuleDomainValue::None => false,
+                RuleDomainValue::Auto => manifest.is_some_and(|manifest| {
+                    domain.manifest_dependencies().iter().any(|dependency| {
+                        manifest.dependencies.contains(dependency)
+                            || manifest.dev_dependencies.contains(dependency)
+                    })
+                }),
+            };
+            Some(enabled)
+        })
+        .reduce(|enabled, other| enabled || other)
+}
+
 /// Linter settings for the entire workspace
 #[derive(Debug, Default)]
 pub struct OverrideLinterSettings {
@@ -489,6 +912,44 @@ pub struct OverrideLinterSettings {
 
     /// List of rules
     pub rules: Option<biome_configuration::analyzer::linter::Rules>,
+
+    /// The configuration of the rule domains
+    pub domains: Option<RuleDomains>,
+}
+
+/// Lint rules of a registry that belong to at least one domain
+#[derive(Debug, Default)]
+pub struct DomainRules(Vec<(RuleFilter<'static>, &'static [RuleDomain])>);
+
+impl DomainRules {
+    /// Whether one of the rules belongs to one of the configured domains
+    pub(crate) fn has_configured_domain(&self, configured_domains: &RuleDomains) -> bool {
+        self.0.iter().any(|(_, domains)| {
+            domains
+                .iter()
+                .any(|domain| configured_domains.contains_key(domain))
+        })
+    }
+}
+
+impl<L: Language> RegistryVisitor<L> for DomainRules {
+    fn record_category<C: GroupCategory<Language = L>>(&mut self) {
+        if C::CATEGORY == RuleCategory::Lint {
+            C::record_groups(self);
+        }
+    }
+
+    fn record_rule<R>(&mut self)
+    where
+        R: Rule<Options: Default, Query: Queryable<Language = L, Output: Clone>> + 'static,
+    {
+        if !R::METADATA.domains.is_empty() {
+            self.0.push((
+                RuleFilter::Rule(<R::Group as RuleGroup>::NAME, R::METADATA.name),
+                R::METADATA.domains,
+            ));
+        }
+    }
 }
 
 /// Linter settings for the entire workspace
@@ -670,6 +1131,34 @@ impl From<PartialGraphqlConfiguration> for LanguageSettings<GraphqlLanguage> {
     }
 }
 
+impl From<PartialHtmlConfiguration> for LanguageSettings<HtmlLanguage> {
+    fn from(html: PartialHtmlConfiguration) -> Self {
+        let mut language_setting: LanguageSettings<HtmlLanguage> = LanguageSettings::default();
+
+        if let Some(formatter) = html.formatter {
+            // TODO: change RHS to `formatter.enabled` when html formatting is enabled by default
+            language_setting.formatter.enabled = Some(formatter.enabled.unwrap_or_default());
+            language_setting.formatter.indent_width = formatter.indent_width;
+            language_setting.formatter.indent_style = formatter.indent_style.map(Into::into);
+            language_setting.formatter.line_width = formatter.line_width;
+            language_setting.formatter.line_ending = formatter.line_ending;
+            language_setting.formatter.attribute_position = formatter.attribute_position;
+            language_setting.formatter.whitespace_sensitivity = formatter.whitespace_sensitivity;
+            language_setting.formatter.indent_script_and_style =
+                formatter.indent_script_and_style.map(Into::into);
+            language_setting.formatter.self_close_void_elements =
+                formatter.self_close_void_elements;
+        }
+
+        if let Some(linter) = html.linter {
+            // TODO: change RHS to `linter.enabled` when html linting is enabled by default
+            language_setting.linter.enabled = Some(linter.enabled.unwrap_or_default());
+        }
+
+        language_setting
+    }
+}
+
 pub trait ServiceLanguage: biome_rowan::Language {
     /// Formatter settings type for this language
     type FormatterSettings: Default;
@@ -742,7 +1231,7 @@ pub struct FilesSettings {
     pub max_size: NonZeroU64,
 
     /// gitignore file patterns
-    pub git_ignore: Option<Gitignore>,
+    pub git_ignore: Option<VcsIgnoreFiles>,
 
     /// List of paths/files to matcher
     pub ignored_files: Matcher,
@@ -754,6 +1243,95 @@ pub struct FilesSettings {
     pub ignore_unknown: bool,
 }
 
+/// The ignore files of the VCS, from the lowest to the highest precedence
+#[derive(Debug)]
+pub struct VcsIgnoreFiles {
+    /// The ignore files of the root directory of the VCS, like `.git/info/exclude`, followed by
+    /// its `.gitignore` file, and the `.gitignore` files of its subdirectories
+    files: Vec<Gitignore>,
+    /// The index of the `.gitignore` file of the root directory
+    root_index: usize,
+}
+
+impl VcsIgnoreFiles {
+    pub(crate) fn new(root_ignore_file: Gitignore) -> Self {
+        Self {
+            files: vec![root_ignore_file],
+            root_index: 0,
+        }
+    }
+
+    /// The root directory of the VCS
+    fn root(&self) -> &Path {
+        self.files[self.root_index].path()
+    }
+
+    /// Inserts an ignore file: the ones of the root directory take precedence over the global
+    /// ignore files, the `.gitignore` file of the root directory takes precedence over them, and
+    /// the `.gitignore` files of the subdirectories take precedence over the ones of their parents.
+    ///
+    /// The `.gitignore` file of a subdirectory replaces the one inserted before for the same
+    /// directory.
+    pub(crate) fn insert(&mut self, ignore_file: Gitignore) {
+        if ignore_file.path() == self.root() {
+            self.files.insert(self.root_index, ignore_file);
+            self.root_index += 1;
+        } else if let Some(index) = self.files[self.root_index + 1..]
+            .iter()
+            .position(|file| file.path() == ignore_file.path())
+        {
+            self.files[self.root_index + 1 + index] = ignore_file;
+        } else {
+            let depth = ignore_file.path().components().count();
+            let index = self.files[self.root_index + 1..]
+                .iter()
+                .position(|file| file.path().components().count() > depth)
+                .map_or(self.files.len(), |index| self.root_index + 1 + index);
+            self.files.insert(index, ignore_file);
+        }
+    }
+
+    /// Whether the VCS ignores the path.
+    ///
+    /// Like Git, a path can't be included again by a negated pattern when one of its parent
+    /// directories is ignored, because Git doesn't look inside ignored directories.
+    pub fn is_ignored(&self, path: &Path, is_dir: bool) -> bool {
+        let root = self.root();
+        // The relative paths are relative to the root directory
+        let path = if path.has_root() {
+            Cow::Borrowed(path)
+        } else {
+            Cow::Owned(root.join(path))
+        };
+        // Paths outside the root directory of the VCS aren't ignored
+        if !path.starts_with(root) {
+            return false;
+        }
+        let mut directories: Vec<_> = path
+            .ancestors()
+            .skip(1)
+            .take_while(|directory| directory.starts_with(root) && *directory != root)
+            .collect();
+        directories.reverse();
+        directories
+            .into_iter()
+            .any(|directory| self.matched(directory, true).is_ignore())
+            || self.matched(&path, is_dir).is_ignore()
+    }
+
+    /// Returns the match of the ignore file with the highest precedence that applies to the
+    /// path, which is inside the root directory
+    fn matched(&self, path: &Path, is_dir: bool) -> Match<&Glob> {
+        self.files
+            .iter()
+            .rev()
+            .filter(|file| path.starts_with(file.path()) && path != file.path())
+            .map(|file| file.matched(path, is_dir))
+            .find(|matched| !matched.is_none())
+            .unwrap_or(Match::None)
+    }
+}
+
 /// Limit the size of files to 1.0 MiB by default
 pub(crate) const DEFAULT_FILE_SIZE_LIMIT: NonZeroU64 =
     // SAFETY: This constant is initialized with a non-zero value
@@ -785,7 +1363,10 @@ fn to_file_settings(
         None
     };
     let git_ignore = if let Some(vcs_config_path) = vcs_config_path {
-        Some(to_git_ignore(vcs_config_path, gitignore_matches)?)
+        Some(VcsIgnoreFiles::new(to_git_ignore(
+            vcs_config_path,
+            gitignore_matches,
+        )?))
     } else {
         None
     };
@@ -807,17 +1388,124 @@ fn to_file_settings(
 #[derive(Debug)]
 pub struct WorkspaceSettingsHandle<'a> {
     inner: RwLockReadGuard<'a, WorkspaceSettings>,
+    /// The path of the file the settings are retrieved for, when they depend on a nested configuration
+    path: Option<PathBuf>,
 }
 
 impl<'a> WorkspaceSettingsHandle<'a> {
     pub(crate) fn new(settings: &'a RwLock<WorkspaceSettings>) -> Self {
         Self {
             inner: settings.read().unwrap(),
+            path: None,
+        }
+    }
+
+    /// Creates a handle whose settings are the ones that apply to the file at the given path
+    pub(crate) fn for_path(settings: &'a RwLock<WorkspaceSettings>, path: &Path) -> Self {
+        Self {
+            inner: settings.read().unwrap(),
+            path: Some(path.to_path_buf()),
         }
     }
 
     pub(crate) fn settings(&self) -> Option<&Settings> {
-        self.inner.get_current_settings()
+        match &self.path {
+            Some(path) => self.inner.get_settings_for_path(path),
+            None => self.inner.get_current_settings(),
+        }
+    }
+}
+
+/// Resolves the formatter options of the language of the file from the given settings
+fn resolve_format_options<L>(
+    settings: &Settings,
+    path: &BiomePath,
+    file_source: &DocumentFileSource,
+) -> L::FormatOptions
+where
+    L: ServiceLanguage,
+{
+    L::resolve_format_options(
+        Some(&settings.formatter),
+        Some(&settings.override_settings),
+        Some(&L::lookup_settings(&settings.languages).formatter),
+        path,
+        file_source,
+    )
+}
+
+/// The values of the formatter options that all the languages have, serialized as JSON
+fn common_format_option_values(options: &impl FormatOptions) -> Vec<(&'static str, String)> {
+    vec![
+        ("indentStyle", to_json(options.indent_style())),
+        ("indentWidth", to_json(options.indent_width())),
+        ("lineEnding", to_json(options.line_ending())),
+        ("lineWidth", to_json(options.line_width())),
+    ]
+}
+
+fn to_json(value: impl serde::Serialize) -> String {
+    serde_json::to_string(&value).unwrap_or_default()
+}
+
+/// The configurations a formatter option can be set in, serialized as JSON, used to find where
+/// the value of the option comes from
+struct FormatterOptionLayers {
+    editorconfig: serde_json::Value,
+    configuration: serde_json::Value,
+    command_line: serde_json::Value,
+    /// The overrides that match the file
+    overrides: Vec<serde_json::Value>,
+}
+
+impl FormatterOptionLayers {
+    fn new(
+        configuration: &PartialConfiguration,
+        override_indexes: &[usize],
+        editorconfig: Option<&PartialConfiguration>,
+        command_line: Option<&PartialConfiguration>,
+    ) -> Self {
+        let overrides = configuration
+            .overrides
+            .as_ref()
+            .map(|overrides| {
+                override_indexes
+                    .iter()
+                    .filter_map(|index| overrides.0.get(*index))
+                    .map(|pattern| serde_json::to_value(pattern).unwrap_or_default())
+                    .collect()
+            })
+            .unwrap_or_default();
+        Self {
+            editorconfig: serde_json::to_value(editorconfig).unwrap_or_default(),
+            configuration: serde_json::to_value(configuration).unwrap_or_default(),
+            command_line: serde_json::to_value(command_line).unwrap_or_default(),
+            overrides,
+        }
+    }
+
+    /// Returns the source with the highest precedence that sets the option, either in the
+    /// formatter section of the language or in the one of all the languages
+    fn source(&self, language: &str, name: &str) -> ResolvedSettingSource {
+        let sets_option = |configuration: &serde_json::Value| {
+            [
+                &configuration["formatter"][name],
+                &configuration[language]["formatter"][name],
+            ]
+            .into_iter()
+            .any(|value| !value.is_null())
+        };
+        if self.overrides.iter().any(sets_option) {
+            ResolvedSettingSource::Override
+        } else if sets_option(&self.command_line) {
+            ResolvedSettingSource::CommandLine
+        } else if sets_option(&self.configuration) {
+            ResolvedSettingSource::Configuration
+        } else if sets_option(&self.editorconfig) {
+            ResolvedSettingSource::EditorConfig
+        } else {
+            ResolvedSettingSource::Default
+        }
     }
 }
 
@@ -837,7 +1525,7 @@ impl<'a> WorkspaceSettingsHandle<'a> {
     where
         L: ServiceLanguage,
     {
-        let settings = self.inner.get_current_settings();
+        let settings = self.inner.get_settings_for_path(path);
         let formatter = settings.map(|s| &s.formatter);
         let overrides = settings.map(|s| &s.override_settings);
         let editor_settings = settings
@@ -846,6 +1534,148 @@ impl<'a> WorkspaceSettingsHandle<'a> {
         L::resolve_format_options(formatter, overrides, editor_settings, path, file_source)
     }
 
+    /// Resolves the settings that apply to the file at the given path.
+    ///
+    /// The configurations of the `.editorconfig` file and of the command line are applied
+    /// around the configuration files, so that the source of each formatter option is known.
+    pub(crate) fn resolved_settings(
+        &self,
+        path: &BiomePath,
+        file_source: &DocumentFileSource,
+        editorconfig: Option<PartialConfiguration>,
+        command_line: Option<PartialConfiguration>,
+    ) -> Result<ResolvedSettings, WorkspaceError> {
+        let Some((directory, configuration)) = self.inner.get_configuration_for_path(path) else {
+            return Ok(ResolvedSettings::default());
+        };
+        let mut layered_configuration = editorconfig.clone().unwrap_or_default();
+        layered_configuration.merge_with(configuration.clone());
+        layered_configuration.merge_with(command_line.clone().unwrap_or_default());
+        let mut settings = Settings::default();
+        settings.merge_with_configuration(
+            layered_configuration,
+            Some(directory.to_path_buf()),
+            None,
+            &[],
+        )?;
+
+        let options = match file_source {
+            DocumentFileSource::Js(_) => {
+                let options = resolve_format_options::<JsLanguage>(&settings, path, file_source);
+                let mut values = common_format_option_values(&options);
+                values.extend([
+                    ("quoteStyle", to_json(options.quote_style())),
+                    ("jsxQuoteStyle", to_json(options.jsx_quote_style())),
+                    ("quoteProperties", to_json(options.quote_properties())),
+                    ("trailingCommas", to_json(options.trailing_commas())),
+                    ("semicolons", to_json(options.semicolons())),
+                    ("arrowParentheses", to_json(options.arrow_parentheses())),
+                    ("bracketSpacing", to_json(options.bracket_spacing())),
+                    ("bracketSameLine", to_json(options.bracket_same_line())),
+                    ("attributePosition", to_json(options.attribute_position())),
+                ]);
+                Some(("javascript", values))
+            }
+            DocumentFileSource::Json(_) => {
+                let options = resolve_format_options::<JsonLanguage>(&settings, path, file_source);
+                let mut values = common_format_option_values(&options);
+                values.push(("trailingCommas", to_json(options.trailing_commas())));
+                Some(("json", values))
+            }
+            DocumentFileSource::Css(_) => {
+                let options = resolve_format_options::<CssLanguage>(&settings, path, file_source);
+                let mut values = common_format_option_values(&options);
+                values.push(("quoteStyle", to_json(options.quote_style())));
+                Some(("css", values))
+            }
+            DocumentFileSource::Graphql(_) => {
+                let options =
+                    resolve_format_options::<GraphqlLanguage>(&settings, path, file_source);
+                let mut values = common_format_option_values(&options);
+                values.extend([
+                    ("bracketSpacing", to_json(options.bracket_spacing())),
+                    ("quoteStyle", to_json(options.quote_style())),
+                ]);
+                Some(("graphql", values))
+            }
+            DocumentFileSource::Html(_) => {
+                let options = resolve_format_options::<HtmlLanguage>(&settings, path, file_source);
+                let mut values = common_format_option_values(&options);
+                values.extend([
+                    ("attributePosition", to_json(options.attribute_position())),
+                    (
+                        "whitespaceSensitivity",
+                        to_json(options.whitespace_sensitivity()),
+                    ),
+                    (
+                        "indentScriptAndStyle",
+                        to_json(options.indent_script_and_style()),
+                    ),
+                    (
+                        "selfCloseVoidElements",
+                        to_json(options.self_close_void_elements()),
+                    ),
+                ]);
+                Some(("html", values))
+            }
+            DocumentFileSource::Grit(_) => {
+                let options = resolve_format_options::<GritLanguage>(&settings, path, file_source);
+                Some(("grit", common_format_option_values(&options)))
+            }
+            DocumentFileSource::Unknown => None,
+        };
+        let formatter_options = options
+            .map(|(language, values)| {
+                let layers = FormatterOptionLayers::new(
+                    configuration,
+                    &settings.override_settings.matching_indexes(path),
+                    editorconfig.as_ref(),
+                    command_line.as_ref(),
+                );
+                values
+                    .into_iter()
+                    .map(|(name, value)| ResolvedFormatterOption {
+                        name: name.to_string(),
+                        value,
+                        source: layers.source(language, name),
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        let metadata = match file_source {
+            DocumentFileSource::Js(_) => Some(biome_js_analyze::METADATA.deref()),
+            DocumentFileSource::Json(_) => Some(biome_json_analyze::METADATA.deref()),
+            DocumentFileSource::Css(_) => Some(biome_css_analyze::METADATA.deref()),
+            DocumentFileSource::Graphql(_) => Some(biome_graphql_analyze::METADATA.deref()),
+            _ => None,
+        };
+        let mut domain_rules = DomainRules::default();
+        match file_source {
+            DocumentFileSource::Js(_) => biome_js_analyze::visit_registry(&mut domain_rules),
+            DocumentFileSource::Json(_) => biome_json_analyze::visit_registry(&mut domain_rules),
+            DocumentFileSource::Css(_) => biome_css_analyze::visit_registry(&mut domain_rules),
+            DocumentFileSource::Graphql(_) => {
+                biome_graphql_analyze::visit_registry(&mut domain_rules)
+            }
+            _ => {}
+        }
+        let manifest = self.inner.get_current_manifest();
+
+        Ok(ResolvedSettings {
+            nested_configuration_directory: self
+                .inner
+                .get_nested_configuration_directory(path)
+                .map(Path::to_path_buf),
+            overrides: settings.override_settings.matching_indexes(path),
+            formatter_options,
+            rules: metadata
+                .map(|metadata| {
+                    settings.as_resolved_linter_rules(path, metadata, &domain_rules, manifest)
+                })
+                .unwrap_or_default(),
+        })
+    }
+
     pub(crate) fn analyzer_options<L>(
         &self,
         path: &BiomePath,
@@ -855,7 +1685,7 @@ impl<'a> WorkspaceSettingsHandle<'a> {
     where
         L: ServiceLanguage,
     {
-        let settings = self.inner.get_current_settings();
+        let settings = self.inner.get_settings_for_path(path);
         let linter = settings.map(|s| &s.linter);
         let overrides = settings.map(|s| &s.override_settings);
         let editor_settings = settings
@@ -906,6 +1736,18 @@ impl OverrideSettings {
         }
         None
     }
+    /// Returns the indexes of the overrides that apply to the provided `path`
+    pub fn matching_indexes(&self, path: &Path) -> Vec<usize> {
+        self.patterns
+            .iter()
+            .enumerate()
+            .filter(|(_, pattern)| {
+                pattern.include.matches_path(path) && !pattern.exclude.matches_path(path)
+            })
+            .map(|(index, _)| index)
+            .collect()
+    }
+
     /// Checks whether at least one override include the provided `path`
     pub fn is_path_included(&self, path: &Path) -> Option<bool> {
         for pattern in &self.patterns {
@@ -1411,6 +2253,21 @@ impl OverrideSettingPattern {
         if let Some(line_width) = html_formatter.line_width.or(formatter.line_width) {
             options.set_line_width(line_width);
         }
+        if let Some(attribute_position) = html_formatter
+            .attribute_position
+            .or(formatter.attribute_position)
+        {
+            options.set_attribute_position(attribute_position);
+        }
+        if let Some(whitespace_sensitivity) = html_formatter.whitespace_sensitivity {
+            options.set_whitespace_sensitivity(whitespace_sensitivity);
+        }
+        if let Some(indent_script_and_style) = html_formatter.indent_script_and_style {
+            options.set_indent_script_and_style(indent_script_and_style);
+        }
+        if let Some(self_close_void_elements) = html_formatter.self_close_void_elements {
+            options.set_self_close_void_elements(self_close_void_elements);
+        }
 
         if let Ok(mut writeonly_cache) = self.cached_html_format_options.write() {
             let options = options.clone();
@@ -1503,7 +2360,10 @@ pub fn to_matcher(
     Ok(matcher)
 }
 
-fn to_git_ignore(path: PathBuf, matches: &[String]) -> Result<Gitignore, WorkspaceError> {
+pub(crate) fn to_git_ignore(
+    path: PathBuf,
+    matches: &[String],
+) -> Result<Gitignore, WorkspaceError> {
     let mut gitignore_builder = GitignoreBuilder::new(path.clone());
 
     for the_match in matches {
@@ -1550,6 +2410,47 @@ impl TryFrom<OverrideOrganizeImportsConfiguration> for OrganizeImportsSettings {
     }
 }
 
+/// Returns an override applying the formatter, linter and language settings of `configuration`
+/// to all the files
+fn as_override_pattern(configuration: &PartialConfiguration) -> OverridePattern {
+    OverridePattern {
+        ignore: None,
+        include: Some(StringSet::from_iter(["**".to_string()])),
+        javascript: configuration.javascript.clone(),
+        json: configuration.json.clone(),
+        css: configuration.css.clone(),
+        graphql: configuration.graphql.clone(),
+        html: configuration.html.clone(),
+        formatter: configuration.formatter.as_ref().map(|formatter| {
+            OverrideFormatterConfiguration {
+                enabled: formatter.enabled,
+                format_with_errors: formatter.format_with_errors,
+                indent_style: formatter.indent_style,
+                indent_size: formatter.indent_size,
+                indent_width: formatter.indent_width,
+                line_ending: formatter.line_ending,
+                line_width: formatter.line_width,
+                attribute_position: formatter.attribute_position,
+                bracket_spacing: formatter.bracket_spacing,
+            }
+        }),
+        linter: configuration
+            .linter
+            .as_ref()
+            .map(|linter| OverrideLinterConfiguration {
+                enabled: linter.enabled,
+                rules: linter.rules.clone(),
+                domains: linter.domains.clone(),
+            }),
+        organize_imports: configuration
+            .organize_imports
+            .as_ref()
+            .map(|organize_imports| OverrideOrganizeImportsConfiguration {
+                enabled: organize_imports.enabled,
+            }),
+    }
+}
+
 pub fn to_override_settings(
     working_directory: Option<PathBuf>,
     overrides: Overrides,
@@ -1577,6 +2478,7 @@ pub fn to_override_settings(
             .map(|linter| OverrideLinterSettings {
                 enabled: linter.enabled,
                 rules: linter.rules,
+                domains: linter.domains,
             })
             .unwrap_or_default();
         let organize_imports = OverrideOrganizeImportsSettings {
@@ -1590,6 +2492,7 @@ pub fn to_override_settings(
         let json = pattern.json.take().unwrap_or_default();
         let css = pattern.css.take().unwrap_or_default();
         let graphql = pattern.graphql.take().unwrap_or_default();
+        let html = pattern.html.take().unwrap_or_default();
         languages.javascript =
             to_javascript_language_settings(javascript, &current_settings.languages.javascript);
 
@@ -1597,6 +2500,7 @@ pub fn to_override_settings(
         languages.css = to_css_language_settings(css, &current_settings.languages.css);
         languages.graphql =
             to_graphql_language_settings(graphql, &current_settings.languages.graphql);
+        languages.html = to_html_language_settings(html, &current_settings.languages.html);
 
         let pattern_setting = OverrideSettingPattern {
             include: to_matcher(working_directory.clone(), pattern.include.as_ref())?,
@@ -1726,6 +2630,39 @@ fn to_graphql_language_settings(
     language_setting
 }
 
+fn to_html_language_settings(
+    mut conf: PartialHtmlConfiguration,
+    parent_settings: &LanguageSettings<HtmlLanguage>,
+) -> LanguageSettings<HtmlLanguage> {
+    let mut language_setting: LanguageSettings<HtmlLanguage> = LanguageSettings::default();
+    let formatter = conf.formatter.take().unwrap_or_default();
+
+    language_setting.formatter.enabled = formatter.enabled;
+    language_setting.formatter.line_width = formatter.line_width;
+    language_setting.formatter.line_ending = formatter.line_ending;
+    language_setting.formatter.indent_width = formatter.indent_width.map(Into::into);
+    language_setting.formatter.indent_style = formatter.indent_style.map(Into::into);
+    language_setting.formatter.attribute_position = formatter.attribute_position;
+
+    // The options that only exist for HTML files don't have a global fallback
+    let parent_formatter = &parent_settings.formatter;
+    language_setting.formatter.whitespace_sensitivity = formatter
+        .whitespace_sensitivity
+        .or(parent_formatter.whitespace_sensitivity);
+    language_setting.formatter.indent_script_and_style = formatter
+        .indent_script_and_style
+        .map(Into::into)
+        .or(parent_formatter.indent_script_and_style);
+    language_setting.formatter.self_close_void_elements = formatter
+        .self_close_void_elements
+        .or(parent_formatter.self_close_void_elements);
+
+    let linter = conf.linter.take().unwrap_or_default();
+    language_setting.linter.enabled = linter.enabled;
+
+    language_setting
+}
+
 pub fn to_format_settings(
     working_directory: Option<PathBuf>,
     conf: FormatterConfiguration,
@@ -1786,6 +2723,8 @@ pub fn to_linter_settings(
         rules: Some(conf.rules),
         ignored_files: to_matcher(working_directory.clone(), Some(&conf.ignore))?,
         included_files: to_matcher(working_directory.clone(), Some(&conf.include))?,
+        domains: conf.domains,
+        suppressions: conf.suppressions,
     })
 }
 
@@ -1798,6 +2737,8 @@ impl TryFrom<OverrideLinterConfiguration> for LinterSettings {
             rules: conf.rules,
             ignored_files: Matcher::empty(),
             included_files: Matcher::empty(),
+            domains: RuleDomains::default(),
+            suppressions: SuppressionsConfiguration::default(),
         })
     }
 }
@@ -1826,3 +2767,29 @@ impl TryFrom<OverrideAssistsConfiguration> for AssistsSettings {
         })
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::env;
+
+    #[test]
+    fn vcs_ignore_files_match_relative_paths() {
+        let root = env::current_dir().unwrap();
+        let mut ignore_files =
+            VcsIgnoreFiles::new(to_git_ignore(root.clone(), &["*.generated.js".into()]).unwrap());
+        ignore_files.insert(
+            to_git_ignore(
+                root.join("packages/a"),
+                &["ignored.js".into(), "!kept.generated.js".into()],
+            )
+            .unwrap(),
+        );
+
+        assert!(ignore_files.is_ignored(Path::new("packages/a/ignored.js"), false));
+        assert!(ignore_files.is_ignored(&root.join("packages/a/ignored.js"), false));
+        assert!(!ignore_files.is_ignored(Path::new("packages/a/kept.generated.js"), false));
+        assert!(ignore_files.is_ignored(Path::new("packages/b/other.generated.js"), false));
+        assert!(!ignore_files.is_ignored(Path::new("packages/b/ignored.js"), false));
+    }
+}
@@ -58,13 +58,14 @@ use crate::settings::Settings;
 use crate::{Deserialize, Serialize, WorkspaceError};
 use biome_analyze::ActionCategory;
 pub use biome_analyze::RuleCategories;
-use biome_configuration::analyzer::RuleSelector;
+use biome_configuration::analyzer::{RulePlainConfiguration, RuleSelector};
 use biome_configuration::PartialConfiguration;
 use biome_console::{markup, Markup, MarkupBuf};
 use biome_diagnostics::CodeSuggestion;
 use biome_formatter::Printed;
 use biome_fs::BiomePath;
 use biome_js_syntax::{TextRange, TextSize};
+use biome_project::ModuleGraph;
 use biome_text_edit::TextEdit;
 use core::str;
 use enumflags2::{bitflags, BitFlags};
@@ -186,6 +187,8 @@ impl FileFeaturesResult {
                 !settings.formatter().enabled || settings.json_formatter_disabled()
             } else if file_source.is_css_like() {
                 !settings.formatter().enabled || settings.css_formatter_disabled()
+            } else if file_source.is_html_like() {
+                !settings.formatter().enabled || settings.html_formatter_disabled()
             } else {
                 !settings.formatter().enabled
             };
@@ -203,6 +206,8 @@ impl FileFeaturesResult {
                 !settings.linter().enabled || settings.json_linter_disabled()
             } else if file_source.is_css_like() {
                 !settings.linter().enabled || settings.css_linter_disabled()
+            } else if file_source.is_html_like() {
+                !settings.linter().enabled || settings.html_linter_disabled()
             } else {
                 !settings.linter().enabled
             }
@@ -512,6 +517,40 @@ pub struct UpdateSettingsParams {
     // @ematipico TODO: have a better data structure for this
     pub gitignore_matches: Vec<String>,
     pub workspace_directory: Option<PathBuf>,
+    /// Whether the configuration comes from a configuration file of a subdirectory of the project.
+    /// The settings then only apply to the files inside `workspace_directory`.
+    #[serde(default)]
+    pub nested: bool,
+    /// The other ignore files of the VCS, from the lowest to the highest precedence.
+    /// `gitignore_matches` take precedence over the ones of `vcs_base_path`, and the ones of its
+    /// subdirectories, also registered with [Workspace::register_vcs_ignore_files], take
+    /// precedence over `gitignore_matches`.
+    #[serde(default)]
+    pub ignore_files: Vec<VcsIgnoreFile>,
+    /// The GritQL files listed in the `plugins` field of the configuration
+    #[serde(default)]
+    pub plugins: Vec<PluginFile>,
+}
+
+/// The patterns of an ignore file of the VCS, e.g. the `.gitignore` file of a subdirectory
+#[derive(Debug, Clone, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct VcsIgnoreFile {
+    /// The directory the patterns are relative to
+    pub directory: PathBuf,
+    pub patterns: Vec<String>,
+}
+
+/// A GritQL file listed in the `plugins` field of the configuration
+#[derive(Debug, Clone, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct PluginFile {
+    /// The path of the file, used to name the plugin and report its errors
+    pub path: PathBuf,
+    pub content: String,
+    /// The severity of the diagnostics of the plugin, when it's configured
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub severity: Option<RulePlainConfiguration>,
 }
 
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
@@ -550,6 +589,37 @@ impl From<(BiomePath, String)> for SetManifestForProjectParams {
     }
 }
 
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct RegisterVcsIgnoreFilesParams {
+    /// The `.gitignore` files of the directories reached while traversing the project
+    pub ignore_files: Vec<VcsIgnoreFile>,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateModuleGraphParams {
+    /// The modules to add to the module graph of the current project
+    pub module_graph: ModuleGraph,
+    /// The paths of the modules to remove from the module graph of the current project, e.g.
+    /// because they changed on disk or they aren't imported anymore
+    #[serde(default)]
+    pub removed_paths: Vec<PathBuf>,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct GetModuleGraphPathsParams {}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct GetModuleGraphPathsResult {
+    /// The paths of the modules of the module graph of the current project
+    pub paths: Vec<PathBuf>,
+}
+
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct GetSyntaxTreeParams {
@@ -576,6 +646,80 @@ pub struct GetFormatterIRParams {
     pub path: BiomePath,
 }
 
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct GetResolvedSettingsParams {
+    pub path: BiomePath,
+    /// The configuration read from the `.editorconfig` file, which the configuration files take
+    /// precedence over. It shouldn't be merged in the configuration of the workspace.
+    #[serde(default)]
+    pub editorconfig: Option<PartialConfiguration>,
+    /// The configuration passed as arguments of the command line, which takes precedence over
+    /// the configuration files. It shouldn't be merged in the configuration of the workspace.
+    #[serde(default)]
+    pub command_line: Option<PartialConfiguration>,
+}
+
+/// The settings Biome uses for a file, once the configuration files, the nested
+/// configurations and the overrides are resolved
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct ResolvedSettings {
+    /// The directory of the nested configuration file that applies to the file, if any
+    pub nested_configuration_directory: Option<PathBuf>,
+    /// The indexes of the overrides that match the file
+    pub overrides: Vec<usize>,
+    /// The formatter options of the language of the file, empty if Biome can't format it
+    pub formatter_options: Vec<ResolvedFormatterOption>,
+    /// The lint rules enabled for the file, sorted by name
+    pub rules: Vec<ResolvedRule>,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct ResolvedRule {
+    /// The name of the rule, e.g. `style/useConst`
+    pub name: String,
+    /// The severity of the diagnostics emitted by the rule
+    pub severity: biome_diagnostics::Severity,
+    /// The options of the rule serialized as JSON, if they are configured
+    pub options: Option<String>,
+    /// Where the configuration of the rule comes from
+    pub source: ResolvedSettingSource,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct ResolvedFormatterOption {
+    /// The name of the option in the configuration, e.g. `indentWidth`
+    pub name: String,
+    /// The value of the option serialized as JSON
+    pub value: String,
+    /// Where the value of the option comes from
+    pub source: ResolvedSettingSource,
+}
+
+/// Where a setting comes from, from the lowest to the highest precedence
+#[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum ResolvedSettingSource {
+    /// The default value of Biome
+    Default,
+    /// The `.editorconfig` file
+    EditorConfig,
+    /// The configuration file
+    Configuration,
+    /// The arguments of the command line
+    CommandLine,
+    /// An override of the configuration file
+    Override,
+}
+
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct GetFileContentParams {
@@ -604,6 +748,9 @@ pub struct PullDiagnosticsParams {
     pub max_diagnostics: u64,
     pub only: Vec<RuleSelector>,
     pub skip: Vec<RuleSelector>,
+    /// Whether the time spent in each rule is measured and returned in the result
+    #[serde(default)]
+    pub profile_rules: bool,
 }
 
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
@@ -612,6 +759,22 @@ pub struct PullDiagnosticsResult {
     pub diagnostics: Vec<biome_diagnostics::serde::Diagnostic>,
     pub errors: usize,
     pub skipped_diagnostics: u64,
+    /// The time spent in each rule, when `profile_rules` is set
+    #[serde(default)]
+    pub rule_durations: Vec<RuleDurations>,
+}
+
+/// The time spent in a rule while processing a file
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct RuleDurations {
+    /// The group and the name of the rule, e.g. `suspicious/noDebugger`
+    pub rule: String,
+    /// The time spent checking each node matched by the rule, in nanoseconds
+    pub run: Vec<u64>,
+    /// The time spent computing each code action of the rule, in nanoseconds
+    pub action: Vec<u64>,
 }
 
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
@@ -680,6 +843,9 @@ pub struct FixFileParams {
     pub skip: Vec<RuleSelector>,
     pub rule_categories: RuleCategories,
     pub suppression_reason: Option<String>,
+    /// Whether the time spent in each rule is measured and returned in the result
+    #[serde(default)]
+    pub profile_rules: bool,
 }
 
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
@@ -695,6 +861,10 @@ pub struct FixFileResult {
 
     /// number of skipped suggested fixes
     pub skipped_suggested_fixes: u32,
+
+    /// The time spent in each rule, when `profile_rules` is set
+    #[serde(default)]
+    pub rule_durations: Vec<RuleDurations>,
 }
 
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
@@ -809,6 +979,9 @@ pub struct SearchPatternParams {
 pub struct SearchResults {
     pub file: BiomePath,
     pub matches: Vec<TextRange>,
+    /// The content of the file once the rewrites of the pattern are applied,
+    /// if the pattern rewrites it.
+    pub rewritten: Option<String>,
 }
 
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
@@ -897,6 +1070,23 @@ pub trait Workspace: Send + Sync + RefUnwindSafe {
         params: SetManifestForProjectParams,
     ) -> Result<(), WorkspaceError>;
 
+    /// Add the ignore files of the VCS found while traversing the project to the settings of the
+    /// current project
+    fn register_vcs_ignore_files(
+        &self,
+        params: RegisterVcsIgnoreFilesParams,
+    ) -> Result<(), WorkspaceError>;
+
+    /// Add the modules of the project, used by the rules of the project phase, to the module
+    /// graph of the current project
+    fn update_module_graph(&self, params: UpdateModuleGraphParams) -> Result<(), WorkspaceError>;
+
+    /// Return the paths of the modules of the module graph of the current project
+    fn get_module_graph_paths(
+        &self,
+        params: GetModuleGraphPathsParams,
+    ) -> Result<GetModuleGraphPathsResult, WorkspaceError>;
+
     /// Register a possible workspace project folder. Returns the key of said project. Use this key when you want to switch to different projects.
     fn register_project_folder(
         &self,
@@ -924,6 +1114,12 @@ pub trait Workspace: Send + Sync + RefUnwindSafe {
     // Return a textual, debug representation of the formatter IR for a given document
     fn get_formatter_ir(&self, params: GetFormatterIRParams) -> Result<String, WorkspaceError>;
 
+    /// Return the settings that Biome uses for a given path
+    fn get_resolved_settings(
+        &self,
+        params: GetResolvedSettingsParams,
+    ) -> Result<ResolvedSettings, WorkspaceError>;
+
     /// Return the content of a file
     fn get_file_content(&self, params: GetFileContentParams) -> Result<String, WorkspaceError>;
 
@@ -1011,13 +1207,40 @@ where
 pub struct FileGuard<'app, W: Workspace + ?Sized> {
     workspace: &'app W,
     path: BiomePath,
+    /// Whether the file stays open in the workspace when the guard is dropped
+    keep_open: bool,
 }
 
 impl<'app, W: Workspace + ?Sized> FileGuard<'app, W> {
     pub fn open(workspace: &'app W, params: OpenFileParams) -> Result<Self, WorkspaceError> {
         let path = params.path.clone();
         workspace.open_file(params)?;
-        Ok(Self { workspace, path })
+        Ok(Self {
+            workspace,
+            path,
+            keep_open: false,
+        })
+    }
+
+    /// Like [FileGuard::open], but the file stays open in the workspace when the guard
+    /// is dropped. A file that is still open with the same content isn't opened again,
+    /// so that its syntax tree is reused.
+    pub fn open_persistent(
+        workspace: &'app W,
+        params: OpenFileParams,
+    ) -> Result<Self, WorkspaceError> {
+        let path = params.path.clone();
+        let is_unchanged = workspace
+            .get_file_content(GetFileContentParams { path: path.clone() })
+            .is_ok_and(|content| content == params.content);
+        if !is_unchanged {
+            workspace.open_file(params)?;
+        }
+        Ok(Self {
+            workspace,
+            path,
+            keep_open: true,
+        })
     }
 
     pub fn get_syntax_tree(&self) -> Result<GetSyntaxTreeResult, WorkspaceError> {
@@ -1054,6 +1277,7 @@ impl<'app, W: Workspace + ?Sized> FileGuard<'app, W> {
         max_diagnostics: u32,
         only: Vec<RuleSelector>,
         skip: Vec<RuleSelector>,
+        profile_rules: bool,
     ) -> Result<PullDiagnosticsResult, WorkspaceError> {
         self.workspace.pull_diagnostics(PullDiagnosticsParams {
             path: self.path.clone(),
@@ -1061,6 +1285,7 @@ impl<'app, W: Workspace + ?Sized> FileGuard<'app, W> {
             max_diagnostics: max_diagnostics.into(),
             only,
             skip,
+            profile_rules,
         })
     }
 
@@ -1100,6 +1325,7 @@ impl<'app, W: Workspace + ?Sized> FileGuard<'app, W> {
         })
     }
 
+    #[expect(clippy::too_many_arguments)]
     pub fn fix_file(
         &self,
         fix_file_mode: FixFileMode,
@@ -1108,6 +1334,7 @@ impl<'app, W: Workspace + ?Sized> FileGuard<'app, W> {
         only: Vec<RuleSelector>,
         skip: Vec<RuleSelector>,
         suppression_reason: Option<String>,
+        profile_rules: bool,
     ) -> Result<FixFileResult, WorkspaceError> {
         self.workspace.fix_file(FixFileParams {
             path: self.path.clone(),
@@ -1117,6 +1344,7 @@ impl<'app, W: Workspace + ?Sized> FileGuard<'app, W> {
             skip,
             rule_categories,
             suppression_reason,
+            profile_rules,
         })
     }
 
@@ -1136,6 +1364,9 @@ impl<'app, W: Workspace + ?Sized> FileGuard<'app, W> {
 
 impl<'app, W: Workspace + ?Sized> Drop for FileGuard<'app, W> {
     fn drop(&mut self) {
+        if self.keep_open {
+            return;
+        }
         self.workspace
             .close_file(CloseFileParams {
                 path: self.path.clone(),
@@ -1,7 +1,9 @@
 use crate::workspace::{
-    FileFeaturesResult, GetFileContentParams, IsPathIgnoredParams, OrganizeImportsParams,
-    OrganizeImportsResult, ProjectKey, RageParams, RageResult, RegisterProjectFolderParams,
-    ServerInfo, SetManifestForProjectParams, UnregisterProjectFolderParams,
+    FileFeaturesResult, GetFileContentParams, GetModuleGraphPathsParams, GetModuleGraphPathsResult,
+    GetResolvedSettingsParams, IsPathIgnoredParams, OrganizeImportsParams, OrganizeImportsResult,
+    ProjectKey, RageParams, RageResult, RegisterProjectFolderParams, RegisterVcsIgnoreFilesParams,
+    ResolvedSettings, ServerInfo, SetManifestForProjectParams, UnregisterProjectFolderParams,
+    UpdateModuleGraphParams,
 };
 use crate::{TransportError, Workspace, WorkspaceError};
 use biome_formatter::Printed;
@@ -125,6 +127,24 @@ where
         self.request("biome/set_manifest_for_project", params)
     }
 
+    fn register_vcs_ignore_files(
+        &self,
+        params: RegisterVcsIgnoreFilesParams,
+    ) -> Result<(), WorkspaceError> {
+        self.request("biome/register_vcs_ignore_files", params)
+    }
+
+    fn update_module_graph(&self, params: UpdateModuleGraphParams) -> Result<(), WorkspaceError> {
+        self.request("biome/update_module_graph", params)
+    }
+
+    fn get_module_graph_paths(
+        &self,
+        params: GetModuleGraphPathsParams,
+    ) -> Result<GetModuleGraphPathsResult, WorkspaceError> {
+        self.request("biome/get_module_graph_paths", params)
+    }
+
     fn register_project_folder(
         &self,
         params: RegisterProjectFolderParams,
@@ -157,6 +177,13 @@ where
         self.request("biome/get_formatter_ir", params)
     }
 
+    fn get_resolved_settings(
+        &self,
+        params: GetResolvedSettingsParams,
+    ) -> Result<ResolvedSettings, WorkspaceError> {
+        self.request("biome/get_resolved_settings", params)
+    }
+
     fn get_file_content(&self, params: GetFileContentParams) -> Result<String, WorkspaceError> {
         self.request("biome/get_file_content", params)
     }
@@ -1,11 +1,12 @@
 use super::{
     ChangeFileParams, CloseFileParams, FeatureKind, FeatureName, FixFileResult, FormatFileParams,
     FormatOnTypeParams, FormatRangeParams, GetControlFlowGraphParams, GetFormatterIRParams,
-    GetSyntaxTreeParams, GetSyntaxTreeResult, OpenFileParams, ParsePatternParams,
-    ParsePatternResult, PatternId, ProjectKey, PullActionsParams, PullActionsResult,
-    PullDiagnosticsParams, PullDiagnosticsResult, RegisterProjectFolderParams, RenameResult,
+    GetModuleGraphPathsParams, GetModuleGraphPathsResult, GetSyntaxTreeParams, GetSyntaxTreeResult,
+    OpenFileParams, ParsePatternParams, ParsePatternResult, PatternId, ProjectKey,
+    PullActionsParams, PullActionsResult, PullDiagnosticsParams, PullDiagnosticsResult,
+    RegisterProjectFolderParams, RegisterVcsIgnoreFilesParams, RenameResult, RuleDurations,
     SearchPatternParams, SearchResults, SetManifestForProjectParams, SupportsFeatureParams,
-    UnregisterProjectFolderParams, UpdateSettingsParams,
+    UnregisterProjectFolderParams, UpdateModuleGraphParams, UpdateSettingsParams,
 };
 use crate::diagnostics::{InvalidPattern, SearchError};
 use crate::file_handlers::{
@@ -13,12 +14,14 @@ use crate::file_handlers::{
 };
 use crate::settings::{WorkspaceSettings, WorkspaceSettingsHandleMut};
 use crate::workspace::{
-    FileFeaturesResult, GetFileContentParams, IsPathIgnoredParams, OrganizeImportsParams,
-    OrganizeImportsResult, RageEntry, RageParams, RageResult, ServerInfo,
+    FileFeaturesResult, GetFileContentParams, GetResolvedSettingsParams, IsPathIgnoredParams,
+    OrganizeImportsParams, OrganizeImportsResult, RageEntry, RageParams, RageResult,
+    ResolvedSettings, ServerInfo,
 };
 use crate::{
     file_handlers::Features, settings::WorkspaceSettingsHandle, Workspace, WorkspaceError,
 };
+use biome_analyze::{RuleKey, RulePhase, RuleProfiler};
 use biome_configuration::DEFAULT_FILE_SIZE_LIMIT;
 use biome_diagnostics::{
     serde::Diagnostic as SerdeDiagnostic, Diagnostic, DiagnosticExt, Severity,
@@ -34,10 +37,13 @@ use biome_project::{NodeJsProject, PackageJson, PackageType, Project};
 use biome_rowan::NodeCache;
 use dashmap::{mapref::entry::Entry, DashMap};
 use indexmap::IndexSet;
+use rustc_hash::FxHashMap;
 use std::ffi::OsStr;
 use std::fs;
 use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
 use std::{panic::RefUnwindSafe, sync::RwLock};
 use tracing::{debug, info, info_span};
 
@@ -99,6 +105,11 @@ impl WorkspaceServer {
         WorkspaceSettingsHandle::new(&self.settings)
     }
 
+    /// Returns the settings that apply to `path`, which are the ones of the closest nested configuration file
+    fn workspace_for_path(&self, path: &Path) -> WorkspaceSettingsHandle {
+        WorkspaceSettingsHandle::for_path(&self.settings, path)
+    }
+
     fn workspaces_mut(&self) -> WorkspaceSettingsHandleMut {
         WorkspaceSettingsHandleMut::new(&self.settings)
     }
@@ -237,7 +248,7 @@ impl WorkspaceServer {
                     .ok_or_else(self.build_capability_error(biome_path))?;
 
                 let size_limit = {
-                    let workspace = self.workspace();
+                    let workspace = self.workspace_for_path(biome_path);
                     let settings = workspace.settings();
                     let limit =
                         settings.map_or(DEFAULT_FILE_SIZE_LIMIT.get(), |s| s.files.max_size.get());
@@ -254,7 +265,7 @@ impl WorkspaceServer {
                     ));
                 }
 
-                let workspace = self.workspace();
+                let workspace = self.workspace_for_path(biome_path);
                 let Some(file_source) = self.get_source(document.file_source_index) else {
                     return Err(WorkspaceError::not_found());
                 };
@@ -301,7 +312,7 @@ impl WorkspaceServer {
 
     /// Check whether a file is ignored in the top-level config `files.ignore`/`files.include`
     fn is_ignored_by_top_level_config(&self, path: &Path) -> bool {
-        let settings = self.workspace();
+        let settings = self.workspace_for_path(path);
         let settings = settings.settings();
         let Some(settings) = settings else {
             return false;
@@ -311,24 +322,16 @@ impl WorkspaceServer {
             || settings.files.included_files.matches_path(path);
         !is_included
             || settings.files.ignored_files.matches_path(path)
-            || settings.files.git_ignore.as_ref().is_some_and(|ignore| {
-                // `matched_path_or_any_parents` panics if `source` is not under the gitignore root.
-                // This checks excludes absolute paths that are not a prefix of the base root.
-                if !path.has_root() || path.starts_with(ignore.path()) {
-                    // Because Biome passes a list of paths,
-                    // we use `matched_path_or_any_parents` instead of `matched`.
-                    ignore
-                        .matched_path_or_any_parents(path, path.is_dir())
-                        .is_ignore()
-                } else {
-                    false
-                }
-            })
+            || settings
+                .files
+                .git_ignore
+                .as_ref()
+                .is_some_and(|ignore| ignore.is_ignored(path, path.is_dir()))
     }
 
     /// Check whether a file is ignored in the feature `ignore`/`include`
     fn is_ignored_by_feature_config(&self, path: &Path, feature: FeatureKind) -> bool {
-        let settings = self.workspace();
+        let settings = self.workspace_for_path(path);
         let settings = settings.settings();
         let Some(settings) = settings else {
             return false;
@@ -372,7 +375,7 @@ impl Workspace for WorkspaceServer {
         let capabilities = self.get_file_capabilities(&params.path);
         let language = DocumentFileSource::from_path(&params.path);
         let path = params.path.as_path();
-        let settings = self.workspace();
+        let settings = self.workspace_for_path(path);
         let settings = settings.settings();
         let mut file_features = FileFeaturesResult::new();
 
@@ -421,15 +424,37 @@ impl Workspace for WorkspaceServer {
     #[tracing::instrument(level = "trace", skip(self))]
     fn update_settings(&self, params: UpdateSettingsParams) -> Result<(), WorkspaceError> {
         let mut workspace = self.workspaces_mut();
-        workspace
-            .as_mut()
-            .get_current_settings_mut()
-            .merge_with_configuration(
+        if params.nested {
+            workspace.as_mut().insert_nested_configuration(
+                params.workspace_directory.unwrap_or_default(),
                 params.configuration,
-                params.workspace_directory,
                 params.vcs_base_path,
                 params.gitignore_matches.as_slice(),
+                params.ignore_files.as_slice(),
+                params.plugins.as_slice(),
             )?;
+        } else {
+            workspace
+                .as_mut()
+                .get_current_settings_mut()
+                .merge_with_configuration(
+                    params.configuration.clone(),
+                    params.workspace_directory,
+                    params.vcs_base_path,
+                    params.gitignore_matches.as_slice(),
+                )?;
+            workspace
+                .as_mut()
+                .get_current_settings_mut()
+                .merge_with_vcs_ignore_files(params.ignore_files.as_slice())?;
+            workspace
+                .as_mut()
+                .get_current_settings_mut()
+                .set_plugins(params.plugins.as_slice())?;
+            workspace
+                .as_mut()
+                .merge_current_configuration(params.configuration);
+        }
 
         Ok(())
     }
@@ -485,6 +510,34 @@ impl Workspace for WorkspaceServer {
         Ok(())
     }
 
+    fn register_vcs_ignore_files(
+        &self,
+        params: RegisterVcsIgnoreFilesParams,
+    ) -> Result<(), WorkspaceError> {
+        let mut workspace = self.workspaces_mut();
+        workspace
+            .as_mut()
+            .register_vcs_ignore_files(params.ignore_files.as_slice())
+    }
+
+    fn update_module_graph(&self, params: UpdateModuleGraphParams) -> Result<(), WorkspaceError> {
+        let mut workspace = self.workspaces_mut();
+        workspace
+            .as_mut()
+            .update_module_graph(params.module_graph, &params.removed_paths);
+        Ok(())
+    }
+
+    fn get_module_graph_paths(
+        &self,
+        _params: GetModuleGraphPathsParams,
+    ) -> Result<GetModuleGraphPathsResult, WorkspaceError> {
+        let module_graph = self.workspace().as_ref().get_current_module_graph();
+        Ok(GetModuleGraphPathsResult {
+            paths: module_graph.paths().map(Path::to_path_buf).collect(),
+        })
+    }
+
     fn register_project_folder(
         &self,
         params: RegisterProjectFolderParams,
@@ -563,7 +616,7 @@ impl Workspace for WorkspaceServer {
             .debug
             .debug_formatter_ir
             .ok_or_else(self.build_capability_error(&params.path))?;
-        let workspace = self.workspace();
+        let workspace = self.workspace_for_path(&params.path);
         let settings = workspace.settings();
         let parse = self.get_parse(params.path.clone())?;
 
@@ -577,6 +630,20 @@ impl Workspace for WorkspaceServer {
         debug_formatter_ir(&params.path, &document_file_source, parse, workspace)
     }
 
+    fn get_resolved_settings(
+        &self,
+        params: GetResolvedSettingsParams,
+    ) -> Result<ResolvedSettings, WorkspaceError> {
+        let workspace = self.workspace_for_path(&params.path);
+        let file_source = self.get_file_source(&params.path);
+        workspace.resolved_settings(
+            &params.path,
+            &file_source,
+            params.editorconfig,
+            params.command_line,
+        )
+    }
+
     fn get_file_content(&self, params: GetFileContentParams) -> Result<String, WorkspaceError> {
         let document = self
             .documents
@@ -620,12 +687,14 @@ impl Workspace for WorkspaceServer {
     ) -> Result<PullDiagnosticsResult, WorkspaceError> {
         let parse = self.get_parse(params.path.clone())?;
         let manifest = self.get_current_manifest()?;
+        let module_graph = self.workspace().as_ref().get_current_module_graph();
+        let recorder = RuleDurationsRecorder::new(params.profile_rules);
         let (diagnostics, errors, skipped_diagnostics) =
             if let Some(lint) = self.get_file_capabilities(&params.path).analyzer.lint {
                 info_span!("Pulling diagnostics", categories =? params.categories).in_scope(|| {
                     let results = lint(LintParams {
                         parse,
-                        workspace: &self.workspace(),
+                        workspace: &self.workspace_for_path(&params.path),
                         max_diagnostics: params.max_diagnostics as u32,
                         path: &params.path,
                         only: params.only,
@@ -633,7 +702,11 @@ impl Workspace for WorkspaceServer {
                         language: self.get_file_source(&params.path),
                         categories: params.categories,
                         manifest,
+                        module_graph,
                         suppression_reason: None,
+                        profiler: recorder
+                            .clone()
+                            .map(|recorder| recorder as Arc<dyn RuleProfiler>),
                     });
 
                     (
@@ -663,6 +736,7 @@ impl Workspace for WorkspaceServer {
                 .collect(),
             errors,
             skipped_diagnostics: skipped_diagnostics.into(),
+            rule_durations: RuleDurationsRecorder::into_durations(recorder),
         })
     }
 
@@ -677,8 +751,9 @@ impl Workspace for WorkspaceServer {
             .ok_or_else(self.build_capability_error(&params.path))?;
 
         let parse = self.get_parse(params.path.clone())?;
-        let workspace = self.workspace();
+        let workspace = self.workspace_for_path(&params.path);
         let manifest = self.get_current_manifest()?;
+        let module_graph = self.workspace().as_ref().get_current_module_graph();
         let language = self.get_file_source(&params.path);
         Ok(code_actions(CodeActionsParams {
             parse,
@@ -686,6 +761,7 @@ impl Workspace for WorkspaceServer {
             workspace: &workspace,
             path: &params.path,
             manifest,
+            module_graph,
             language,
             only: params.only,
             skip: params.skip,
@@ -701,7 +777,7 @@ impl Workspace for WorkspaceServer {
             .formatter
             .format
             .ok_or_else(self.build_capability_error(&params.path))?;
-        let workspace = self.workspace();
+        let workspace = self.workspace_for_path(&params.path);
         let settings = workspace.settings();
         let parse = self.get_parse(params.path.clone())?;
 
@@ -720,7 +796,7 @@ impl Workspace for WorkspaceServer {
             .formatter
             .format_range
             .ok_or_else(self.build_capability_error(&params.path))?;
-        let workspace = self.workspace();
+        let workspace = self.workspace_for_path(&params.path);
         let settings = workspace.settings();
         let parse = self.get_parse(params.path.clone())?;
 
@@ -746,7 +822,7 @@ impl Workspace for WorkspaceServer {
             .format_on_type
             .ok_or_else(self.build_capability_error(&params.path))?;
 
-        let workspace = self.workspace();
+        let workspace = self.workspace_for_path(&params.path);
         let settings = workspace.settings();
         let parse = self.get_parse(params.path.clone())?;
         if let Some(settings) = settings {
@@ -775,22 +851,30 @@ impl Workspace for WorkspaceServer {
         let parse = self.get_parse(params.path.clone())?;
 
         let manifest = self.get_current_manifest()?;
+        let module_graph = self.workspace().as_ref().get_current_module_graph();
         let language = self.get_file_source(&params.path);
-        fix_all(FixAllParams {
+        let recorder = RuleDurationsRecorder::new(params.profile_rules);
+        let mut result = fix_all(FixAllParams {
             parse,
             // rules: rules.as_ref().map(|x| x.borrow()),
             fix_file_mode: params.fix_file_mode,
             // filter,
-            workspace: self.workspace(),
+            workspace: self.workspace_for_path(&params.path),
             should_format: params.should_format,
             biome_path: &params.path,
             manifest,
+            module_graph,
             document_file_source: language,
             only: params.only,
             skip: params.skip,
             rule_categories: params.rule_categories,
             suppression_reason: params.suppression_reason,
-        })
+            profiler: recorder
+                .clone()
+                .map(|recorder| recorder as Arc<dyn RuleProfiler>),
+        })?;
+        result.rule_durations = RuleDurationsRecorder::into_durations(recorder);
+        Ok(result)
     }
 
     fn rename(&self, params: super::RenameParams) -> Result<RenameResult, WorkspaceError> {
@@ -842,22 +926,17 @@ impl Workspace for WorkspaceServer {
             .search
             .search
             .ok_or_else(self.build_capability_error(&params.path))?;
-        let workspace = self.workspace();
+        let workspace = self.workspace_for_path(&params.path);
         let parse = self.get_parse(params.path.clone())?;
 
         let document_file_source = self.get_file_source(&params.path);
-        let matches = search(
+        search(
             &params.path,
             &document_file_source,
             parse,
             &query,
             workspace,
-        )?;
-
-        Ok(SearchResults {
-            file: params.path,
-            matches,
-        })
+        )
     }
 
     fn drop_pattern(&self, params: super::DropPatternParams) -> Result<(), WorkspaceError> {
@@ -886,6 +965,43 @@ impl Workspace for WorkspaceServer {
     }
 }
 
+/// Collects the durations of the rules that run while processing a single file
+#[derive(Default)]
+struct RuleDurationsRecorder {
+    rules: Mutex<FxHashMap<RuleKey, RuleDurations>>,
+}
+
+impl RuleDurationsRecorder {
+    /// Returns a recorder when the rules are measured
+    fn new(profile_rules: bool) -> Option<Arc<Self>> {
+        profile_rules.then(Arc::default)
+    }
+
+    fn into_durations(recorder: Option<Arc<Self>>) -> Vec<RuleDurations> {
+        // The analyzer doesn't keep the profiler once the file is processed
+        recorder
+            .and_then(Arc::into_inner)
+            .map(|recorder| recorder.rules.into_inner().unwrap().into_values().collect())
+            .unwrap_or_default()
+    }
+}
+
+impl RuleProfiler for RuleDurationsRecorder {
+    fn record(&self, rule: RuleKey, phase: RulePhase, duration: Duration) {
+        let mut rules = self.rules.lock().unwrap();
+        let durations = rules.entry(rule).or_insert_with(|| RuleDurations {
+            rule: format!("{}/{}", rule.group(), rule.rule_name()),
+            run: Vec::new(),
+            action: Vec::new(),
+        });
+        let durations = match phase {
+            RulePhase::Run => &mut durations.run,
+            RulePhase::Action => &mut durations.action,
+        };
+        durations.push(duration.as_nanos() as u64);
+    }
+}
+
 /// Returns `true` if `path` is a directory or
 /// if it is a symlink that resolves to a directory.
 fn is_dir(path: &Path) -> bool {
@@ -56,6 +56,35 @@ fn instance_type<'a>(
         // If the instance type is an object, generate a TS object type with the corresponding properties
         InstanceType::Object => {
             let object = schema.object.as_deref().unwrap();
+            // If the object only has additional properties, generate a TS object type with an index signature
+            if let (true, Some(additional_properties)) = (
+                object.properties.is_empty(),
+                object.additional_properties.as_deref(),
+            ) {
+                let (ts_type, optional, _) = schema_type(queue, root_schema, additional_properties);
+                assert!(!optional, "optional nested types are not supported");
+
+                return AnyTsType::from(make::ts_object_type(
+                    make::token(T!['{']),
+                    make::ts_type_member_list([AnyTsTypeMember::from(
+                        make::ts_index_signature_type_member(
+                            make::token(T!['[']),
+                            make::ts_index_signature_parameter(
+                                make::js_identifier_binding(make::ident("key")),
+                                make::ts_type_annotation(
+                                    make::token(T![:]),
+                                    AnyTsType::from(make::ts_string_type(make::token(T![string]))),
+                                ),
+                            ),
+                            make::token(T![']']),
+                            make::ts_type_annotation(make::token(T![:]), ts_type),
+                        )
+                        .build(),
+                    )]),
+                    make::token(T!['}']),
+                ));
+            }
+
             AnyTsType::from(make::ts_object_type(
                 make::token(T!['{']),
                 make::ts_type_member_list(object.properties.iter().map(|(property, schema)| {
@@ -451,12 +480,15 @@ macro_rules! workspace_method {
 }
 
 /// Returns a list of signature for all the methods in the [Workspace] trait
-pub fn methods() -> [WorkspaceMethod; 19] {
+pub fn methods() -> [WorkspaceMethod; 23] {
     [
         workspace_method!(file_features),
         workspace_method!(update_settings),
         workspace_method!(register_project_folder),
         workspace_method!(set_manifest_for_project),
+        workspace_method!(register_vcs_ignore_files),
+        workspace_method!(update_module_graph),
+        workspace_method!(get_module_graph_paths),
         workspace_method!(open_file),
         workspace_method!(change_file),
         workspace_method!(close_file),
@@ -465,6 +497,7 @@ pub fn methods() -> [WorkspaceMethod; 19] {
         workspace_method!(get_file_content),
         workspace_method!(get_control_flow_graph),
         workspace_method!(get_formatter_ir),
+        workspace_method!(get_resolved_settings),
         workspace_method!(pull_diagnostics),
         workspace_method!(pull_actions),
         workspace_method!(format_file),
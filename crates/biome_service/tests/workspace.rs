@@ -4,11 +4,14 @@ mod test {
     use biome_configuration::analyzer::{RuleGroup, RuleSelector};
     use biome_fs::BiomePath;
     use biome_js_syntax::{JsFileSource, TextSize};
+    use biome_project::{ModuleGraph, ModuleInfo};
     use biome_service::file_handlers::DocumentFileSource;
     use biome_service::workspace::{
-        server, FileGuard, OpenFileParams, RegisterProjectFolderParams,
+        server, FileGuard, GetModuleGraphPathsParams, OpenFileParams, RegisterProjectFolderParams,
+        UpdateModuleGraphParams,
     };
     use biome_service::Workspace;
+    use std::path::PathBuf;
     fn create_server() -> Box<dyn Workspace> {
         let workspace = server();
         workspace
@@ -243,6 +246,7 @@ type User {
                 "useDeprecatedReason",
             )],
             vec![],
+            false,
         );
         assert!(result.is_ok());
         let diagnostics = result.unwrap().diagnostics;
@@ -272,4 +276,33 @@ type User {
 
         assert!(syntax.starts_with("GritRoot"))
     }
+
+    #[test]
+    fn update_module_graph_removes_paths() {
+        let workspace = create_server();
+
+        let mut module_graph = ModuleGraph::default();
+        module_graph.insert_module(PathBuf::from("a.js"), ModuleInfo::default());
+        module_graph.insert_module(PathBuf::from("b.js"), ModuleInfo::default());
+        workspace
+            .update_module_graph(UpdateModuleGraphParams {
+                module_graph,
+                removed_paths: Vec::new(),
+            })
+            .unwrap();
+
+        let mut module_graph = ModuleGraph::default();
+        module_graph.insert_module(PathBuf::from("c.js"), ModuleInfo::default());
+        workspace
+            .update_module_graph(UpdateModuleGraphParams {
+                module_graph,
+                removed_paths: vec![PathBuf::from("a.js")],
+            })
+            .unwrap();
+
+        let result = workspace
+            .get_module_graph_paths(GetModuleGraphPathsParams {})
+            .unwrap();
+        assert_eq!(result.paths, [PathBuf::from("b.js"), PathBuf::from("c.js")]);
+    }
 }
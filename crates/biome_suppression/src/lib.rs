@@ -14,6 +14,9 @@ use biome_rowan::{TextRange, TextSize};
 /// A suppression must specify a reason: this part has no semantic meaning but
 /// is required to document why a particular feature is being disable for this
 /// line (lint false-positive, specific formatting requirements, ...)
+///
+/// The `biome-ignore-all`, `biome-ignore-start` and `biome-ignore-end` variants
+/// of the comment share the same syntax, see [SuppressionKind].
 #[derive(Debug, PartialEq, Eq)]
 pub struct Suppression<'a> {
     /// List of categories for this suppression
@@ -25,6 +28,41 @@ pub struct Suppression<'a> {
     pub reason: &'a str,
     /// If the comment is `// biome-ignore`
     pub is_legacy: bool,
+    /// What the suppression applies to
+    pub kind: SuppressionKind,
+}
+
+/// The part of the file a suppression comment applies to
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum SuppressionKind {
+    /// `// biome-ignore`: the suppression applies to the next line
+    #[default]
+    Classic,
+    /// `// biome-ignore-all`: the suppression applies to the whole file
+    All,
+    /// `// biome-ignore-start`: the suppression applies until the matching
+    /// `// biome-ignore-end` comment
+    RangeStart,
+    /// `// biome-ignore-end`: the end of a range suppression
+    RangeEnd,
+}
+
+impl SuppressionKind {
+    pub const fn is_classic(&self) -> bool {
+        matches!(self, Self::Classic)
+    }
+
+    pub const fn is_all(&self) -> bool {
+        matches!(self, Self::All)
+    }
+
+    pub const fn is_range_start(&self) -> bool {
+        matches!(self, Self::RangeStart)
+    }
+
+    pub const fn is_range_end(&self) -> bool {
+        matches!(self, Self::RangeEnd)
+    }
 }
 
 pub fn parse_suppression_comment(
@@ -96,6 +134,7 @@ pub fn parse_suppression_comment(
         ];
 
         let mut is_legacy = false;
+        let mut kind = SuppressionKind::Classic;
         // it's a biome-ignore comment
         if line.starts_with("biome-ignore") {
             // Checks for `/biome[-_]ignore/i` without a regex, or skip the line
@@ -103,6 +142,19 @@ pub fn parse_suppression_comment(
             for pattern in PATTERN {
                 line = line.strip_prefix(pattern)?;
             }
+
+            // Checks for the `-all`, `-start` and `-end` suffixes
+            for (suffix, suffix_kind) in [
+                ("-all", SuppressionKind::All),
+                ("-start", SuppressionKind::RangeStart),
+                ("-end", SuppressionKind::RangeEnd),
+            ] {
+                if let Some(rest) = line.strip_prefix(suffix) {
+                    line = rest;
+                    kind = suffix_kind;
+                    break;
+                }
+            }
         } else {
             is_legacy = true;
             for pattern in DEPRECATED_PATTERNS {
@@ -115,7 +167,7 @@ pub fn parse_suppression_comment(
 
         let line = line.trim_start();
         Some(
-            parse_suppression_line(line, is_legacy).map_err(|err| SuppressionDiagnostic {
+            parse_suppression_line(line, is_legacy, kind).map_err(|err| SuppressionDiagnostic {
                 message: err.message,
                 // Adjust the position of the diagnostic in the whole comment
                 span: err.span + offset_from(base, line),
@@ -184,6 +236,7 @@ impl biome_console::fmt::Display for SuppressionDiagnosticKind {
 fn parse_suppression_line(
     base: &str,
     is_legacy: bool,
+    kind: SuppressionKind,
 ) -> Result<Suppression, SuppressionDiagnostic> {
     let mut line = base;
     let mut categories = Vec::new();
@@ -259,6 +312,7 @@ fn parse_suppression_line(
         categories,
         reason,
         is_legacy,
+        kind,
     })
 }
 
@@ -294,7 +348,7 @@ mod tests {
 
     use crate::{offset_from, SuppressionDiagnostic, SuppressionDiagnosticKind};
 
-    use super::{parse_suppression_comment, Suppression};
+    use super::{parse_suppression_comment, Suppression, SuppressionKind};
 
     #[test]
     fn parse_simple_suppression() {
@@ -303,7 +357,8 @@ mod tests {
             vec![Ok(Suppression {
                 categories: vec![(category!("parse"), None)],
                 reason: "explanation1",
-                is_legacy: true
+                is_legacy: true,
+                kind: SuppressionKind::Classic
             })],
         );
 
@@ -312,7 +367,8 @@ mod tests {
             vec![Ok(Suppression {
                 categories: vec![(category!("parse"), None)],
                 reason: "explanation2",
-                is_legacy: true
+                is_legacy: true,
+                kind: SuppressionKind::Classic
             })],
         );
 
@@ -326,7 +382,8 @@ mod tests {
             vec![Ok(Suppression {
                 categories: vec![(category!("parse"), None)],
                 reason: "explanation3",
-                is_legacy: true
+                is_legacy: true,
+                kind: SuppressionKind::Classic
             })],
         );
 
@@ -341,7 +398,8 @@ mod tests {
             vec![Ok(Suppression {
                 categories: vec![(category!("parse"), None)],
                 reason: "explanation4",
-                is_legacy: true
+                is_legacy: true,
+                kind: SuppressionKind::Classic
             })],
         );
     }
@@ -352,7 +410,8 @@ mod tests {
             vec![Ok(Suppression {
                 categories: vec![(category!("format"), None)],
                 reason: "explanation",
-                is_legacy: true
+                is_legacy: true,
+                kind: SuppressionKind::Classic
             })],
         );
 
@@ -361,7 +420,8 @@ mod tests {
             vec![Ok(Suppression {
                 categories: vec![(category!("format"), None)],
                 reason: "explanation",
-                is_legacy: true
+                is_legacy: true,
+                kind: SuppressionKind::Classic
             })],
         );
 
@@ -370,7 +430,8 @@ mod tests {
             vec![Ok(Suppression {
                 categories: vec![(category!("format"), None)],
                 reason: "explanation",
-                is_legacy: true
+                is_legacy: true,
+                kind: SuppressionKind::Classic
             })],
         );
     }
@@ -386,7 +447,8 @@ mod tests {
                     (category!("parse"), Some("dog"))
                 ],
                 reason: "explanation",
-                is_legacy: true
+                is_legacy: true,
+                kind: SuppressionKind::Classic
             })],
         );
 
@@ -399,7 +461,8 @@ mod tests {
                     (category!("parse"), Some("cat"))
                 ],
                 reason: "explanation",
-                is_legacy: true
+                is_legacy: true,
+                kind: SuppressionKind::Classic
             })],
         );
 
@@ -416,7 +479,8 @@ mod tests {
                     (category!("parse"), Some("frog"))
                 ],
                 reason: "explanation",
-                is_legacy: true
+                is_legacy: true,
+                kind: SuppressionKind::Classic
             })],
         );
 
@@ -434,7 +498,8 @@ mod tests {
                     (category!("parse"), Some("fish"))
                 ],
                 reason: "explanation",
-                is_legacy: true
+                is_legacy: true,
+                kind: SuppressionKind::Classic
             })],
         );
     }
@@ -447,7 +512,8 @@ mod tests {
             vec![Ok(Suppression {
                 categories: vec![(category!("format"), None), (category!("lint"), None)],
                 reason: "explanation",
-                is_legacy: true
+                is_legacy: true,
+                kind: SuppressionKind::Classic
             })],
         );
     }
@@ -517,7 +583,7 @@ mod tests_biome_ignore {
 
     use crate::{offset_from, SuppressionDiagnostic, SuppressionDiagnosticKind};
 
-    use super::{parse_suppression_comment, Suppression};
+    use super::{parse_suppression_comment, Suppression, SuppressionKind};
 
     #[test]
     fn parse_simple_suppression() {
@@ -526,7 +592,8 @@ mod tests_biome_ignore {
             vec![Ok(Suppression {
                 categories: vec![(category!("parse"), None)],
                 reason: "explanation1",
-                is_legacy: false
+                is_legacy: false,
+                kind: SuppressionKind::Classic
             })],
         );
 
@@ -536,7 +603,8 @@ mod tests_biome_ignore {
             vec![Ok(Suppression {
                 categories: vec![(category!("parse"), None)],
                 reason: "explanation2",
-                is_legacy: false
+                is_legacy: false,
+                kind: SuppressionKind::Classic
             })],
         );
 
@@ -550,7 +618,8 @@ mod tests_biome_ignore {
             vec![Ok(Suppression {
                 categories: vec![(category!("parse"), None)],
                 reason: "explanation3",
-                is_legacy: false
+                is_legacy: false,
+                kind: SuppressionKind::Classic
             })],
         );
 
@@ -565,7 +634,8 @@ mod tests_biome_ignore {
             vec![Ok(Suppression {
                 categories: vec![(category!("parse"), None)],
                 reason: "explanation4",
-                is_legacy: false
+                is_legacy: false,
+                kind: SuppressionKind::Classic
             })],
         );
     }
@@ -576,7 +646,8 @@ mod tests_biome_ignore {
             vec![Ok(Suppression {
                 categories: vec![(category!("format"), None)],
                 reason: "explanation",
-                is_legacy: false
+                is_legacy: false,
+                kind: SuppressionKind::Classic
             })],
         );
 
@@ -585,7 +656,8 @@ mod tests_biome_ignore {
             vec![Ok(Suppression {
                 categories: vec![(category!("format"), None)],
                 reason: "explanation",
-                is_legacy: false
+                is_legacy: false,
+                kind: SuppressionKind::Classic
             })],
         );
 
@@ -594,7 +666,8 @@ mod tests_biome_ignore {
             vec![Ok(Suppression {
                 categories: vec![(category!("format"), None)],
                 reason: "explanation",
-                is_legacy: false
+                is_legacy: false,
+                kind: SuppressionKind::Classic
             })],
         );
     }
@@ -610,7 +683,8 @@ mod tests_biome_ignore {
                     (category!("parse"), Some("dog"))
                 ],
                 reason: "explanation",
-                is_legacy: false
+                is_legacy: false,
+                kind: SuppressionKind::Classic
             })],
         );
 
@@ -623,7 +697,8 @@ mod tests_biome_ignore {
                     (category!("parse"), Some("cat"))
                 ],
                 reason: "explanation",
-                is_legacy: false
+                is_legacy: false,
+                kind: SuppressionKind::Classic
             })],
         );
 
@@ -640,7 +715,8 @@ mod tests_biome_ignore {
                     (category!("parse"), Some("frog"))
                 ],
                 reason: "explanation",
-                is_legacy: false
+                is_legacy: false,
+                kind: SuppressionKind::Classic
             })],
         );
 
@@ -658,7 +734,8 @@ mod tests_biome_ignore {
                     (category!("parse"), Some("fish"))
                 ],
                 reason: "explanation",
-                is_legacy: false
+                is_legacy: false,
+                kind: SuppressionKind::Classic
             })],
         );
     }
@@ -671,7 +748,8 @@ mod tests_biome_ignore {
             vec![Ok(Suppression {
                 categories: vec![(category!("format"), None), (category!("lint"), None)],
                 reason: "explanation",
-                is_legacy: false
+                is_legacy: false,
+                kind: SuppressionKind::Classic
             })],
         );
     }
@@ -732,4 +810,43 @@ mod tests_biome_ignore {
             })],
         );
     }
+
+    #[test]
+    fn parse_all_suppression() {
+        assert_eq!(
+            parse_suppression_comment("// biome-ignore-all lint: generated file")
+                .collect::<Vec<_>>(),
+            vec![Ok(Suppression {
+                categories: vec![(category!("lint"), None)],
+                reason: "generated file",
+                is_legacy: false,
+                kind: SuppressionKind::All
+            })],
+        );
+    }
+
+    #[test]
+    fn parse_range_suppressions() {
+        assert_eq!(
+            parse_suppression_comment("/* biome-ignore-start lint/style/useWhile: explanation */")
+                .collect::<Vec<_>>(),
+            vec![Ok(Suppression {
+                categories: vec![(category!("lint/style/useWhile"), None)],
+                reason: "explanation",
+                is_legacy: false,
+                kind: SuppressionKind::RangeStart
+            })],
+        );
+
+        assert_eq!(
+            parse_suppression_comment("// biome-ignore-end lint/style/useWhile: explanation")
+                .collect::<Vec<_>>(),
+            vec![Ok(Suppression {
+                categories: vec![(category!("lint/style/useWhile"), None)],
+                reason: "explanation",
+                is_legacy: false,
+                kind: SuppressionKind::RangeEnd
+            })],
+        );
+    }
 }
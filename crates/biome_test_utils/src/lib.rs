@@ -5,17 +5,19 @@ use biome_console::fmt::{Formatter, Termcolor};
 use biome_console::markup;
 use biome_diagnostics::termcolor::Buffer;
 use biome_diagnostics::{DiagnosticExt, Error, PrintDiagnostic};
+use biome_fs::OsFileSystem;
 use biome_json_parser::{JsonParserOptions, ParseDiagnostic};
-use biome_project::PackageJson;
+use biome_project::{ModuleGraph, PackageJson};
 use biome_rowan::{SyntaxKind, SyntaxNode, SyntaxSlot};
-use biome_service::configuration::to_analyzer_rules;
+use biome_service::configuration::{to_analyzer_rules, to_suppression_policy};
+use biome_service::module_graph::{ModuleGraphBuilder, ProjectRules};
 use biome_service::settings::{ServiceLanguage, Settings};
 use json_comments::StripComments;
 use similar::TextDiff;
 use std::ffi::{c_int, OsStr};
 use std::fmt::Write;
 use std::path::Path;
-use std::sync::Once;
+use std::sync::{Arc, Once};
 
 pub fn scripts_from_json(extension: &OsStr, input_code: &str) -> Option<Vec<String>> {
     if extension == "json" || extension == "jsonc" {
@@ -44,6 +46,8 @@ pub fn create_analyzer_options(
         preferred_quote: PreferredQuote::Double,
         preferred_jsx_quote: PreferredQuote::Double,
         jsx_runtime: Some(JsxRuntime::Transparent),
+        suppression_policy: Default::default(),
+        plugins: vec![],
     };
     let options_file = input_file.with_extension("options.json");
     if let Ok(json) = std::fs::read_to_string(options_file.clone()) {
@@ -108,6 +112,7 @@ pub fn create_analyzer_options(
                 .merge_with_configuration(configuration, None, None, &[])
                 .unwrap();
             analyzer_configuration.rules = to_analyzer_rules(&settings, input_file);
+            analyzer_configuration.suppression_policy = to_suppression_policy(&settings);
         }
     }
 
@@ -117,6 +122,19 @@ pub fn create_analyzer_options(
     }
 }
 
+/// Builds the module graph of the modules imported by `input_file`, the modules of its
+/// directory, when the rule `rule` of the group `group` needs it
+pub fn load_module_graph(input_file: &Path, group: &str, rule: &str) -> Arc<ModuleGraph> {
+    if !ProjectRules::new().contains(group, rule) {
+        return Arc::default();
+    }
+    let directory = input_file.parent().unwrap();
+    let fs = OsFileSystem::new(directory.to_path_buf());
+    let mut builder = ModuleGraphBuilder::new(&fs, directory);
+    builder.add_path(input_file);
+    Arc::new(builder.build())
+}
+
 pub fn load_manifest(input_file: &Path, diagnostics: &mut Vec<String>) -> Option<PackageJson> {
     let options_file = input_file.with_extension("package.json");
     if let Ok(json) = std::fs::read_to_string(options_file.clone()) {
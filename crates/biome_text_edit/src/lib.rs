@@ -104,35 +104,183 @@ impl TextEdit {
     /// revision of the string since [TextEdit] doesn't store the content of
     /// text sections that are equal between revisions
     pub fn new_string(&self, old_string: &str) -> String {
+        self.iter_text(old_string)
+            .filter(|(tag, _)| *tag != ChangeTag::Delete)
+            .map(|(_, text)| text)
+            .collect()
+    }
+
+    /// Return the hunks of the unified diff from the "old" revision of the
+    /// text to the "new" one, with `context_lines` lines of unchanged text
+    /// around the changes. As for [TextEdit::new_string], this method needs
+    /// to be provided with the "old" revision of the string.
+    ///
+    /// The lines that have a change are reported as deleted from the "old"
+    /// revision and inserted in the "new" one, like `diff -u` or `git diff`
+    /// do, so that the result can be applied with `patch` or `git apply`.
+    pub fn unified_diff(&self, old_string: &str, context_lines: usize) -> String {
+        let lines = self.diff_lines(old_string);
+
+        let changes: Vec<_> = lines
+            .iter()
+            .enumerate()
+            .filter(|(_, (tag, _))| *tag != ChangeTag::Equal)
+            .map(|(index, _)| index)
+            .collect();
+
+        // The line numbers where each line starts, in the "old" and the "new" revisions
+        let mut line_numbers = Vec::with_capacity(lines.len());
+        let (mut old_line, mut new_line) = (1, 1);
+        for (tag, _) in &lines {
+            line_numbers.push((old_line, new_line));
+            match tag {
+                ChangeTag::Equal => {
+                    old_line += 1;
+                    new_line += 1;
+                }
+                ChangeTag::Delete => old_line += 1,
+                ChangeTag::Insert => new_line += 1,
+            }
+        }
+
         let mut output = String::new();
-        let mut input_position = TextSize::from(0);
+        let mut changes = changes.into_iter().peekable();
+        while let Some(first_change) = changes.next() {
+            // The changes separated by few unchanged lines share the same hunk
+            let mut last_change = first_change;
+            while let Some(change) =
+                changes.next_if(|change| change - last_change <= 2 * context_lines + 1)
+            {
+                last_change = change;
+            }
 
-        for op in &self.ops {
-            match op {
-                CompressedOp::DiffOp(DiffOp::Equal { range }) => {
-                    output.push_str(&self.dictionary[*range]);
-                    input_position += range.len();
+            let start = first_change.saturating_sub(context_lines);
+            let end = (last_change + 1 + context_lines).min(lines.len());
+            let hunk = &lines[start..end];
+
+            let old_count = hunk
+                .iter()
+                .filter(|(tag, _)| *tag != ChangeTag::Insert)
+                .count();
+            let new_count = hunk
+                .iter()
+                .filter(|(tag, _)| *tag != ChangeTag::Delete)
+                .count();
+            let (old_start, new_start) = line_numbers[start];
+            output.push_str(&format!(
+                "@@ -{} +{} @@\n",
+                HunkRange(old_start, old_count),
+                HunkRange(new_start, new_count)
+            ));
+
+            for (tag, line) in hunk {
+                output.push(match tag {
+                    ChangeTag::Equal => ' ',
+                    ChangeTag::Delete => '-',
+                    ChangeTag::Insert => '+',
+                });
+                output.push_str(line);
+                if !line.ends_with('\n') {
+                    output.push_str("\n\\ No newline at end of file\n");
                 }
-                CompressedOp::DiffOp(DiffOp::Insert { range }) => {
-                    output.push_str(&self.dictionary[*range]);
+            }
+        }
+
+        output
+    }
+
+    /// Return the text of each operation of this [TextEdit], reading the text
+    /// of the compressed equal lines from the "old" revision of the string
+    fn iter_text<'a>(&'a self, old_string: &'a str) -> impl Iterator<Item = (ChangeTag, &'a str)> {
+        let mut input_position = 0;
+
+        self.ops.iter().map(move |op| match op {
+            CompressedOp::DiffOp(op) => {
+                let text = op.text(self);
+                if op.tag() != ChangeTag::Insert {
+                    input_position += text.len();
+                }
+                (op.tag(), text)
+            }
+            CompressedOp::EqualLines { line_count } => {
+                let input = &old_string[input_position..];
+
+                let line_break_count = line_count.get() as usize + 1;
+                let len = input
+                    .split_inclusive('\n')
+                    .take(line_break_count)
+                    .map(str::len)
+                    .sum::<usize>();
+                input_position += len;
+                (ChangeTag::Equal, &input[..len])
+            }
+        })
+    }
+
+    /// Return the lines of the diff. The lines that contain a change are
+    /// grouped until both revisions reach the end of a line, and they're
+    /// returned as deleted lines followed by inserted lines.
+    fn diff_lines(&self, old_string: &str) -> Vec<(ChangeTag, String)> {
+        let mut lines = Vec::new();
+        let mut old_line = String::new();
+        let mut new_line = String::new();
+        let mut has_changes = false;
+
+        let mut flush = |old_line: &mut String, new_line: &mut String, has_changes: bool| {
+            if has_changes {
+                for line in old_line.split_inclusive('\n') {
+                    lines.push((ChangeTag::Delete, line.to_string()));
                 }
-                CompressedOp::DiffOp(DiffOp::Delete { range }) => {
-                    input_position += range.len();
+                for line in new_line.split_inclusive('\n') {
+                    lines.push((ChangeTag::Insert, line.to_string()));
                 }
-                CompressedOp::EqualLines { line_count } => {
-                    let start = u32::from(input_position) as usize;
-                    let input = &old_string[start..];
-
-                    let line_break_count = line_count.get() as usize + 1;
-                    for line in input.split_inclusive('\n').take(line_break_count) {
-                        output.push_str(line);
-                        input_position += TextSize::of(line);
+            } else if !old_line.is_empty() {
+                lines.push((ChangeTag::Equal, old_line.clone()));
+            }
+            old_line.clear();
+            new_line.clear();
+        };
+
+        for (tag, text) in self.iter_text(old_string) {
+            match tag {
+                ChangeTag::Equal => {
+                    for line in text.split_inclusive('\n') {
+                        old_line.push_str(line);
+                        new_line.push_str(line);
+                        if line.ends_with('\n') {
+                            flush(&mut old_line, &mut new_line, has_changes);
+                            has_changes = false;
+                        }
                     }
                 }
+                ChangeTag::Delete => {
+                    old_line.push_str(text);
+                    has_changes = true;
+                }
+                ChangeTag::Insert => {
+                    new_line.push_str(text);
+                    has_changes = true;
+                }
             }
         }
+        flush(&mut old_line, &mut new_line, has_changes);
 
-        output
+        lines
+    }
+}
+
+/// The range of lines of a hunk, in one of the revisions
+struct HunkRange(usize, usize);
+
+impl std::fmt::Display for HunkRange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let HunkRange(start, count) = *self;
+        match count {
+            // An empty range refers to the line before the hunk
+            0 => write!(f, "{},0", start - 1),
+            1 => write!(f, "{start}"),
+            _ => write!(f, "{start},{count}"),
+        }
     }
 }
 
@@ -372,4 +520,96 @@ line 7 new";
 
         assert_eq!(new_string, NEW);
     }
+
+    #[test]
+    fn unified_diff() {
+        const OLD: &str = "line 1 old
+line 2
+line 3
+line 4
+line 5
+line 6
+line 7
+line 8
+line 9
+line 10 old
+";
+
+        const NEW: &str = "line 1 new
+line 2
+line 3
+line 4
+line 5
+line 6
+line 7
+line 8
+line 9
+line 10
+line 11 new
+";
+
+        let diff = TextEdit::from_unicode_words(OLD, NEW);
+
+        assert_eq!(
+            diff.unified_diff(OLD, 3),
+            "@@ -1,4 +1,4 @@
+-line 1 old
++line 1 new
+ line 2
+ line 3
+ line 4
+@@ -7,4 +7,5 @@
+ line 7
+ line 8
+ line 9
+-line 10 old
++line 10
++line 11 new
+"
+        );
+        assert_eq!(
+            diff.unified_diff(OLD, 4),
+            "@@ -1,10 +1,11 @@
+-line 1 old
++line 1 new
+ line 2
+ line 3
+ line 4
+ line 5
+ line 6
+ line 7
+ line 8
+ line 9
+-line 10 old
++line 10
++line 11 new
+"
+        );
+    }
+
+    #[test]
+    fn unified_diff_missing_newline() {
+        const OLD: &str = "let a = 1";
+        const NEW: &str = "let a = 1;\n";
+
+        let diff = TextEdit::from_unicode_words(OLD, NEW);
+
+        assert_eq!(
+            diff.unified_diff(OLD, 3),
+            "@@ -1 +1 @@
+-let a = 1
+\\ No newline at end of file
++let a = 1;
+"
+        );
+    }
+
+    #[test]
+    fn unified_diff_unchanged() {
+        const TEXT: &str = "line 1\nline 2\n";
+
+        let diff = TextEdit::from_unicode_words(TEXT, TEXT);
+
+        assert_eq!(diff.unified_diff(TEXT, 3), "");
+    }
 }
@@ -4,8 +4,9 @@ use wasm_bindgen::prelude::*;
 use biome_service::workspace::{
     self, ChangeFileParams, CloseFileParams, FixFileParams, FormatFileParams, FormatOnTypeParams,
     FormatRangeParams, GetControlFlowGraphParams, GetFileContentParams, GetFormatterIRParams,
-    GetSyntaxTreeParams, OrganizeImportsParams, PullActionsParams, PullDiagnosticsParams,
-    RegisterProjectFolderParams, RenameParams, UpdateSettingsParams,
+    GetModuleGraphPathsParams, GetResolvedSettingsParams, GetSyntaxTreeParams,
+    OrganizeImportsParams, PullActionsParams, PullDiagnosticsParams, RegisterProjectFolderParams,
+    RegisterVcsIgnoreFilesParams, RenameParams, UpdateModuleGraphParams, UpdateSettingsParams,
 };
 use biome_service::workspace::{OpenFileParams, SupportsFeatureParams};
 
@@ -55,6 +56,41 @@ impl Workspace {
         self.inner.update_settings(params).map_err(into_error)
     }
 
+    #[wasm_bindgen(js_name = registerVcsIgnoreFiles)]
+    pub fn register_vcs_ignore_files(
+        &self,
+        params: IRegisterVcsIgnoreFilesParams,
+    ) -> Result<(), Error> {
+        let params: RegisterVcsIgnoreFilesParams =
+            serde_wasm_bindgen::from_value(params.into()).map_err(into_error)?;
+        self.inner
+            .register_vcs_ignore_files(params)
+            .map_err(into_error)
+    }
+
+    #[wasm_bindgen(js_name = updateModuleGraph)]
+    pub fn update_module_graph(&self, params: IUpdateModuleGraphParams) -> Result<(), Error> {
+        let params: UpdateModuleGraphParams =
+            serde_wasm_bindgen::from_value(params.into()).map_err(into_error)?;
+        self.inner.update_module_graph(params).map_err(into_error)
+    }
+
+    #[wasm_bindgen(js_name = getModuleGraphPaths)]
+    pub fn get_module_graph_paths(
+        &self,
+        params: IGetModuleGraphPathsParams,
+    ) -> Result<IGetModuleGraphPathsResult, Error> {
+        let params: GetModuleGraphPathsParams =
+            serde_wasm_bindgen::from_value(params.into()).map_err(into_error)?;
+        let result = self
+            .inner
+            .get_module_graph_paths(params)
+            .map_err(into_error)?;
+        to_value(&result)
+            .map(IGetModuleGraphPathsResult::from)
+            .map_err(into_error)
+    }
+
     #[wasm_bindgen(js_name = registerProjectFolder)]
     pub fn register_workspace_folder(
         &self,
@@ -116,6 +152,22 @@ impl Workspace {
         self.inner.get_formatter_ir(params).map_err(into_error)
     }
 
+    #[wasm_bindgen(js_name = getResolvedSettings)]
+    pub fn get_resolved_settings(
+        &self,
+        params: IGetResolvedSettingsParams,
+    ) -> Result<IResolvedSettings, Error> {
+        let params: GetResolvedSettingsParams =
+            serde_wasm_bindgen::from_value(params.into()).map_err(into_error)?;
+        let result = self
+            .inner
+            .get_resolved_settings(params)
+            .map_err(into_error)?;
+        to_value(&result)
+            .map(IResolvedSettings::from)
+            .map_err(into_error)
+    }
+
     #[wasm_bindgen(js_name = changeFile)]
     pub fn change_file(&self, params: IChangeFileParams) -> Result<(), Error> {
         let params: ChangeFileParams =
@@ -37,11 +37,29 @@ export type FileKind2 =
 	| "Inspectable"
 	| "Handleable";
 export interface FileFeaturesResult {
-	features_supported: {};
-}
+	features_supported: { [key: string]: SupportKind };
+}
+export type SupportKind =
+	| "Supported"
+	| "Ignored"
+	| "Protected"
+	| "FeatureNotEnabled"
+	| "FileNotSupported";
 export interface UpdateSettingsParams {
 	configuration: PartialConfiguration;
 	gitignore_matches: string[];
+	/**
+	 * The other ignore files of the VCS, from the lowest to the highest precedence. `gitignore_matches` take precedence over the ones of `vcs_base_path`, and the ones of its subdirectories, also registered with [Workspace::register_vcs_ignore_files], take precedence over `gitignore_matches`.
+	 */
+	ignore_files?: VcsIgnoreFile[];
+	/**
+	 * Whether the configuration comes from a configuration file of a subdirectory of the project. The settings then only apply to the files inside `workspace_directory`.
+	 */
+	nested?: boolean;
+	/**
+	 * The GritQL files listed in the `plugins` field of the configuration
+	 */
+	plugins?: PluginFile[];
 	vcs_base_path?: string;
 	workspace_directory?: string;
 }
@@ -77,6 +95,10 @@ export interface PartialConfiguration {
 	 * Specific configuration for the GraphQL language
 	 */
 	graphql?: PartialGraphqlConfiguration;
+	/**
+	 * Specific configuration for the HTML language
+	 */
+	html?: PartialHtmlConfiguration;
 	/**
 	 * Specific configuration for the JavaScript language
 	 */
@@ -97,11 +119,45 @@ export interface PartialConfiguration {
 	 * A list of granular patterns that should be applied only to a sub set of files
 	 */
 	overrides?: Overrides;
+	/**
+	 * A list of GritQL files, which are run as lint rules alongside the rules of Biome. Each entry is either the path of the file, relative to the configuration file, or an object with its `path` and the `severity` of its diagnostics.
+	 */
+	plugins?: Plugins;
+	/**
+	* Whether this is the root configuration of the project. `true` by default.
+
+Set it to `false` in the configuration file of a subdirectory, so that it extends the configuration of the parent directories instead of replacing it. 
+	 */
+	root?: boolean;
 	/**
 	 * The configuration of the VCS integration
 	 */
 	vcs?: PartialVcsConfiguration;
 }
+/**
+ * The patterns of an ignore file of the VCS, e.g. the `.gitignore` file of a subdirectory
+ */
+export interface VcsIgnoreFile {
+	/**
+	 * The directory the patterns are relative to
+	 */
+	directory: string;
+	patterns: string[];
+}
+/**
+ * A GritQL file listed in the `plugins` field of the configuration
+ */
+export interface PluginFile {
+	content: string;
+	/**
+	 * The path of the file, used to name the plugin and report its errors
+	 */
+	path: string;
+	/**
+	 * The severity of the diagnostics of the plugin, when it's configured
+	 */
+	severity?: RulePlainConfiguration;
+}
 export interface PartialAssistsConfiguration {
 	/**
 	 * Whether Biome should fail in CLI if the assists were not applied to the code.
@@ -146,6 +202,10 @@ export type StringSet = string[];
  * The configuration of the filesystem
  */
 export interface PartialFilesConfiguration {
+	/**
+	 * Whether the commands `check`, `lint` and `format` reuse the results of their previous runs for the files that didn't change since then, like with the option `--cache`. Defaults to `false`
+	 */
+	cache?: boolean;
 	/**
 	 * A list of Unix shell style patterns. Biome will ignore files/folders that will match these patterns.
 	 */
@@ -223,6 +283,23 @@ export interface PartialGraphqlConfiguration {
 	formatter?: PartialGraphqlFormatter;
 	linter?: PartialGraphqlLinter;
 }
+/**
+ * Options applied to HTML files
+ */
+export interface PartialHtmlConfiguration {
+	/**
+	 * HTML formatter options
+	 */
+	formatter?: PartialHtmlFormatter;
+	/**
+	 * HTML linter options
+	 */
+	linter?: PartialHtmlLinter;
+	/**
+	 * HTML parsing options
+	 */
+	parser?: PartialHtmlParser;
+}
 /**
  * A set of options applied to the JavaScript files
  */
@@ -277,6 +354,10 @@ export interface PartialJsonConfiguration {
 	parser?: PartialJsonParser;
 }
 export interface PartialLinterConfiguration {
+	/**
+	 * An object where the keys are the names of the domains, and the values are `all`, `none` or `auto`. A domain groups the rules of a library, a framework or a runtime.
+	 */
+	domains?: { [key: string]: RuleDomainValue };
 	/**
 	 * if `false`, it disables the feature and the linter won't be executed. `true` by default
 	 */
@@ -293,6 +374,10 @@ export interface PartialLinterConfiguration {
 	 * List of rules
 	 */
 	rules?: Rules;
+	/**
+	 * The policy that the suppression comments must follow: whether they require a reason, the patterns of the reasons, the rules that can't be suppressed and the maximum number of suppressions per file.
+	 */
+	suppressions?: SuppressionsConfiguration;
 }
 export interface PartialOrganizeImports {
 	/**
@@ -309,6 +394,10 @@ export interface PartialOrganizeImports {
 	include?: StringSet;
 }
 export type Overrides = OverridePattern[];
+/**
+ * The lint plugins of the configuration
+ */
+export type Plugins = PluginConfiguration[];
 /**
  * Set of properties to integrate Biome with a VCS software.
  */
@@ -336,6 +425,7 @@ If Biome can't find the configuration, it will attempt to use the current workin
 	 */
 	useIgnoreFile?: boolean;
 }
+export type RulePlainConfiguration = "warn" | "error" | "info" | "off";
 export interface Actions {
 	source?: Source;
 }
@@ -452,6 +542,60 @@ export interface PartialGraphqlLinter {
 	 */
 	enabled?: boolean;
 }
+/**
+ * Options that changes how the HTML formatter behaves
+ */
+export interface PartialHtmlFormatter {
+	/**
+	 * The attribute position style in HTML elements. Defaults to auto.
+	 */
+	attributePosition?: AttributePosition;
+	/**
+	 * Control the formatter for HTML files.
+	 */
+	enabled?: boolean;
+	/**
+	 * Whether to indent the content of `<script>` and `<style>` tags. Defaults to false.
+	 */
+	indentScriptAndStyle?: boolean;
+	/**
+	 * The indent style applied to HTML files.
+	 */
+	indentStyle?: IndentStyle;
+	/**
+	 * The size of the indentation applied to HTML files. Default to 2.
+	 */
+	indentWidth?: IndentWidth;
+	/**
+	 * The type of line ending applied to HTML files.
+	 */
+	lineEnding?: LineEnding;
+	/**
+	 * What's the max width of a line applied to HTML files. Defaults to 80.
+	 */
+	lineWidth?: LineWidth;
+	/**
+	 * Whether void elements, such as `<br>` or `<img>`, are self-closed. Defaults to "always".
+	 */
+	selfCloseVoidElements?: SelfCloseVoidElements;
+	/**
+	 * Whether the whitespace around elements is significant: `css` follows the default CSS `display` of the elements, `strict` considers all the whitespace significant, and `ignore` considers none of it significant. Defaults to "css".
+	 */
+	whitespaceSensitivity?: WhitespaceSensitivity;
+}
+/**
+ * Options that changes how the HTML linter behaves
+ */
+export interface PartialHtmlLinter {
+	/**
+	 * Control the linter for HTML files.
+	 */
+	enabled?: boolean;
+}
+/**
+ * Options that changes how the HTML parser behaves
+ */
+export interface PartialHtmlParser {}
 /**
  * Linter options specific to the JavaScript linter
  */
@@ -620,6 +764,7 @@ export interface PartialJsonParser {
 	 */
 	allowTrailingCommas?: boolean;
 }
+export type RuleDomainValue = "all" | "none" | "auto";
 export interface Rules {
 	a11y?: A11y;
 	/**
@@ -638,6 +783,29 @@ export interface Rules {
 	style?: Style;
 	suspicious?: Suspicious;
 }
+/**
+	* The policy that the suppression comments must follow.
+
+Rule groups and rules are referenced by name, e.g. `security` or `security/noGlobalEval`. 
+	 */
+export interface SuppressionsConfiguration {
+	/**
+	 * A list of rule groups and rules that can't be suppressed.
+	 */
+	forbidden?: StringSet;
+	/**
+	 * The maximum number of suppression comments allowed in a file.
+	 */
+	maxPerFile?: number;
+	/**
+	 * An object where the keys are rule groups or rules, and the values are regular expressions that the reason of their suppressions must match. The pattern of a rule takes precedence over the pattern of its group. A suppression of all the rules must match every pattern.
+	 */
+	reasonPatterns?: { [key: string]: ReasonPattern };
+	/**
+	 * If `true`, the suppression comments must have a reason that isn't a placeholder such as `<explanation>`. `false` by default.
+	 */
+	requireReason?: boolean;
+}
 export interface OverridePattern {
 	/**
 	 * Specific configuration for the Css language
@@ -651,6 +819,10 @@ export interface OverridePattern {
 	 * Specific configuration for the Graphql language
 	 */
 	graphql?: PartialGraphqlConfiguration;
+	/**
+	 * Specific configuration for the HTML language
+	 */
+	html?: PartialHtmlConfiguration;
 	/**
 	 * A list of Unix shell style patterns. The formatter will ignore files/folders that will match these patterns.
 	 */
@@ -676,6 +848,10 @@ export interface OverridePattern {
 	 */
 	organizeImports?: OverrideOrganizeImportsConfiguration;
 }
+/**
+ * A plugin, given as the path of its GritQL file, or as an object with its path and its options
+ */
+export type PluginConfiguration = string | PluginWithOptions;
 export type VcsClientKind = "git";
 /**
  * A list of rules that belong to this group
@@ -695,6 +871,16 @@ export interface Source {
 	useSortedKeys?: RuleAssistConfiguration_for_Null;
 }
 export type QuoteStyle = "double" | "single";
+/**
+ * Controls whether void elements, such as `<br>` or `<img>`, are self-closed.
+ */
+export type SelfCloseVoidElements = "always" | "never";
+/**
+	* Whitespace sensitivity for HTML formatting.
+
+The formatter may add or remove whitespace between elements and text only where it doesn't change how the document is rendered. 
+	 */
+export type WhitespaceSensitivity = "css" | "strict" | "ignore";
 export type ArrowParentheses = "always" | "asNeeded";
 export type QuoteProperties = "asNeeded" | "preserve";
 export type Semicolons = "always" | "asNeeded";
@@ -1354,6 +1540,10 @@ export interface Nursery {
 	 * Disallow unknown type selectors.
 	 */
 	noUnknownTypeSelector?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow importing names that the imported module doesn't export.
+	 */
+	noUnresolvedImports?: RuleConfiguration_for_Null;
 	/**
 	 * Disallow unnecessary escape sequence in regular expression literals.
 	 */
@@ -2015,6 +2205,7 @@ export interface Suspicious {
 	 */
 	useValidTypeof?: RuleFixConfiguration_for_Null;
 }
+export type ReasonPattern = string;
 export interface OverrideFormatterConfiguration {
 	/**
 	 * The attribute position style.
@@ -2051,6 +2242,10 @@ export interface OverrideFormatterConfiguration {
 	lineWidth?: LineWidth;
 }
 export interface OverrideLinterConfiguration {
+	/**
+	 * An object where the keys are the names of the domains, and the values are `all`, `none` or `auto`. They take precedence over the domains of `linter.domains`.
+	 */
+	domains?: { [key: string]: RuleDomainValue };
 	/**
 	 * if `false`, it disables the feature and the linter won't be executed. `true` by default
 	 */
@@ -2066,6 +2261,16 @@ export interface OverrideOrganizeImportsConfiguration {
 	 */
 	enabled?: boolean;
 }
+export interface PluginWithOptions {
+	/**
+	 * The path of the GritQL file of the plugin, relative to the configuration file
+	 */
+	path: string;
+	/**
+	 * The severity of the diagnostics of the plugin, which replaces the severity chosen by the plugin. The plugin doesn't run when it's `off`.
+	 */
+	severity?: RulePlainConfiguration;
+}
 export type RuleAssistConfiguration_for_Options =
 	| RuleAssistPlainConfiguration
 	| RuleAssistWithOptions_for_Options;
@@ -2171,7 +2376,6 @@ export interface RuleAssistWithOptions_for_Null {
 	 */
 	options: null;
 }
-export type RulePlainConfiguration = "warn" | "error" | "info" | "off";
 export interface RuleWithFixOptions_for_Null {
 	/**
 	 * The kind of the code actions emitted by the rule
@@ -2571,7 +2775,7 @@ export interface UseImportExtensionsOptions {
 	/**
 	 * A map of custom import extension mappings, where the key is the inspected file extension, and the value is a pair of `module` extension and `component` import extension
 	 */
-	suggestedExtensions?: {};
+	suggestedExtensions?: { [key: string]: SuggestedExtensionMapping };
 }
 /**
  * Options for the rule `noRestrictedImports`.
@@ -2580,10 +2784,10 @@ export interface RestrictedImportsOptions {
 	/**
 	 * A list of import paths that should trigger the rule.
 	 */
-	paths: {};
+	paths: { [key: string]: CustomRestrictedImport };
 }
 export interface NoRestrictedTypesOptions {
-	types?: {};
+	types?: { [key: string]: CustomRestrictedType };
 }
 export interface NoSecretsOptions {
 	/**
@@ -2725,6 +2929,18 @@ For example, for React's `useRef()` hook the value would be `true`, while for `u
 	 */
 	stableResult?: StableHookResult;
 }
+export interface SuggestedExtensionMapping {
+	/**
+	 * Extension that should be used for component file imports
+	 */
+	component?: string;
+	/**
+	 * Extension that should be used for module imports
+	 */
+	module?: string;
+}
+export type CustomRestrictedImport = string | CustomRestrictedImportOptions;
+export type CustomRestrictedType = string | CustomRestrictedTypeOptions;
 export type Accessibility = "noPublic" | "explicit" | "none";
 export type ConsistentArrayType = "shorthand" | "generic";
 export type FilenameCases = FilenameCase[];
@@ -2757,6 +2973,24 @@ export type PredefinedImportGroup =
 	| ":node:"
 	| ":types:";
 export type StableHookResult = boolean | number[];
+export interface CustomRestrictedImportOptions {
+	/**
+	 * Names of the exported members that allowed to be not be used.
+	 */
+	allowImportNames: string[];
+	/**
+	 * Names of the exported members that should not be used.
+	 */
+	importNames: string[];
+	/**
+	 * The message to display when this module is imported.
+	 */
+	message: string;
+}
+export interface CustomRestrictedTypeOptions {
+	message?: string;
+	use?: string;
+}
 /**
  * Supported cases for file names.
  */
@@ -2838,6 +3072,65 @@ export interface SetManifestForProjectParams {
 	manifest_path: BiomePath;
 	version: number;
 }
+export interface RegisterVcsIgnoreFilesParams {
+	/**
+	 * The `.gitignore` files of the directories reached while traversing the project
+	 */
+	ignoreFiles: VcsIgnoreFile[];
+}
+export interface UpdateModuleGraphParams {
+	/**
+	 * The modules to add to the module graph of the current project
+	 */
+	moduleGraph: ModuleGraph;
+	/**
+	 * The paths of the modules to remove from the module graph of the current project, e.g. because they changed on disk or they aren't imported anymore
+	 */
+	removedPaths?: string[];
+}
+/**
+	* The modules of a project, with the modules that their imports resolve to and the names that they export.
+
+It lets the rules of the project phase look at the other files of the project. 
+	 */
+export interface ModuleGraph {
+	modules: { [key: string]: ModuleInfo };
+}
+/**
+ * A module of the [ModuleGraph]
+ */
+export interface ModuleInfo {
+	/**
+	 * The names exported by the module, `default` being the name of the default export
+	 */
+	exports: string[];
+	/**
+	 * Whether the exports of the module can't be known statically, e.g. because it's a CommonJS module
+	 */
+	has_dynamic_exports: boolean;
+	/**
+	 * What the specifiers of the imports and re-exports of the module resolve to
+	 */
+	imports: { [key: string]: ResolvedImport };
+	/**
+	 * The specifiers of the `export * from` declarations of the module
+	 */
+	reexports: string[];
+}
+/**
+ * What the specifier of an import resolves to
+ */
+export type ResolvedImport =
+	| { Module: string }
+	| { Builtin: string }
+	| "Unresolved";
+export interface GetModuleGraphPathsParams {}
+export interface GetModuleGraphPathsResult {
+	/**
+	 * The paths of the modules of the module graph of the current project
+	 */
+	paths: string[];
+}
 export interface OpenFileParams {
 	content: string;
 	document_file_source?: DocumentFileSource;
@@ -2937,11 +3230,92 @@ export type TextSize = number;
 export interface GetFormatterIRParams {
 	path: BiomePath;
 }
+export interface GetResolvedSettingsParams {
+	/**
+	 * The configuration passed as arguments of the command line, which takes precedence over the configuration files. It shouldn't be merged in the configuration of the workspace.
+	 */
+	commandLine?: PartialConfiguration;
+	/**
+	 * The configuration read from the `.editorconfig` file, which the configuration files take precedence over. It shouldn't be merged in the configuration of the workspace.
+	 */
+	editorconfig?: PartialConfiguration;
+	path: BiomePath;
+}
+/**
+ * The settings Biome uses for a file, once the configuration files, the nested configurations and the overrides are resolved
+ */
+export interface ResolvedSettings {
+	/**
+	 * The formatter options of the language of the file, empty if Biome can't format it
+	 */
+	formatterOptions: ResolvedFormatterOption[];
+	/**
+	 * The directory of the nested configuration file that applies to the file, if any
+	 */
+	nestedConfigurationDirectory?: string;
+	/**
+	 * The indexes of the overrides that match the file
+	 */
+	overrides: number[];
+	/**
+	 * The lint rules enabled for the file, sorted by name
+	 */
+	rules: ResolvedRule[];
+}
+export interface ResolvedFormatterOption {
+	/**
+	 * The name of the option in the configuration, e.g. `indentWidth`
+	 */
+	name: string;
+	/**
+	 * Where the value of the option comes from
+	 */
+	source: ResolvedSettingSource;
+	/**
+	 * The value of the option serialized as JSON
+	 */
+	value: string;
+}
+export interface ResolvedRule {
+	/**
+	 * The name of the rule, e.g. `style/useConst`
+	 */
+	name: string;
+	/**
+	 * The options of the rule serialized as JSON, if they are configured
+	 */
+	options?: string;
+	/**
+	 * The severity of the diagnostics emitted by the rule
+	 */
+	severity: Severity;
+	/**
+	 * Where the configuration of the rule comes from
+	 */
+	source: ResolvedSettingSource;
+}
+/**
+ * Where a setting comes from, from the lowest to the highest precedence
+ */
+export type ResolvedSettingSource =
+	| "default"
+	| "editorConfig"
+	| "configuration"
+	| "commandLine"
+	| "override";
+/**
+ * The severity to associate to a diagnostic.
+ */
+export type Severity = "hint" | "information" | "warning" | "error" | "fatal";
 export interface PullDiagnosticsParams {
 	categories: RuleCategories;
 	max_diagnostics: number;
 	only: RuleCode[];
 	path: BiomePath;
+	/**
+	 * Whether the time spent in each rule is measured and returned in the result
+	 */
+	profile_rules?: boolean;
 	skip: RuleCode[];
 }
 export type RuleCategories = RuleCategory[];
@@ -2950,6 +3324,10 @@ export type RuleCategory = "Syntax" | "Lint" | "Action" | "Transformation";
 export interface PullDiagnosticsResult {
 	diagnostics: Diagnostic[];
 	errors: number;
+	/**
+	 * The time spent in each rule, when `profile_rules` is set
+	 */
+	rule_durations?: RuleDurations[];
 	skipped_diagnostics: number;
 }
 /**
@@ -2966,6 +3344,23 @@ export interface Diagnostic {
 	tags: DiagnosticTags;
 	verboseAdvices: Advices;
 }
+/**
+ * The time spent in a rule while processing a file
+ */
+export interface RuleDurations {
+	/**
+	 * The time spent computing each code action of the rule, in nanoseconds
+	 */
+	action: number[];
+	/**
+	 * The group and the name of the rule, e.g. `suspicious/noDebugger`
+	 */
+	rule: string;
+	/**
+	 * The time spent checking each node matched by the rule, in nanoseconds
+	 */
+	run: number[];
+}
 /**
  * Implementation of [Visitor] collecting serializable [Advice] into a vector.
  */
@@ -3144,6 +3539,7 @@ export type Category =
 	| "lint/nursery/noUnknownTypeSelector"
 	| "lint/nursery/noUnknownUnit"
 	| "lint/nursery/noUnmatchableAnbSelector"
+	| "lint/nursery/noUnresolvedImports"
 	| "lint/nursery/noUnusedFunctionParameters"
 	| "lint/nursery/noUselessEscapeInRegex"
 	| "lint/nursery/noUselessStringRaw"
@@ -3316,6 +3712,8 @@ export type Category =
 	| "deserialize"
 	| "project"
 	| "search"
+	| "search/rewrite"
+	| "baseline"
 	| "internalError/io"
 	| "internalError/fs"
 	| "internalError/panic"
@@ -3330,6 +3728,7 @@ export type Category =
 	| "lint/correctness"
 	| "lint/nursery"
 	| "lint/performance"
+	| "lint/plugin"
 	| "lint/security"
 	| "lint/style"
 	| "lint/suspicious"
@@ -3338,6 +3737,10 @@ export type Category =
 	| "suppressions/unknownRule"
 	| "suppressions/unused"
 	| "suppressions/deprecatedSuppressionComment"
+	| "suppressions/missingReason"
+	| "suppressions/invalidReason"
+	| "suppressions/forbidden"
+	| "suppressions/tooMany"
 	| "args/fileNotFound"
 	| "flags/invalid"
 	| "semanticTests";
@@ -3347,10 +3750,6 @@ export interface Location {
 	span?: TextRange;
 }
 export type MarkupBuf = MarkupNodeBuf[];
-/**
- * The severity to associate to a diagnostic.
- */
-export type Severity = "hint" | "information" | "warning" | "error" | "fatal";
 export type DiagnosticTags = DiagnosticTag[];
 /**
 	* Serializable representation of a [Diagnostic](super::Diagnostic) advice
@@ -3523,6 +3922,10 @@ export interface FixFileParams {
 	fix_file_mode: FixFileMode;
 	only: RuleCode[];
 	path: BiomePath;
+	/**
+	 * Whether the time spent in each rule is measured and returned in the result
+	 */
+	profile_rules?: boolean;
 	rule_categories: RuleCategories;
 	should_format: boolean;
 	skip: RuleCode[];
@@ -3548,6 +3951,10 @@ export interface FixFileResult {
 	 * Number of errors
 	 */
 	errors: number;
+	/**
+	 * The time spent in each rule, when `profile_rules` is set
+	 */
+	rule_durations?: RuleDurations[];
 	/**
 	 * number of skipped suggested fixes
 	 */
@@ -3586,6 +3993,11 @@ export interface Workspace {
 		params: RegisterProjectFolderParams,
 	): Promise<ProjectKey>;
 	setManifestForProject(params: SetManifestForProjectParams): Promise<void>;
+	registerVcsIgnoreFiles(params: RegisterVcsIgnoreFilesParams): Promise<void>;
+	updateModuleGraph(params: UpdateModuleGraphParams): Promise<void>;
+	getModuleGraphPaths(
+		params: GetModuleGraphPathsParams,
+	): Promise<GetModuleGraphPathsResult>;
 	openFile(params: OpenFileParams): Promise<void>;
 	changeFile(params: ChangeFileParams): Promise<void>;
 	closeFile(params: CloseFileParams): Promise<void>;
@@ -3596,6 +4008,9 @@ export interface Workspace {
 	getFileContent(params: GetFileContentParams): Promise<string>;
 	getControlFlowGraph(params: GetControlFlowGraphParams): Promise<string>;
 	getFormatterIr(params: GetFormatterIRParams): Promise<string>;
+	getResolvedSettings(
+		params: GetResolvedSettingsParams,
+	): Promise<ResolvedSettings>;
 	pullDiagnostics(
 		params: PullDiagnosticsParams,
 	): Promise<PullDiagnosticsResult>;
@@ -3621,6 +4036,15 @@ export function createWorkspace(transport: Transport): Workspace {
 		setManifestForProject(params) {
 			return transport.request("biome/set_manifest_for_project", params);
 		},
+		registerVcsIgnoreFiles(params) {
+			return transport.request("biome/register_vcs_ignore_files", params);
+		},
+		updateModuleGraph(params) {
+			return transport.request("biome/update_module_graph", params);
+		},
+		getModuleGraphPaths(params) {
+			return transport.request("biome/get_module_graph_paths", params);
+		},
 		openFile(params) {
 			return transport.request("biome/open_file", params);
 		},
@@ -3645,6 +4069,9 @@ export function createWorkspace(transport: Transport): Workspace {
 		getFormatterIr(params) {
 			return transport.request("biome/get_formatter_ir", params);
 		},
+		getResolvedSettings(params) {
+			return transport.request("biome/get_resolved_settings", params);
+		},
 		pullDiagnostics(params) {
 			return transport.request("biome/pull_diagnostics", params);
 		},
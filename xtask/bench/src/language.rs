@@ -195,6 +195,7 @@ impl Analyze {
                     &options,
                     JsFileSource::default(),
                     None,
+                    Default::default(),
                     |event| {
                         black_box(event.diagnostic());
                         black_box(event.actions());
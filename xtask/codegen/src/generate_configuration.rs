@@ -337,6 +337,22 @@ fn generate_for_groups(
                 Some(severity)
             }
 
+            /// Returns the level of the given rule, if the rule is configured.
+            ///
+            /// Unlike [Self::get_severity_from_code], the presets such as `recommended` or `all`
+            /// aren't taken into account.
+            pub fn get_rule_level(&self, group: RuleGroup, rule_name: &str) -> Option<RulePlainConfiguration> {
+                match group {
+                    #(
+                        RuleGroup::#group_pascal_idents => self
+                            .#group_idents
+                            .as_ref()
+                            .and_then(|group| group.get_rule_configuration(rule_name))
+                            .map(|(level, _)| level),
+                    )*
+                }
+            }
+
         }
     };
 
@@ -89,8 +89,9 @@ pub enum TaskCommand {
     /// Generate the part of the configuration that depends on some metadata
     #[bpaf(command)]
     Configuration,
+    /// Generate the mapping of the ESLint and Stylelint rules used by `biome migrate`
     #[bpaf(command)]
-    MigrateEslint,
+    Migrate,
     /// Generate the JSON schema for the Biome configuration file format
     #[bpaf(command)]
     Schema,
@@ -6,6 +6,8 @@ mod generate_configuration;
 mod generate_license;
 #[cfg(feature = "configuration")]
 mod generate_migrate_eslint;
+#[cfg(feature = "configuration")]
+mod generate_migrate_stylelint;
 #[cfg(feature = "schema")]
 mod generate_schema;
 mod promote_rule;
@@ -19,6 +21,8 @@ use crate::generate_configuration::generate_rules_configuration;
 use crate::generate_license::generate_license;
 #[cfg(feature = "configuration")]
 use crate::generate_migrate_eslint::generate_migrate_eslint;
+#[cfg(feature = "configuration")]
+use crate::generate_migrate_stylelint::generate_migrate_stylelint;
 #[cfg(feature = "schema")]
 use crate::generate_schema::generate_configuration_schema;
 use crate::promote_rule::promote_rule;
@@ -44,9 +48,11 @@ fn main() -> Result<()> {
             #[cfg(feature = "configuration")]
             generate_rules_configuration(Overwrite)?;
         }
-        TaskCommand::MigrateEslint => {
+        TaskCommand::Migrate => {
             #[cfg(feature = "configuration")]
             generate_migrate_eslint(Overwrite)?;
+            #[cfg(feature = "configuration")]
+            generate_migrate_stylelint(Overwrite)?;
         }
         TaskCommand::Schema => {
             #[cfg(feature = "schema")]
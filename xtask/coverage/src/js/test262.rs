@@ -11,6 +11,7 @@ use std::io;
 use std::path::Path;
 use std::process::Command;
 use xtask::project_root;
+use yaml_rust2::{Yaml, YamlLoader};
 
 const BASE_PATH: &str = "xtask/coverage/test262/test";
 
@@ -217,7 +218,31 @@ fn read_metadata(code: &str) -> io::Result<MetaData> {
         .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "no metadata found"))?
         .as_str();
 
-    serde_yaml::from_str(yaml).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    let documents = YamlLoader::load_from_str(yaml)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    let value = documents
+        .into_iter()
+        .next()
+        .map_or(serde_json::Value::Null, yaml_to_json_value);
+    serde_json::from_value(value).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
+fn yaml_to_json_value(yaml: Yaml) -> serde_json::Value {
+    match yaml {
+        Yaml::Real(_) => yaml
+            .as_f64()
+            .and_then(serde_json::Number::from_f64)
+            .map_or(serde_json::Value::Null, serde_json::Value::Number),
+        Yaml::Integer(integer) => serde_json::Value::from(integer),
+        Yaml::String(string) => serde_json::Value::String(string),
+        Yaml::Boolean(boolean) => serde_json::Value::Bool(boolean),
+        Yaml::Array(array) => array.into_iter().map(yaml_to_json_value).collect(),
+        Yaml::Hash(hash) => hash
+            .into_iter()
+            .filter_map(|(key, value)| Some((key.into_string()?, yaml_to_json_value(value))))
+            .collect(),
+        Yaml::Alias(_) | Yaml::Null | Yaml::BadValue => serde_json::Value::Null,
+    }
 }
 
 fn merge_outcomes(l: TestRunOutcome, r: TestRunOutcome) -> TestRunOutcome {